@@ -37,6 +37,7 @@ fn main() -> ! {
     let options: &thrustc_options::CompilerOptions = cli.get_options();
 
     thrustc_cli::set_up_ansi(options);
+    thrustc_ice::install(options);
 
     let start_time: std::time::Instant = std::time::Instant::now();
 
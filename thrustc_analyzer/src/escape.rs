@@ -0,0 +1,571 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+
+use ahash::AHashMap as HashMap;
+
+use thrustc_ast::{Ast, traits::AstGetType};
+use thrustc_errors::{CompilationIssue, CompilationIssueCode};
+use thrustc_span::Span;
+use thrustc_typesystem::traits::TypeIsExtensions;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Escape {
+    Definite,
+    Possible,
+}
+
+#[derive(Debug)]
+enum StoreTarget<'analyzer> {
+    Local(&'analyzer str),
+    Stack,
+    Global,
+    Unknown,
+}
+
+#[derive(Debug)]
+pub struct EscapeAnalysis<'analyzer> {
+    scopes: Vec<HashMap<&'analyzer str, Option<Escape>>>,
+    conditional_depth: u32,
+
+    errors: Vec<CompilationIssue>,
+    warnings: Vec<CompilationIssue>,
+}
+
+impl<'analyzer> EscapeAnalysis<'analyzer> {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            scopes: Vec::with_capacity(u8::MAX as usize),
+            conditional_depth: 0,
+
+            errors: Vec::with_capacity(u8::MAX as usize),
+            warnings: Vec::with_capacity(u8::MAX as usize),
+        }
+    }
+}
+
+impl<'analyzer> EscapeAnalysis<'analyzer> {
+    pub fn analyze_function(
+        mut self,
+        parameters: &'analyzer [Ast<'analyzer>],
+        body: &'analyzer Ast<'analyzer>,
+    ) -> (Vec<CompilationIssue>, Vec<CompilationIssue>) {
+        self.begin_scope();
+
+        for parameter in parameters.iter() {
+            if let Ast::FunctionParameter { name, .. } = parameter {
+                self.declare(name, None);
+            }
+        }
+
+        self.analyze_stmt(body);
+        self.end_scope();
+
+        (self.errors, self.warnings)
+    }
+}
+
+impl<'analyzer> EscapeAnalysis<'analyzer> {
+    fn analyze_stmt(&mut self, node: &'analyzer Ast<'analyzer>) {
+        match node {
+            Ast::Block { nodes, post, .. } => {
+                self.begin_scope();
+
+                nodes.iter().for_each(|node| self.analyze_stmt(node));
+                post.iter().for_each(|node| self.analyze_stmt(node));
+
+                self.end_scope();
+            }
+
+            Ast::Defer { node, .. } => self.analyze_stmt(node),
+
+            Ast::If {
+                then_branch,
+                else_if_branch,
+                else_branch,
+                ..
+            } => {
+                self.conditional_depth += 1;
+
+                self.analyze_stmt(then_branch);
+                else_if_branch
+                    .iter()
+                    .for_each(|node| self.analyze_stmt(node));
+
+                if let Some(node) = else_branch {
+                    self.analyze_stmt(node);
+                }
+
+                self.conditional_depth -= 1;
+            }
+
+            Ast::Elif { block, .. } | Ast::Else { block, .. } => self.analyze_stmt(block),
+
+            Ast::For { local, block, .. } => {
+                self.begin_scope();
+                self.analyze_stmt(local);
+
+                self.conditional_depth += 1;
+                self.analyze_stmt(block);
+                self.conditional_depth -= 1;
+
+                self.end_scope();
+            }
+
+            Ast::While {
+                variable, block, ..
+            } => {
+                self.begin_scope();
+
+                if let Some(variable) = variable {
+                    self.analyze_stmt(variable);
+                }
+
+                self.conditional_depth += 1;
+                self.analyze_stmt(block);
+                self.conditional_depth -= 1;
+
+                self.end_scope();
+            }
+
+            Ast::Loop { block, .. } => {
+                self.conditional_depth += 1;
+                self.analyze_stmt(block);
+                self.conditional_depth -= 1;
+            }
+
+            Ast::Var { name, value, .. } => {
+                let escape: Option<Escape> = value
+                    .as_ref()
+                    .and_then(|value| self.classify(value))
+                    .map(|escape| self.adjust(escape));
+
+                self.declare(name, escape);
+            }
+
+            Ast::Const { name, value, .. } => {
+                let escape: Option<Escape> = self.classify(value).map(|escape| self.adjust(escape));
+
+                self.declare(name, escape);
+            }
+
+            Ast::Return {
+                expression: Some(expression),
+                span,
+                ..
+            } => {
+                if let Some(escape) = self.classify(expression) {
+                    self.report(escape, "returned from its function", *span);
+                }
+            }
+
+            Ast::Mut {
+                source,
+                value,
+                span,
+                ..
+            } => self.analyze_store(source, value, *span),
+
+            Ast::Write {
+                source,
+                write_value,
+                span,
+                ..
+            } => {
+                let Some(escape) = self.classify(write_value) else {
+                    return;
+                };
+
+                if self.classify(source).is_some() {
+                    return;
+                }
+
+                match self.get_pointer_root(source) {
+                    StoreTarget::Global => self.report(escape, "written into a global", *span),
+                    _ => self.report(
+                        Escape::Possible,
+                        "written through a pointer that may outlive it",
+                        *span,
+                    ),
+                }
+            }
+
+            _ => (),
+        }
+    }
+
+    fn analyze_store(
+        &mut self,
+        source: &'analyzer Ast<'analyzer>,
+        value: &'analyzer Ast<'analyzer>,
+        span: Span,
+    ) {
+        let escape: Option<Escape> = self.classify(value);
+
+        match self.get_store_target(source) {
+            StoreTarget::Local(name) => {
+                let escape: Option<Escape> = escape.map(|escape| self.adjust(escape));
+                let is_conditional: bool = self.conditional_depth > 0;
+
+                if let Some(current) = self.lookup_mut(name) {
+                    match escape {
+                        Some(escape) => *current = Some(escape),
+                        None if !is_conditional => *current = None,
+                        None => {
+                            if current.is_some() {
+                                *current = Some(Escape::Possible);
+                            }
+                        }
+                    }
+                }
+            }
+
+            StoreTarget::Global => {
+                if let Some(escape) = escape {
+                    self.report(escape, "stored into a global", span);
+                }
+            }
+
+            StoreTarget::Unknown => {
+                if escape.is_some() {
+                    self.report(
+                        Escape::Possible,
+                        "stored into a structure field that may outlive it",
+                        span,
+                    );
+                }
+            }
+
+            StoreTarget::Stack => (),
+        }
+    }
+}
+
+impl<'analyzer> EscapeAnalysis<'analyzer> {
+    fn classify(&self, expr: &'analyzer Ast<'analyzer>) -> Option<Escape> {
+        match expr {
+            Ast::DirectRef { expr, .. } => {
+                if self.is_stack_rooted(expr) {
+                    return Some(Escape::Definite);
+                }
+
+                self.classify(expr)
+            }
+
+            Ast::Address { source, .. } => {
+                let is_pointer: bool = source.get_any_type().is_ok_and(|kind| kind.is_ptr_type());
+
+                if !is_pointer && self.is_stack_rooted(source) {
+                    return Some(Escape::Definite);
+                }
+
+                self.classify(source)
+            }
+
+            Ast::Reference { name, metadata, .. } => {
+                if metadata.is_static_ref() || metadata.is_constant_ref() {
+                    return None;
+                }
+
+                self.lookup(name).flatten()
+            }
+
+            Ast::Group { node, .. } => self.classify(node),
+            Ast::As { from, .. } => self.classify(from),
+
+            _ => None,
+        }
+    }
+
+    fn is_stack_rooted(&self, expr: &'analyzer Ast<'analyzer>) -> bool {
+        match expr {
+            Ast::Reference { name, metadata, .. } => {
+                !metadata.is_static_ref() && !metadata.is_constant_ref() && self.is_local(name)
+            }
+
+            Ast::Property { source, .. } | Ast::Index { source, .. } => {
+                let is_pointer: bool = source.get_any_type().is_ok_and(|kind| kind.is_ptr_type());
+
+                !is_pointer && self.is_stack_rooted(source)
+            }
+
+            Ast::Group { node, .. } => self.is_stack_rooted(node),
+
+            _ => false,
+        }
+    }
+
+    fn get_store_target(&self, source: &'analyzer Ast<'analyzer>) -> StoreTarget<'analyzer> {
+        match source {
+            Ast::Reference { name, metadata, .. } => {
+                if metadata.is_static_ref() || !self.is_local(name) {
+                    StoreTarget::Global
+                } else {
+                    StoreTarget::Local(name)
+                }
+            }
+
+            Ast::Property { .. } | Ast::Index { .. } => {
+                if self.is_stack_rooted(source) {
+                    StoreTarget::Stack
+                } else {
+                    self.get_pointer_root(source)
+                }
+            }
+
+            Ast::Group { node, .. } => self.get_store_target(node),
+
+            _ => self.get_pointer_root(source),
+        }
+    }
+
+    fn get_pointer_root(&self, expr: &'analyzer Ast<'analyzer>) -> StoreTarget<'analyzer> {
+        match expr {
+            Ast::Reference { name, metadata, .. } => {
+                if metadata.is_static_ref() || !self.is_local(name) {
+                    StoreTarget::Global
+                } else if self.lookup(name).flatten().is_some() {
+                    StoreTarget::Stack
+                } else {
+                    StoreTarget::Unknown
+                }
+            }
+
+            Ast::Property { source, .. }
+            | Ast::Index { source, .. }
+            | Ast::Address { source, .. }
+            | Ast::Load { source, .. } => self.get_pointer_root(source),
+
+            Ast::Deref { value, .. } => self.get_pointer_root(value),
+            Ast::Group { node, .. } => self.get_pointer_root(node),
+            Ast::As { from, .. } => self.get_pointer_root(from),
+
+            _ => StoreTarget::Unknown,
+        }
+    }
+}
+
+impl<'analyzer> EscapeAnalysis<'analyzer> {
+    #[inline]
+    fn begin_scope(&mut self) {
+        self.scopes.push(HashMap::with_capacity(u8::MAX as usize));
+    }
+
+    #[inline]
+    fn end_scope(&mut self) {
+        self.scopes.pop();
+    }
+
+    #[inline]
+    fn declare(&mut self, name: &'analyzer str, escape: Option<Escape>) {
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.insert(name, escape);
+        }
+    }
+
+    #[inline]
+    fn lookup(&self, name: &str) -> Option<Option<Escape>> {
+        self.scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(name).copied())
+    }
+
+    #[inline]
+    fn lookup_mut(&mut self, name: &str) -> Option<&mut Option<Escape>> {
+        self.scopes
+            .iter_mut()
+            .rev()
+            .find_map(|scope| scope.get_mut(name))
+    }
+
+    #[inline]
+    fn is_local(&self, name: &str) -> bool {
+        self.lookup(name).is_some()
+    }
+}
+
+impl EscapeAnalysis<'_> {
+    #[inline]
+    fn adjust(&self, escape: Escape) -> Escape {
+        if self.conditional_depth > 0 {
+            Escape::Possible
+        } else {
+            escape
+        }
+    }
+
+    fn report(&mut self, escape: Escape, what: &str, span: Span) {
+        match escape {
+            Escape::Definite => self.errors.push(CompilationIssue::Error(
+                CompilationIssueCode::E0040,
+                format!("The address of a stack allocation is {}.", what),
+                "The stack allocation is released when the function returns; allocate it with 'halloc' or keep it in a static instead.".into(),
+                None,
+                span,
+            )),
+
+            Escape::Possible => self.warnings.push(CompilationIssue::Warning(
+                CompilationIssueCode::W0019,
+                format!("The address of a stack allocation may be {}.", what),
+                span,
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use thrustc_ast::{
+        Ast, NodeId,
+        metadata::{LocalMetadata, ReferenceMetadata, ReferenceType},
+    };
+    use thrustc_errors::CompilationIssue;
+    use thrustc_span::Span;
+    use thrustc_typesystem::Type;
+
+    use crate::escape::EscapeAnalysis;
+
+    fn void() -> Type {
+        Type::Void(Span::nothing())
+    }
+
+    fn block<'a>(nodes: Vec<Ast<'a>>) -> Ast<'a> {
+        Ast::Block {
+            nodes,
+            post: Vec::new(),
+            kind: void(),
+            span: Span::nothing(),
+            id: NodeId::new(),
+        }
+    }
+
+    fn local<'a>(name: &'a str, value: Option<Ast<'a>>) -> Ast<'a> {
+        Ast::Var {
+            name,
+            ascii_name: name,
+            kind: void(),
+            value: value.map(Box::new),
+            attributes: Vec::new(),
+            modificators: Vec::new(),
+            metadata: LocalMetadata::new(false, true, false, None),
+            span: Span::nothing(),
+            id: NodeId::new(),
+        }
+    }
+
+    fn reference(name: &str) -> Ast<'_> {
+        Ast::Reference {
+            name,
+            kind: void(),
+            metadata: ReferenceMetadata::new(true, true, ReferenceType::None),
+            span: Span::nothing(),
+            id: NodeId::new(),
+        }
+    }
+
+    fn address_of(name: &str) -> Ast<'_> {
+        Ast::DirectRef {
+            expr: Box::new(self::reference(name)),
+            kind: void(),
+            span: Span::nothing(),
+            id: NodeId::new(),
+        }
+    }
+
+    fn store<'a>(name: &'a str, value: Ast<'a>) -> Ast<'a> {
+        Ast::Mut {
+            source: Box::new(self::reference(name)),
+            value: Box::new(value),
+            kind: void(),
+            span: Span::nothing(),
+            id: NodeId::new(),
+        }
+    }
+
+    fn ret(value: Ast<'_>) -> Ast<'_> {
+        Ast::Return {
+            expression: Some(Box::new(value)),
+            kind: void(),
+            span: Span::nothing(),
+            id: NodeId::new(),
+        }
+    }
+
+    fn analyze(body: &Ast) -> (Vec<CompilationIssue>, Vec<CompilationIssue>) {
+        EscapeAnalysis::new().analyze_function(&[], body)
+    }
+
+    #[test]
+    fn returned_stack_address_is_an_error() {
+        let body: Ast = self::block(vec![
+            self::local("x", None),
+            self::ret(self::address_of("x")),
+        ]);
+
+        let (errors, warnings) = self::analyze(&body);
+
+        assert_eq!(errors.len(), 1);
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn store_into_global_after_shadowing_scope_ends_is_an_error() {
+        let body: Ast = self::block(vec![
+            self::local("x", None),
+            self::block(vec![self::local("global", None)]),
+            self::store("global", self::address_of("x")),
+        ]);
+
+        let (errors, _) = self::analyze(&body);
+
+        assert_eq!(errors.len(), 1);
+    }
+
+    #[test]
+    fn store_into_shadowing_local_is_not_an_error() {
+        let body: Ast = self::block(vec![
+            self::local("x", None),
+            self::block(vec![
+                self::local("global", None),
+                self::store("global", self::address_of("x")),
+            ]),
+        ]);
+
+        let (errors, warnings) = self::analyze(&body);
+
+        assert!(errors.is_empty());
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn inner_pointer_does_not_taint_the_outer_local() {
+        let body: Ast = self::block(vec![
+            self::local("x", None),
+            self::local("pointer", None),
+            self::block(vec![self::local("pointer", Some(self::address_of("x")))]),
+            self::ret(self::reference("pointer")),
+        ]);
+
+        let (errors, warnings) = self::analyze(&body);
+
+        assert!(errors.is_empty());
+        assert!(warnings.is_empty());
+    }
+}
@@ -55,6 +55,23 @@ pub fn validate<'analyzer>(
             Ok(())
         }
 
+        Ast::Try { expression, .. } => {
+            analyzer.analyze_expr(expression)?;
+
+            Ok(())
+        }
+
+        Ast::Catch {
+            expression,
+            fallback,
+            ..
+        } => {
+            analyzer.analyze_expr(expression)?;
+            analyzer.analyze_expr(fallback)?;
+
+            Ok(())
+        }
+
         Ast::FixedArray { items, .. } => {
             {
                 for node in items.iter() {
@@ -134,6 +151,20 @@ pub fn validate<'analyzer>(
             Ok(())
         }
 
+        Ast::DynamicCall { object, args, .. } => {
+            analyzer.analyze_expr(object)?;
+
+            {
+                for argument in args.iter() {
+                    analyzer.analyze_expr(argument)?;
+                }
+            }
+
+            Ok(())
+        }
+
+        Ast::InterfaceObject { value, .. } => analyzer.analyze_expr(value),
+
         Ast::DirectRef { expr, span, .. } => {
             let expr_type: &Type = expr.get_value_type()?;
 
@@ -198,7 +229,37 @@ pub fn validate<'analyzer>(
                 Ok(())
             }
 
+            AstBuiltin::Splat { value, .. } => {
+                analyzer.analyze_expr(value)?;
+
+                Ok(())
+            }
+
+            AstBuiltin::Shuffle { left, right, .. } => {
+                analyzer.analyze_expr(left)?;
+                analyzer.analyze_expr(right)?;
+
+                Ok(())
+            }
+
+            AstBuiltin::VaArg { list, .. }
+            | AstBuiltin::VaEnd { list, .. }
+            | AstBuiltin::VaCopy { list, .. } => {
+                analyzer.analyze_expr(list)?;
+
+                Ok(())
+            }
+
+            AstBuiltin::Print { args, .. } => {
+                for arg in args.iter() {
+                    analyzer.analyze_expr(arg)?;
+                }
+
+                Ok(())
+            }
+
             AstBuiltin::Halloc { .. }
+            | AstBuiltin::VaStart { .. }
             | AstBuiltin::AlignOf { .. }
             | AstBuiltin::SizeOf { .. }
             | AstBuiltin::AbiSizeOf { .. }
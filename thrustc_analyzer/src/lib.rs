@@ -32,8 +32,10 @@ use thrustc_span::Span;
 use thrustc_typesystem::{Type, traits::TypeExtensions};
 
 use crate::context::AnalyzerContext;
+use crate::escape::EscapeAnalysis;
 
 mod context;
+mod escape;
 mod expressions;
 
 #[derive(Debug)]
@@ -63,7 +65,7 @@ impl<'analyzer> Analyzer<'analyzer> {
             errors: Vec::with_capacity(u8::MAX as usize),
             warnings: Vec::with_capacity(u8::MAX as usize),
 
-            diagnostician: Diagnostician::new(file, options),
+            diagnostician: Diagnostician::with_allowed_warnings(file, options, ast),
             context: AnalyzerContext::new(),
         }
     }
@@ -102,7 +104,7 @@ impl<'analyzer> Analyzer<'analyzer> {
             return true;
         }
 
-        false
+        self.diagnostician.has_denied_warnings()
     }
 }
 
@@ -143,6 +145,14 @@ impl<'analyzer> Analyzer<'analyzer> {
 
                 if let Some(body) = body {
                     self.analyze_stmt(body)?;
+
+                    let (errors, warnings): (Vec<CompilationIssue>, Vec<CompilationIssue>) =
+                        EscapeAnalysis::new().analyze_function(parameters, body);
+
+                    errors.into_iter().for_each(|error| self.add_error(error));
+                    warnings
+                        .into_iter()
+                        .for_each(|warning| self.add_warning(warning));
                 }
 
                 Ok(())
@@ -446,6 +456,12 @@ impl<'analyzer> Analyzer<'analyzer> {
                 Ok(())
             }
 
+            Ast::Raise { expression, .. } => {
+                self.analyze_expr(expression)?;
+
+                Ok(())
+            }
+
             node => self.analyze_expr(node),
         }
     }
@@ -465,6 +481,11 @@ impl Analyzer<'_> {
     fn add_bug(&mut self, error: CompilationIssue) {
         self.bugs.push(error);
     }
+
+    #[inline]
+    fn add_warning(&mut self, warning: CompilationIssue) {
+        self.warnings.push(warning);
+    }
 }
 
 impl Analyzer<'_> {
@@ -50,6 +50,38 @@ pub enum AstBuiltin<'mir> {
         size: std::boxed::Box<Ast<'mir>>,
         span: Span,
     },
+    Splat {
+        of: Type,
+        value: std::boxed::Box<Ast<'mir>>,
+        span: Span,
+    },
+    Shuffle {
+        left: std::boxed::Box<Ast<'mir>>,
+        right: std::boxed::Box<Ast<'mir>>,
+        mask: std::vec::Vec<u32>,
+        span: Span,
+    },
+    VaStart {
+        span: Span,
+    },
+    VaArg {
+        list: std::boxed::Box<Ast<'mir>>,
+        of: Type,
+        span: Span,
+    },
+    VaEnd {
+        list: std::boxed::Box<Ast<'mir>>,
+        span: Span,
+    },
+    VaCopy {
+        list: std::boxed::Box<Ast<'mir>>,
+        span: Span,
+    },
+    Print {
+        pieces: std::vec::Vec<std::string::String>,
+        args: std::vec::Vec<Ast<'mir>>,
+        span: Span,
+    },
     BitSizeOf {
         of: Type,
         span: Span,
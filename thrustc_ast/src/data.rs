@@ -24,17 +24,20 @@ use crate::Ast;
 
 pub type StructureData<'ctx> = (
     &'ctx str,
-    Vec<(&'ctx str, Type, u32, Span)>,
+    Vec<(&'ctx str, Type, u32, Span, Option<u8>)>,
     StructureTypeModificator,
     Span,
 );
 
-pub type StructureDataFields<'ctx> = Vec<(&'ctx str, Type, u32, Span)>;
-pub type StructDataField<'ctx> = (usize, &'ctx (&'ctx str, Type, u32, Span));
+pub type StructureDataFields<'ctx> = Vec<(&'ctx str, Type, u32, Span, Option<u8>)>;
+pub type StructDataField<'ctx> = (usize, &'ctx (&'ctx str, Type, u32, Span, Option<u8>));
 
 pub type EnumData<'ctx> = Vec<(&'ctx str, Type, Ast<'ctx>)>;
 pub type EnumDataField<'ctx> = (&'ctx str, Type, Ast<'ctx>);
 
+pub type InterfaceData<'ctx> = Vec<(&'ctx str, Vec<Type>, Type, Span)>;
+pub type InterfaceDataMethod<'ctx> = (&'ctx str, Vec<Type>, Type, Span);
+
 pub type ConstructorData<'ctx> = Vec<(&'ctx str, Ast<'ctx>, Type, u32)>;
 
 pub type PropertyData = Vec<(Type, (Type, u32))>;
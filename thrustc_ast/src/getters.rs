@@ -68,6 +68,7 @@ impl AstGetType for Ast<'_> {
             Ast::FunctionParameter { kind, .. } => Ok(kind),
             Ast::AssemblerFunctionParameter { kind, .. } => Ok(kind),
             Ast::Return { kind, .. } => Ok(kind),
+            Ast::Raise { kind, .. } => Ok(kind),
             Ast::Function { return_type, .. } => Ok(return_type),
             Ast::AssemblerFunction { return_type, .. } => Ok(return_type),
 
@@ -89,6 +90,8 @@ impl AstGetType for Ast<'_> {
             Ast::UnaryOp { kind, .. } => Ok(kind),
             Ast::Group { kind, .. } => Ok(kind),
             Ast::Index { kind, .. } => Ok(kind),
+            Ast::Try { kind, .. } => Ok(kind),
+            Ast::Catch { kind, .. } => Ok(kind),
             Ast::AsmValue { kind, .. } => Ok(kind),
 
             // Builtins
@@ -103,6 +106,11 @@ impl AstGetType for Ast<'_> {
             Ast::Struct { kind, .. } => Ok(kind),
             Ast::Enum { kind, .. } => Ok(kind),
 
+            // Interfaces
+            Ast::Interface { kind, .. } => Ok(kind),
+            Ast::Implementation { kind, .. } => Ok(kind),
+            Ast::InterfaceObject { kind, .. } => Ok(kind),
+
             // Type Conversions
             Ast::As { cast, .. } => Ok(cast),
 
@@ -115,6 +123,7 @@ impl AstGetType for Ast<'_> {
 
             // Indirect Call
             Ast::IndirectCall { kind, .. } => Ok(kind),
+            Ast::DynamicCall { kind, .. } => Ok(kind),
 
             // Control flow
             Ast::If { kind, .. } => Ok(kind),
@@ -177,6 +186,7 @@ impl AstGetType for Ast<'_> {
             Ast::Constructor { kind, .. } => Ok(kind),
             Ast::Property { kind, .. } => Ok(kind),
             Ast::EnumValue { kind, .. } => Ok(kind),
+            Ast::InterfaceObject { kind, .. } => Ok(kind),
 
             // Expressions
             Ast::ModuleExpression { data, .. } => {
@@ -196,6 +206,9 @@ impl AstGetType for Ast<'_> {
             Ast::UnaryOp { kind, .. } => Ok(kind),
             Ast::Group { kind, .. } => Ok(kind),
             Ast::Index { kind, .. } => Ok(kind),
+            Ast::Try { kind, .. } => Ok(kind),
+            Ast::Catch { kind, .. } => Ok(kind),
+            Ast::DynamicCall { kind, .. } => Ok(kind),
 
             // Type operations
             Ast::As { cast: kind, .. } => Ok(kind),
@@ -278,6 +291,10 @@ impl AstCodeLocation for Ast<'_> {
             Ast::Constructor { span, .. } => *span,
             Ast::Property { span, .. } => *span,
 
+            Ast::Interface { span, .. } => *span,
+            Ast::Implementation { span, .. } => *span,
+            Ast::InterfaceObject { span, .. } => *span,
+
             // Expressions and operators
             Ast::ModuleExpression { span, .. } => *span,
             Ast::Call { span, .. } => *span,
@@ -285,6 +302,8 @@ impl AstCodeLocation for Ast<'_> {
             Ast::UnaryOp { span, .. } => *span,
             Ast::Group { span, .. } => *span,
             Ast::Index { span, .. } => *span,
+            Ast::Try { span, .. } => *span,
+            Ast::Catch { span, .. } => *span,
 
             // Type conversions
             Ast::As { span, .. } => *span,
@@ -310,6 +329,7 @@ impl AstCodeLocation for Ast<'_> {
             Ast::Function { span, .. } => *span,
             Ast::AssemblerFunction { span, .. } => *span,
             Ast::Return { span, .. } => *span,
+            Ast::Raise { span, .. } => *span,
 
             // Low-level and special operations
             Ast::AsmValue { span, .. } => *span,
@@ -328,6 +348,7 @@ impl AstCodeLocation for Ast<'_> {
 
             // Indirect Call
             Ast::IndirectCall { span, .. } => *span,
+            Ast::DynamicCall { span, .. } => *span,
 
             // Unreachable marker
             Ast::Unreachable { span, .. } => *span,
@@ -17,6 +17,8 @@
 
 */
 
+use thrustc_typesystem::traits::{TypeIsExtensions, TypeStructExtensions};
+
 use crate::{
     Ast,
     traits::{AstBuiltinsExtensions, AstConstantExtensions},
@@ -45,8 +47,10 @@ impl AstConstantExtensions for Ast<'_> {
             Ast::As { metadata, .. } => metadata.is_constant(),
             Ast::FixedArray { items, .. } => items.iter().all(|item| item.is_constant_value()),
             Ast::Array { items, .. } => items.iter().all(|item| item.is_constant_value()),
-            Ast::Constructor { data, .. } => {
-                data.iter().all(|(_, node, ..)| node.is_constant_value())
+            Ast::Constructor { data, kind, .. } => {
+                !kind.is_union_type()
+                    && kind.get_struct_bitfields().is_empty()
+                    && data.iter().all(|(_, node, ..)| node.is_constant_value())
             }
 
             _ => false,
@@ -101,7 +101,7 @@ impl AstStandardExtensions for Ast<'_> {
 
     #[inline]
     fn is_terminator_keyword(&self) -> bool {
-        matches!(self, Ast::Return { .. })
+        matches!(self, Ast::Return { .. } | Ast::Raise { .. })
     }
 
     #[inline]
@@ -176,6 +176,7 @@ impl AstStatementExtensions for Ast<'_> {
                 | Ast::For { .. }
                 | Ast::Loop { .. }
                 | Ast::Return { .. }
+                | Ast::Raise { .. }
                 | Ast::Break { .. }
                 | Ast::BreakAll { .. }
                 | Ast::Continue { .. }
@@ -198,6 +199,8 @@ impl AstDeclarationExtensions for Ast<'_> {
                 | Ast::Const { .. }
                 | Ast::Static { .. }
                 | Ast::Enum { .. }
+                | Ast::Interface { .. }
+                | Ast::Implementation { .. }
                 | Ast::Function { .. }
                 | Ast::Intrinsic { .. }
                 | Ast::AssemblerFunction { .. }
@@ -351,7 +354,7 @@ impl AstConstructorDataExtensions for ConstructorData<'_> {
     #[inline]
     fn get_type(&self, name: &str, modificator: StructureTypeModificator, span: Span) -> Type {
         let types: Vec<Type> = self.iter().map(|field| field.2.clone()).collect();
-        Type::create_struct_type(name.to_string(), types.as_slice(), modificator, span)
+        Type::create_struct_type(name.to_string(), types.as_slice(), &[], modificator, span)
     }
 }
 
@@ -381,10 +384,27 @@ impl AstStructFieldsDataExtensions for StructureData<'_> {
     fn get_type(&self) -> Type {
         let types: Vec<Type> = self.1.iter().map(|field| field.1.clone()).collect();
 
+        let bitfields: Vec<(u32, u8)> = self
+            .1
+            .iter()
+            .enumerate()
+            .filter_map(|(index, field)| {
+                field
+                    .4
+                    .map(|width| (u32::try_from(index).unwrap_or(u32::MAX), width))
+            })
+            .collect();
+
         let name: String = self.0.to_string();
         let span: Span = self.3;
 
-        Type::create_struct_type(name, types.as_slice(), self.get_modificator(), span)
+        Type::create_struct_type(
+            name,
+            types.as_slice(),
+            bitfields.as_slice(),
+            self.get_modificator(),
+            span,
+        )
     }
 
     #[inline]
@@ -28,7 +28,7 @@ use thrustc_typesystem::Type;
 
 use crate::{
     builtins::AstBuiltin,
-    data::{ConstructorData, EnumData, PropertyData, StructureData},
+    data::{ConstructorData, EnumData, InterfaceData, PropertyData, StructureData},
     metadata::{
         CastingMetadata, ConstantMetadata, DereferenceMetadata, FunctionParameterMetadata,
         LocalMetadata, PropertyMetadata, ReferenceMetadata, StaticMetadata,
@@ -77,7 +77,7 @@ pub enum Ast<'ast> {
 
     Integer {
         kind: Type,
-        value: u64,
+        value: u128,
         signed: bool,
         span: Span,
         id: NodeId,
@@ -273,6 +273,31 @@ pub enum Ast<'ast> {
         id: NodeId,
     },
 
+    // Interfaces
+    Interface {
+        name: &'ast str,
+        data: InterfaceData<'ast>,
+        kind: Type,
+        span: Span,
+        id: NodeId,
+    },
+    Implementation {
+        interface: &'ast str,
+        target: &'ast str,
+        methods: std::vec::Vec<&'ast str>,
+        kind: Type,
+        span: Span,
+        id: NodeId,
+    },
+    InterfaceObject {
+        value: std::boxed::Box<Ast<'ast>>,
+        interface: &'ast str,
+        target: &'ast str,
+        kind: Type,
+        span: Span,
+        id: NodeId,
+    },
+
     // Functions
     Intrinsic {
         name: &'ast str,
@@ -334,6 +359,12 @@ pub enum Ast<'ast> {
         span: Span,
         id: NodeId,
     },
+    Raise {
+        expression: std::boxed::Box<Ast<'ast>>,
+        kind: Type,
+        span: Span,
+        id: NodeId,
+    },
 
     // Static
     Static {
@@ -465,6 +496,16 @@ pub enum Ast<'ast> {
         id: NodeId,
     },
 
+    DynamicCall {
+        object: std::boxed::Box<Ast<'ast>>,
+        index: u32,
+        function_type: Type,
+        args: std::vec::Vec<Ast<'ast>>,
+        kind: Type,
+        span: Span,
+        id: NodeId,
+    },
+
     AsmValue {
         assembler: String,
         constraints: String,
@@ -500,6 +541,21 @@ pub enum Ast<'ast> {
         id: NodeId,
     },
 
+    // Error Unions
+    Try {
+        expression: std::boxed::Box<Ast<'ast>>,
+        kind: Type,
+        span: Span,
+        id: NodeId,
+    },
+    Catch {
+        expression: std::boxed::Box<Ast<'ast>>,
+        fallback: std::boxed::Box<Ast<'ast>>,
+        kind: Type,
+        span: Span,
+        id: NodeId,
+    },
+
     // Builtins
     Builtin {
         builtin: AstBuiltin<'ast>,
@@ -510,6 +566,7 @@ pub enum Ast<'ast> {
 
     // Module Import
     Import {
+        attributes: ThrustAttributes,
         span: Span,
         kind: Type,
         id: NodeId,
@@ -549,7 +606,7 @@ impl<'ast> Ast<'ast> {
     }
 
     #[inline]
-    pub fn new_integer(kind: Type, value: u64, signed: bool, span: Span) -> Ast<'ast> {
+    pub fn new_integer(kind: Type, value: u128, signed: bool, span: Span) -> Ast<'ast> {
         Ast::Integer {
             kind,
             value,
@@ -126,6 +126,8 @@ impl<'ast_verifier> AstVerifier<'ast_verifier> {
                     | Ast::CustomType { .. }
                     | Ast::Import { .. }
                     | Ast::Embedded { .. }
+                    | Ast::Interface { .. }
+                    | Ast::Implementation { .. }
                     | Ast::Struct { .. } => {}
 
                     _ => {
@@ -266,6 +268,11 @@ impl<'ast_verifier> AstVerifier<'ast_verifier> {
                 }
             }
 
+            Ast::Raise { expression, .. } => {
+                self.expected_expression(expression);
+                self.analyze_expression(expression);
+            }
+
             Ast::Defer { node, .. } => {
                 self.expected_statement_or_loose_expression(node);
                 self.analyze_stmt(node);
@@ -295,6 +302,23 @@ impl<'ast_verifier> AstVerifier<'ast_verifier> {
                 self.analyze_expression(node);
             }
 
+            Ast::Try { expression, .. } => {
+                self.expected_expression(expression);
+                self.analyze_expression(expression);
+            }
+
+            Ast::Catch {
+                expression,
+                fallback,
+                ..
+            } => {
+                self.expected_expression(expression);
+                self.analyze_expression(expression);
+
+                self.expected_expression(fallback);
+                self.analyze_expression(fallback);
+            }
+
             Ast::FixedArray { items, .. } => {
                 for node in items.iter() {
                     self.expected_expression(node);
@@ -343,6 +367,21 @@ impl<'ast_verifier> AstVerifier<'ast_verifier> {
                 }
             }
 
+            Ast::DynamicCall { object, args, .. } => {
+                self.expected_expression(object);
+                self.analyze_expression(object);
+
+                for node in args.iter() {
+                    self.expected_expression(node);
+                    self.analyze_expression(node);
+                }
+            }
+
+            Ast::InterfaceObject { value, .. } => {
+                self.expected_expression(value);
+                self.analyze_expression(value);
+            }
+
             Ast::Defer { node, .. } => {
                 self.expected_expression(node);
                 self.analyze_expression(node);
@@ -406,6 +445,29 @@ impl<'ast_verifier> AstVerifier<'ast_verifier> {
                     self.expected_expression(size);
                     self.analyze_expression(size);
                 }
+                AstBuiltin::Splat { value, .. } => {
+                    self.expected_expression(value);
+                    self.analyze_expression(value);
+                }
+                AstBuiltin::Shuffle { left, right, .. } => {
+                    self.expected_expression(left);
+                    self.analyze_expression(left);
+
+                    self.expected_expression(right);
+                    self.analyze_expression(right);
+                }
+                AstBuiltin::VaArg { list, .. }
+                | AstBuiltin::VaEnd { list, .. }
+                | AstBuiltin::VaCopy { list, .. } => {
+                    self.expected_expression(list);
+                    self.analyze_expression(list);
+                }
+                AstBuiltin::Print { args, .. } => {
+                    for arg in args.iter() {
+                        self.expected_expression(arg);
+                        self.analyze_expression(arg);
+                    }
+                }
 
                 _ => (),
             },
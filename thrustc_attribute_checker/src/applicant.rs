@@ -30,4 +30,5 @@ pub enum AttributeCheckerAttributeApplicant<'attr_checker> {
     Struct,
     Enum,
     Local,
+    Import,
 }
@@ -26,7 +26,10 @@ use thrustc_diagnostician::Diagnostician;
 use thrustc_errors::{CompilationIssue, CompilationIssueCode};
 use thrustc_options::{CompilationUnit, CompilerOptions};
 use thrustc_span::Span;
-use thrustc_typesystem::traits::TypeIsExtensions;
+use thrustc_typesystem::{
+    Type,
+    traits::{TypeIsExtensions, TypePointerExtensions},
+};
 
 use crate::applicant::AttributeCheckerAttributeApplicant;
 
@@ -56,7 +59,7 @@ impl<'attr_checker> AttributeChecker<'attr_checker> {
             errors: Vec::with_capacity(u8::MAX as usize),
             warnings: Vec::with_capacity(u8::MAX as usize),
 
-            diagnostician: Diagnostician::new(file, options),
+            diagnostician: Diagnostician::with_allowed_warnings(file, options, ast),
         }
     }
 }
@@ -89,7 +92,7 @@ impl<'attr_checker> AttributeChecker<'attr_checker> {
             return true;
         }
 
-        false
+        self.diagnostician.has_denied_warnings()
     }
 }
 
@@ -99,6 +102,7 @@ impl<'attr_checker> AttributeChecker<'attr_checker> {
             Ast::Function {
                 attributes,
                 body,
+                parameter_types,
                 return_type,
                 span,
                 ..
@@ -129,6 +133,8 @@ impl<'attr_checker> AttributeChecker<'attr_checker> {
                     self.analyze_ast(body);
                 }
 
+                self.check_format_attribute(attributes, parameter_types);
+
                 self.analyze_attrs(
                     attributes,
                     AttributeCheckerAttributeApplicant::Function { return_type },
@@ -136,8 +142,13 @@ impl<'attr_checker> AttributeChecker<'attr_checker> {
                 );
             }
             Ast::Intrinsic {
-                attributes, span, ..
+                attributes,
+                parameters_types,
+                span,
+                ..
             } => {
+                self.check_format_attribute(attributes, parameters_types);
+
                 self.analyze_attrs(
                     attributes,
                     AttributeCheckerAttributeApplicant::Intrinsic,
@@ -216,6 +227,11 @@ impl<'attr_checker> AttributeChecker<'attr_checker> {
             } => {
                 self.analyze_attrs(attributes, AttributeCheckerAttributeApplicant::Local, *span);
             }
+            Ast::Import {
+                attributes, span, ..
+            } => {
+                self.analyze_attrs(attributes, AttributeCheckerAttributeApplicant::Import, *span);
+            }
             Ast::Block { nodes, post, .. } => {
                 for node in nodes.iter() {
                     self.analyze_ast(node);
@@ -356,6 +372,7 @@ impl<'attr_checker> AttributeChecker<'attr_checker> {
             AttributeCheckerAttributeApplicant::Intrinsic => {
                 self.check_irrelevant_attributes(attributes, applicant);
                 self.check_illogical_attributes(attributes);
+                self.check_arbitrary_args_attribute(attributes);
 
                 if !attributes.has_public_attribute() {
                     self.add_error(CompilationIssue::Error(
@@ -396,6 +413,7 @@ impl<'attr_checker> AttributeChecker<'attr_checker> {
             AttributeCheckerAttributeApplicant::AssemblerFunction => {
                 self.check_irrelevant_attributes(attributes, applicant);
                 self.check_illogical_attributes(attributes);
+                self.check_arbitrary_args_attribute(attributes);
 
                 if !attributes.has_asmsyntax_attribute() {
                     if let Some(span) = attributes.match_attr(ThrustAttributeComparator::Extern) {
@@ -455,7 +473,8 @@ impl<'attr_checker> AttributeChecker<'attr_checker> {
             AttributeCheckerAttributeApplicant::Constant
             | AttributeCheckerAttributeApplicant::Struct
             | AttributeCheckerAttributeApplicant::Enum
-            | AttributeCheckerAttributeApplicant::Local => {
+            | AttributeCheckerAttributeApplicant::Local
+            | AttributeCheckerAttributeApplicant::Import => {
                 self.check_irrelevant_attributes(attributes, applicant);
                 self.check_illogical_attributes(attributes);
 
@@ -498,6 +517,8 @@ impl<'attr_checker> AttributeChecker<'attr_checker> {
             ThrustAttributeComparator::Thunk,
             ThrustAttributeComparator::Constructor,
             ThrustAttributeComparator::Destructor,
+            ThrustAttributeComparator::Allow,
+            ThrustAttributeComparator::Format,
         ];
 
         const VALID_INTRINSIC_ATTRIBUTES: &[ThrustAttributeComparator] = &[
@@ -516,6 +537,8 @@ impl<'attr_checker> AttributeChecker<'attr_checker> {
             ThrustAttributeComparator::StrongStack,
             ThrustAttributeComparator::PreciseFloats,
             ThrustAttributeComparator::Linkage,
+            ThrustAttributeComparator::Allow,
+            ThrustAttributeComparator::Format,
         ];
 
         const VALID_ASSEMBLER_FUNCTION_ATTRIBUTES: &[ThrustAttributeComparator] = &[
@@ -540,6 +563,7 @@ impl<'attr_checker> AttributeChecker<'attr_checker> {
             ThrustAttributeComparator::AsmThrow,
             ThrustAttributeComparator::Constructor,
             ThrustAttributeComparator::Destructor,
+            ThrustAttributeComparator::Allow,
         ];
 
         const VALID_STATIC_ATTRIBUTES: &[ThrustAttributeComparator] = &[
@@ -547,6 +571,7 @@ impl<'attr_checker> AttributeChecker<'attr_checker> {
             ThrustAttributeComparator::Extern,
             ThrustAttributeComparator::Linkage,
             ThrustAttributeComparator::Align,
+            ThrustAttributeComparator::Allow,
         ];
 
         const VALID_CONSTANT_ATTRIBUTES: &[ThrustAttributeComparator] = &[
@@ -554,14 +579,18 @@ impl<'attr_checker> AttributeChecker<'attr_checker> {
             ThrustAttributeComparator::Extern,
             ThrustAttributeComparator::Linkage,
             ThrustAttributeComparator::Align,
+            ThrustAttributeComparator::Allow,
         ];
 
-        const VALID_ENUM_ATTRIBUTES: &[ThrustAttributeComparator] =
-            &[ThrustAttributeComparator::Public];
+        const VALID_ENUM_ATTRIBUTES: &[ThrustAttributeComparator] = &[
+            ThrustAttributeComparator::Public,
+            ThrustAttributeComparator::Allow,
+        ];
 
         const VALID_STRUCTS_ATTRIBUTES: &[ThrustAttributeComparator] = &[
             ThrustAttributeComparator::Public,
             ThrustAttributeComparator::Packed,
+            ThrustAttributeComparator::Allow,
         ];
 
         const VALID_LOCAL_ATTRIBUTES: &[ThrustAttributeComparator] = &[
@@ -569,6 +598,9 @@ impl<'attr_checker> AttributeChecker<'attr_checker> {
             ThrustAttributeComparator::Align,
         ];
 
+        const VALID_IMPORT_ATTRIBUTES: &[ThrustAttributeComparator] =
+            &[ThrustAttributeComparator::Allow];
+
         match applicant {
             AttributeCheckerAttributeApplicant::Function { .. } => {
                 attributes.iter().for_each(|attr| {
@@ -658,6 +690,31 @@ impl<'attr_checker> AttributeChecker<'attr_checker> {
                     }
                 });
             }
+            AttributeCheckerAttributeApplicant::Import => {
+                attributes.iter().for_each(|attr| {
+                    if !VALID_IMPORT_ATTRIBUTES.contains(&attr.as_attr_cmp()) {
+                        self.add_warning(CompilationIssue::Warning(
+                            CompilationIssueCode::W0001,
+                            "This attribute is not applicable for imports.".into(),
+                            attr.get_span(),
+                        ));
+                    }
+                });
+            }
+        }
+    }
+
+    fn check_arbitrary_args_attribute(&mut self, attributes: &ThrustAttributes) {
+        if !attributes.has_extern_attribute() && attributes.has_ignore_attribute() {
+            if let Some(span) = attributes.match_attr(ThrustAttributeComparator::Ignore) {
+                self.add_error(CompilationIssue::Error(
+                    CompilationIssueCode::E0013,
+                    "Attribute conflict".into(),
+                    "The @arbitraryArgs attribute requires a FFI symbol. You should add the external FFI attribute '@extern(\"externalName\")'.".into(),
+                    None,
+                    span,
+                ));
+            }
         }
     }
 
@@ -786,18 +843,6 @@ impl<'attr_checker> AttributeChecker<'attr_checker> {
             }
         }
 
-        if !attributes.has_extern_attribute() && attributes.has_ignore_attribute() {
-            if let Some(span) = attributes.match_attr(ThrustAttributeComparator::Ignore) {
-                self.add_error(CompilationIssue::Error(
-                    CompilationIssueCode::E0013,
-                    "Attribute conflict".into(),
-                    "The @arbitraryArgs attribute requires a FFI symbol. You should add the external FFI attribute '@extern(\"externalName\")'.".into(),
-                    None,
-                    span,
-                ));
-            }
-        }
-
         if attributes.has_inlinealways_attr() && attributes.has_inline_attr() {
             if let Some(span) = attributes.match_attr(ThrustAttributeComparator::InlineHint) {
                 self.add_error(CompilationIssue::Error(
@@ -836,6 +881,57 @@ impl<'attr_checker> AttributeChecker<'attr_checker> {
     }
 }
 
+impl<'attr_checker> AttributeChecker<'attr_checker> {
+    fn check_format_attribute(&mut self, attributes: &ThrustAttributes, parameter_types: &[Type]) {
+        let Some(ThrustAttribute::Format(position, span)) =
+            attributes.get_attr(ThrustAttributeComparator::Format)
+        else {
+            return;
+        };
+
+        if !attributes.has_ignore_attribute() {
+            self.add_error(CompilationIssue::Error(
+                CompilationIssueCode::E0013,
+                "Format checking requires variadic arguments.".into(),
+                "Add the '@arbitraryArgs' attribute.".into(),
+                None,
+                span,
+            ));
+        }
+
+        if position != parameter_types.len() as u64 {
+            self.add_error(CompilationIssue::Error(
+                CompilationIssueCode::E0013,
+                format!(
+                    "The format string must be the last parameter, expected position '{}', not '{}'.",
+                    parameter_types.len(),
+                    position
+                ),
+                "You should point it to the parameter right before the variadic arguments.".into(),
+                None,
+                span,
+            ));
+
+            return;
+        }
+
+        if let Some(format_type) = parameter_types.last() {
+            if !format_type.is_ptr_like_type() {
+                self.add_error(CompilationIssue::Error(
+                    CompilationIssueCode::E0013,
+                    format!(
+                        "The format string parameter must be a pointer, not '{}'.",
+                        format_type
+                    ),
+                    "You should declare it as 'const array[char]'.".into(),
+                    None,
+                    span,
+                ));
+            }
+        }
+    }
+}
+
 impl<'attr_checker> AttributeChecker<'attr_checker> {
     fn get_repeated_attrs(&self, attributes: &'attr_checker ThrustAttributes) -> ThrustAttributes {
         let mut storage: HashSet<ThrustAttributeComparator> =
@@ -52,6 +52,16 @@ impl std::fmt::Display for ThrustAttribute {
             ThrustAttribute::Thunk(..) => write!(f, "@thunk"),
             ThrustAttribute::Constructor(..) => write!(f, "@constructor"),
             ThrustAttribute::Destructor(..) => write!(f, "@destructor"),
+            ThrustAttribute::Allow(codes, ..) => write!(
+                f,
+                "@allow({})",
+                codes
+                    .iter()
+                    .map(|code| format!("{:?}", code))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            ThrustAttribute::Format(position, ..) => write!(f, "@format(printf, {})", position),
         }
     }
 }
@@ -17,6 +17,7 @@
 
 */
 
+use thrustc_errors::CompilationIssueCode;
 use thrustc_span::Span;
 use thrustc_token_type::TokenType;
 
@@ -74,6 +75,13 @@ pub enum ThrustAttribute {
     //Ctors & Dtors
     Constructor(Span),
     Destructor(Span),
+
+    // Diagnostics
+    Allow(
+        #[cfg_attr(feature = "fuzz", arbitrary(default))] Vec<CompilationIssueCode>,
+        Span,
+    ),
+    Format(u64, Span),
 }
 
 #[derive(Debug, Clone, PartialEq, Hash, Eq)]
@@ -110,6 +118,9 @@ pub enum ThrustAttributeComparator {
 
     Constructor,
     Destructor,
+
+    Allow,
+    Format,
 }
 
 impl ThrustAttribute {
@@ -207,6 +218,16 @@ impl ThrustAttribute {
     pub fn is_align_attribute(&self) -> bool {
         matches!(self, ThrustAttribute::Align(..))
     }
+
+    #[inline]
+    pub fn is_allow_attribute(&self) -> bool {
+        matches!(self, ThrustAttribute::Allow(..))
+    }
+
+    #[inline]
+    pub fn is_format_attribute(&self) -> bool {
+        matches!(self, ThrustAttribute::Format(..))
+    }
 }
 
 impl ThrustAttribute {
@@ -241,6 +262,8 @@ impl ThrustAttribute {
             ThrustAttribute::Thunk(span) => *span,
             ThrustAttribute::Constructor(span) => *span,
             ThrustAttribute::Destructor(span) => *span,
+            ThrustAttribute::Allow(_, span) => *span,
+            ThrustAttribute::Format(_, span) => *span,
         }
     }
 }
@@ -364,6 +387,22 @@ impl ThrustAttributesExtensions for ThrustAttributes {
         self.iter().any(|attr| attr.is_destructor_attribute())
     }
 
+    #[inline]
+    fn has_allowed_warning(&self, code: CompilationIssueCode) -> bool {
+        self.iter().any(|attr| match attr {
+            ThrustAttribute::Allow(codes, ..) => codes.contains(&code),
+            _ => false,
+        })
+    }
+
+    #[inline]
+    fn get_format_argument(&self) -> Option<u64> {
+        self.iter().find_map(|attr| match attr {
+            ThrustAttribute::Format(position, ..) => Some(*position),
+            _ => None,
+        })
+    }
+
     #[inline]
     fn match_attr(&self, cmp: ThrustAttributeComparator) -> Option<Span> {
         if let Some(attr_found) = self.iter().find(|attr| attr.as_attr_cmp() == cmp) {
@@ -415,6 +454,8 @@ impl ThrustAttributeComparatorExtensions for ThrustAttribute {
             ThrustAttribute::Thunk(..) => ThrustAttributeComparator::Thunk,
             ThrustAttribute::Constructor(..) => ThrustAttributeComparator::Constructor,
             ThrustAttribute::Destructor(..) => ThrustAttributeComparator::Destructor,
+            ThrustAttribute::Allow(..) => ThrustAttributeComparator::Allow,
+            ThrustAttribute::Format(..) => ThrustAttributeComparator::Format,
         }
     }
 }
@@ -17,6 +17,7 @@
 
 */
 
+use thrustc_errors::CompilationIssueCode;
 use thrustc_span::Span;
 
 use crate::{ThrustAttribute, ThrustAttributeComparator};
@@ -42,6 +43,8 @@ pub trait ThrustAttributesExtensions {
     fn has_destructor_attribute(&self) -> bool;
     fn has_asmsyntax_attribute(&self) -> bool;
     fn has_convention_attribute(&self) -> bool;
+    fn has_allowed_warning(&self, code: CompilationIssueCode) -> bool;
+    fn get_format_argument(&self) -> Option<u64>;
 
     fn match_attr(&self, cmp: ThrustAttributeComparator) -> Option<Span>;
     fn get_attr(&self, cmp: ThrustAttributeComparator) -> Option<ThrustAttribute>;
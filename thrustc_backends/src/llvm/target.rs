@@ -97,6 +97,7 @@ impl LLVMTarget {
     #[inline]
     pub fn set_target_triple(&mut self, raw_target_triple: String) {
         self.target_triple = TargetTriple::create(&raw_target_triple);
+        self.normalized_target_triple = LLVMTargetTriple::new(raw_target_triple);
     }
 
     #[inline]
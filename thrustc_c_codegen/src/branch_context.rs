@@ -0,0 +1,91 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+
+#[derive(Debug)]
+pub struct CLoopContext {
+    depth: usize,
+    labels: usize,
+    continueall_label: Option<(String, bool)>,
+    breakall_label: Option<(String, bool)>,
+}
+
+impl CLoopContext {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            depth: 0,
+            labels: 0,
+            continueall_label: None,
+            breakall_label: None,
+        }
+    }
+}
+
+impl CLoopContext {
+    pub fn begin_loop(&mut self) -> bool {
+        let is_outermost: bool = self.depth == 0;
+
+        if is_outermost {
+            self.continueall_label = Some((format!("__thrust_continueall_{}", self.labels), false));
+            self.breakall_label = Some((format!("__thrust_breakall_{}", self.labels), false));
+            self.labels += 1;
+        }
+
+        self.depth += 1;
+
+        is_outermost
+    }
+
+    #[inline]
+    pub fn end_loop(&mut self) {
+        self.depth = self.depth.saturating_sub(1);
+    }
+}
+
+impl CLoopContext {
+    #[inline]
+    pub fn use_continueall_label(&mut self) -> Option<String> {
+        self.continueall_label.as_mut().map(|(label, used)| {
+            *used = true;
+            label.clone()
+        })
+    }
+
+    #[inline]
+    pub fn use_breakall_label(&mut self) -> Option<String> {
+        self.breakall_label.as_mut().map(|(label, used)| {
+            *used = true;
+            label.clone()
+        })
+    }
+
+    #[inline]
+    pub fn take_continueall_label(&mut self) -> Option<String> {
+        self.continueall_label
+            .take()
+            .and_then(|(label, used)| used.then_some(label))
+    }
+
+    #[inline]
+    pub fn take_breakall_label(&mut self) -> Option<String> {
+        self.breakall_label
+            .take()
+            .and_then(|(label, used)| used.then_some(label))
+    }
+}
@@ -0,0 +1,384 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+
+use thrustc_ast::{Ast, builtins::AstBuiltin, traits::AstCodeLocation};
+use thrustc_typesystem::{Type, traits::TypeVectorExtensions};
+
+use crate::{abort, context::CCodeGenContext, expressions, typegeneration, utils};
+
+// Without a libc, writes go straight to the standard output through the
+// system call where the convention is known, same as the LLVM backend.
+const FREESTANDING_PRINT_RUNTIME: &str = r#"static void __thrust_write(const char* buffer, size_t length) {
+#if defined(__linux__) && defined(__x86_64__)
+    long result;
+    __asm__ volatile("syscall" : "=a"(result) : "a"(1L), "D"(1L), "S"(buffer), "d"(length) : "rcx", "r11", "memory");
+    (void)result;
+#elif defined(__linux__) && defined(__aarch64__)
+    register long x8 __asm__("x8") = 64;
+    register long x0 __asm__("x0") = 1;
+    register const char* x1 __asm__("x1") = buffer;
+    register size_t x2 __asm__("x2") = length;
+    __asm__ volatile("svc #0" : "+r"(x0) : "r"(x8), "r"(x1), "r"(x2) : "memory");
+#else
+    extern long write(int, const void*, size_t);
+    (void)write(1, buffer, length);
+#endif
+}
+static void __thrust_write_string(const char* string) {
+    size_t length = 0;
+    while (string[length] != '\0') length++;
+    __thrust_write(string, length);
+}
+static void __thrust_write_char(char value) {
+    __thrust_write(&value, 1);
+}
+static void __thrust_write_unsigned(unsigned long long value, bool negative) {
+    char buffer[21];
+    size_t position = sizeof(buffer);
+    do { buffer[--position] = (char)('0' + value % 10); value /= 10; } while (value != 0);
+    if (negative) buffer[--position] = '-';
+    __thrust_write(buffer + position, sizeof(buffer) - position);
+}
+static void __thrust_write_signed(long long value) {
+    unsigned long long magnitude = (unsigned long long)value;
+    __thrust_write_unsigned(value < 0 ? 0ULL - magnitude : magnitude, value < 0);
+}"#;
+
+pub fn compile(context: &mut CCodeGenContext, builtin: &AstBuiltin) -> String {
+    match builtin {
+        AstBuiltin::Halloc { of, .. } => {
+            let ptr_type: String = typegeneration::generate_pointer_type(context, of);
+            let of: String = typegeneration::generate_type(context, of);
+
+            format!("(({})malloc(sizeof({})))", ptr_type, of)
+        }
+
+        AstBuiltin::MemCpy { src, dst, size, .. } => {
+            let dst: String = expressions::compile(context, dst);
+            let src: String = expressions::compile(context, src);
+            let size: String = expressions::compile(context, size);
+
+            format!("memcpy({}, {}, (size_t)({}))", dst, src, size)
+        }
+
+        AstBuiltin::MemMove { src, dst, size, .. } => {
+            let dst: String = expressions::compile(context, dst);
+            let src: String = expressions::compile(context, src);
+            let size: String = expressions::compile(context, size);
+
+            format!("memmove({}, {}, (size_t)({}))", dst, src, size)
+        }
+
+        AstBuiltin::MemSet {
+            dst,
+            new_size,
+            size,
+            ..
+        } => {
+            let dst: String = expressions::compile(context, dst);
+            let new_size: String = expressions::compile(context, new_size);
+            let size: String = expressions::compile(context, size);
+
+            format!("memset({}, (int)({}), (size_t)({}))", dst, new_size, size)
+        }
+
+        AstBuiltin::Splat { of, value, .. } => {
+            let lanes: u32 = of.get_vector_lanes();
+            let element: Type = of.get_vector_base_type();
+
+            let value: String = expressions::compile(context, value);
+            let vector: String = typegeneration::generate_type(context, of);
+            let element: String = typegeneration::generate_type(context, &element);
+
+            let temporary: String = context.new_temporary();
+            let lanes: Vec<&str> = vec![temporary.as_str(); lanes as usize];
+
+            context.write_line(&format!(
+                "{} {} = ({})({});",
+                element, temporary, element, value
+            ));
+
+            format!("(({}){{{}}})", vector, lanes.join(", "))
+        }
+
+        AstBuiltin::Shuffle {
+            left, right, mask, ..
+        } => {
+            let left: String = expressions::compile(context, left);
+            let right: String = expressions::compile(context, right);
+
+            let mask: Vec<String> = mask.iter().map(|lane| lane.to_string()).collect();
+
+            format!(
+                "__builtin_shufflevector({}, {}, {})",
+                left,
+                right,
+                mask.join(", ")
+            )
+        }
+
+        AstBuiltin::VaStart { .. } => {
+            let list: String = context.new_temporary();
+            let last_parameter: String = context.get_last_parameter().unwrap_or("").to_string();
+
+            context.write_line(&format!("va_list {};", list));
+
+            format!("(va_start({}, {}), (void*)&{})", list, last_parameter, list)
+        }
+
+        AstBuiltin::VaArg { list, of, .. } => {
+            let list: String = expressions::compile(context, list);
+            let of: String = typegeneration::generate_type(context, of);
+
+            format!("va_arg(*(va_list*)({}), {})", list, of)
+        }
+
+        AstBuiltin::VaEnd { list, .. } => {
+            let list: String = expressions::compile(context, list);
+            format!("va_end(*(va_list*)({}))", list)
+        }
+
+        AstBuiltin::VaCopy { list, .. } => {
+            let source: String = expressions::compile(context, list);
+            let list: String = context.new_temporary();
+
+            context.write_line(&format!("va_list {};", list));
+
+            format!(
+                "(va_copy({}, *(va_list*)({})), (void*)&{})",
+                list, source, list
+            )
+        }
+
+        AstBuiltin::Print { pieces, args, .. } => {
+            if context.get_compiler_options().is_freestanding() {
+                return self::compile_freestanding_print(context, pieces, args);
+            }
+
+            let mut format: String = String::with_capacity(u8::MAX as usize);
+            let mut values: Vec<String> = Vec::with_capacity(args.len());
+
+            for (position, piece) in pieces.iter().enumerate() {
+                format.push_str(&piece.replace('%', "%%"));
+
+                if let Some(arg) = args.get(position) {
+                    let kind: &Type = expressions::get_type(context, arg);
+                    let value: String = expressions::compile(context, arg);
+
+                    let (specifier, value) = self::print_conversion(kind, value);
+
+                    format.push_str(specifier);
+                    values.push(value);
+                }
+            }
+
+            let format: String = utils::escape_string(format.as_bytes());
+
+            if values.is_empty() {
+                format!("(void)printf({})", format)
+            } else {
+                format!("(void)printf({}, {})", format, values.join(", "))
+            }
+        }
+
+        AstBuiltin::BitSizeOf { of, .. } => {
+            let of: String = typegeneration::generate_type(context, of);
+            format!("(sizeof({}) * 8)", of)
+        }
+
+        AstBuiltin::SizeOf { of, .. } | AstBuiltin::AbiSizeOf { of, .. } => {
+            let of: String = typegeneration::generate_type(context, of);
+            format!("sizeof({})", of)
+        }
+
+        AstBuiltin::AlignOf { of, .. } | AstBuiltin::AbiAlignOf { of, .. } => {
+            let of: String = typegeneration::generate_type(context, of);
+            format!("_Alignof({})", of)
+        }
+    }
+}
+
+fn compile_freestanding_print(
+    context: &mut CCodeGenContext,
+    pieces: &[String],
+    args: &[Ast],
+) -> String {
+    if context.add_print_runtime() {
+        context.write_global(FREESTANDING_PRINT_RUNTIME);
+    }
+
+    let mut writes: Vec<String> = Vec::with_capacity(pieces.len() + args.len());
+
+    for (position, piece) in pieces.iter().enumerate() {
+        if !piece.is_empty() {
+            writes.push(format!(
+                "__thrust_write({}, {})",
+                utils::escape_string(piece.as_bytes()),
+                piece.len()
+            ));
+        }
+
+        if let Some(arg) = args.get(position) {
+            let kind: &Type = expressions::get_type(context, arg);
+            let value: String = expressions::compile(context, arg);
+
+            let write: String = match kind {
+                Type::Const(subtype, ..) => self::freestanding_write(subtype, value),
+                kind => self::freestanding_write(kind, value),
+            }
+            .unwrap_or_else(|| {
+                abort::abort_codegen(
+                    context,
+                    "Failed to format a floating point value in a freestanding build!",
+                    arg.get_span(),
+                    std::path::PathBuf::from(file!()),
+                    line!(),
+                )
+            });
+
+            writes.push(write);
+        }
+    }
+
+    if writes.is_empty() {
+        return "((void)0)".into();
+    }
+
+    format!("((void)({}))", writes.join(", "))
+}
+
+fn freestanding_write(kind: &Type, value: String) -> Option<String> {
+    match kind {
+        Type::S8 { .. }
+        | Type::S16 { .. }
+        | Type::S32 { .. }
+        | Type::S64 { .. }
+        | Type::SSize { .. } => Some(format!("__thrust_write_signed((long long)({}))", value)),
+        Type::U8 { .. }
+        | Type::U16 { .. }
+        | Type::U32 { .. }
+        | Type::U64 { .. }
+        | Type::USize { .. } => Some(format!(
+            "__thrust_write_unsigned((unsigned long long)({}), false)",
+            value
+        )),
+
+        Type::F16 { .. } | Type::BF16 { .. } | Type::F32 { .. } | Type::F64 { .. } => None,
+
+        Type::Char(..) => Some(format!("__thrust_write_char((char)({}))", value)),
+        Type::Bool(..) => Some(format!(
+            "__thrust_write_string(({}) ? \"true\" : \"false\")",
+            value
+        )),
+
+        _ => Some(format!("__thrust_write_string((const char*)({}))", value)),
+    }
+}
+
+fn print_conversion(kind: &Type, value: String) -> (&'static str, String) {
+    match kind {
+        Type::Const(subtype, ..) => self::print_conversion(subtype, value),
+
+        Type::S8 { .. } | Type::S16 { .. } | Type::S32 { .. } | Type::S64 { .. } => {
+            ("%lld", format!("(long long)({})", value))
+        }
+        Type::U8 { .. } | Type::U16 { .. } | Type::U32 { .. } | Type::U64 { .. } => {
+            ("%llu", format!("(unsigned long long)({})", value))
+        }
+        Type::SSize { .. } => ("%td", format!("(ptrdiff_t)({})", value)),
+        Type::USize { .. } => ("%zu", format!("(size_t)({})", value)),
+
+        Type::F16 { .. } | Type::BF16 { .. } | Type::F32 { .. } | Type::F64 { .. } => {
+            ("%g", format!("(double)({})", value))
+        }
+
+        Type::Char(..) => ("%c", format!("(int)({})", value)),
+        Type::Bool(..) => ("%s", format!("(({}) ? \"true\" : \"false\")", value)),
+
+        _ => ("%s", format!("(const char*)({})", value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use thrustc_ast::{Ast, NodeId, builtins::AstBuiltin};
+    use thrustc_diagnostician::Diagnostician;
+    use thrustc_options::{CompilationUnit, CompilerOptions};
+    use thrustc_span::Span;
+    use thrustc_typesystem::Type;
+
+    use crate::context::CCodeGenContext;
+
+    fn print(options: &CompilerOptions) -> (String, String) {
+        let file: CompilationUnit = CompilationUnit::new(
+            "test".into(),
+            PathBuf::from("test.thrust"),
+            String::new(),
+            "test".into(),
+        );
+
+        let mut context: CCodeGenContext =
+            CCodeGenContext::new(Diagnostician::new(&file, options), options);
+
+        let builtin: AstBuiltin = AstBuiltin::Print {
+            pieces: vec!["value: ".into(), "\n".into()],
+            args: vec![Ast::Integer {
+                kind: Type::S32 {
+                    span: Span::nothing(),
+                },
+                value: 7,
+                signed: true,
+                span: Span::nothing(),
+                id: NodeId::new(),
+            }],
+            span: Span::nothing(),
+        };
+
+        let expression: String = super::compile(&mut context, &builtin);
+
+        (expression, context.get_source())
+    }
+
+    #[test]
+    fn print_uses_printf_in_hosted_builds() {
+        let (expression, source): (String, String) = self::print(&CompilerOptions::new());
+
+        assert!(expression.starts_with("(void)printf(\"value: %lld\\n\""));
+        assert!(source.contains("#include <stdio.h>"));
+        assert!(!source.contains("__thrust_write"));
+    }
+
+    #[test]
+    fn print_writes_directly_in_freestanding_builds() {
+        let mut options: CompilerOptions = CompilerOptions::new();
+        options.set_freestanding();
+
+        let (expression, source): (String, String) = self::print(&options);
+
+        assert_eq!(
+            expression,
+            "((void)(__thrust_write(\"value: \", 7), __thrust_write_signed((long long)(((int32_t)-7ULL))), __thrust_write(\"\\n\", 1)))"
+        );
+        assert!(!source.contains("printf"));
+        assert!(!source.contains("#include <stdio.h>"));
+        assert_eq!(source.matches("static void __thrust_write(").count(), 1);
+    }
+}
@@ -0,0 +1,85 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+
+use thrustc_ast::Ast;
+
+use crate::{context::CCodeGenContext, declarations};
+
+#[derive(Debug)]
+pub struct CCodegen<'a, 'b, 'ctx> {
+    context: &'a mut CCodeGenContext<'b>,
+    ast: &'ctx [Ast<'ctx>],
+}
+
+impl<'a, 'b, 'ctx> CCodegen<'a, 'b, 'ctx> {
+    pub fn codegen(context: &'a mut CCodeGenContext<'b>, ast: &'ctx [Ast<'ctx>]) {
+        Self { context, ast }.compile();
+    }
+}
+
+impl CCodegen<'_, '_, '_> {
+    fn compile(&mut self) {
+        self.init_top_entities();
+
+        for node in self.ast.iter() {
+            self.codegen_declaration(node);
+        }
+    }
+
+    fn init_top_entities(&mut self) {
+        declarations::compile_structures(self.context, self.ast);
+
+        for node in self.ast.iter() {
+            match node {
+                Ast::Function { .. } | Ast::AssemblerFunction { .. } | Ast::Intrinsic { .. } => {
+                    declarations::compile_function_prototype(self.context, node);
+                }
+
+                Ast::Const { .. } | Ast::Static { .. } => {
+                    declarations::compile_global(self.context, node);
+                }
+
+                Ast::Implementation { .. } => {
+                    declarations::compile_vtable(self.context, node);
+                }
+
+                Ast::GlobalAssembler { asm, .. } => {
+                    let asm: String = crate::utils::escape_string(asm.as_bytes());
+                    self.context.write_assembly(&format!("__asm__({});", asm));
+                }
+
+                _ => {}
+            }
+        }
+    }
+
+    fn codegen_declaration(&mut self, node: &Ast) {
+        match node {
+            Ast::Function { body, .. } if body.is_some() => {
+                declarations::compile_function(self.context, node);
+            }
+
+            Ast::AssemblerFunction { .. } => {
+                declarations::compile_asm_function(self.context, node);
+            }
+
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,367 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+
+use ahash::{AHashMap as HashMap, AHashSet as HashSet};
+
+use thrustc_diagnostician::Diagnostician;
+use thrustc_options::CompilerOptions;
+use thrustc_typesystem::Type;
+
+use crate::{branch_context::CLoopContext, table::CSymbolsTable};
+
+#[derive(Debug)]
+pub struct CCodeGenContext<'a> {
+    table: CSymbolsTable,
+    loop_ctx: CLoopContext,
+    structs: HashMap<String, Vec<String>>,
+    constants: HashMap<String, String>,
+    inline_constants: bool,
+
+    error_unions: HashSet<String>,
+    interfaces: HashSet<String>,
+    print_runtime: bool,
+    return_type: Option<Type>,
+    last_parameter: Option<String>,
+    defers: Vec<String>,
+
+    forward_declarations: String,
+    structures: String,
+    assembly: String,
+    prototypes: String,
+    globals: String,
+    functions: String,
+
+    indentation: usize,
+    temporaries: usize,
+
+    diagnostician: Diagnostician,
+    options: &'a CompilerOptions,
+}
+
+impl<'a> CCodeGenContext<'a> {
+    #[inline]
+    pub fn new(diagnostician: Diagnostician, options: &'a CompilerOptions) -> Self {
+        Self {
+            table: CSymbolsTable::new(),
+            loop_ctx: CLoopContext::new(),
+            structs: HashMap::with_capacity(u8::MAX as usize),
+            constants: HashMap::with_capacity(u8::MAX as usize),
+            inline_constants: false,
+
+            error_unions: HashSet::with_capacity(u8::MAX as usize),
+            interfaces: HashSet::with_capacity(u8::MAX as usize),
+            print_runtime: false,
+            return_type: None,
+            last_parameter: None,
+            defers: Vec::with_capacity(u8::MAX as usize),
+
+            forward_declarations: String::with_capacity(u8::MAX as usize),
+            structures: String::with_capacity(u8::MAX as usize),
+            assembly: String::with_capacity(u8::MAX as usize),
+            prototypes: String::with_capacity(u8::MAX as usize),
+            globals: String::with_capacity(u8::MAX as usize),
+            functions: String::with_capacity(u8::MAX as usize),
+
+            indentation: 0,
+            temporaries: 0,
+
+            diagnostician,
+            options,
+        }
+    }
+}
+
+impl CCodeGenContext<'_> {
+    pub fn get_source(&self) -> String {
+        let mut source: String = String::with_capacity(
+            self.forward_declarations.len()
+                + self.structures.len()
+                + self.assembly.len()
+                + self.prototypes.len()
+                + self.globals.len()
+                + self.functions.len()
+                + u8::MAX as usize,
+        );
+
+        source.push_str(&format!(
+            "/* Generated by {}. Do not edit. */\n\n",
+            thrustc_constants::COMPILER_ID
+        ));
+
+        source.push_str("#include <stdarg.h>\n");
+        source.push_str("#include <stdint.h>\n");
+        source.push_str("#include <stdbool.h>\n");
+        source.push_str("#include <stddef.h>\n");
+
+        if !self.options.is_freestanding() {
+            source.push_str("#include <stdio.h>\n");
+        }
+
+        source.push_str("#include <stdlib.h>\n");
+        source.push_str("#include <string.h>\n\n");
+
+        source.push_str("#if defined(__x86_64__) || defined(__i386__)\n");
+        source.push_str(
+            "#define __THRUST_ASM_INTEL(code) \".intel_syntax noprefix\\n\\t\" code \"\\n\\t.att_syntax prefix\"\n",
+        );
+        source.push_str("#else\n");
+        source.push_str("#define __THRUST_ASM_INTEL(code) code\n");
+        source.push_str("#endif\n");
+
+        for section in [
+            &self.forward_declarations,
+            &self.structures,
+            &self.assembly,
+            &self.prototypes,
+            &self.globals,
+            &self.functions,
+        ] {
+            if !section.is_empty() {
+                source.push('\n');
+                source.push_str(section);
+            }
+        }
+
+        source
+    }
+}
+
+impl CCodeGenContext<'_> {
+    #[inline]
+    pub fn write_forward_declaration(&mut self, declaration: &str) {
+        self.forward_declarations.push_str(declaration);
+        self.forward_declarations.push('\n');
+    }
+
+    #[inline]
+    pub fn write_structure(&mut self, structure: &str) {
+        self.structures.push_str(structure);
+        self.structures.push('\n');
+    }
+
+    #[inline]
+    pub fn write_assembly(&mut self, assembly: &str) {
+        self.assembly.push_str(assembly);
+        self.assembly.push('\n');
+    }
+
+    #[inline]
+    pub fn write_prototype(&mut self, prototype: &str) {
+        self.prototypes.push_str(prototype);
+        self.prototypes.push('\n');
+    }
+
+    #[inline]
+    pub fn write_global(&mut self, global: &str) {
+        self.globals.push_str(global);
+        self.globals.push('\n');
+    }
+
+    pub fn write_line(&mut self, line: &str) {
+        for _ in 0..self.indentation {
+            self.functions.push_str("    ");
+        }
+
+        self.functions.push_str(line);
+        self.functions.push('\n');
+    }
+
+    #[inline]
+    pub fn write_label(&mut self, label: &str) {
+        self.functions.push_str(&format!("{}: ;\n", label));
+    }
+
+    pub fn capture(&mut self, compile: impl FnOnce(&mut Self)) -> String {
+        let functions: String = std::mem::take(&mut self.functions);
+        let indentation: usize = std::mem::take(&mut self.indentation);
+
+        compile(self);
+
+        let captured: String = std::mem::replace(&mut self.functions, functions);
+        self.indentation = indentation;
+
+        captured
+            .lines()
+            .map(str::trim)
+            .collect::<Vec<&str>>()
+            .join(" ")
+    }
+
+    pub fn capture_expression(
+        &mut self,
+        compile: impl FnOnce(&mut Self) -> String,
+    ) -> (String, String) {
+        let mut value: String = String::new();
+        let prelude: String = self.capture(|context| value = compile(context));
+
+        (prelude, value)
+    }
+}
+
+impl CCodeGenContext<'_> {
+    #[inline]
+    pub fn indent(&mut self) {
+        self.indentation += 1;
+    }
+
+    #[inline]
+    pub fn dedent(&mut self) {
+        self.indentation = self.indentation.saturating_sub(1);
+    }
+
+    #[inline]
+    pub fn new_temporary(&mut self) -> String {
+        self.temporaries += 1;
+        format!("__thrust_tmp_{}", self.temporaries)
+    }
+}
+
+impl CCodeGenContext<'_> {
+    #[inline]
+    pub fn add_struct(&mut self, name: &str, fields: Vec<String>) {
+        self.structs.insert(name.to_string(), fields);
+    }
+
+    #[inline]
+    pub fn get_struct_field(&self, name: &str, index: u32) -> String {
+        self.structs
+            .get(name)
+            .and_then(|fields| fields.get(index as usize))
+            .cloned()
+            .unwrap_or_else(|| format!("f{}", index))
+    }
+}
+
+impl CCodeGenContext<'_> {
+    #[inline]
+    pub fn add_error_union(&mut self, name: &str) -> bool {
+        self.error_unions.insert(name.to_string())
+    }
+
+    #[inline]
+    pub fn add_interface(&mut self, name: &str) -> bool {
+        self.interfaces.insert(name.to_string())
+    }
+
+    #[inline]
+    pub fn add_print_runtime(&mut self) -> bool {
+        !std::mem::replace(&mut self.print_runtime, true)
+    }
+
+    #[inline]
+    pub fn set_return_type(&mut self, return_type: &Type) {
+        self.return_type = Some(return_type.clone());
+    }
+
+    #[inline]
+    pub fn unset_return_type(&mut self) {
+        self.return_type = None;
+    }
+
+    #[inline]
+    pub fn get_return_type(&self) -> Option<&Type> {
+        self.return_type.as_ref()
+    }
+
+    #[inline]
+    pub fn set_last_parameter(&mut self, parameter: Option<String>) {
+        self.last_parameter = parameter;
+    }
+
+    #[inline]
+    pub fn get_last_parameter(&self) -> Option<&str> {
+        self.last_parameter.as_deref()
+    }
+
+    #[inline]
+    pub fn push_defers(&mut self, defers: String) {
+        self.defers.push(defers);
+    }
+
+    #[inline]
+    pub fn pop_defers(&mut self) {
+        self.defers.pop();
+    }
+
+    #[inline]
+    pub fn clear_last_defers(&mut self) {
+        if let Some(defers) = self.defers.last_mut() {
+            defers.clear();
+        }
+    }
+
+    #[inline]
+    pub fn get_pending_defers(&self) -> String {
+        self.defers
+            .iter()
+            .rev()
+            .filter(|defers| !defers.is_empty())
+            .cloned()
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+}
+
+impl CCodeGenContext<'_> {
+    #[inline]
+    pub fn add_constant(&mut self, name: &str, value: String) {
+        self.constants.insert(name.to_string(), value);
+    }
+
+    #[inline]
+    pub fn get_constant(&self, name: &str) -> Option<&str> {
+        if !self.inline_constants {
+            return None;
+        }
+
+        self.constants.get(name).map(|value| value.as_str())
+    }
+
+    #[inline]
+    pub fn set_inline_constants(&mut self, inline_constants: bool) {
+        self.inline_constants = inline_constants;
+    }
+}
+
+impl<'a> CCodeGenContext<'a> {
+    #[inline]
+    pub fn get_table(&self) -> &CSymbolsTable {
+        &self.table
+    }
+
+    #[inline]
+    pub fn get_mut_table(&mut self) -> &mut CSymbolsTable {
+        &mut self.table
+    }
+
+    #[inline]
+    pub fn get_mut_loop_ctx(&mut self) -> &mut CLoopContext {
+        &mut self.loop_ctx
+    }
+
+    #[inline]
+    pub fn get_mut_diagnostician(&mut self) -> &mut Diagnostician {
+        &mut self.diagnostician
+    }
+
+    #[inline]
+    pub fn get_compiler_options(&self) -> &'a CompilerOptions {
+        self.options
+    }
+}
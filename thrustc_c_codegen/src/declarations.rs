@@ -0,0 +1,725 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+
+use ahash::{AHashMap as HashMap, AHashSet as HashSet};
+
+use thrustc_ast::{Ast, traits::AstCodeBlockEntensions};
+use thrustc_attributes::{
+    ThrustAttribute, ThrustAttributeComparator, ThrustAttributes, linkage::ThrustLinkage,
+    traits::ThrustAttributesExtensions,
+};
+use thrustc_span::Span;
+use thrustc_typesystem::{
+    Type,
+    traits::{TypeCodeLocation, TypeIsExtensions, TypePointerExtensions},
+};
+
+use crate::{
+    abort, context::CCodeGenContext, expressions, inlineasm, statements, typegeneration, utils,
+};
+
+pub fn compile_structures(context: &mut CCodeGenContext, ast: &[Ast]) {
+    let mut structures: HashMap<&str, &Ast> = HashMap::with_capacity(u8::MAX as usize);
+    let mut order: Vec<&str> = Vec::with_capacity(u8::MAX as usize);
+
+    for node in ast.iter() {
+        if let Ast::Struct { name, data, .. } = node {
+            let fields: Vec<String> = data
+                .1
+                .iter()
+                .map(|(field_name, ..)| utils::sanitize_identifier(field_name))
+                .collect();
+
+            context.add_struct(name, fields);
+            context.write_forward_declaration(&format!(
+                "{} {};",
+                utils::aggregate_keyword(data.2.is_union()),
+                utils::sanitize_identifier(name)
+            ));
+
+            structures.insert(*name, node);
+            order.push(*name);
+        }
+    }
+
+    let mut emitted: HashSet<String> = HashSet::with_capacity(structures.len());
+
+    for name in order {
+        self::compile_structure(context, &structures, &mut emitted, name);
+    }
+}
+
+fn compile_structure(
+    context: &mut CCodeGenContext,
+    structures: &HashMap<&str, &Ast>,
+    emitted: &mut HashSet<String>,
+    name: &str,
+) {
+    if emitted.contains(name) {
+        return;
+    }
+
+    emitted.insert(name.to_string());
+
+    let Some(Ast::Struct {
+        data, attributes, ..
+    }) = structures.get(name).copied()
+    else {
+        return;
+    };
+
+    for (_, kind, ..) in data.1.iter() {
+        if let Some(dependency) = self::get_value_dependency(kind) {
+            self::compile_structure(context, structures, emitted, dependency);
+        }
+    }
+
+    let mut structure: String = format!(
+        "{} {} {{\n",
+        utils::aggregate_keyword(data.2.is_union()),
+        utils::sanitize_identifier(name)
+    );
+
+    for (field_name, kind, _, _, bit_width) in data.1.iter() {
+        let field: String = typegeneration::generate_declaration(
+            context,
+            kind,
+            &utils::sanitize_identifier(field_name),
+        );
+
+        match bit_width {
+            Some(width) => structure.push_str(&format!("    {} : {};\n", field, width)),
+            None => structure.push_str(&format!("    {};\n", field)),
+        }
+    }
+
+    structure.push('}');
+
+    if data.2.llvm().is_packed()
+        || attributes
+            .match_attr(ThrustAttributeComparator::Packed)
+            .is_some()
+    {
+        structure.push_str(" __attribute__((packed))");
+    }
+
+    if let Some(ThrustAttribute::Align(align, ..)) =
+        attributes.get_attr(ThrustAttributeComparator::Align)
+    {
+        structure.push_str(&format!(" __attribute__((aligned({})))", align));
+    }
+
+    structure.push_str(";\n");
+
+    context.write_structure(&structure);
+}
+
+fn get_value_dependency(kind: &Type) -> Option<&str> {
+    match kind {
+        Type::Struct { name, .. } => Some(name.as_str()),
+        Type::FixedArray(inner, ..) | Type::Const(inner, ..) => self::get_value_dependency(inner),
+
+        _ => None,
+    }
+}
+
+pub fn compile_function_prototype(context: &mut CCodeGenContext, node: &Ast) {
+    match node {
+        Ast::Function {
+            name,
+            ascii_name,
+            parameter_types,
+            body,
+            return_type,
+            attributes,
+            ..
+        } => {
+            let symbol: String = utils::symbol_name(attributes, ascii_name);
+
+            let storage: &str = if body.is_some() && !attributes.has_public_attribute() {
+                "static "
+            } else {
+                ""
+            };
+
+            let parameters: Vec<String> = parameter_types
+                .iter()
+                .map(|kind| typegeneration::generate_type(context, kind))
+                .collect();
+
+            let signature: String = self::generate_signature(
+                context,
+                return_type,
+                &symbol,
+                parameters,
+                attributes.has_ignore_attribute(),
+            );
+
+            let attributes: String = self::generate_function_attributes(attributes);
+
+            context.write_prototype(&format!("{}{}{};", storage, attributes, signature));
+            context.get_mut_table().new_global(name, symbol);
+        }
+
+        Ast::AssemblerFunction {
+            name,
+            ascii_name,
+            parameters_types,
+            return_type,
+            attributes,
+            ..
+        } => {
+            let symbol: String = utils::symbol_name(attributes, ascii_name);
+
+            let storage: &str = if attributes.has_public_attribute() {
+                ""
+            } else {
+                "static "
+            };
+
+            let parameters: Vec<String> = parameters_types
+                .iter()
+                .map(|kind| typegeneration::generate_type(context, kind))
+                .collect();
+
+            let signature: String =
+                self::generate_signature(context, return_type, &symbol, parameters, false);
+
+            let attributes: String = self::generate_function_attributes(attributes);
+
+            context.write_prototype(&format!("{}{}{};", storage, attributes, signature));
+            context.get_mut_table().new_global(name, symbol);
+        }
+
+        Ast::Intrinsic {
+            name,
+            external_name,
+            parameters_types,
+            return_type,
+            span,
+            ..
+        } => {
+            if !external_name.starts_with("llvm.") {
+                let parameters: Vec<String> = parameters_types
+                    .iter()
+                    .map(|kind| typegeneration::generate_type(context, kind))
+                    .collect();
+
+                let signature: String = self::generate_signature(
+                    context,
+                    return_type,
+                    external_name,
+                    parameters,
+                    false,
+                );
+
+                context.write_prototype(&format!("{};", signature));
+                context
+                    .get_mut_table()
+                    .new_global(name, external_name.to_string());
+
+                return;
+            }
+
+            let Some(builtin) = self::get_intrinsic_builtin(external_name) else {
+                abort::abort_codegen(
+                    context,
+                    &format!(
+                        "The intrinsic '{}' has no equivalent in the C backend!",
+                        external_name
+                    ),
+                    *span,
+                    std::path::PathBuf::from(file!()),
+                    line!(),
+                );
+            };
+
+            context.get_mut_table().new_global(name, builtin);
+        }
+
+        _ => {}
+    }
+}
+
+pub fn compile_function(context: &mut CCodeGenContext, node: &Ast) {
+    let Ast::Function {
+        name,
+        parameters,
+        body: Some(body),
+        return_type,
+        attributes,
+        span,
+        ..
+    } = node
+    else {
+        return;
+    };
+
+    let symbol: String = self::get_global_symbol(context, name, *span);
+
+    context.get_mut_table().begin_function();
+
+    let mut generated_parameters: Vec<String> = Vec::with_capacity(parameters.len());
+    let mut last_parameter: Option<String> = None;
+
+    for parameter in parameters.iter() {
+        if let Ast::FunctionParameter { name, kind, .. } = parameter {
+            let parameter_name: String = context.get_mut_table().new_local(name);
+
+            generated_parameters.push(typegeneration::generate_declaration(
+                context,
+                kind,
+                &parameter_name,
+            ));
+
+            last_parameter = Some(parameter_name);
+        }
+    }
+
+    let storage: &str = if attributes.has_public_attribute() {
+        ""
+    } else {
+        "static "
+    };
+
+    let signature: String = self::generate_signature(
+        context,
+        return_type,
+        &symbol,
+        generated_parameters,
+        attributes.has_ignore_attribute(),
+    );
+
+    context.write_line(&format!("{}{}", storage, signature));
+    context.set_return_type(return_type);
+    context.set_last_parameter(last_parameter);
+
+    if return_type.is_error_union_type() && !body.has_terminator() {
+        let kind: String = typegeneration::generate_type(context, return_type);
+
+        context.write_line("{");
+        context.indent();
+
+        statements::compile_block(context, body);
+        context.write_line(&format!("return ({}){{0}};", kind));
+
+        context.dedent();
+        context.write_line("}");
+    } else {
+        statements::compile_block(context, body);
+    }
+
+    context.unset_return_type();
+    context.set_last_parameter(None);
+    context.write_line("");
+
+    context.get_mut_table().end_function();
+}
+
+pub fn compile_asm_function(context: &mut CCodeGenContext, node: &Ast) {
+    let Ast::AssemblerFunction {
+        name,
+        parameters,
+        assembler,
+        constraints,
+        return_type,
+        attributes,
+        span,
+        ..
+    } = node
+    else {
+        return;
+    };
+
+    let symbol: String = self::get_global_symbol(context, name, *span);
+
+    context.get_mut_table().begin_function();
+
+    let mut generated_parameters: Vec<String> = Vec::with_capacity(parameters.len());
+    let mut args: Vec<(String, Type)> = Vec::with_capacity(parameters.len());
+
+    for parameter in parameters.iter() {
+        if let Ast::AssemblerFunctionParameter { name, kind, .. } = parameter {
+            let parameter_name: String = context.get_mut_table().new_local(name);
+
+            generated_parameters.push(typegeneration::generate_declaration(
+                context,
+                kind,
+                &parameter_name,
+            ));
+
+            args.push((parameter_name, kind.clone()));
+        }
+    }
+
+    let storage: &str = if attributes.has_public_attribute() {
+        ""
+    } else {
+        "static "
+    };
+
+    let signature: String =
+        self::generate_signature(context, return_type, &symbol, generated_parameters, false);
+
+    context.write_line(&format!("{}{}", storage, signature));
+    context.write_line("{");
+    context.indent();
+
+    let result: Option<String> = if return_type.is_void_type() {
+        None
+    } else {
+        Some(context.new_temporary())
+    };
+
+    let mut statements: Vec<String> = Vec::with_capacity(args.len() + 1);
+
+    if let Some(result) = &result {
+        statements.push(format!(
+            "{};",
+            typegeneration::generate_declaration(context, return_type, result)
+        ));
+    }
+
+    let asm: String = inlineasm::compile_asm_statement(
+        context,
+        &mut statements,
+        assembler,
+        constraints,
+        result.as_deref().map(|result| (result, return_type)),
+        &args,
+        attributes,
+        *span,
+    );
+
+    statements.push(asm);
+
+    if let Some(result) = result {
+        statements.push(format!("return {};", result));
+    }
+
+    for statement in statements.iter() {
+        context.write_line(statement);
+    }
+
+    context.dedent();
+    context.write_line("}");
+    context.write_line("");
+
+    context.get_mut_table().end_function();
+}
+
+pub fn compile_vtable(context: &mut CCodeGenContext, node: &Ast) {
+    let Ast::Implementation {
+        interface,
+        target,
+        methods,
+        span,
+        ..
+    } = node
+    else {
+        return;
+    };
+
+    let mut entries: Vec<String> = Vec::with_capacity(methods.len());
+
+    for method in methods.iter() {
+        let Some(symbol) = context.get_table().get_symbol(method) else {
+            abort::abort_codegen(
+                context,
+                &format!("Unable to find the symbol '{}'!", method),
+                *span,
+                std::path::PathBuf::from(file!()),
+                line!(),
+            );
+        };
+
+        entries.push(format!("(void*){}", symbol));
+    }
+
+    context.write_global(&format!(
+        "static void* const {}[] = {{ {} }};",
+        self::vtable_name(target, interface),
+        entries.join(", ")
+    ));
+}
+
+#[inline]
+pub fn vtable_name(target: &str, interface: &str) -> String {
+    format!(
+        "__thrust_vtable_{}_{}",
+        utils::sanitize_identifier(target),
+        utils::sanitize_identifier(interface)
+    )
+}
+
+pub fn compile_global(context: &mut CCodeGenContext, node: &Ast) {
+    match node {
+        Ast::Static {
+            name,
+            ascii_name,
+            kind,
+            value,
+            attributes,
+            metadata,
+            ..
+        } => {
+            let symbol: String = utils::symbol_name(attributes, ascii_name);
+
+            let mut declaration: String = String::with_capacity(u8::MAX as usize);
+
+            if attributes.has_extern_attribute() && value.is_none() {
+                declaration.push_str("extern ");
+            } else if !attributes.has_public_attribute() && !attributes.has_linkage_attribute() {
+                declaration.push_str("static ");
+            }
+
+            declaration.push_str(&self::generate_global_qualifiers(
+                attributes,
+                metadata.get_llvm_metadata().thread_local,
+                metadata.get_llvm_metadata().volatile,
+            ));
+
+            declaration.push_str(&typegeneration::generate_declaration(
+                context, kind, &symbol,
+            ));
+            declaration.push_str(&self::generate_global_attributes(attributes));
+
+            if let Some(value) = value {
+                let value: String = self::compile_constant_initializer(context, value);
+                declaration.push_str(&format!(" = {}", value));
+            }
+
+            declaration.push(';');
+
+            context.write_global(&declaration);
+            context.get_mut_table().new_global(name, symbol);
+        }
+
+        Ast::Const {
+            name,
+            ascii_name,
+            kind,
+            value,
+            attributes,
+            metadata,
+            ..
+        } => {
+            let symbol: String = utils::symbol_name(attributes, ascii_name);
+
+            let mut declaration: String = String::with_capacity(u8::MAX as usize);
+
+            if !attributes.has_public_attribute() && !attributes.has_linkage_attribute() {
+                declaration.push_str("static ");
+            }
+
+            declaration.push_str(&self::generate_global_qualifiers(
+                attributes,
+                metadata.get_llvm_metadata().thread_local,
+                metadata.get_llvm_metadata().volatile,
+            ));
+
+            let initializer: String = self::compile_constant_initializer(context, value);
+
+            declaration.push_str(&typegeneration::generate_declaration(
+                context, kind, &symbol,
+            ));
+            declaration.push_str(&self::generate_global_attributes(attributes));
+            declaration.push_str(&format!(" = {};", initializer));
+
+            if !kind.is_fixed_array_type() && !kind.is_struct_type() {
+                context.add_constant(name, initializer);
+            }
+
+            context.write_global(&declaration);
+            context.get_mut_table().new_global(name, symbol);
+        }
+
+        _ => {}
+    }
+}
+
+pub fn compile_constant_initializer(context: &mut CCodeGenContext, value: &Ast) -> String {
+    context.set_inline_constants(true);
+    let initializer: String = expressions::compile_initializer(context, value);
+    context.set_inline_constants(false);
+
+    initializer
+}
+
+fn get_global_symbol(context: &mut CCodeGenContext, name: &str, span: Span) -> String {
+    if let Some(symbol) = context.get_table().get_symbol(name) {
+        return symbol.to_string();
+    }
+
+    abort::abort_codegen(
+        context,
+        &format!("Unable to find the function '{}'!", name),
+        span,
+        std::path::PathBuf::from(file!()),
+        line!(),
+    )
+}
+
+fn generate_signature(
+    context: &mut CCodeGenContext,
+    return_type: &Type,
+    symbol: &str,
+    mut parameters: Vec<String>,
+    ignore: bool,
+) -> String {
+    if ignore {
+        parameters.push("...".into());
+    } else if parameters.is_empty() {
+        parameters.push("void".into());
+    }
+
+    let declarator: String = format!("{}({})", symbol, parameters.join(", "));
+
+    if return_type.is_fixed_array_type() {
+        abort::abort_codegen(
+            context,
+            "Functions returning fixed arrays can't be lowered to C!",
+            return_type.get_span(),
+            std::path::PathBuf::from(file!()),
+            line!(),
+        );
+    }
+
+    if return_type.is_function_reference_type() || return_type.is_ptr_fixed_array_type() {
+        return typegeneration::generate_declaration(context, return_type, &declarator);
+    }
+
+    format!(
+        "{} {}",
+        typegeneration::generate_type(context, return_type),
+        declarator
+    )
+}
+
+fn generate_function_attributes(attributes: &ThrustAttributes) -> String {
+    let mut generated: Vec<String> = Vec::with_capacity(attributes.len());
+
+    for attribute in attributes.iter() {
+        match attribute {
+            ThrustAttribute::Hot(..) => generated.push("hot".into()),
+            ThrustAttribute::NoInline(..) => generated.push("noinline".into()),
+            ThrustAttribute::AlwaysInline(..) => generated.push("always_inline".into()),
+            ThrustAttribute::MinSize(..) => generated.push("cold".into()),
+            ThrustAttribute::Pure(..) => generated.push("pure".into()),
+            ThrustAttribute::Constructor(..) => generated.push("constructor".into()),
+            ThrustAttribute::Destructor(..) => generated.push("destructor".into()),
+
+            ThrustAttribute::Convention(convention, ..) => {
+                if let Some(convention) = utils::calling_convention(convention) {
+                    generated.push(convention.into());
+                }
+            }
+
+            ThrustAttribute::Linkage(linkage, ..) => {
+                if let Some(linkage) = self::linkage_attribute(linkage) {
+                    generated.push(linkage.into());
+                }
+            }
+
+            _ => {}
+        }
+    }
+
+    if generated.is_empty() {
+        return String::new();
+    }
+
+    format!("__attribute__(({})) ", generated.join(", "))
+}
+
+fn generate_global_qualifiers(
+    attributes: &ThrustAttributes,
+    thread_local: bool,
+    volatile: bool,
+) -> String {
+    let mut qualifiers: String = String::with_capacity(u8::MAX as usize);
+
+    if thread_local {
+        qualifiers.push_str("_Thread_local ");
+    }
+
+    if let Some(ThrustAttribute::Align(align, ..)) =
+        attributes.get_attr(ThrustAttributeComparator::Align)
+    {
+        qualifiers.push_str(&format!("_Alignas({}) ", align));
+    }
+
+    if volatile {
+        qualifiers.push_str("volatile ");
+    }
+
+    qualifiers
+}
+
+fn generate_global_attributes(attributes: &ThrustAttributes) -> String {
+    match attributes.get_attr(ThrustAttributeComparator::Linkage) {
+        Some(ThrustAttribute::Linkage(linkage, ..)) => self::linkage_attribute(&linkage)
+            .map(|linkage| format!(" __attribute__(({}))", linkage))
+            .unwrap_or_default(),
+
+        _ => String::new(),
+    }
+}
+
+fn linkage_attribute(linkage: &ThrustLinkage) -> Option<&'static str> {
+    match linkage {
+        ThrustLinkage::Weak | ThrustLinkage::ExternalWeak | ThrustLinkage::LinkerPrivateWeak => {
+            Some("weak")
+        }
+        ThrustLinkage::Common => Some("common"),
+        ThrustLinkage::DLLImport => Some("dllimport"),
+        ThrustLinkage::DLLExport => Some("dllexport"),
+        ThrustLinkage::Internal | ThrustLinkage::LinkerPrivate => Some("visibility(\"hidden\")"),
+        ThrustLinkage::Standard => None,
+    }
+}
+
+fn get_intrinsic_builtin(external_name: &str) -> Option<String> {
+    let mut segments = external_name.trim_start_matches("llvm.").split('.');
+
+    let name: &str = segments.next()?;
+    let suffix: &str = match segments.next() {
+        Some("f32") => "f",
+        Some("f80") | Some("f128") => "l",
+        _ => "",
+    };
+
+    match name {
+        "trap" => Some("__builtin_trap".into()),
+        "debugtrap" => Some("__builtin_trap".into()),
+        "prefetch" => Some("__builtin_prefetch".into()),
+        "returnaddress" => Some("__builtin_return_address".into()),
+        "frameaddress" => Some("__builtin_frame_address".into()),
+
+        "sqrt" | "sin" | "cos" | "exp" | "exp2" | "log" | "log2" | "log10" | "fabs" | "floor"
+        | "ceil" | "trunc" | "rint" | "nearbyint" | "round" | "pow" | "fma" | "copysign" => {
+            Some(format!("__builtin_{}{}", name, suffix))
+        }
+
+        "minnum" => Some(format!("__builtin_fmin{}", suffix)),
+        "maxnum" => Some(format!("__builtin_fmax{}", suffix)),
+
+        _ => None,
+    }
+}
@@ -0,0 +1,754 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+
+use thrustc_ast::{
+    Ast,
+    data::{ConstructorData, PropertyData},
+    metadata::{DereferenceMetadata, LLVMDereferenceMetadata},
+    traits::{AstCodeLocation, AstGetType, AstMemoryExtensions, AstPropertyDataFieldExtensions},
+};
+use thrustc_mir::atomicord::ThrustAtomicOrdering;
+use thrustc_span::Span;
+use thrustc_token_type::TokenType;
+use thrustc_typesystem::{
+    Type,
+    traits::{
+        DereferenceExtensions, InfererTypeExtensions, TypeIsExtensions, TypePointerExtensions,
+        TypeStructExtensions, TypeVectorExtensions,
+    },
+};
+
+use crate::{
+    abort, builtins, context::CCodeGenContext, declarations, inlineasm, typegeneration, utils,
+};
+
+pub fn compile(context: &mut CCodeGenContext, expr: &Ast) -> String {
+    match expr {
+        // Literals
+        Ast::Integer {
+            kind,
+            value,
+            signed,
+            ..
+        } => {
+            let kind: String = typegeneration::generate_type(context, kind);
+            let value: String = self::integer_literal(*value);
+
+            if *signed {
+                format!("(({})-{})", kind, value)
+            } else {
+                format!("(({}){})", kind, value)
+            }
+        }
+
+        Ast::Float {
+            kind,
+            value,
+            signed,
+            ..
+        } => {
+            let kind: String = typegeneration::generate_type(context, kind);
+            let sign: &str = if *signed { "-" } else { "" };
+
+            if value.is_nan() {
+                format!("(({})__builtin_nan(\"\"))", kind)
+            } else if value.is_infinite() {
+                format!("(({}){}__builtin_inf())", kind, sign)
+            } else {
+                format!("(({}){}{:?})", kind, sign, value)
+            }
+        }
+
+        Ast::Boolean { value, .. } => {
+            if *value != 0 {
+                "true".into()
+            } else {
+                "false".into()
+            }
+        }
+
+        Ast::Char { byte, .. } => format!("((char){})", byte),
+
+        Ast::CString { bytes, .. } | Ast::CNString { bytes, .. } => utils::escape_string(bytes),
+
+        Ast::NullPtr { .. } => "NULL".into(),
+
+        // Symbols
+        Ast::Reference { name, span, .. } => self::compile_symbol(context, name, *span),
+
+        Ast::DirectRef { expr, .. } => self::compile_as_ptr(context, expr),
+
+        Ast::EnumValue { value, .. } => self::compile(context, value),
+
+        // Memory Access Operations
+        Ast::Property {
+            source, data, span, ..
+        } => self::compile_property(context, source, data, *span),
+
+        Ast::Index { source, index, .. } => self::compile_index(context, source, index),
+
+        Ast::Deref {
+            value,
+            kind,
+            metadata,
+            ..
+        } => self::compile_deref(context, value, kind, metadata),
+
+        Ast::Mut { source, value, .. } => {
+            let is_fixed_array: bool = self::get_type(context, value).is_fixed_array_type();
+
+            let lvalue: String = self::compile_lvalue(context, source);
+            let value: String = self::compile(context, value);
+
+            if is_fixed_array {
+                format!("memcpy(&{}, {}, sizeof({}))", lvalue, value, lvalue)
+            } else {
+                format!("({} = {})", lvalue, value)
+            }
+        }
+
+        // Composite Values
+        Ast::Constructor {
+            name, data, kind, ..
+        } => {
+            let initializer: String = self::compile_constructor(context, data);
+
+            format!(
+                "(({} {}){})",
+                utils::aggregate_keyword(kind.is_union_type()),
+                utils::sanitize_identifier(name),
+                initializer
+            )
+        }
+
+        Ast::FixedArray { items, kind, .. } => {
+            let kind: String = typegeneration::generate_type(context, kind);
+            let initializer: String = self::compile_items(context, items);
+
+            format!("(({}){})", kind, initializer)
+        }
+
+        Ast::Array { items, kind, .. } => {
+            let base_type: Type = match kind {
+                Type::Array { base_type, .. } => (**base_type).clone(),
+                Type::Const(inner, ..) => match &**inner {
+                    Type::Array { base_type, .. } => (**base_type).clone(),
+                    other => other.clone(),
+                },
+                other => other.clone(),
+            };
+
+            let kind: String = typegeneration::generate_declaration(context, &base_type, "[]");
+            let initializer: String = self::compile_items(context, items);
+
+            format!("(({}){})", kind, initializer)
+        }
+
+        // Calls
+        Ast::Call {
+            name, args, span, ..
+        } => {
+            let function: String = self::compile_symbol(context, name, *span);
+            let args: String = self::compile_arguments(context, args);
+
+            format!("{}({})", function, args)
+        }
+
+        Ast::IndirectCall { function, args, .. } => {
+            let function: String = self::compile(context, function);
+            let args: String = self::compile_arguments(context, args);
+
+            format!("({})({})", function, args)
+        }
+
+        Ast::DynamicCall {
+            object,
+            index,
+            function_type,
+            args,
+            ..
+        } => self::compile_dynamic_call(context, object, *index, function_type, args),
+
+        // Interfaces
+        Ast::InterfaceObject {
+            value,
+            interface,
+            target,
+            kind,
+            ..
+        } => {
+            let kind: String = typegeneration::generate_type(context, kind);
+            let value: String = self::compile(context, value);
+
+            format!(
+                "(({}){{ .data = (void*)({}), .vtable = {} }})",
+                kind,
+                value,
+                declarations::vtable_name(target, interface)
+            )
+        }
+
+        Ast::AsmValue {
+            assembler,
+            constraints,
+            args,
+            kind,
+            attributes,
+            span,
+            ..
+        } => inlineasm::compile_value(
+            context,
+            assembler,
+            constraints,
+            args,
+            kind,
+            attributes,
+            *span,
+        ),
+
+        // Operators
+        Ast::BinaryOp {
+            left,
+            operator,
+            right,
+            kind,
+            span,
+            ..
+        } => {
+            let left: String = self::compile(context, left);
+
+            // Anything the right operand hoists must respect the short-circuit.
+            let (prelude, right): (String, String) =
+                context.capture_expression(|context| self::compile(context, right));
+
+            if !prelude.is_empty() && matches!(operator, TokenType::And | TokenType::Or) {
+                return self::compile_short_circuit(context, &left, operator, &prelude, &right);
+            }
+
+            if !prelude.is_empty() {
+                context.write_line(&prelude);
+            }
+
+            let operator: &str = self::binary_operator(context, operator, *span);
+
+            if kind.is_vector_type() && kind.get_vector_base_type().is_bool_type() {
+                let kind: String = typegeneration::generate_type(context, kind);
+
+                return format!(
+                    "__builtin_convertvector(-({} {} {}), {})",
+                    left, operator, right, kind
+                );
+            }
+
+            format!("({} {} {})", left, operator, right)
+        }
+
+        Ast::UnaryOp {
+            operator,
+            node,
+            before,
+            span,
+            ..
+        } => {
+            let value: String = self::compile(context, node);
+
+            match operator {
+                TokenType::PlusPlus if *before => format!("(++{})", value),
+                TokenType::PlusPlus => format!("({}++)", value),
+                TokenType::MinusMinus if *before => format!("(--{})", value),
+                TokenType::MinusMinus => format!("({}--)", value),
+                TokenType::Minus => format!("(-{})", value),
+                TokenType::Bang => format!("(!{})", value),
+                TokenType::Not => format!("(~{})", value),
+
+                _ => abort::abort_codegen(
+                    context,
+                    "Unknown unary operator!",
+                    *span,
+                    std::path::PathBuf::from(file!()),
+                    line!(),
+                ),
+            }
+        }
+
+        Ast::Group { node, .. } => format!("({})", self::compile(context, node)),
+
+        Ast::As { from, cast, .. } => {
+            let value: String = self::compile(context, from);
+            let cast: String = typegeneration::generate_type(context, cast);
+
+            format!("(({})({}))", cast, value)
+        }
+
+        // Builtins
+        Ast::Builtin { builtin, .. } => builtins::compile(context, builtin),
+
+        Ast::Unreachable { .. } => "__builtin_unreachable()".into(),
+
+        Ast::Try {
+            expression,
+            kind,
+            span,
+            ..
+        } => self::compile_try(context, expression, kind, *span),
+
+        Ast::Catch {
+            expression,
+            fallback,
+            kind,
+            ..
+        } => self::compile_catch(context, expression, fallback, kind),
+
+        // Fallback for unsupported AST nodes
+        what => abort::abort_codegen(
+            context,
+            "Unknown expression or statement!",
+            what.get_span(),
+            std::path::PathBuf::from(file!()),
+            line!(),
+        ),
+    }
+}
+
+pub fn compile_as_ptr(context: &mut CCodeGenContext, expr: &Ast) -> String {
+    match expr {
+        Ast::Reference { name, span, .. } => {
+            format!("(&{})", self::compile_symbol(context, name, *span))
+        }
+
+        _ => self::compile(context, expr),
+    }
+}
+
+pub fn compile_initializer(context: &mut CCodeGenContext, expr: &Ast) -> String {
+    match expr {
+        Ast::FixedArray { items, .. } => self::compile_items(context, items),
+        Ast::Constructor { data, .. } => self::compile_constructor(context, data),
+
+        _ => self::compile(context, expr),
+    }
+}
+
+pub fn compile_lvalue(context: &mut CCodeGenContext, source: &Ast) -> String {
+    match source {
+        Ast::Reference { name, span, .. } => self::compile_symbol(context, name, *span),
+
+        Ast::Property { .. } if self::is_bitfield_property(source) => {
+            self::compile(context, source)
+        }
+
+        _ => {
+            let source_type: &Type = self::get_type(context, source);
+            let value_type: Type = match source_type {
+                Type::Ptr(Some(inner), ..) => (**inner).clone(),
+                other => other.clone(),
+            };
+
+            let ptr: String = self::compile(context, source);
+            let ptr_type: String = typegeneration::generate_pointer_type(context, &value_type);
+
+            format!("(*({})({}))", ptr_type, ptr)
+        }
+    }
+}
+
+pub fn get_type<'ast>(context: &mut CCodeGenContext, expr: &'ast Ast) -> &'ast Type {
+    expr.get_any_type().unwrap_or_else(|_| {
+        abort::abort_codegen(
+            context,
+            "Failed to get the type of the expression!",
+            expr.get_span(),
+            std::path::PathBuf::from(file!()),
+            line!(),
+        )
+    })
+}
+
+fn integer_literal(value: u128) -> String {
+    match u64::try_from(value) {
+        Ok(value) => format!("{}ULL", value),
+        Err(_) => format!(
+            "(((unsigned __int128){}ULL << 64) | {}ULL)",
+            value >> 64,
+            value as u64
+        ),
+    }
+}
+
+fn compile_symbol(context: &mut CCodeGenContext, name: &str, span: Span) -> String {
+    if let Some(value) = context.get_constant(name) {
+        return format!("({})", value);
+    }
+
+    if let Some(symbol) = context.get_table().get_symbol(name) {
+        return symbol.to_string();
+    }
+
+    abort::abort_codegen(
+        context,
+        &format!("Unable to find the symbol '{}'!", name),
+        span,
+        std::path::PathBuf::from(file!()),
+        line!(),
+    )
+}
+
+fn compile_arguments(context: &mut CCodeGenContext, args: &[Ast]) -> String {
+    args.iter()
+        .map(|arg| self::compile(context, arg))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+fn compile_items(context: &mut CCodeGenContext, items: &[Ast]) -> String {
+    let items: Vec<String> = items
+        .iter()
+        .map(|item| self::compile_initializer(context, item))
+        .collect();
+
+    format!("{{{}}}", items.join(", "))
+}
+
+fn compile_constructor(context: &mut CCodeGenContext, data: &ConstructorData) -> String {
+    let mut fields: Vec<String> = Vec::with_capacity(data.len());
+
+    for (field_name, value, ..) in data.iter() {
+        let value: String = self::compile_initializer(context, value);
+
+        fields.push(format!(
+            ".{} = {}",
+            utils::sanitize_identifier(field_name),
+            value
+        ));
+    }
+
+    if fields.is_empty() {
+        return "{0}".into();
+    }
+
+    format!("{{{}}}", fields.join(", "))
+}
+
+fn compile_property(
+    context: &mut CCodeGenContext,
+    source: &Ast,
+    data: &PropertyData,
+    span: Span,
+) -> String {
+    let source_type: &Type = self::get_type(context, source);
+
+    let is_allocated: bool = source.is_memory_assigned_value().unwrap_or_else(|_| {
+        abort::abort_codegen(
+            context,
+            "Failed to compile the property!",
+            span,
+            std::path::PathBuf::from(file!()),
+            line!(),
+        )
+    });
+
+    let is_ptr_composite: bool = source_type.is_ptr_composite_type();
+    let is_address: bool = (is_allocated && source_type.is_struct_type()) || is_ptr_composite;
+
+    let mut property: String = self::compile(context, source);
+
+    for (position, field) in data.iter().enumerate() {
+        let base_type: Type = field.get_base_type();
+
+        let struct_name: String = match self::get_struct_type(&base_type) {
+            Some(name) => name,
+            None => abort::abort_codegen(
+                context,
+                "Failed to compile the property!",
+                span,
+                std::path::PathBuf::from(file!()),
+                line!(),
+            ),
+        };
+
+        let field_name: String = context.get_struct_field(&struct_name, field.get_index());
+
+        property = if position == 0 && is_ptr_composite {
+            format!("({})->{}", property, field_name)
+        } else {
+            format!("({}).{}", property, field_name)
+        };
+    }
+
+    // Bitfields have no address in C, they're read and written in place.
+    if is_address && !self::is_bitfield_property_data(data) {
+        format!("(&{})", property)
+    } else {
+        property
+    }
+}
+
+fn compile_short_circuit(
+    context: &mut CCodeGenContext,
+    left: &str,
+    operator: &TokenType,
+    prelude: &str,
+    right: &str,
+) -> String {
+    let result: String = context.new_temporary();
+    let condition: String = if matches!(operator, TokenType::And) {
+        result.clone()
+    } else {
+        format!("!{}", result)
+    };
+
+    context.write_line(&format!("bool {} = ({});", result, left));
+    context.write_line(&format!(
+        "if ({}) {{ {} {} = ({}); }}",
+        condition, prelude, result, right
+    ));
+
+    result
+}
+
+fn compile_dynamic_call(
+    context: &mut CCodeGenContext,
+    object: &Ast,
+    index: u32,
+    function_type: &Type,
+    args: &[Ast],
+) -> String {
+    let object_type: Type = self::get_type(context, object).clone();
+
+    let (interface_type, dereference): (Type, &str) = match object_type {
+        Type::Ptr(Some(inner), ..) => (*inner, "*"),
+        any => (any, ""),
+    };
+
+    let temporary: String = context.new_temporary();
+    let declaration: String =
+        typegeneration::generate_declaration(context, &interface_type, &temporary);
+    let function: String = typegeneration::generate_type(context, function_type);
+
+    let value: String = self::compile(context, object);
+
+    let mut arguments: Vec<String> = Vec::with_capacity(args.len() + 1);
+
+    arguments.push(format!("{}.data", temporary));
+    arguments.extend(args.iter().map(|arg| self::compile(context, arg)));
+
+    context.write_line(&format!("{} = {}({});", declaration, dereference, value));
+
+    format!(
+        "(({}){}.vtable[{}])({})",
+        function,
+        temporary,
+        index,
+        arguments.join(", ")
+    )
+}
+
+fn compile_try(context: &mut CCodeGenContext, expression: &Ast, kind: &Type, span: Span) -> String {
+    let Some(return_type) = context.get_return_type().cloned() else {
+        abort::abort_codegen(
+            context,
+            "Failed to compile the error propagation outside of a function!",
+            span,
+            std::path::PathBuf::from(file!()),
+            line!(),
+        );
+    };
+
+    let union_type: Type = self::get_type(context, expression).clone();
+
+    let temporary: String = context.new_temporary();
+    let declaration: String =
+        typegeneration::generate_declaration(context, &union_type, &temporary);
+    let return_type: String = typegeneration::generate_type(context, &return_type);
+
+    let value: String = self::compile(context, expression);
+    let defers: String = context.get_pending_defers();
+
+    context.write_line(&format!("{} = {};", declaration, value));
+    context.write_line(&format!(
+        "if ({}.error != 0) {{ {} return ({}){{ .error = {}.error }}; }}",
+        temporary, defers, return_type, temporary
+    ));
+
+    if kind.is_void_type() {
+        return "((void)0)".into();
+    }
+
+    format!("{}.value", temporary)
+}
+
+fn compile_catch(
+    context: &mut CCodeGenContext,
+    expression: &Ast,
+    fallback: &Ast,
+    kind: &Type,
+) -> String {
+    let union_type: Type = self::get_type(context, expression).clone();
+
+    let temporary: String = context.new_temporary();
+    let declaration: String =
+        typegeneration::generate_declaration(context, &union_type, &temporary);
+
+    let value: String = self::compile(context, expression);
+
+    context.write_line(&format!("{} = {};", declaration, value));
+
+    // The fallback only runs on error, anything it hoists stays inside the branch.
+    let (prelude, fallback): (String, String) =
+        context.capture_expression(|context| self::compile(context, fallback));
+
+    if kind.is_void_type() {
+        context.write_line(&format!(
+            "if ({}.error != 0) {{ {} (void)({}); }}",
+            temporary, prelude, fallback
+        ));
+
+        return "((void)0)".into();
+    }
+
+    let result: String = context.new_temporary();
+    let declaration: String = typegeneration::generate_declaration(context, kind, &result);
+
+    context.write_line(&format!("{};", declaration));
+    context.write_line(&format!(
+        "if ({}.error != 0) {{ {} {} = {}; }} else {{ {} = {}.value; }}",
+        temporary, prelude, result, fallback, result, temporary
+    ));
+
+    result
+}
+
+fn is_bitfield_property(property: &Ast) -> bool {
+    match property {
+        Ast::Property { data, .. } => self::is_bitfield_property_data(data),
+        _ => false,
+    }
+}
+
+fn is_bitfield_property_data(data: &PropertyData) -> bool {
+    data.last().is_some_and(|field| {
+        field
+            .get_base_type()
+            .dereference_until_value()
+            .get_struct_bitfield_width(field.get_index())
+            .is_some()
+    })
+}
+
+fn compile_index(context: &mut CCodeGenContext, source: &Ast, index: &Ast) -> String {
+    let mut source_type: Type = self::get_type(context, source).clone();
+
+    if source_type.has_inferer_inner_type() && source_type.is_inferer_inner_type_valid() {
+        source_type = source_type.get_inferer_inner_type();
+    }
+
+    let source: String = self::compile(context, source);
+    let index: String = self::compile(context, index);
+
+    if source_type.is_ptr_fixed_array_type() || source_type.is_ptr_vector_type() {
+        format!("(&(*({}))[{}])", source, index)
+    } else {
+        format!("(&({})[{}])", source, index)
+    }
+}
+
+fn compile_deref(
+    context: &mut CCodeGenContext,
+    value: &Ast,
+    kind: &Type,
+    metadata: &DereferenceMetadata,
+) -> String {
+    let value_type: &Type = self::get_type(context, value);
+
+    if !value_type.is_ptr_like_type() || self::is_bitfield_property(value) {
+        return self::compile(context, value);
+    }
+
+    let ptr: String = self::compile(context, value);
+    let ptr_type: String = typegeneration::generate_pointer_type(context, kind);
+
+    let metadata: LLVMDereferenceMetadata = metadata.get_llvm_metadata();
+
+    if let Some(ordering) = metadata.atomic_ord.and_then(self::atomic_ordering) {
+        return format!("__atomic_load_n(({})({}), {})", ptr_type, ptr, ordering);
+    }
+
+    if metadata.volatile {
+        format!("(*(volatile {})({}))", ptr_type, ptr)
+    } else {
+        format!("(*({})({}))", ptr_type, ptr)
+    }
+}
+
+fn binary_operator(
+    context: &mut CCodeGenContext,
+    operator: &TokenType,
+    span: Span,
+) -> &'static str {
+    match operator {
+        TokenType::Plus => "+",
+        TokenType::Minus => "-",
+        TokenType::Star => "*",
+        TokenType::Slash => "/",
+        TokenType::Arith => "%",
+        TokenType::LShift => "<<",
+        TokenType::RShift => ">>",
+        TokenType::Xor => "^",
+        TokenType::Bor => "|",
+        TokenType::BAnd => "&",
+        TokenType::And => "&&",
+        TokenType::Or => "||",
+        TokenType::EqEq => "==",
+        TokenType::BangEq => "!=",
+        TokenType::Greater => ">",
+        TokenType::GreaterEq => ">=",
+        TokenType::Less => "<",
+        TokenType::LessEq => "<=",
+
+        _ => abort::abort_codegen(
+            context,
+            "Unknown binary operator!",
+            span,
+            std::path::PathBuf::from(file!()),
+            line!(),
+        ),
+    }
+}
+
+fn get_struct_type(kind: &Type) -> Option<String> {
+    match kind {
+        Type::Struct { name, .. } => Some(name.to_string()),
+        Type::Ptr(Some(inner), ..) | Type::Const(inner, ..) => self::get_struct_type(inner),
+
+        _ => None,
+    }
+}
+
+fn atomic_ordering(ordering: ThrustAtomicOrdering) -> Option<&'static str> {
+    match ordering {
+        ThrustAtomicOrdering::AtomicNone => None,
+        ThrustAtomicOrdering::AtomicFree | ThrustAtomicOrdering::AtomicRelax => {
+            Some("__ATOMIC_RELAXED")
+        }
+        ThrustAtomicOrdering::AtomicGrab => Some("__ATOMIC_ACQUIRE"),
+        ThrustAtomicOrdering::AtomicDrop => Some("__ATOMIC_RELEASE"),
+        ThrustAtomicOrdering::AtomicSync => Some("__ATOMIC_ACQ_REL"),
+        ThrustAtomicOrdering::AtomicStrict => Some("__ATOMIC_SEQ_CST"),
+    }
+}
@@ -0,0 +1,286 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+
+use thrustc_ast::Ast;
+use thrustc_attributes::{
+    ThrustAttribute, ThrustAttributeComparator, ThrustAttributes,
+    traits::ThrustAttributesExtensions,
+};
+use thrustc_span::Span;
+use thrustc_typesystem::{Type, traits::TypeIsExtensions};
+
+use crate::{abort, context::CCodeGenContext, expressions, typegeneration, utils};
+
+pub fn compile_value(
+    context: &mut CCodeGenContext,
+    assembler: &str,
+    constraints: &str,
+    args: &[Ast],
+    kind: &Type,
+    attributes: &ThrustAttributes,
+    span: Span,
+) -> String {
+    let args: Vec<(String, Type)> = args
+        .iter()
+        .map(|arg| {
+            let kind: Type = expressions::get_type(context, arg).clone();
+            (expressions::compile(context, arg), kind)
+        })
+        .collect();
+
+    let result: Option<String> = if kind.is_void_type() {
+        None
+    } else {
+        Some(context.new_temporary())
+    };
+
+    let mut statements: Vec<String> = Vec::with_capacity(args.len() + 3);
+
+    if let Some(result) = &result {
+        statements.push(format!(
+            "{};",
+            typegeneration::generate_declaration(context, kind, result)
+        ));
+    }
+
+    let asm: String = self::compile_asm_statement(
+        context,
+        &mut statements,
+        assembler,
+        constraints,
+        result.as_deref().map(|result| (result, kind)),
+        &args,
+        attributes,
+        span,
+    );
+
+    statements.push(asm);
+
+    for statement in statements {
+        context.write_line(&statement);
+    }
+
+    result.unwrap_or_else(|| "((void)0)".into())
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn compile_asm_statement(
+    context: &mut CCodeGenContext,
+    statements: &mut Vec<String>,
+    assembler: &str,
+    constraints: &str,
+    output: Option<(&str, &Type)>,
+    args: &[(String, Type)],
+    attributes: &ThrustAttributes,
+    span: Span,
+) -> String {
+    let mut outputs: Vec<String> = Vec::with_capacity(1);
+    let mut inputs: Vec<String> = Vec::with_capacity(args.len());
+    let mut clobbers: Vec<String> = Vec::with_capacity(u8::MAX as usize);
+    let mut bindings: Vec<String> = Vec::with_capacity(1);
+
+    let mut next_arg: usize = 0;
+
+    for constraint in constraints
+        .split(',')
+        .map(|constraint| constraint.trim())
+        .filter(|constraint| !constraint.is_empty())
+    {
+        if let Some(clobber) = constraint.strip_prefix('~') {
+            let clobber: &str = clobber.trim_start_matches('{').trim_end_matches('}');
+
+            match clobber {
+                "memory" => clobbers.push("\"memory\"".into()),
+                "cc" | "flags" | "eflags" | "rflags" | "dirflag" | "fpsr" => {
+                    if !clobbers.iter().any(|other| other == "\"cc\"") {
+                        clobbers.push("\"cc\"".into());
+                    }
+                }
+                register => clobbers.push(format!("\"{}\"", register)),
+            }
+
+            continue;
+        }
+
+        if constraint.contains('*') {
+            abort::abort_codegen(
+                context,
+                "Indirect inline assembler constraints can't be lowered to C!",
+                span,
+                std::path::PathBuf::from(file!()),
+                line!(),
+            );
+        }
+
+        if let Some(constraint) = constraint.strip_prefix('=') {
+            let Some((result, kind)) = output else {
+                abort::abort_codegen(
+                    context,
+                    "An inline assembler output constraint requires a return type!",
+                    span,
+                    std::path::PathBuf::from(file!()),
+                    line!(),
+                );
+            };
+
+            let (early_clobber, constraint) = match constraint.strip_prefix('&') {
+                Some(constraint) => ("&", constraint),
+                None => ("", constraint),
+            };
+
+            if let Some(register) = self::get_register(constraint) {
+                let name: String = context.new_temporary();
+
+                statements.push(format!(
+                    "register {} __asm__(\"{}\");",
+                    typegeneration::generate_declaration(context, kind, &name),
+                    register
+                ));
+
+                outputs.push(format!("\"={}r\"({})", early_clobber, name));
+                bindings.push(format!("{} = {};", result, name));
+            } else {
+                outputs.push(format!("\"={}{}\"({})", early_clobber, constraint, result));
+            }
+
+            continue;
+        }
+
+        let Some((arg, kind)) = args.get(next_arg) else {
+            abort::abort_codegen(
+                context,
+                "An inline assembler input constraint is missing its argument!",
+                span,
+                std::path::PathBuf::from(file!()),
+                line!(),
+            );
+        };
+
+        next_arg += 1;
+
+        if let Some(register) = self::get_register(constraint) {
+            let name: String = context.new_temporary();
+
+            statements.push(format!(
+                "register {} __asm__(\"{}\") = {};",
+                typegeneration::generate_declaration(context, kind, &name),
+                register,
+                arg
+            ));
+
+            inputs.push(format!("\"r\"({})", name));
+        } else {
+            inputs.push(format!("\"{}\"({})", constraint, arg));
+        }
+    }
+
+    let volatile: &str = if attributes.has_asmsideffects_attribute() {
+        " volatile"
+    } else {
+        ""
+    };
+
+    let template: String = utils::escape_string(self::translate_template(assembler).as_bytes());
+
+    let template: String = match attributes.get_attr(ThrustAttributeComparator::AsmSyntax) {
+        Some(ThrustAttribute::AsmSyntax(syntax, ..)) if syntax == "AT&T" => template,
+        _ => format!("__THRUST_ASM_INTEL({})", template),
+    };
+
+    let mut asm: String = format!(
+        "__asm__{}({} : {} : {}",
+        volatile,
+        template,
+        outputs.join(", "),
+        inputs.join(", ")
+    );
+
+    if !clobbers.is_empty() {
+        asm.push_str(&format!(" : {}", clobbers.join(", ")));
+    }
+
+    asm.push_str(");");
+
+    for binding in bindings {
+        asm.push(' ');
+        asm.push_str(&binding);
+    }
+
+    asm
+}
+
+fn get_register(constraint: &str) -> Option<&str> {
+    constraint
+        .strip_prefix('{')
+        .and_then(|register| register.strip_suffix('}'))
+}
+
+fn translate_template(assembler: &str) -> String {
+    let mut template: String = String::with_capacity(assembler.len());
+    let mut chars = assembler.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '%' => template.push_str("%%"),
+
+            '$' => match chars.peek().copied() {
+                Some('$') => {
+                    chars.next();
+                    template.push('$');
+                }
+
+                Some('{') => {
+                    chars.next();
+
+                    let mut operand: String = String::with_capacity(8);
+
+                    for c in chars.by_ref() {
+                        if c == '}' {
+                            break;
+                        }
+
+                        operand.push(c);
+                    }
+
+                    match operand.split_once(':') {
+                        Some((position, modifier)) => {
+                            template.push_str(&format!("%{}{}", modifier, position))
+                        }
+                        None => template.push_str(&format!("%{}", operand)),
+                    }
+                }
+
+                Some(digit) if digit.is_ascii_digit() => {
+                    template.push('%');
+
+                    while let Some(digit) = chars.peek().copied().filter(char::is_ascii_digit) {
+                        chars.next();
+                        template.push(digit);
+                    }
+                }
+
+                _ => template.push('$'),
+            },
+
+            c => template.push(c),
+        }
+    }
+
+    template
+}
@@ -0,0 +1,45 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+
+use thrustc_ast::Ast;
+
+use crate::{codegen::CCodegen, context::CCodeGenContext};
+
+mod abort;
+mod branch_context;
+mod builtins;
+mod codegen;
+pub mod context;
+mod declarations;
+mod expressions;
+mod inlineasm;
+mod statements;
+mod table;
+mod typegeneration;
+mod utils;
+
+pub struct CCompiler;
+
+impl CCompiler {
+    #[inline]
+    pub fn compile(context: &mut CCodeGenContext, ast: &[Ast]) -> String {
+        CCodegen::codegen(context, ast);
+        context.get_source()
+    }
+}
@@ -0,0 +1,612 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+
+use thrustc_ast::Ast;
+use thrustc_typesystem::{Type, traits::TypeIsExtensions};
+
+use crate::{abort, context::CCodeGenContext, declarations, expressions, typegeneration};
+
+pub fn compile_block(context: &mut CCodeGenContext, node: &Ast) {
+    context.write_line("{");
+    context.indent();
+
+    self::compile_block_contents(context, node);
+
+    context.dedent();
+    context.write_line("}");
+}
+
+fn compile_block_contents(context: &mut CCodeGenContext, node: &Ast) {
+    match node {
+        Ast::Block { nodes, post, .. } => {
+            context.get_mut_table().begin_scope();
+
+            let defers: String = context.capture(|context| {
+                for postnode in post.iter() {
+                    self::compile_statement(context, postnode);
+                }
+            });
+
+            context.push_defers(defers);
+
+            let nodes_size: usize = nodes.len();
+
+            for (idx, node) in nodes.iter().enumerate() {
+                let is_final_node: bool = idx == nodes_size.saturating_sub(1);
+
+                if is_final_node {
+                    for postnode in post.iter() {
+                        self::compile_statement(context, postnode);
+                    }
+
+                    context.clear_last_defers();
+                }
+
+                self::compile_statement(context, node);
+            }
+
+            context.pop_defers();
+            context.get_mut_table().end_scope();
+        }
+
+        node => self::compile_statement(context, node),
+    }
+}
+
+pub fn compile_statement(context: &mut CCodeGenContext, node: &Ast) {
+    match node {
+        Ast::Block { .. } => self::compile_block(context, node),
+
+        Ast::Defer { node, .. } => self::compile_statement(context, node),
+
+        // Conditionals
+        Ast::If {
+            condition,
+            then_branch,
+            else_if_branch,
+            else_branch,
+            ..
+        } => {
+            let condition: String = expressions::compile(context, condition);
+
+            context.write_line(&format!("if ({})", condition));
+            self::compile_block(context, then_branch);
+
+            self::compile_else_branches(context, else_if_branch, else_branch.as_deref());
+        }
+
+        // Loops
+        Ast::While {
+            variable,
+            condition,
+            block,
+            ..
+        } => {
+            context.write_line("{");
+            context.indent();
+            context.get_mut_table().begin_scope();
+
+            if let Some(variable) = variable {
+                self::compile_statement(context, variable);
+            }
+
+            let (prelude, condition): (String, String) =
+                context.capture_expression(|context| expressions::compile(context, condition));
+
+            if prelude.is_empty() {
+                context.write_line(&format!("while ({})", condition));
+                self::compile_loop_body(context, block, None);
+            } else {
+                let entry: String = format!("{} if (!({})) break;", prelude, condition);
+
+                context.write_line("for (;;)");
+                self::compile_loop_body(context, block, Some(&entry));
+            }
+
+            context.get_mut_table().end_scope();
+            context.dedent();
+            context.write_line("}");
+        }
+
+        Ast::For {
+            local,
+            condition,
+            actions,
+            block,
+            ..
+        } => {
+            context.write_line("{");
+            context.indent();
+            context.get_mut_table().begin_scope();
+
+            self::compile_statement(context, local);
+
+            let (condition_prelude, condition): (String, String) =
+                context.capture_expression(|context| expressions::compile(context, condition));
+            let (actions_prelude, actions): (String, String) =
+                context.capture_expression(|context| expressions::compile(context, actions));
+
+            if condition_prelude.is_empty() && actions_prelude.is_empty() {
+                context.write_line(&format!("for (; {}; {})", condition, actions));
+                self::compile_loop_body(context, block, None);
+            } else {
+                // The actions run at the top of every iteration but the first, so
+                // 'continue' still goes through them and the condition.
+                let started: String = context.new_temporary();
+
+                let entry: String = format!(
+                    "if ({}) {{ {} {}; }} {} if (!({})) break;",
+                    started, actions_prelude, actions, condition_prelude, condition
+                );
+
+                context.write_line(&format!(
+                    "for (bool {} = false;; {} = true)",
+                    started, started
+                ));
+                self::compile_loop_body(context, block, Some(&entry));
+            }
+
+            context.get_mut_table().end_scope();
+            context.dedent();
+            context.write_line("}");
+        }
+
+        Ast::Loop { block, .. } => {
+            context.write_line("for (;;)");
+            self::compile_loop_body(context, block, None);
+        }
+
+        // Loop control flow
+        Ast::Break { .. } => context.write_line("break;"),
+        Ast::Continue { .. } => context.write_line("continue;"),
+
+        Ast::BreakAll { span, .. } => match context.get_mut_loop_ctx().use_breakall_label() {
+            Some(label) => context.write_line(&format!("goto {};", label)),
+            None => abort::abort_codegen(
+                context,
+                "Loop control flow 'breakall' label couldn't be obtained.",
+                *span,
+                std::path::PathBuf::from(file!()),
+                line!(),
+            ),
+        },
+
+        Ast::ContinueAll { span, .. } => match context.get_mut_loop_ctx().use_continueall_label() {
+            Some(label) => context.write_line(&format!("goto {};", label)),
+            None => abort::abort_codegen(
+                context,
+                "Loop control flow 'continueall' label couldn't be obtained.",
+                *span,
+                std::path::PathBuf::from(file!()),
+                line!(),
+            ),
+        },
+
+        // Functions
+        Ast::Return { expression, .. } => {
+            let return_type: Option<Type> = context
+                .get_return_type()
+                .filter(|return_type| return_type.is_error_union_type())
+                .cloned();
+
+            match (expression, return_type) {
+                (Some(expression), Some(return_type))
+                    if !expressions::get_type(context, expression).is_error_union_type() =>
+                {
+                    let kind: String = typegeneration::generate_type(context, &return_type);
+                    let value: String = expressions::compile(context, expression);
+
+                    context.write_line(&format!("return ({}){{ .value = {} }};", kind, value));
+                }
+
+                (Some(expression), _) => {
+                    let value: String = expressions::compile(context, expression);
+                    context.write_line(&format!("return {};", value));
+                }
+
+                (None, Some(return_type)) => {
+                    let kind: String = typegeneration::generate_type(context, &return_type);
+                    context.write_line(&format!("return ({}){{0}};", kind));
+                }
+
+                (None, None) => context.write_line("return;"),
+            }
+        }
+
+        Ast::Raise {
+            expression, span, ..
+        } => {
+            let Some(return_type) = context.get_return_type().cloned() else {
+                abort::abort_codegen(
+                    context,
+                    "Failed to compile the 'raise' outside of a function!",
+                    *span,
+                    std::path::PathBuf::from(file!()),
+                    line!(),
+                );
+            };
+
+            let kind: String = typegeneration::generate_type(context, &return_type);
+            let code: String = expressions::compile(context, expression);
+
+            context.write_line(&format!("return ({}){{ .error = {} }};", kind, code));
+        }
+
+        // Declarations
+        Ast::Var {
+            name,
+            kind,
+            value,
+            metadata,
+            ..
+        } => {
+            let value: Option<String> = if metadata.is_undefined() {
+                None
+            } else {
+                value
+                    .as_ref()
+                    .map(|value| expressions::compile_initializer(context, value))
+            };
+
+            let symbol: String = context.get_mut_table().new_local(name);
+            let declaration: String = typegeneration::generate_declaration(context, kind, &symbol);
+
+            match value {
+                Some(value) => context.write_line(&format!("{} = {};", declaration, value)),
+                None => context.write_line(&format!("{};", declaration)),
+            }
+        }
+
+        Ast::Const {
+            name, kind, value, ..
+        } => {
+            let value: String = expressions::compile_initializer(context, value);
+
+            let symbol: String = context.get_mut_table().new_local(name);
+            let declaration: String = typegeneration::generate_declaration(context, kind, &symbol);
+
+            context.write_line(&format!("{} = {};", declaration, value));
+        }
+
+        Ast::Static {
+            name,
+            kind,
+            value,
+            metadata,
+            ..
+        } => {
+            let value: Option<String> = value
+                .as_ref()
+                .map(|value| declarations::compile_constant_initializer(context, value));
+
+            let symbol: String = context.get_mut_table().new_local(name);
+            let declaration: String = typegeneration::generate_declaration(context, kind, &symbol);
+
+            let thread_local: &str = if metadata.get_llvm_metadata().thread_local {
+                "_Thread_local "
+            } else {
+                ""
+            };
+
+            match value {
+                Some(value) => context.write_line(&format!(
+                    "static {}{} = {};",
+                    thread_local, declaration, value
+                )),
+                None => context.write_line(&format!("static {}{};", thread_local, declaration)),
+            }
+        }
+
+        // Type declarations
+        Ast::Struct { .. }
+        | Ast::Enum { .. }
+        | Ast::CustomType { .. }
+        | Ast::Import { .. }
+        | Ast::ImportC { .. } => {}
+
+        Ast::GlobalAssembler { span, .. } => abort::abort_codegen(
+            context,
+            "Global assembler can't be declared inside a function!",
+            *span,
+            std::path::PathBuf::from(file!()),
+            line!(),
+        ),
+
+        // Expressions as statements
+        node => {
+            let expression: String = expressions::compile(context, node);
+            context.write_line(&format!("{};", expression));
+        }
+    }
+}
+
+fn compile_else_branches(
+    context: &mut CCodeGenContext,
+    else_if_branch: &[Ast],
+    else_branch: Option<&Ast>,
+) {
+    for (position, elif) in else_if_branch.iter().enumerate() {
+        let Ast::Elif {
+            condition, block, ..
+        } = elif
+        else {
+            continue;
+        };
+
+        let (prelude, condition): (String, String) =
+            context.capture_expression(|context| expressions::compile(context, condition));
+
+        if prelude.is_empty() {
+            context.write_line(&format!("else if ({})", condition));
+            self::compile_block(context, block);
+
+            continue;
+        }
+
+        // The condition needs statements of its own, so the rest of the chain
+        // nests inside a plain 'else' block.
+        context.write_line("else");
+        context.write_line("{");
+        context.indent();
+
+        context.write_line(&prelude);
+        context.write_line(&format!("if ({})", condition));
+        self::compile_block(context, block);
+
+        self::compile_else_branches(context, &else_if_branch[position + 1..], else_branch);
+
+        context.dedent();
+        context.write_line("}");
+
+        return;
+    }
+
+    if let Some(Ast::Else { block, .. }) = else_branch {
+        context.write_line("else");
+        self::compile_block(context, block);
+    }
+}
+
+fn compile_loop_body(context: &mut CCodeGenContext, block: &Ast, entry: Option<&str>) {
+    let is_outermost: bool = context.get_mut_loop_ctx().begin_loop();
+
+    context.write_line("{");
+    context.indent();
+
+    if let Some(entry) = entry {
+        context.write_line(entry);
+    }
+
+    self::compile_block_contents(context, block);
+
+    let continueall_label: Option<String> = if is_outermost {
+        context.get_mut_loop_ctx().take_continueall_label()
+    } else {
+        None
+    };
+
+    if let Some(label) = continueall_label {
+        context.write_label(&label);
+    }
+
+    context.dedent();
+    context.write_line("}");
+
+    context.get_mut_loop_ctx().end_loop();
+
+    let breakall_label: Option<String> = if is_outermost {
+        context.get_mut_loop_ctx().take_breakall_label()
+    } else {
+        None
+    };
+
+    if let Some(label) = breakall_label {
+        context.write_label(&label);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use thrustc_ast::{Ast, NodeId};
+    use thrustc_diagnostician::Diagnostician;
+    use thrustc_options::{CompilationUnit, CompilerOptions};
+    use thrustc_span::Span;
+    use thrustc_token_type::TokenType;
+    use thrustc_typesystem::Type;
+
+    use crate::context::CCodeGenContext;
+
+    fn int() -> Type {
+        Type::S32 {
+            span: Span::nothing(),
+        }
+    }
+
+    fn boolean() -> Type {
+        Type::Bool(Span::nothing())
+    }
+
+    fn fallible() -> Type {
+        Type::ErrorUnion(Box::new(self::int()), Span::nothing())
+    }
+
+    fn call(name: &str, kind: Type) -> Ast<'_> {
+        Ast::Call {
+            name,
+            args: Vec::new(),
+            kind,
+            span: Span::nothing(),
+            id: NodeId::new(),
+        }
+    }
+
+    fn attempt(expression: Ast<'_>) -> Ast<'_> {
+        Ast::Try {
+            expression: Box::new(expression),
+            kind: self::int(),
+            span: Span::nothing(),
+            id: NodeId::new(),
+        }
+    }
+
+    fn block(nodes: Vec<Ast<'_>>) -> Ast<'_> {
+        Ast::Block {
+            nodes,
+            post: Vec::new(),
+            kind: Type::Void(Span::nothing()),
+            span: Span::nothing(),
+            id: NodeId::new(),
+        }
+    }
+
+    fn compile(node: &Ast) -> String {
+        let options: CompilerOptions = CompilerOptions::new();
+        let file: CompilationUnit = CompilationUnit::new(
+            "test".into(),
+            PathBuf::from("test.thrust"),
+            String::new(),
+            "test".into(),
+        );
+
+        let mut context: CCodeGenContext =
+            CCodeGenContext::new(Diagnostician::new(&file, &options), &options);
+
+        for name in ["check", "load", "fallback", "done"] {
+            context.get_mut_table().new_global(name, name.into());
+        }
+
+        context.set_return_type(&self::fallible());
+        context.capture(|context| super::compile_statement(context, node))
+    }
+
+    #[test]
+    fn try_is_lowered_before_the_statement_using_it() {
+        let node: Ast = Ast::Return {
+            expression: Some(Box::new(self::attempt(self::call(
+                "load",
+                self::fallible(),
+            )))),
+            kind: Type::Void(Span::nothing()),
+            span: Span::nothing(),
+            id: NodeId::new(),
+        };
+
+        assert_eq!(
+            self::compile(&node),
+            "struct __thrust_errunion_s32 __thrust_tmp_1 = load(); \
+             if (__thrust_tmp_1.error != 0) {  return (struct __thrust_errunion_s32){ .error = __thrust_tmp_1.error }; } \
+             return (struct __thrust_errunion_s32){ .value = __thrust_tmp_1.value };"
+        );
+    }
+
+    #[test]
+    fn catch_only_evaluates_the_fallback_on_error() {
+        let node: Ast = Ast::Catch {
+            expression: Box::new(self::call("load", self::fallible())),
+            fallback: Box::new(self::attempt(self::call("fallback", self::fallible()))),
+            kind: self::int(),
+            span: Span::nothing(),
+            id: NodeId::new(),
+        };
+
+        let compiled: String = self::compile(&node);
+
+        assert!(!compiled.contains("({"));
+        assert!(compiled.contains(
+            "if (__thrust_tmp_1.error != 0) { struct __thrust_errunion_s32 __thrust_tmp_2 = fallback();"
+        ));
+        assert!(
+            compiled.ends_with("} else { __thrust_tmp_3 = __thrust_tmp_1.value; } __thrust_tmp_3;")
+        );
+    }
+
+    #[test]
+    fn short_circuit_guards_the_hoisted_right_operand() {
+        let node: Ast = Ast::BinaryOp {
+            left: Box::new(self::call("check", self::boolean())),
+            operator: TokenType::And,
+            right: Box::new(self::attempt(self::call("load", self::fallible()))),
+            kind: self::boolean(),
+            span: Span::nothing(),
+            id: NodeId::new(),
+        };
+
+        let compiled: String = self::compile(&node);
+
+        assert!(compiled.starts_with("bool __thrust_tmp_2 = (check()); if (__thrust_tmp_2) {"));
+        assert!(compiled.ends_with("__thrust_tmp_2 = (__thrust_tmp_1.value); } __thrust_tmp_2;"));
+    }
+
+    #[test]
+    fn elif_with_hoisted_condition_nests_the_rest_of_the_chain() {
+        let node: Ast = Ast::If {
+            condition: Box::new(self::call("check", self::boolean())),
+            then_branch: Box::new(self::block(Vec::new())),
+            else_if_branch: vec![Ast::Elif {
+                condition: Box::new(self::attempt(self::call("load", self::fallible()))),
+                block: Box::new(self::block(Vec::new())),
+                kind: Type::Void(Span::nothing()),
+                span: Span::nothing(),
+                id: NodeId::new(),
+            }],
+            else_branch: Some(Box::new(Ast::Else {
+                block: Box::new(self::block(vec![self::call(
+                    "done",
+                    Type::Void(Span::nothing()),
+                )])),
+                kind: Type::Void(Span::nothing()),
+                span: Span::nothing(),
+                id: NodeId::new(),
+            })),
+            kind: Type::Void(Span::nothing()),
+            span: Span::nothing(),
+            id: NodeId::new(),
+        };
+
+        let compiled: String = self::compile(&node);
+
+        assert!(!compiled.contains("else if"));
+        assert!(compiled.starts_with("if (check()) { } else { struct __thrust_errunion_s32"));
+        assert!(compiled.ends_with("if (__thrust_tmp_1.value) { } else { done(); } }"));
+    }
+
+    #[test]
+    fn while_with_hoisted_condition_checks_it_every_iteration() {
+        let node: Ast = Ast::While {
+            variable: None,
+            condition: Box::new(self::attempt(self::call("load", self::fallible()))),
+            block: Box::new(self::block(vec![self::call(
+                "done",
+                Type::Void(Span::nothing()),
+            )])),
+            kind: Type::Void(Span::nothing()),
+            span: Span::nothing(),
+            id: NodeId::new(),
+        };
+
+        let compiled: String = self::compile(&node);
+
+        assert!(
+            compiled.contains("for (;;) { struct __thrust_errunion_s32 __thrust_tmp_1 = load();")
+        );
+        assert!(compiled.contains("if (!(__thrust_tmp_1.value)) break; done(); }"));
+    }
+}
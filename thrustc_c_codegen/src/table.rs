@@ -0,0 +1,101 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+
+use ahash::AHashMap as HashMap;
+
+#[derive(Debug)]
+pub struct CSymbolsTable {
+    globals: HashMap<String, String>,
+    locals: Vec<HashMap<String, String>>,
+    names: HashMap<String, usize>,
+}
+
+impl CSymbolsTable {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            globals: HashMap::with_capacity(u8::MAX as usize),
+            locals: Vec::with_capacity(u8::MAX as usize),
+            names: HashMap::with_capacity(u8::MAX as usize),
+        }
+    }
+}
+
+impl CSymbolsTable {
+    #[inline]
+    pub fn new_global(&mut self, name: &str, symbol: String) {
+        self.globals.insert(name.to_string(), symbol);
+    }
+
+    pub fn new_local(&mut self, name: &str) -> String {
+        let base: String = crate::utils::sanitize_identifier(name);
+
+        let counter: &mut usize = self.names.entry(base.clone()).or_insert(0);
+        let symbol: String = if *counter == 0 {
+            base
+        } else {
+            format!("{}_{}", base, counter)
+        };
+
+        *counter += 1;
+
+        if let Some(scope) = self.locals.last_mut() {
+            scope.insert(name.to_string(), symbol.clone());
+        }
+
+        symbol
+    }
+}
+
+impl CSymbolsTable {
+    #[must_use]
+    pub fn get_symbol(&self, name: &str) -> Option<&str> {
+        for scope in self.locals.iter().rev() {
+            if let Some(local) = scope.get(name) {
+                return Some(local);
+            }
+        }
+
+        self.globals.get(name).map(|symbol| symbol.as_str())
+    }
+}
+
+impl CSymbolsTable {
+    #[inline]
+    pub fn begin_scope(&mut self) {
+        self.locals.push(HashMap::with_capacity(u8::MAX as usize));
+    }
+
+    #[inline]
+    pub fn end_scope(&mut self) {
+        self.locals.pop();
+    }
+
+    #[inline]
+    pub fn begin_function(&mut self) {
+        self.names.clear();
+        self.begin_scope();
+    }
+
+    #[inline]
+    pub fn end_function(&mut self) {
+        self.locals.clear();
+        self.names.clear();
+    }
+}
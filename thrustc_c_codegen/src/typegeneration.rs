@@ -0,0 +1,222 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+
+use thrustc_span::Span;
+use thrustc_typesystem::{
+    Type,
+    traits::{InfererTypeExtensions, TypeCodeLocation, TypeIsExtensions},
+};
+
+use crate::{abort, context::CCodeGenContext};
+
+pub fn generate_type(context: &mut CCodeGenContext, kind: &Type) -> String {
+    self::generate_declaration(context, kind, "")
+}
+
+pub fn generate_pointer_type(context: &mut CCodeGenContext, pointee: &Type) -> String {
+    self::generate_pointer_declaration(context, pointee, "")
+}
+
+pub fn generate_declaration(
+    context: &mut CCodeGenContext,
+    kind: &Type,
+    declarator: &str,
+) -> String {
+    let specifier: &str = match kind {
+        Type::S8 { .. } => "int8_t",
+        Type::S16 { .. } => "int16_t",
+        Type::S32 { .. } => "int32_t",
+        Type::S64 { .. } => "int64_t",
+        Type::S128 { .. } => "__int128",
+        Type::SSize { .. } => "intptr_t",
+        Type::U8 { .. } => "uint8_t",
+        Type::U16 { .. } => "uint16_t",
+        Type::U32 { .. } => "uint32_t",
+        Type::U64 { .. } => "uint64_t",
+        Type::U128 { .. } => "unsigned __int128",
+        Type::USize { .. } => "uintptr_t",
+        Type::F16 { .. } => "_Float16",
+        Type::BF16 { .. } => "__bf16",
+        Type::F32 { .. } => "float",
+        Type::F64 { .. } => "double",
+        Type::F128 { .. } => "__float128",
+        Type::FX8680 { .. } => "long double",
+        Type::FPPC128 { .. } => "__ibm128",
+        Type::Bool(..) => "bool",
+        Type::Char(..) => "char",
+        Type::Void(..) => "void",
+
+        Type::Ptr(None, ..) | Type::Addr(..) => {
+            return self::specifier("void", &format!("*{}", declarator));
+        }
+
+        Type::Struct { name, modifier, .. } => {
+            return self::specifier(
+                &format!(
+                    "{} {}",
+                    crate::utils::aggregate_keyword(modifier.is_union()),
+                    crate::utils::sanitize_identifier(name)
+                ),
+                declarator,
+            );
+        }
+
+        Type::Const(inner, ..) => return self::generate_declaration(context, inner, declarator),
+
+        Type::Ptr(Some(inner), ..) => {
+            return self::generate_pointer_declaration(context, inner, declarator);
+        }
+
+        Type::FixedArray(inner, size, ..) => {
+            return self::generate_declaration(
+                context,
+                inner,
+                &format!("{}[{}]", declarator, size),
+            );
+        }
+
+        Type::ErrorUnion(inner, ..) => {
+            let name: String = self::generate_error_union(context, inner);
+            return self::specifier(&format!("struct {}", name), declarator);
+        }
+
+        Type::Interface(name, ..) => {
+            let name: String = self::generate_interface(context, name);
+            return self::specifier(&format!("struct {}", name), declarator);
+        }
+
+        Type::Vector(inner, lanes, ..) => {
+            let element: String = if inner.is_bool_type() {
+                "int8_t".into()
+            } else {
+                self::generate_type(context, inner)
+            };
+
+            return self::specifier(
+                &format!(
+                    "{} __attribute__((vector_size({} * sizeof({}))))",
+                    element, lanes, element
+                ),
+                declarator,
+            );
+        }
+
+        Type::Array { .. } if kind.is_inferer_inner_type_valid() => {
+            return self::generate_declaration(context, &kind.get_inferer_inner_type(), declarator);
+        }
+
+        Type::Array { base_type, .. } => {
+            return self::generate_pointer_declaration(context, base_type, declarator);
+        }
+
+        Type::Fn(parameters, return_type, modificator, ..) => {
+            let mut generated_parameters: Vec<String> = Vec::with_capacity(parameters.len() + 1);
+
+            for parameter in parameters.iter() {
+                generated_parameters.push(self::generate_type(context, parameter));
+            }
+
+            if modificator.llvm().has_ignore() {
+                generated_parameters.push("...".into());
+            } else if generated_parameters.is_empty() {
+                generated_parameters.push("void".into());
+            }
+
+            return self::generate_declaration(
+                context,
+                return_type,
+                &format!("(*{})({})", declarator, generated_parameters.join(", ")),
+            );
+        }
+
+        Type::Unresolved { .. } => {
+            let span: Span = kind.get_span();
+
+            abort::abort_codegen(
+                context,
+                "An unresolved type can't be lowered to C!",
+                span,
+                std::path::PathBuf::from(file!()),
+                line!(),
+            );
+        }
+    };
+
+    self::specifier(specifier, declarator)
+}
+
+fn generate_error_union(context: &mut CCodeGenContext, value_type: &Type) -> String {
+    let name: String = format!(
+        "__thrust_errunion_{}",
+        crate::utils::sanitize_identifier(&value_type.to_string())
+    );
+
+    if context.add_error_union(&name) {
+        let value: String = if value_type.is_void_type() {
+            String::new()
+        } else {
+            format!(
+                " {};",
+                self::generate_declaration(context, value_type, "value")
+            )
+        };
+
+        context.write_structure(&format!("struct {} {{ uint32_t error;{} }};", name, value));
+    }
+
+    name
+}
+
+fn generate_interface(context: &mut CCodeGenContext, interface: &str) -> String {
+    let name: String = format!(
+        "__thrust_iface_{}",
+        crate::utils::sanitize_identifier(interface)
+    );
+
+    if context.add_interface(&name) {
+        context.write_structure(&format!(
+            "struct {} {{ void* data; void* const* vtable; }};",
+            name
+        ));
+    }
+
+    name
+}
+
+fn generate_pointer_declaration(
+    context: &mut CCodeGenContext,
+    pointee: &Type,
+    declarator: &str,
+) -> String {
+    if pointee.is_fixed_array_type() || pointee.is_function_reference_type() {
+        self::generate_declaration(context, pointee, &format!("(*{})", declarator))
+    } else {
+        self::generate_declaration(context, pointee, &format!("*{}", declarator))
+    }
+}
+
+fn specifier(specifier: &str, declarator: &str) -> String {
+    if declarator.is_empty() {
+        specifier.to_string()
+    } else if declarator.starts_with('[') {
+        format!("{}{}", specifier, declarator)
+    } else {
+        format!("{} {}", specifier, declarator)
+    }
+}
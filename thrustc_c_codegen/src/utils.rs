@@ -0,0 +1,164 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+
+use thrustc_attributes::{ThrustAttribute, ThrustAttributes};
+
+const C_RESERVED_KEYWORDS: [&str; 44] = [
+    "auto",
+    "break",
+    "case",
+    "char",
+    "const",
+    "continue",
+    "default",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "extern",
+    "float",
+    "for",
+    "goto",
+    "if",
+    "inline",
+    "int",
+    "long",
+    "register",
+    "restrict",
+    "return",
+    "short",
+    "signed",
+    "sizeof",
+    "static",
+    "struct",
+    "switch",
+    "typedef",
+    "union",
+    "unsigned",
+    "void",
+    "volatile",
+    "while",
+    "_Alignas",
+    "_Alignof",
+    "_Atomic",
+    "_Bool",
+    "_Noreturn",
+    "_Static_assert",
+    "_Thread_local",
+    "bool",
+    "true",
+    "false",
+];
+
+pub fn sanitize_identifier(name: &str) -> String {
+    let mut identifier: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+
+    if identifier.is_empty() || identifier.starts_with(|c: char| c.is_ascii_digit()) {
+        identifier.insert(0, '_');
+    }
+
+    if C_RESERVED_KEYWORDS.contains(&identifier.as_str()) || identifier.starts_with("__thrust_") {
+        identifier.push('_');
+    }
+
+    identifier
+}
+
+pub fn symbol_name(attributes: &ThrustAttributes, ascii_name: &str) -> String {
+    attributes
+        .iter()
+        .find_map(|attribute| match attribute {
+            ThrustAttribute::Extern(name, ..) => Some(name.to_string()),
+            _ => None,
+        })
+        .unwrap_or_else(|| self::sanitize_identifier(ascii_name))
+}
+
+pub fn escape_string(bytes: &[u8]) -> String {
+    let bytes: &[u8] = bytes.strip_suffix(&[0]).unwrap_or(bytes);
+
+    let mut escaped: String = String::with_capacity(bytes.len() + 2);
+
+    escaped.push('"');
+
+    for (position, byte) in bytes.iter().enumerate() {
+        match byte {
+            b'"' => escaped.push_str("\\\""),
+            b'\\' => escaped.push_str("\\\\"),
+            b'\n' => escaped.push_str("\\n"),
+            b'\r' => escaped.push_str("\\r"),
+            b'\t' => escaped.push_str("\\t"),
+            b'?' => escaped.push_str("\\?"),
+            0x20..=0x7e => escaped.push(*byte as char),
+            _ => {
+                escaped.push_str(&format!("\\{:03o}", byte));
+
+                if bytes
+                    .get(position + 1)
+                    .is_some_and(|next| (b'0'..=b'7').contains(next))
+                {
+                    escaped.push_str("\"\"");
+                }
+            }
+        }
+    }
+
+    escaped.push('"');
+
+    escaped
+}
+
+pub fn calling_convention(convention: &str) -> Option<&'static str> {
+    match convention {
+        "X86StdCall" => Some("stdcall"),
+        "X86FastCall" => Some("fastcall"),
+        "X86ThisCall" => Some("thiscall"),
+        "X86VectorCall" => Some("vectorcall"),
+        "X86RegCall" => Some("regcall"),
+        "Win64" => Some("ms_abi"),
+        "X86_64_SysV" => Some("sysv_abi"),
+        "ARMAPCS" => Some("pcs(\"apcs\")"),
+        "ARMAAPCS" => Some("pcs(\"aapcs\")"),
+        "ARM_AAPCS_VFP" => Some("pcs(\"aapcs-vfp\")"),
+        "AArch64VectorCall" => Some("aarch64_vector_pcs"),
+        "AArch64SVEVectorCall" => Some("aarch64_sve_pcs"),
+        "weakReg" => Some("preserve_most"),
+        "strongReg" => Some("preserve_all"),
+        "PreserveNone" => Some("preserve_none"),
+        "Swift" => Some("swiftcall"),
+        "SwiftTail" => Some("swiftasynccall"),
+        "RISCVVectorCall" => Some("riscv_vector_cc"),
+
+        _ => None,
+    }
+}
+
+#[inline]
+pub fn aggregate_keyword(is_union: bool) -> &'static str {
+    if is_union { "union" } else { "struct" }
+}
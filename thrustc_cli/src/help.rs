@@ -65,6 +65,17 @@ pub fn show_help() -> ! {
         ),
     );
 
+    thrustc_logging::write(
+        thrustc_logging::OutputIn::Stderr,
+        &format!(
+            "{} {} {} {}\n",
+            "•".bold(),
+            "fix".custom_color((141, 141, 142)).bold(),
+            "[files..]",
+            "Apply every machine-applicable suggestion in place.",
+        ),
+    );
+
     thrustc_logging::write(thrustc_logging::OutputIn::Stderr, "\nLinkage flags:\n\n");
 
     thrustc_logging::write(
@@ -203,7 +214,7 @@ pub fn show_help() -> ! {
             "{} {} [{}] {}\n",
             "•".bold(),
             "-emit".custom_color((141, 141, 142)).bold(),
-            "llvm-bc|llvm-ir|asm|unopt-llvm-ir|unopt-llvm-bc|unopt-asm|obj|unchecked-ast|ast|tokens",
+            "llvm-bc|llvm-ir|asm|unopt-llvm-ir|unopt-llvm-bc|unopt-asm|obj|unchecked-ast|ast|tokens|c-header|c",
             "Compile the code into specified representation.",
         ),
     );
@@ -214,11 +225,54 @@ pub fn show_help() -> ! {
             "{} {} [{}] {}\n",
             "•".bold(),
             "-print".custom_color((141, 141, 142)).bold(),
-            "llvm-ir|unopt-llvm-ir|asm|unopt-asm|unchecked-ast|ast|tokens",
+            "llvm-ir|unopt-llvm-ir|asm|unopt-asm|unchecked-ast|ast|mir|tokens",
             "Displays the final compilation on standard output.",
         ),
     );
 
+    thrustc_logging::write(
+        thrustc_logging::OutputIn::Stderr,
+        &format!(
+            "{} {} [{}] {}\n",
+            "•".bold(),
+            "-W".custom_color((141, 141, 142)).bold(),
+            "W0001",
+            "Report the specified warning as a warning.",
+        ),
+    );
+
+    thrustc_logging::write(
+        thrustc_logging::OutputIn::Stderr,
+        &format!(
+            "{} {} [{}] {}\n",
+            "•".bold(),
+            "-A".custom_color((141, 141, 142)).bold(),
+            "W0001",
+            "Silence the specified warning.",
+        ),
+    );
+
+    thrustc_logging::write(
+        thrustc_logging::OutputIn::Stderr,
+        &format!(
+            "{} {} [{}] {}\n",
+            "•".bold(),
+            "-D".custom_color((141, 141, 142)).bold(),
+            "W0001",
+            "Report the specified warning as an error and fail the compilation.",
+        ),
+    );
+
+    thrustc_logging::write(
+        thrustc_logging::OutputIn::Stderr,
+        &format!(
+            "{} {} {}\n",
+            "•".bold(),
+            "-Werror".custom_color((141, 141, 142)).bold(),
+            "Treat every warning without an explicit level as an error.",
+        ),
+    );
+
     thrustc_logging::write(
         thrustc_logging::OutputIn::Stderr,
         &format!(
@@ -230,6 +284,26 @@ pub fn show_help() -> ! {
         ),
     );
 
+    thrustc_logging::write(
+        thrustc_logging::OutputIn::Stderr,
+        &format!(
+            "{} {} {}\n",
+            "•".bold(),
+            "-llvm-backend".custom_color((141, 141, 142)).bold(),
+            "Use the LLVM backend for code generation (default).",
+        ),
+    );
+
+    thrustc_logging::write(
+        thrustc_logging::OutputIn::Stderr,
+        &format!(
+            "{} {} {}\n",
+            "•".bold(),
+            "-c-backend".custom_color((141, 141, 142)).bold(),
+            "Generate portable C11 source and build it with the linking C compiler.",
+        ),
+    );
+
     thrustc_logging::write(
         thrustc_logging::OutputIn::Stderr,
         &format!(
@@ -442,6 +516,17 @@ pub fn show_help() -> ! {
         ),
     );
 
+    thrustc_logging::write(
+        thrustc_logging::OutputIn::Stderr,
+        &format!(
+            "{} {} {} {}\n",
+            "•".bold(),
+            "--cfg".custom_color((141, 141, 142)).bold(),
+            "[foo|key=value]",
+            "Enable a configuration option evaluated by '@cfg' attributes.",
+        ),
+    );
+
     thrustc_logging::write(
         thrustc_logging::OutputIn::Stderr,
         &format!(
@@ -749,6 +834,26 @@ pub fn show_help() -> ! {
         ),
     );
 
+    thrustc_logging::write(
+        thrustc_logging::OutputIn::Stderr,
+        &format!(
+            "{} {} {}\n",
+            "•".bold(),
+            "--deterministic".custom_color((141, 141, 142)).bold(),
+            "Derive build ids and obfuscated names from the source content, so that the same input always produces the same artifacts. Enabled when 'SOURCE_DATE_EPOCH' is set, which is also used as the build timestamp."
+        ),
+    );
+
+    thrustc_logging::write(
+        thrustc_logging::OutputIn::Stderr,
+        &format!(
+            "{} {} {}\n",
+            "•".bold(),
+            "--freestanding".custom_color((141, 141, 142)).bold(),
+            "Assume no C runtime is available, so builtins such as 'print' are lowered to system calls instead of libc functions."
+        ),
+    );
+
     thrustc_logging::write(
         thrustc_logging::OutputIn::Stderr,
         &format!(
@@ -883,7 +988,7 @@ pub fn show_emission_help() -> ! {
             "-emit value; -emit=value; -emit:value;"
                 .custom_color((141, 141, 142))
                 .bold(),
-            "llvm-bc|llvm-ir|asm|unopt-llvm-ir|unopt-llvm-bc|unopt-asm|obj|tokens|unchecked-ast|ast",
+            "llvm-bc|llvm-ir|asm|unopt-llvm-ir|unopt-llvm-bc|unopt-asm|obj|tokens|unchecked-ast|ast|c-header|c",
         ),
     );
 
@@ -987,6 +1092,36 @@ pub fn show_emission_help() -> ! {
         ),
     );
 
+    thrustc_logging::write(
+        thrustc_logging::OutputIn::Stderr,
+        &format!(
+            "{} {} {}\n",
+            "•".bold(),
+            "c-header".custom_color((141, 141, 142)).bold(),
+            "Emit a C header with the public declarations.",
+        ),
+    );
+
+    thrustc_logging::write(
+        thrustc_logging::OutputIn::Stderr,
+        &format!(
+            "{} {} {}\n",
+            "•".bold(),
+            "c".custom_color((141, 141, 142)).bold(),
+            "Emit the C11 source generated by the C backend.",
+        ),
+    );
+
+    thrustc_logging::write(
+        thrustc_logging::OutputIn::Stderr,
+        &format!(
+            "{} {} {}\n",
+            "•".bold(),
+            "dep-info".custom_color((141, 141, 142)).bold(),
+            "Emit a Makefile-style '.d' file with every imported and embedded source of the output.",
+        ),
+    );
+
     std::process::exit(thrustc_constants::FAILURE_CODE)
 }
 
@@ -1000,7 +1135,7 @@ pub fn show_printing_help() -> ! {
             "-print value; -print=value; -print:value;"
                 .custom_color((141, 141, 142))
                 .bold(),
-            "llvm-ir|unopt-llvm-ir|asm|unopt-asm|tokens|unchecked-ast|ast",
+            "llvm-ir|unopt-llvm-ir|asm|unopt-asm|tokens|unchecked-ast|ast|mir",
         ),
     );
 
@@ -1074,6 +1209,16 @@ pub fn show_printing_help() -> ! {
         ),
     );
 
+    thrustc_logging::write(
+        thrustc_logging::OutputIn::Stderr,
+        &format!(
+            "{} {} {}\n",
+            "•".bold(),
+            "mir".custom_color((141, 141, 142)).bold(),
+            "Print the control flow graph based Mid-level Intermediate Representation.",
+        ),
+    );
+
     std::process::exit(thrustc_constants::FAILURE_CODE)
 }
 
@@ -32,6 +32,9 @@ use thrustc_logging::OutputIn;
 use thrustc_options::CompilerOptions;
 use thrustc_options::EmitableUnit;
 use thrustc_options::PrintableUnit;
+use thrustc_options::warnings::WarningLevel;
+
+use thrustc_errors::CompilationIssueCode;
 
 use ahash::AHashMap as HashMap;
 
@@ -158,7 +161,23 @@ impl CommandLine {
 
 impl CommandLine {
     fn validate(&mut self) {
-        if !self.get_options().get_llvm_backend().is_full_jit() {
+        if let Some(epoch) = std::env::var_os("SOURCE_DATE_EPOCH") {
+            match epoch
+                .to_str()
+                .and_then(|epoch| epoch.trim().parse::<u64>().ok())
+            {
+                Some(epoch) => self.get_mut_options().set_source_date_epoch(epoch),
+                None => self.report_error(
+                    "'SOURCE_DATE_EPOCH' must be a number of seconds since the Unix epoch.",
+                ),
+            }
+        }
+
+        self.get_mut_options().set_up_deterministic_build_id();
+
+        if !self.get_options().get_llvm_backend().is_full_jit()
+            && !self.get_options().need_apply_fixes()
+        {
             self.get_mut_options()
                 .get_mut_linking_compilers_configuration()
                 .comprobate_status();
@@ -222,6 +241,11 @@ impl CommandLine {
                 std::process::exit(0);
             }
 
+            "fix" if self.current == 0 => {
+                self.advance();
+                self.get_mut_options().set_apply_fixes();
+            }
+
             "-build-dir" => {
                 self.advance();
 
@@ -243,6 +267,21 @@ impl CommandLine {
                 self.advance();
             }
 
+            "-llvm-backend" => {
+                self.advance();
+
+                self.get_mut_options().set_use_c_backend(false);
+                self.get_mut_options().set_use_llvm_backend(true);
+            }
+
+            "-c-backend" => {
+                self.advance();
+                self.validate_aot_is_enable(arg);
+
+                self.get_mut_options().set_use_llvm_backend(false);
+                self.get_mut_options().set_use_c_backend(true);
+            }
+
             "-jit" => {
                 self.advance();
                 self.validate_llvm_required(arg);
@@ -320,7 +359,11 @@ impl CommandLine {
 
             "-clang-link" => {
                 self.advance();
-                self.validate_llvm_required(arg);
+
+                if !self.options.c() {
+                    self.validate_llvm_required(arg);
+                }
+
                 self.validate_not_gcc_active();
 
                 let path: PathBuf = self.peek().into();
@@ -462,10 +505,18 @@ impl CommandLine {
 
             "-emit" => {
                 self.advance();
-                self.validate_llvm_required(arg);
 
                 let emitable: EmitableUnit = self.parse_emit_option(self.peek());
 
+                match emitable {
+                    EmitableUnit::CSource => self.validate_c_required(arg),
+                    EmitableUnit::UnCheckedAst
+                    | EmitableUnit::Ast
+                    | EmitableUnit::Tokens
+                    | EmitableUnit::CHeader => (),
+                    _ => self.validate_llvm_required(arg),
+                }
+
                 self.get_mut_options().add_emit_option(emitable);
 
                 self.advance();
@@ -688,6 +739,56 @@ impl CommandLine {
                 self.advance();
             }
 
+            "--cfg" => {
+                self.advance();
+
+                let cfg: String = self.peek().to_string();
+
+                let (key, value): (String, String) = match cfg.split_once('=') {
+                    Some((key, value)) => (
+                        key.trim().to_string(),
+                        value.trim().trim_matches('"').to_string(),
+                    ),
+                    None => ("feature".into(), cfg.trim().to_string()),
+                };
+
+                if key.is_empty() || value.is_empty() {
+                    self.report_error(
+                        "Configuration option must be a 'name' or a 'key=value' pair.",
+                    );
+                }
+
+                self.get_mut_options().add_cfg(key, value);
+
+                self.advance();
+            }
+
+            "-W" | "-A" | "-D" => {
+                self.advance();
+
+                let code: CompilationIssueCode = self.parse_warning_code(self.peek());
+
+                let level: WarningLevel = match arg {
+                    "-A" => WarningLevel::Allow,
+                    "-D" => WarningLevel::Deny,
+                    _ => WarningLevel::Warn,
+                };
+
+                self.get_mut_options()
+                    .get_mut_warning_control()
+                    .set_level(code, level);
+
+                self.advance();
+            }
+
+            "-Werror" => {
+                self.advance();
+
+                self.get_mut_options()
+                    .get_mut_warning_control()
+                    .set_warnings_as_errors();
+            }
+
             "--disable-all-cpu-features" => {
                 self.advance();
                 self.validate_llvm_required(arg);
@@ -918,6 +1019,16 @@ impl CommandLine {
                 self.get_mut_options().set_no_obfuscate_ir();
             }
 
+            "--deterministic" => {
+                self.advance();
+                self.get_mut_options().set_deterministic();
+            }
+
+            "--freestanding" => {
+                self.advance();
+                self.get_mut_options().set_freestanding();
+            }
+
             "--enable-ansi-color" => {
                 self.advance();
                 self.get_mut_options().set_enable_ansi_colors();
@@ -1212,6 +1323,7 @@ impl CommandLine {
             "tokens" => PrintableUnit::Tokens,
             "unchecked-ast" => PrintableUnit::UnCheckedAst,
             "ast" => PrintableUnit::Ast,
+            "mir" => PrintableUnit::Mir,
 
             any => {
                 self.report_error(&format!("Unknown print option: '{}'.", any));
@@ -1219,6 +1331,13 @@ impl CommandLine {
         }
     }
 
+    #[inline]
+    fn parse_warning_code(&self, code: &str) -> CompilationIssueCode {
+        CompilationIssueCode::from_warning(code).unwrap_or_else(|| {
+            self.report_error(&format!("Unknown warning code: '{}'.", code));
+        })
+    }
+
     #[inline]
     fn parse_emit_option(&self, emit: &str) -> EmitableUnit {
         match emit {
@@ -1232,6 +1351,9 @@ impl CommandLine {
             "unchecked-ast" => EmitableUnit::UnCheckedAst,
             "ast" => EmitableUnit::Ast,
             "tokens" => EmitableUnit::Tokens,
+            "c-header" => EmitableUnit::CHeader,
+            "c" => EmitableUnit::CSource,
+            "dep-info" => EmitableUnit::DepInfo,
 
             any => {
                 self.report_error(&format!("Unknown emission option: '{}'.", any));
@@ -1319,6 +1441,15 @@ impl CommandLine {
         }
     }
 
+    fn validate_c_required(&self, arg: &str) {
+        if !self.options.c() {
+            self.report_error(&format!(
+                "Can't use '{}' without '-c-backend' flag previously.",
+                arg
+            ));
+        }
+    }
+
     fn validate_jit_required(&self, arg: &str) {
         if !self.options.get_llvm_backend().is_full_jit() {
             self.report_error(&format!(
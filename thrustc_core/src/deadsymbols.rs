@@ -0,0 +1,73 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+use thrustc_diagnostician::Diagnostician;
+use thrustc_errors::{CompilationIssue, CompilationIssueCode};
+use thrustc_linter::usage::LinterSymbolUsage;
+use thrustc_options::CompilationUnit;
+
+use crate::ThrustCompiler;
+
+pub fn check_dead_public_symbols(compiler: &ThrustCompiler) -> bool {
+    // Objects and libraries export their public symbols to code outside of this build.
+    if !compiler
+        .get_compilation_options()
+        .get_linking_compilers_configuration()
+        .links_executable()
+    {
+        return false;
+    }
+
+    let usages: &[(&CompilationUnit, LinterSymbolUsage)] = compiler.get_symbol_usages();
+
+    let mut denied: bool = false;
+
+    for (file, usage) in usages.iter() {
+        let mut diagnostician: Diagnostician = Diagnostician::with_allowed_warnings_set(
+            file,
+            compiler.get_compilation_options(),
+            usage.get_allowed_warnings().clone(),
+        );
+
+        for (name, span) in usage.get_public_definitions().iter() {
+            let referenced: bool = usages
+                .iter()
+                .any(|(_, other_usage)| other_usage.is_referenced(name));
+
+            if referenced {
+                continue;
+            }
+
+            diagnostician.dispatch_diagnostic(
+                &CompilationIssue::Warning(
+                    CompilationIssueCode::W0028,
+                    format!(
+                        "'{}' is public, but no compilation unit in this build uses it.",
+                        name
+                    ),
+                    *span,
+                ),
+                thrustc_logging::LoggingType::Warning,
+            );
+        }
+
+        denied = denied || diagnostician.has_denied_warnings();
+    }
+
+    denied
+}
@@ -164,6 +164,67 @@ pub fn llvm_before_optimization(
     Ok(false)
 }
 
+pub fn c_source(
+    compiler: &mut ThrustCompiler,
+    source: &str,
+    build_dir: &std::path::Path,
+    file: &CompilationUnit,
+    file_time: std::time::Instant,
+) -> Result<bool, ()> {
+    let compiler_options: &CompilerOptions = compiler.get_compilation_options();
+
+    if compiler_options.contains_emitable(EmitableUnit::CSource) {
+        if let Err(error) =
+            emitters::csource::emit_c_source(compiler, source, build_dir, file.get_name())
+        {
+            thrustc_logging::print_error(
+                thrustc_logging::LoggingType::Error,
+                &format!(
+                    "Failed to emit C source for file '{}': {}.",
+                    file.get_path().display(),
+                    error
+                ),
+            );
+
+            interrupt::archive_compilation_unit(compiler, file, file_time)?;
+        }
+
+        return Ok(true);
+    }
+
+    Ok(false)
+}
+
+pub fn dep_info(
+    compiler: &mut ThrustCompiler,
+    target: &std::path::Path,
+    dependencies: &[std::path::PathBuf],
+    build_dir: &std::path::Path,
+    file: &CompilationUnit,
+    file_time: std::time::Instant,
+) -> Result<(), ()> {
+    let compiler_options: &CompilerOptions = compiler.get_compilation_options();
+
+    if compiler_options.contains_emitable(EmitableUnit::DepInfo) {
+        if let Err(error) =
+            emitters::depinfo::emit_dep_info(target, dependencies, build_dir, file.get_name())
+        {
+            thrustc_logging::print_error(
+                thrustc_logging::LoggingType::Error,
+                &format!(
+                    "Failed to emit dependency info for file '{}': {}.",
+                    file.get_path().display(),
+                    error
+                ),
+            );
+
+            interrupt::archive_compilation_unit(compiler, file, file_time)?;
+        }
+    }
+
+    Ok(())
+}
+
 pub fn frontend_before(
     compiler: &mut ThrustCompiler,
     build_dir: &std::path::Path,
@@ -223,5 +284,26 @@ pub fn frontend_after(
         }
     }
 
+    if compiler_options.contains_emitable(EmitableUnit::CHeader) {
+        if let Emited::Ast(ast) = emited {
+            if let Err(error) =
+                emitters::cheader::emit_c_header(compiler, ast, build_dir, file.get_name())
+            {
+                thrustc_logging::print_error(
+                    thrustc_logging::LoggingType::Error,
+                    &format!(
+                        "Failed to emit C header for file '{}': {}.",
+                        file.get_path().display(),
+                        error
+                    ),
+                );
+
+                return false;
+            }
+
+            return true;
+        }
+    }
+
     false
 }
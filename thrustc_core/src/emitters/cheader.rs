@@ -0,0 +1,970 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write as WriteFmt;
+use std::path::{Path, PathBuf};
+
+use thrustc_ast::Ast;
+use thrustc_ast::data::StructureDataFields;
+use thrustc_attributes::traits::ThrustAttributesExtensions;
+use thrustc_attributes::{ThrustAttribute, ThrustAttributeComparator, ThrustAttributes};
+use thrustc_options::CompilerOptions;
+use thrustc_token_type::TokenType;
+use thrustc_typesystem::Type;
+use thrustc_typesystem::traits::TypeIsExtensions;
+
+use crate::{ThrustCompiler, utils};
+
+pub fn emit_c_header(
+    compiler: &ThrustCompiler,
+    ast: &[Ast],
+    build_dir: &Path,
+    file_name: &str,
+) -> Result<(), std::io::Error> {
+    let compiler_options: &CompilerOptions = compiler.get_compilation_options();
+    let need_obfuscation: bool = compiler_options.need_obfuscate_archive_names();
+
+    let cheader_base_path: PathBuf = build_dir.join("emit").join("c-header");
+
+    if !cheader_base_path.exists() {
+        let _ = std::fs::create_dir_all(&cheader_base_path);
+    }
+
+    let cheader_file_name: String = if need_obfuscation {
+        format!(
+            "{}_{}.h",
+            utils::generate_random_string(thrustc_constants::COMPILER_HARD_OBFUSCATION_LEVEL),
+            file_name
+        )
+    } else {
+        format!("{}.h", file_name)
+    };
+
+    let header: String = CHeaderGenerator::new(ast).generate(file_name);
+
+    std::fs::write(cheader_base_path.join(cheader_file_name), header)
+}
+
+#[derive(Debug)]
+struct CHeaderGenerator<'a, 'ast> {
+    ast: &'a [Ast<'ast>],
+    structs: HashMap<&'ast str, &'a Ast<'ast>>,
+
+    forward_structs: Vec<String>,
+    forward_declared: HashSet<String>,
+    defined: HashSet<String>,
+
+    structs_section: String,
+    constants_section: String,
+    declarations_section: String,
+}
+
+impl<'a, 'ast> CHeaderGenerator<'a, 'ast> {
+    fn new(ast: &'a [Ast<'ast>]) -> Self {
+        let structs: HashMap<&'ast str, &'a Ast<'ast>> = ast
+            .iter()
+            .filter_map(|node| match node {
+                Ast::Struct { name, .. } => Some((*name, node)),
+                _ => None,
+            })
+            .collect();
+
+        Self {
+            ast,
+            structs,
+            forward_structs: Vec::with_capacity(u8::MAX as usize),
+            forward_declared: HashSet::with_capacity(u8::MAX as usize),
+            defined: HashSet::with_capacity(u8::MAX as usize),
+            structs_section: String::with_capacity(u8::MAX as usize),
+            constants_section: String::with_capacity(u8::MAX as usize),
+            declarations_section: String::with_capacity(u8::MAX as usize),
+        }
+    }
+}
+
+impl CHeaderGenerator<'_, '_> {
+    fn generate(mut self, file_name: &str) -> String {
+        let ast: &[Ast] = self.ast;
+
+        for node in ast.iter() {
+            match node {
+                Ast::Struct {
+                    name, attributes, ..
+                } if attributes.has_public_attribute() => {
+                    self.emit_struct(name);
+                }
+
+                Ast::Enum {
+                    name,
+                    data,
+                    attributes,
+                    ..
+                } if attributes.has_public_attribute() => {
+                    for (field_name, kind, value) in data.iter() {
+                        let macro_name: String = format!("{}_{}", name, field_name);
+
+                        match (self.render(kind, "", false), self::literal(value)) {
+                            (Some(ctype), Some(literal)) => {
+                                let _ = writeln!(
+                                    self.constants_section,
+                                    "#define {} (({}) {})",
+                                    macro_name, ctype, literal
+                                );
+                            }
+                            _ => {
+                                let _ = writeln!(
+                                    self.constants_section,
+                                    "/* #define {}: the value cannot be represented as a C constant. */",
+                                    macro_name
+                                );
+                            }
+                        }
+                    }
+                }
+
+                Ast::Function {
+                    ascii_name,
+                    parameters,
+                    parameter_types,
+                    body,
+                    return_type,
+                    attributes,
+                    ..
+                } if attributes.has_public_attribute() && body.is_some() => {
+                    let symbol_name: &str = self::symbol_name(attributes, ascii_name);
+
+                    self.emit_function(
+                        symbol_name,
+                        parameters,
+                        parameter_types,
+                        return_type,
+                        attributes,
+                    );
+                }
+
+                Ast::Const {
+                    ascii_name,
+                    kind,
+                    attributes,
+                    metadata,
+                    ..
+                } if attributes.has_public_attribute() => {
+                    let symbol_name: &str = self::symbol_name(attributes, ascii_name);
+                    let thread_local: bool = metadata.get_llvm_metadata().thread_local;
+
+                    self.emit_global(symbol_name, kind, attributes, true, thread_local);
+                }
+
+                Ast::Static {
+                    ascii_name,
+                    kind,
+                    value,
+                    attributes,
+                    metadata,
+                    ..
+                } if attributes.has_public_attribute() && value.is_some() => {
+                    let symbol_name: &str = self::symbol_name(attributes, ascii_name);
+                    let thread_local: bool = metadata.get_llvm_metadata().thread_local;
+
+                    self.emit_global(
+                        symbol_name,
+                        kind,
+                        attributes,
+                        !metadata.is_mutable(),
+                        thread_local,
+                    );
+                }
+
+                _ => (),
+            }
+        }
+
+        let guard: String = self::header_guard(file_name);
+
+        let mut header: String = String::with_capacity(u8::MAX as usize);
+
+        let _ = writeln!(
+            header,
+            "/* Generated by {} from '{}'. Do not edit. */\n",
+            thrustc_constants::COMPILER_ID,
+            file_name
+        );
+
+        let _ = writeln!(header, "#ifndef {}", guard);
+        let _ = writeln!(header, "#define {}\n", guard);
+
+        let _ = writeln!(header, "#include <stdint.h>");
+        let _ = writeln!(header, "#include <stdbool.h>");
+        let _ = writeln!(header, "#include <stddef.h>\n");
+
+        let _ = writeln!(header, "#ifdef __cplusplus");
+        let _ = writeln!(header, "extern \"C\" {{");
+        let _ = writeln!(header, "#endif\n");
+
+        if !self.forward_structs.is_empty() {
+            self.forward_structs.iter().for_each(|tag| {
+                let _ = writeln!(header, "{};", tag);
+            });
+
+            header.push('\n');
+        }
+
+        [
+            &self.structs_section,
+            &self.constants_section,
+            &self.declarations_section,
+        ]
+        .iter()
+        .filter(|section| !section.is_empty())
+        .for_each(|section| {
+            header.push_str(section);
+            header.push('\n');
+        });
+
+        let _ = writeln!(header, "#ifdef __cplusplus");
+        let _ = writeln!(header, "}}");
+        let _ = writeln!(header, "#endif\n");
+
+        let _ = writeln!(header, "#endif /* {} */", guard);
+
+        header
+    }
+}
+
+impl CHeaderGenerator<'_, '_> {
+    fn emit_struct(&mut self, name: &str) {
+        if !self.defined.insert(name.to_string()) {
+            return;
+        }
+
+        let Some(Ast::Struct {
+            data, attributes, ..
+        }) = self.structs.get(name).copied()
+        else {
+            return;
+        };
+
+        let keyword: &str = if data.2.is_union() { "union" } else { "struct" };
+
+        self.forward_declare(format!("{} {}", keyword, name));
+
+        let fields: &StructureDataFields = &data.1;
+
+        fields
+            .iter()
+            .for_each(|(_, kind, ..)| self.emit_struct_dependencies(kind));
+
+        let mut definition: String = String::with_capacity(u8::MAX as usize);
+
+        let _ = writeln!(definition, "{} {} {{", keyword, name);
+
+        for (field_name, kind, _, _, bit_width) in fields.iter() {
+            match self.render(kind, field_name, false) {
+                Some(field) => match bit_width {
+                    Some(width) => {
+                        let _ = writeln!(definition, "    {} : {};", field, width);
+                    }
+                    None => {
+                        let _ = writeln!(definition, "    {};", field);
+                    }
+                },
+                None => {
+                    let _ = writeln!(
+                        definition,
+                        "    /* '{}': the type '{}' cannot be represented in C. */",
+                        field_name, kind
+                    );
+                }
+            }
+        }
+
+        let mut type_attributes: Vec<String> = Vec::with_capacity(2);
+
+        if data.2.llvm().is_packed()
+            || attributes
+                .match_attr(ThrustAttributeComparator::Packed)
+                .is_some()
+        {
+            type_attributes.push("packed".into());
+        }
+
+        if let Some(ThrustAttribute::Align(align, ..)) =
+            attributes.get_attr(ThrustAttributeComparator::Align)
+        {
+            type_attributes.push(format!("aligned({})", align));
+        }
+
+        if type_attributes.is_empty() {
+            let _ = writeln!(definition, "}};\n");
+        } else {
+            let _ = writeln!(
+                definition,
+                "}} __attribute__(({}));\n",
+                type_attributes.join(", ")
+            );
+        }
+
+        self.structs_section.push_str(&definition);
+    }
+
+    /// A forward declaration only names the tag; the definition is still emitted on its own.
+    fn forward_declare(&mut self, tag: String) {
+        if self.forward_declared.insert(tag.clone()) {
+            self.forward_structs.push(tag);
+        }
+    }
+
+    fn emit_struct_dependencies(&mut self, kind: &Type) {
+        match kind {
+            Type::Struct { name, .. } => self.emit_struct(name),
+            Type::Const(inner, ..) | Type::FixedArray(inner, ..) => {
+                self.emit_struct_dependencies(inner)
+            }
+            Type::Array {
+                infered_type: Some((inner, ..)),
+                ..
+            } => self.emit_struct_dependencies(inner),
+            Type::ErrorUnion(inner, ..) => {
+                self.emit_struct_dependencies(inner);
+                self.emit_error_union(inner);
+            }
+            Type::Interface(name, ..) => self.emit_interface(name),
+
+            _ => (),
+        }
+    }
+
+    fn emit_error_union(&mut self, value_type: &Type) {
+        let name: String = self::error_union_name(value_type);
+
+        if !self.defined.insert(name.clone()) {
+            return;
+        }
+
+        let mut definition: String = String::with_capacity(u8::MAX as usize);
+
+        let _ = writeln!(definition, "struct {} {{", name);
+        let _ = writeln!(definition, "    uint32_t error;");
+
+        if !value_type.is_void_type() {
+            match self.render(value_type, "value", false) {
+                Some(field) => {
+                    let _ = writeln!(definition, "    {};", field);
+                }
+                None => {
+                    let _ = writeln!(
+                        definition,
+                        "    /* 'value': the type '{}' cannot be represented in C. */",
+                        value_type
+                    );
+                }
+            }
+        }
+
+        let _ = writeln!(definition, "}};\n");
+
+        self.structs_section.push_str(&definition);
+    }
+
+    fn emit_interface(&mut self, interface: &str) {
+        let name: String = self::interface_name(interface);
+
+        if !self.defined.insert(name.clone()) {
+            return;
+        }
+
+        let mut definition: String = String::with_capacity(u8::MAX as usize);
+
+        let _ = writeln!(definition, "struct {} {{", name);
+        let _ = writeln!(definition, "    void* data;");
+        let _ = writeln!(definition, "    void* const* vtable;");
+        let _ = writeln!(definition, "}};\n");
+
+        self.structs_section.push_str(&definition);
+    }
+
+    fn emit_function(
+        &mut self,
+        name: &str,
+        parameters: &[Ast],
+        parameter_types: &[Type],
+        return_type: &Type,
+        attributes: &ThrustAttributes,
+    ) {
+        let convention: Option<&str> =
+            match attributes.get_attr(ThrustAttributeComparator::Convention) {
+                Some(ThrustAttribute::Convention(convention, ..)) => {
+                    match self::calling_convention(&convention) {
+                        Some(annotation) => Some(annotation),
+                        None => {
+                            let _ = writeln!(
+                                self.declarations_section,
+                                "/* '{}': the calling convention '{}' has no C equivalent. */",
+                                name, convention
+                            );
+
+                            return;
+                        }
+                    }
+                }
+
+                _ => None,
+            };
+
+        if parameter_types
+            .iter()
+            .any(|kind| kind.is_fixed_array_type())
+        {
+            let _ = writeln!(
+                self.declarations_section,
+                "/* '{}': fixed arrays passed by value cannot be represented in C. */",
+                name
+            );
+
+            return;
+        }
+
+        let mut rendered_parameters: Vec<String> = Vec::with_capacity(parameters.len() + 1);
+
+        for (parameter, kind) in parameters.iter().zip(parameter_types.iter()) {
+            let parameter_name: &str = match parameter {
+                Ast::FunctionParameter { name, .. } => name,
+                _ => "",
+            };
+
+            self.collect_struct_references(kind);
+
+            match self.render(kind, parameter_name, false) {
+                Some(rendered) => rendered_parameters.push(rendered),
+                None => {
+                    let _ = writeln!(
+                        self.declarations_section,
+                        "/* '{}': the type '{}' cannot be represented in C. */",
+                        name, kind
+                    );
+
+                    return;
+                }
+            }
+        }
+
+        if attributes.has_ignore_attribute() {
+            rendered_parameters.push("...".into());
+        } else if rendered_parameters.is_empty() {
+            rendered_parameters.push("void".into());
+        }
+
+        let declarator: String = format!("{}({})", name, rendered_parameters.join(", "));
+
+        self.collect_struct_references(return_type);
+
+        match self.render(return_type, &declarator, false) {
+            Some(prototype) => {
+                if let Some(annotation) = convention.filter(|annotation| !annotation.is_empty()) {
+                    let _ = writeln!(
+                        self.declarations_section,
+                        "__attribute__(({})) {};",
+                        annotation, prototype
+                    );
+                } else {
+                    let _ = writeln!(self.declarations_section, "{};", prototype);
+                }
+            }
+            None => {
+                let _ = writeln!(
+                    self.declarations_section,
+                    "/* '{}': the type '{}' cannot be represented in C. */",
+                    name, return_type
+                );
+            }
+        }
+    }
+
+    fn emit_global(
+        &mut self,
+        name: &str,
+        kind: &Type,
+        attributes: &ThrustAttributes,
+        constant: bool,
+        thread_local: bool,
+    ) {
+        self.collect_struct_references(kind);
+
+        let Some(rendered) = self.render(kind, name, constant) else {
+            let _ = writeln!(
+                self.declarations_section,
+                "/* '{}': the type '{}' cannot be represented in C. */",
+                name, kind
+            );
+
+            return;
+        };
+
+        let mut declaration: String = String::from("extern ");
+
+        if thread_local {
+            declaration.push_str("_Thread_local ");
+        }
+
+        if let Some(ThrustAttribute::Align(align, ..)) =
+            attributes.get_attr(ThrustAttributeComparator::Align)
+        {
+            let _ = write!(declaration, "_Alignas({}) ", align);
+        }
+
+        let _ = writeln!(self.declarations_section, "{}{};", declaration, rendered);
+    }
+
+    fn collect_struct_references(&mut self, kind: &Type) {
+        match kind {
+            Type::Struct { name, .. } => self.emit_struct(name),
+            Type::Const(inner, ..) | Type::FixedArray(inner, ..) => {
+                self.collect_struct_references(inner)
+            }
+            Type::Ptr(Some(inner), ..) => self.collect_struct_references(inner),
+            Type::Array {
+                base_type,
+                infered_type,
+                ..
+            } => {
+                if let Some((inner, ..)) = infered_type {
+                    self.collect_struct_references(inner);
+                } else {
+                    self.collect_struct_references(base_type);
+                }
+            }
+            Type::Fn(parameters, return_type, ..) => {
+                parameters
+                    .iter()
+                    .for_each(|parameter| self.collect_struct_references(parameter));
+
+                self.collect_struct_references(return_type);
+            }
+
+            _ => (),
+        }
+    }
+
+    fn render(&mut self, kind: &Type, declarator: &str, constant: bool) -> Option<String> {
+        let specifier: &str = match kind {
+            Type::S8 { .. } => "int8_t",
+            Type::S16 { .. } => "int16_t",
+            Type::S32 { .. } => "int32_t",
+            Type::S64 { .. } => "int64_t",
+            Type::S128 { .. } => "__int128",
+            Type::SSize { .. } => "intptr_t",
+            Type::U8 { .. } => "uint8_t",
+            Type::U16 { .. } => "uint16_t",
+            Type::U32 { .. } => "uint32_t",
+            Type::U64 { .. } => "uint64_t",
+            Type::U128 { .. } => "unsigned __int128",
+            Type::USize { .. } => "uintptr_t",
+            Type::F16 { .. } => "_Float16",
+            Type::BF16 { .. } => "__bf16",
+            Type::F32 { .. } => "float",
+            Type::F64 { .. } => "double",
+            Type::F128 { .. } => "__float128",
+            Type::FX8680 { .. } => "long double",
+            Type::FPPC128 { .. } => "__ibm128",
+            Type::Bool(..) => "bool",
+            Type::Char(..) => "char",
+            Type::Void(..) => "void",
+            Type::Ptr(None, ..) | Type::Addr(..) => {
+                return Some(self::specifier(
+                    "void",
+                    &format!("*{}", declarator),
+                    constant,
+                ));
+            }
+
+            Type::Struct { name, modifier, .. } => {
+                let tag: String = if modifier.is_union() {
+                    format!("union {}", name)
+                } else {
+                    format!("struct {}", name)
+                };
+
+                self.forward_declare(tag.clone());
+
+                return Some(self::specifier(&tag, declarator, constant));
+            }
+
+            Type::Const(inner, ..) => return self.render(inner, declarator, true),
+
+            Type::Ptr(Some(inner), ..) => return self.render_pointer(inner, declarator, constant),
+
+            Type::FixedArray(inner, size, ..) => {
+                return self.render(inner, &format!("{}[{}]", declarator, size), constant);
+            }
+
+            Type::ErrorUnion(inner, ..) => {
+                self.emit_struct_dependencies(kind);
+
+                let tag: String = format!("struct {}", self::error_union_name(inner));
+
+                return Some(self::specifier(&tag, declarator, constant));
+            }
+
+            Type::Interface(name, ..) => {
+                self.emit_struct_dependencies(kind);
+
+                let tag: String = format!("struct {}", self::interface_name(name));
+
+                return Some(self::specifier(&tag, declarator, constant));
+            }
+
+            Type::Vector(inner, lanes, ..) => {
+                let element: String = if let Type::Bool(..) = **inner {
+                    "int8_t".into()
+                } else {
+                    self.render(inner, "", false)?
+                };
+
+                let vector: String = format!(
+                    "{} __attribute__((vector_size({} * sizeof({}))))",
+                    element, lanes, element
+                );
+
+                return Some(self::specifier(&vector, declarator, constant));
+            }
+
+            Type::Array {
+                infered_type: Some((inner, ..)),
+                ..
+            } => return self.render(inner, declarator, constant),
+
+            Type::Array { base_type, .. } => {
+                return self.render_pointer(base_type, declarator, constant);
+            }
+
+            Type::Fn(parameters, return_type, modificator, ..) => {
+                let mut rendered_parameters: Vec<String> = Vec::with_capacity(parameters.len() + 1);
+
+                for parameter in parameters.iter() {
+                    rendered_parameters.push(self.render(parameter, "", false)?);
+                }
+
+                if modificator.llvm().has_ignore() {
+                    rendered_parameters.push("...".into());
+                } else if rendered_parameters.is_empty() {
+                    rendered_parameters.push("void".into());
+                }
+
+                return self.render(
+                    return_type,
+                    &format!("(*{})({})", declarator, rendered_parameters.join(", ")),
+                    false,
+                );
+            }
+
+            Type::Unresolved { .. } => return None,
+        };
+
+        Some(self::specifier(specifier, declarator, constant))
+    }
+
+    fn render_pointer(
+        &mut self,
+        pointee: &Type,
+        declarator: &str,
+        constant: bool,
+    ) -> Option<String> {
+        if pointee.is_fixed_array_type() {
+            self.render(pointee, &format!("(*{})", declarator), constant)
+        } else {
+            self.render(pointee, &format!("*{}", declarator), constant)
+        }
+    }
+}
+
+fn specifier(specifier: &str, declarator: &str, constant: bool) -> String {
+    let qualifier: &str = if constant { "const " } else { "" };
+
+    if declarator.is_empty() {
+        format!("{}{}", qualifier, specifier)
+    } else {
+        format!("{}{} {}", qualifier, specifier, declarator)
+    }
+}
+
+fn symbol_name<'a>(attributes: &'a ThrustAttributes, ascii_name: &'a str) -> &'a str {
+    attributes
+        .iter()
+        .find_map(|attribute| match attribute {
+            ThrustAttribute::Extern(extern_name, ..) => Some(extern_name.as_str()),
+            _ => None,
+        })
+        .unwrap_or(ascii_name)
+}
+
+fn literal(value: &Ast) -> Option<String> {
+    match value {
+        Ast::Integer { value, .. } if u64::try_from(*value).is_err() => None,
+        Ast::Integer { value, signed, .. } => {
+            if *signed {
+                Some(self::signed_literal(*value as i64))
+            } else {
+                Some(format!("{}ULL", value))
+            }
+        }
+
+        Ast::Float { value, .. } if value.is_finite() => Some(format!("{:?}", value)),
+        Ast::Boolean { value, .. } => Some(format!("{}", *value != 0)),
+        Ast::Char { byte, .. } => Some(format!("{}", byte)),
+
+        Ast::UnaryOp {
+            operator: TokenType::Minus,
+            node,
+            ..
+        } => match node.as_ref() {
+            Ast::Integer { value, .. } => i128::try_from(*value)
+                .ok()
+                .and_then(|value| i64::try_from(-value).ok())
+                .map(self::signed_literal),
+            Ast::Float { value, .. } if value.is_finite() => Some(format!("-{:?}", value)),
+            _ => None,
+        },
+
+        Ast::Group { node, .. } => self::literal(node),
+
+        _ => None,
+    }
+}
+
+/// 'INT64_MIN' cannot be written as a negated 'LL' literal, since its magnitude overflows 'long long'.
+fn signed_literal(value: i64) -> String {
+    if value == i64::MIN {
+        format!("({}LL - 1)", i64::MIN + 1)
+    } else {
+        format!("{}LL", value)
+    }
+}
+
+fn calling_convention(convention: &str) -> Option<&'static str> {
+    match convention {
+        "C" => Some(""),
+        "X86StdCall" => Some("stdcall"),
+        "X86FastCall" => Some("fastcall"),
+        "X86ThisCall" => Some("thiscall"),
+        "X86VectorCall" => Some("vectorcall"),
+        "X86RegCall" => Some("regcall"),
+        "Win64" => Some("ms_abi"),
+        "X86_64_SysV" => Some("sysv_abi"),
+        "ARMAPCS" => Some("pcs(\"apcs\")"),
+        "ARMAAPCS" => Some("pcs(\"aapcs\")"),
+        "ARM_AAPCS_VFP" => Some("pcs(\"aapcs-vfp\")"),
+        "AArch64VectorCall" => Some("aarch64_vector_pcs"),
+        "AArch64SVEVectorCall" => Some("aarch64_sve_pcs"),
+        "weakReg" => Some("preserve_most"),
+        "strongReg" => Some("preserve_all"),
+        "PreserveNone" => Some("preserve_none"),
+        "Swift" => Some("swiftcall"),
+        "SwiftTail" => Some("swiftasynccall"),
+        "RISCVVectorCall" => Some("riscv_vector_cc"),
+
+        _ => None,
+    }
+}
+
+fn error_union_name(value_type: &Type) -> String {
+    let sanitized: String = value_type
+        .to_string()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+
+    format!("__thrust_errunion_{}", sanitized)
+}
+
+fn interface_name(interface: &str) -> String {
+    let sanitized: String = interface
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+
+    format!("__thrust_iface_{}", sanitized)
+}
+
+fn header_guard(file_name: &str) -> String {
+    let sanitized: String = file_name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+
+    format!("THRUST_{}_H", sanitized)
+}
+
+#[cfg(test)]
+mod tests {
+    use thrustc_ast::{Ast, NodeId};
+    use thrustc_attributes::ThrustAttribute;
+    use thrustc_span::Span;
+    use thrustc_token_type::TokenType;
+    use thrustc_typesystem::{Type, type_modificators::StructureTypeModificator};
+
+    use super::CHeaderGenerator;
+
+    fn public() -> Vec<ThrustAttribute> {
+        vec![ThrustAttribute::Public(Span::nothing())]
+    }
+
+    fn struct_type(name: &str) -> Type {
+        Type::Struct {
+            name: name.into(),
+            fields: Vec::new(),
+            bitfields: Vec::new(),
+            modifier: StructureTypeModificator::default(),
+            span: Span::nothing(),
+        }
+    }
+
+    fn structure<'a>(name: &'a str, fields: Vec<(&'a str, Type)>) -> Ast<'a> {
+        Ast::Struct {
+            name,
+            data: (
+                name,
+                fields
+                    .into_iter()
+                    .enumerate()
+                    .map(|(idx, (field, kind))| (field, kind, idx as u32, Span::nothing(), None))
+                    .collect(),
+                StructureTypeModificator::default(),
+                Span::nothing(),
+            ),
+            kind: self::struct_type(name),
+            span: Span::nothing(),
+            attributes: self::public(),
+            id: NodeId::new(),
+        }
+    }
+
+    fn s64(value: u128) -> Ast<'static> {
+        Ast::Integer {
+            kind: Type::S64 {
+                span: Span::nothing(),
+            },
+            value,
+            signed: true,
+            span: Span::nothing(),
+            id: NodeId::new(),
+        }
+    }
+
+    fn negate(node: Ast<'static>) -> Ast<'static> {
+        Ast::UnaryOp {
+            operator: TokenType::Minus,
+            node: Box::new(node),
+            kind: Type::S64 {
+                span: Span::nothing(),
+            },
+            before: true,
+            span: Span::nothing(),
+            id: NodeId::new(),
+        }
+    }
+
+    #[test]
+    fn defines_structs_that_were_only_forward_declared() {
+        let ast: Vec<Ast> = vec![
+            self::structure(
+                "A",
+                vec![(
+                    "b",
+                    Type::Ptr(Some(Box::new(self::struct_type("B"))), Span::nothing()),
+                )],
+            ),
+            self::structure(
+                "B",
+                vec![(
+                    "value",
+                    Type::U32 {
+                        span: Span::nothing(),
+                    },
+                )],
+            ),
+        ];
+
+        let header: String = CHeaderGenerator::new(&ast).generate("test");
+
+        assert!(header.contains("struct B;"));
+        assert!(header.contains("struct A {"));
+        assert!(header.contains("struct B {"));
+        assert_eq!(header.matches("struct B;").count(), 1);
+    }
+
+    #[test]
+    fn defines_each_struct_once() {
+        let ast: Vec<Ast> = vec![
+            self::structure("A", vec![("b", self::struct_type("B"))]),
+            self::structure(
+                "B",
+                vec![(
+                    "value",
+                    Type::U32 {
+                        span: Span::nothing(),
+                    },
+                )],
+            ),
+        ];
+
+        let header: String = CHeaderGenerator::new(&ast).generate("test");
+
+        assert_eq!(header.matches("struct B {").count(), 1);
+        assert!(header.find("struct B {") < header.find("struct A {"));
+    }
+
+    #[test]
+    fn renders_signed_limits_as_valid_c() {
+        assert_eq!(
+            super::literal(&self::negate(self::s64(1 << 63))),
+            Some("(-9223372036854775807LL - 1)".into())
+        );
+        assert_eq!(
+            super::literal(&self::negate(self::s64(5))),
+            Some("-5LL".into())
+        );
+        assert_eq!(
+            super::literal(&self::s64(i64::MAX as u128)),
+            Some("9223372036854775807LL".into())
+        );
+        assert_eq!(
+            super::literal(&self::negate(self::s64((1 << 63) + 1))),
+            None
+        );
+    }
+}
@@ -0,0 +1,50 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+
+use thrustc_options::CompilerOptions;
+
+use crate::{ThrustCompiler, utils};
+
+pub fn emit_c_source(
+    compiler: &ThrustCompiler,
+    source: &str,
+    build_dir: &std::path::Path,
+    file_name: &str,
+) -> Result<(), std::io::Error> {
+    let compiler_options: &CompilerOptions = compiler.get_compilation_options();
+    let need_obfuscation: bool = compiler_options.need_obfuscate_archive_names();
+
+    let csource_base_path: std::path::PathBuf = build_dir.join("emit").join("c");
+
+    if !csource_base_path.exists() {
+        let _ = std::fs::create_dir_all(&csource_base_path);
+    }
+
+    let csource_file_name: String = if need_obfuscation {
+        format!(
+            "{}_{}.c",
+            utils::generate_random_string(thrustc_constants::COMPILER_HARD_OBFUSCATION_LEVEL),
+            file_name
+        )
+    } else {
+        format!("{}.c", file_name)
+    };
+
+    std::fs::write(csource_base_path.join(csource_file_name), source)
+}
@@ -0,0 +1,107 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+
+use thrustc_ast::Ast;
+use thrustc_options::CompilationUnit;
+use thrustc_preprocessor::module::Module;
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+pub fn collect_dependencies(
+    file: &CompilationUnit,
+    modules: &[Module],
+    ast: &[Ast],
+) -> Vec<PathBuf> {
+    let mut dependencies: Vec<PathBuf> = Vec::with_capacity(u8::MAX as usize);
+
+    let current_dir: &Path = file.get_path().parent().unwrap_or(Path::new("."));
+
+    dependencies.push(file.get_path().to_path_buf());
+
+    self::collect_module_dependencies(modules, &mut dependencies);
+
+    ast.iter().for_each(|node| {
+        if let Ast::Embedded { path, .. } = node {
+            let resource: PathBuf = if path.is_relative() {
+                current_dir.join(path)
+            } else {
+                path.clone()
+            };
+
+            dependencies.push(resource);
+        }
+    });
+
+    let mut seen: HashSet<PathBuf> = HashSet::with_capacity(dependencies.len());
+    dependencies.retain(|dependency| seen.insert(dependency.clone()));
+
+    dependencies
+}
+
+fn collect_module_dependencies(modules: &[Module], dependencies: &mut Vec<PathBuf>) {
+    for module in modules.iter() {
+        dependencies.push(module.get_path().to_path_buf());
+        self::collect_module_dependencies(module.get_submodules(), dependencies);
+    }
+}
+
+pub fn emit_dep_info(
+    target: &Path,
+    dependencies: &[PathBuf],
+    build_dir: &Path,
+    file_name: &str,
+) -> Result<(), std::io::Error> {
+    let depinfo_base_path: PathBuf = build_dir.join("emit").join("dep-info");
+
+    if !depinfo_base_path.exists() {
+        let _ = std::fs::create_dir_all(&depinfo_base_path);
+    }
+
+    let mut depinfo: String = format!("{}:", self::escape(target));
+
+    for dependency in dependencies.iter() {
+        depinfo.push_str(&format!(" \\\n  {}", self::escape(dependency)));
+    }
+
+    depinfo.push('\n');
+
+    for dependency in dependencies.iter().skip(1) {
+        depinfo.push_str(&format!("\n{}:\n", self::escape(dependency)));
+    }
+
+    std::fs::write(depinfo_base_path.join(format!("{}.d", file_name)), depinfo)
+}
+
+fn escape(path: &Path) -> String {
+    let mut escaped: String = String::with_capacity(u8::MAX as usize);
+
+    for c in path.to_string_lossy().chars() {
+        match c {
+            ' ' | '#' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '$' => escaped.push_str("$$"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
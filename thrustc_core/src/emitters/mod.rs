@@ -18,6 +18,9 @@
 */
 
 pub mod assembler;
+pub mod cheader;
+pub mod csource;
+pub mod depinfo;
 pub mod llvmbitcode;
 pub mod llvmir;
 pub mod objfile;
@@ -115,3 +115,43 @@ pub fn llvm_obj_compilation(
 
     obj_file_path
 }
+
+#[inline]
+pub fn c_source_compilation(
+    source: &str,
+    build_dir: &std::path::Path,
+    file_name: &str,
+) -> std::path::PathBuf {
+    let path: std::path::PathBuf = build_dir.join("c");
+
+    if !path.exists() {
+        std::fs::create_dir_all(&path).unwrap_or_else(|_| {
+            thrustc_logging::print_critical_error(
+                thrustc_logging::LoggingType::Error,
+                &format!(
+                    "Cannot create directory '{}' for C source files compilation.",
+                    path.display()
+                ),
+            )
+        });
+    }
+
+    let c_file_path: std::path::PathBuf = path.join(format!(
+        "{}_{}.c",
+        utils::generate_random_string(thrustc_constants::COMPILER_HARD_OBFUSCATION_LEVEL),
+        file_name
+    ));
+
+    std::fs::write(&c_file_path, source).unwrap_or_else(|error| {
+        thrustc_logging::print_backend_panic(
+            thrustc_logging::LoggingType::BackendPanic,
+            &format!(
+                "'{}' cannot be emited as C source file '{}'.",
+                c_file_path.display(),
+                error
+            ),
+        );
+    });
+
+    c_file_path
+}
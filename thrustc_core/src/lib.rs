@@ -18,6 +18,7 @@
 */
 
 pub mod cleaner;
+pub mod deadsymbols;
 pub mod emit;
 pub mod emitters;
 pub mod finisher;
@@ -46,8 +47,11 @@ use thrustc_backends::llvm::LLVMBackend;
 use thrustc_backends::llvm::jit;
 use thrustc_backends::llvm::jit::JITConfiguration;
 use thrustc_backends::llvm::target::LLVMTarget;
+use thrustc_c_codegen::context::CCodeGenContext;
 use thrustc_diagnostician::Diagnostician;
+use thrustc_errors::CompilationEdit;
 use thrustc_lexer::Lexer;
+use thrustc_linter::usage::LinterSymbolUsage;
 use thrustc_llvm_callconventions_checker::LLVMCallConventionsChecker;
 use thrustc_llvm_codegen::context::LLVMCodeGenContext;
 use thrustc_llvm_codegen::jit::LLVMJITCompiler;
@@ -55,6 +59,7 @@ use thrustc_llvm_codegen::optimizer::LLVMOptimizationConfig;
 use thrustc_llvm_codegen::optimizer::LLVMOptimizerFlags;
 use thrustc_llvm_codegen::optimizer::LLVMOptimizerPasses;
 use thrustc_llvm_intrinsic_checker::LLVMIntrinsicChecker;
+use thrustc_mir_builder::MirBuilder;
 use thrustc_options::CompilationUnit;
 use thrustc_options::CompilerOptions;
 use thrustc_options::Emited;
@@ -71,6 +76,8 @@ pub struct ThrustCompiler<'thrustc> {
 
     options: &'thrustc CompilerOptions,
 
+    symbol_usages: Vec<(&'thrustc CompilationUnit, LinterSymbolUsage)>,
+
     linking_time: std::time::Duration,
     thrustc_frontend_time: std::time::Duration,
     thrustc_backend_time: std::time::Duration,
@@ -93,6 +100,8 @@ impl<'thrustc> ThrustCompiler<'thrustc> {
 
             options,
 
+            symbol_usages: Vec::with_capacity(files.len()),
+
             linking_time: std::time::Duration::default(),
             thrustc_frontend_time: std::time::Duration::default(),
             thrustc_backend_time: std::time::Duration::default(),
@@ -103,6 +112,10 @@ impl<'thrustc> ThrustCompiler<'thrustc> {
 
 impl ThrustCompiler<'_> {
     pub fn compile(&mut self) -> CompileTime {
+        if self.get_compilation_options().need_apply_fixes() {
+            return self.compile_fixes();
+        }
+
         if self.get_compilation_options().llvm() {
             Target::initialize_all(&InitializationConfig::default());
 
@@ -115,10 +128,12 @@ impl ThrustCompiler<'_> {
             } else {
                 return self.compile_aot_llvm();
             }
+        } else if self.get_compilation_options().c() {
+            return self.compile_aot_c();
         } else {
             thrustc_logging::print_warn(
                 thrustc_logging::LoggingType::Warning,
-                "Unrecognizable code generator selection. You should select either LLVM or GCC.",
+                "Unrecognizable code generator selection. You should select either LLVM or C.",
             );
         }
 
@@ -147,6 +162,8 @@ impl<'thrustc> ThrustCompiler<'thrustc> {
             || self.get_compilation_options().get_was_emited()
             || self.get_compiled_files().is_empty();
 
+        disrupted = disrupted || deadsymbols::check_dead_public_symbols(self);
+
         if disrupted {
             return (
                 disrupted,
@@ -182,6 +199,7 @@ impl<'thrustc> ThrustCompiler<'thrustc> {
         let frontend_time: std::time::Instant = std::time::Instant::now();
 
         starter::archive_compilation_unit(file);
+        utils::seed_compilation_unit(self.options, file);
 
         let llvm_backend: &LLVMBackend = self.options.get_llvm_backend();
         let build_dir: &std::path::PathBuf = self.options.get_build_dir();
@@ -200,6 +218,14 @@ impl<'thrustc> ThrustCompiler<'thrustc> {
             return finisher::archive_compilation(self, file_time, file);
         }
 
+        let Ok(tokens) = Preprocessor::configure(tokens, self.options, file) else {
+            return interrupt::archive_compilation_unit(self, file, file_time);
+        };
+
+        let Ok(tokens) = Preprocessor::expand(tokens, self.options, file) else {
+            return interrupt::archive_compilation_unit(self, file, file_time);
+        };
+
         let mut preprocessor: Preprocessor = Preprocessor::new();
         let modules: Result<&[thrustc_preprocessor::module::Module], ()> =
             preprocessor.generate_modules(&tokens, self.options, file);
@@ -230,6 +256,8 @@ impl<'thrustc> ThrustCompiler<'thrustc> {
         let parser_context: ParserContext = parser_result.0;
 
         let ast: &[Ast] = parser_context.get_ast();
+        let dependencies: Vec<std::path::PathBuf> =
+            emitters::depinfo::collect_dependencies(file, modules, ast);
 
         if emit::frontend_before(self, build_dir, file, Emited::Ast(ast)) {
             return finisher::archive_compilation(self, file_time, file);
@@ -239,8 +267,10 @@ impl<'thrustc> ThrustCompiler<'thrustc> {
             return finisher::archive_compilation(self, file_time, file);
         }
 
+        let mut semantic_analysis: SemanticAnalysis =
+            SemanticAnalysis::new(ast, file, self.options);
         let semantic_analysis_throwed_errors: bool =
-            SemanticAnalysis::new(ast, file, self.options).analyze(parser_throwed_errors);
+            semantic_analysis.analyze(parser_throwed_errors);
 
         self.update_thrustc_frontend_time(frontend_time.elapsed());
 
@@ -248,6 +278,8 @@ impl<'thrustc> ThrustCompiler<'thrustc> {
             return finisher::archive_compilation(self, file_time, file);
         }
 
+        self.add_symbol_usage(file, semantic_analysis.get_symbol_usage());
+
         let mut intrinsic_checker: LLVMIntrinsicChecker<'_> =
             LLVMIntrinsicChecker::new(ast, file, self.options);
 
@@ -263,6 +295,21 @@ impl<'thrustc> ThrustCompiler<'thrustc> {
             return interrupt::archive_compilation_unit(self, file, file_time);
         }
 
+        // Every unit goes through the MIR builder and its verifier before codegen, even
+        // though the LLVM backend itself still lowers from the AST.
+        let mut mir_builder: MirBuilder<'_> = MirBuilder::new(ast, file, self.options);
+        let mir_builder_result: bool = mir_builder.build();
+
+        self.update_thrustc_frontend_time(frontend_time.elapsed());
+
+        if mir_builder_result {
+            return interrupt::archive_compilation_unit(self, file, file_time);
+        }
+
+        if print::mir(self, file, mir_builder.get_bodies()) {
+            return finisher::archive_compilation(self, file_time, file);
+        }
+
         if print::frontend_after(self, file, Emited::Ast(ast)) {
             return finisher::archive_compilation(self, file_time, file);
         }
@@ -416,6 +463,8 @@ impl<'thrustc> ThrustCompiler<'thrustc> {
             file.get_name(),
         );
 
+        emit::dep_info(self, &obj_file, &dependencies, build_dir, file, file_time)?;
+
         self.add_compiled_unit(obj_file);
 
         self.update_thrustc_backend_time(backend_time.elapsed());
@@ -426,6 +475,252 @@ impl<'thrustc> ThrustCompiler<'thrustc> {
     }
 }
 
+impl<'thrustc> ThrustCompiler<'thrustc> {
+    fn compile_aot_c(&mut self) -> CompileTime {
+        cleaner::auto_clean(self.get_compilation_options());
+
+        let mut disrupted: bool = false;
+
+        for file in self.unready.iter() {
+            disrupted = self.compile_file_with_c(file).is_err();
+        }
+
+        disrupted = disrupted
+            || self.get_compilation_options().get_was_printed()
+            || self.get_compilation_options().get_was_emited()
+            || self.get_compiled_files().is_empty();
+
+        disrupted = disrupted || deadsymbols::check_dead_public_symbols(self);
+
+        if disrupted {
+            return (
+                disrupted,
+                self.thrustc_time,
+                self.thrustc_frontend_time,
+                self.thrustc_backend_time,
+                self.linking_time,
+            );
+        }
+
+        starter::linking_phase(self.get_compiled_files());
+
+        let linking_compiler_config: &LinkingCompilersConfiguration =
+            self.options.get_linking_compilers_configuration();
+
+        if linking_compiler_config.get_use_clang() {
+            linkage::link_with_clang(self);
+        } else if linking_compiler_config.get_use_gcc() {
+            linkage::link_with_gcc(self);
+        }
+
+        (
+            disrupted,
+            self.thrustc_time,
+            self.thrustc_frontend_time,
+            self.thrustc_backend_time,
+            self.linking_time,
+        )
+    }
+
+    fn compile_file_with_c(&mut self, file: &'thrustc CompilationUnit) -> Result<(), ()> {
+        let file_time: std::time::Instant = std::time::Instant::now();
+        let frontend_time: std::time::Instant = std::time::Instant::now();
+
+        starter::archive_compilation_unit(file);
+        utils::seed_compilation_unit(self.options, file);
+
+        let build_dir: &std::path::PathBuf = self.options.get_build_dir();
+
+        let Ok(tokens) = Lexer::lex(file, self.options) else {
+            return interrupt::archive_compilation_unit(self, file, file_time);
+        };
+
+        self.update_thrustc_frontend_time(frontend_time.elapsed());
+
+        if print::frontend_before(self, file, Emited::Tokens(&tokens)) {
+            return finisher::archive_compilation(self, file_time, file);
+        }
+
+        if emit::frontend_before(self, build_dir, file, Emited::Tokens(&tokens)) {
+            return finisher::archive_compilation(self, file_time, file);
+        }
+
+        let Ok(tokens) = Preprocessor::configure(tokens, self.options, file) else {
+            return interrupt::archive_compilation_unit(self, file, file_time);
+        };
+
+        let Ok(tokens) = Preprocessor::expand(tokens, self.options, file) else {
+            return interrupt::archive_compilation_unit(self, file, file_time);
+        };
+
+        let mut preprocessor: Preprocessor = Preprocessor::new();
+        let modules: Result<&[thrustc_preprocessor::module::Module], ()> =
+            preprocessor.generate_modules(&tokens, self.options, file);
+
+        self.update_thrustc_frontend_time(frontend_time.elapsed());
+
+        let modules: &[thrustc_preprocessor::module::Module] = modules.map_err(|_| {
+            let _ = interrupt::archive_compilation_unit_with_message(
+                self,
+                thrustc_logging::LoggingType::Error,
+                "Failed to get all modules from the preprocessor. Maybe this is a issue.",
+                file,
+                file_time,
+            );
+        })?;
+
+        let parser_result: (ParserContext, bool) =
+            Parser::parse(&tokens, modules, file, self.options);
+
+        let parser_throwed_errors: bool = parser_result.1;
+        let parser_context: ParserContext = parser_result.0;
+
+        let ast: &[Ast] = parser_context.get_ast();
+        let dependencies: Vec<std::path::PathBuf> =
+            emitters::depinfo::collect_dependencies(file, modules, ast);
+
+        if emit::frontend_before(self, build_dir, file, Emited::Ast(ast)) {
+            return finisher::archive_compilation(self, file_time, file);
+        }
+
+        if print::frontend_before(self, file, Emited::Ast(ast)) {
+            return finisher::archive_compilation(self, file_time, file);
+        }
+
+        let mut semantic_analysis: SemanticAnalysis =
+            SemanticAnalysis::new(ast, file, self.options);
+        let semantic_analysis_throwed_errors: bool =
+            semantic_analysis.analyze(parser_throwed_errors);
+
+        self.update_thrustc_frontend_time(frontend_time.elapsed());
+
+        if parser_throwed_errors || semantic_analysis_throwed_errors {
+            return finisher::archive_compilation(self, file_time, file);
+        }
+
+        self.add_symbol_usage(file, semantic_analysis.get_symbol_usage());
+
+        if print::frontend_after(self, file, Emited::Ast(ast)) {
+            return finisher::archive_compilation(self, file_time, file);
+        }
+
+        if emit::frontend_after(self, build_dir, file, Emited::Ast(ast)) {
+            return finisher::archive_compilation(self, file_time, file);
+        }
+
+        let backend_time: std::time::Instant = std::time::Instant::now();
+
+        let mut c_codegen_context: CCodeGenContext =
+            CCodeGenContext::new(Diagnostician::new(file, self.options), self.options);
+
+        let source: String = thrustc_c_codegen::CCompiler::compile(&mut c_codegen_context, ast);
+
+        self.update_thrustc_backend_time(backend_time.elapsed());
+
+        if emit::c_source(self, &source, build_dir, file, file_time)? {
+            return finisher::archive_compilation(self, file_time, file);
+        }
+
+        let c_file: std::path::PathBuf =
+            finisher::c_source_compilation(&source, build_dir, file.get_name());
+
+        emit::dep_info(self, &c_file, &dependencies, build_dir, file, file_time)?;
+
+        self.add_compiled_unit(c_file);
+
+        self.update_thrustc_backend_time(backend_time.elapsed());
+
+        finisher::archive_compilation(self, file_time, file)?;
+
+        Ok(())
+    }
+}
+
+impl<'thrustc> ThrustCompiler<'thrustc> {
+    fn compile_fixes(&mut self) -> CompileTime {
+        let mut disrupted: bool = false;
+
+        for file in self.unready.iter() {
+            disrupted |= self.fix_file(file).is_err();
+        }
+
+        (
+            disrupted,
+            self.thrustc_time,
+            self.thrustc_frontend_time,
+            self.thrustc_backend_time,
+            self.linking_time,
+        )
+    }
+
+    fn fix_file(&mut self, file: &'thrustc CompilationUnit) -> Result<(), ()> {
+        let file_time: std::time::Instant = std::time::Instant::now();
+        let frontend_time: std::time::Instant = std::time::Instant::now();
+
+        starter::archive_compilation_unit(file);
+        utils::seed_compilation_unit(self.options, file);
+
+        let Ok(tokens) = Lexer::lex(file, self.options) else {
+            return interrupt::archive_compilation_unit(self, file, file_time);
+        };
+
+        let Ok(tokens) = Preprocessor::configure(tokens, self.options, file) else {
+            return interrupt::archive_compilation_unit(self, file, file_time);
+        };
+
+        let Ok(tokens) = Preprocessor::expand(tokens, self.options, file) else {
+            return interrupt::archive_compilation_unit(self, file, file_time);
+        };
+
+        let mut preprocessor: Preprocessor = Preprocessor::new();
+
+        let Ok(modules) = preprocessor.generate_modules(&tokens, self.options, file) else {
+            return interrupt::archive_compilation_unit(self, file, file_time);
+        };
+
+        let parser_result: (ParserContext, bool) =
+            Parser::parse(&tokens, modules, file, self.options);
+
+        let parser_throwed_errors: bool = parser_result.1;
+        let parser_context: ParserContext = parser_result.0;
+
+        let ast: &[Ast] = parser_context.get_ast();
+
+        SemanticAnalysis::new(ast, file, self.options).analyze(parser_throwed_errors);
+
+        self.update_thrustc_frontend_time(frontend_time.elapsed());
+
+        let edits: Vec<CompilationEdit> = thrustc_diagnostician::fixes::take_edits(file.get_path());
+
+        let (fixed_code, applied): (String, usize) =
+            thrustc_diagnostician::fixes::apply_edits(file.get_unit_content(), &edits);
+
+        if applied > 0 && std::fs::write(file.get_path(), fixed_code).is_err() {
+            return interrupt::archive_compilation_unit_with_message(
+                self,
+                thrustc_logging::LoggingType::Error,
+                &format!(
+                    "Unable to write the fixed source code to '{}'.",
+                    file.get_path().display()
+                ),
+                file,
+                file_time,
+            );
+        }
+
+        thrustc_logging::write(
+            thrustc_logging::OutputIn::Stdout,
+            &format!(
+                "Applied {} fix(es) to '{}'.\n",
+                applied,
+                file.get_path().display()
+            ),
+        );
+
+        finisher::archive_compilation(self, file_time, file)
+    }
+}
+
 impl<'thrustc> ThrustCompiler<'thrustc> {
     fn compile_jit_llvm(&mut self) -> CompileTime {
         cleaner::auto_clean(self.get_compilation_options());
@@ -455,6 +750,8 @@ impl<'thrustc> ThrustCompiler<'thrustc> {
             || self.get_compilation_options().get_was_emited()
             || modules.is_empty();
 
+        disrupted = disrupted || deadsymbols::check_dead_public_symbols(self);
+
         if disrupted {
             return (
                 disrupted,
@@ -520,6 +817,7 @@ impl<'thrustc> ThrustCompiler<'thrustc> {
         let frontend_time: std::time::Instant = std::time::Instant::now();
 
         starter::archive_compilation_unit(file);
+        utils::seed_compilation_unit(self.options, file);
 
         let llvm_backend: &LLVMBackend = self.options.get_llvm_backend();
         let build_dir: &std::path::PathBuf = self.options.get_build_dir();
@@ -538,6 +836,14 @@ impl<'thrustc> ThrustCompiler<'thrustc> {
             return finisher::archive_compilation_module_jit(self, file_time, file);
         }
 
+        let Ok(tokens) = Preprocessor::configure(tokens, self.options, file) else {
+            return interrupt::archive_compilation_unit_jit(self, file, file_time);
+        };
+
+        let Ok(tokens) = Preprocessor::expand(tokens, self.options, file) else {
+            return interrupt::archive_compilation_unit_jit(self, file, file_time);
+        };
+
         let mut preprocessor: Preprocessor = Preprocessor::new();
         let modules: Result<&[thrustc_preprocessor::module::Module], ()> =
             preprocessor.generate_modules(&tokens, self.options, file);
@@ -575,8 +881,10 @@ impl<'thrustc> ThrustCompiler<'thrustc> {
             return finisher::archive_compilation_module_jit(self, file_time, file);
         }
 
+        let mut semantic_analysis: SemanticAnalysis =
+            SemanticAnalysis::new(ast, file, self.options);
         let semantic_analysis_throwed_errors: bool =
-            SemanticAnalysis::new(ast, file, self.options).analyze(parser_throwed_errors);
+            semantic_analysis.analyze(parser_throwed_errors);
 
         self.update_thrustc_frontend_time(frontend_time.elapsed());
 
@@ -584,6 +892,8 @@ impl<'thrustc> ThrustCompiler<'thrustc> {
             return finisher::archive_compilation_module_jit(self, file_time, file);
         }
 
+        self.add_symbol_usage(file, semantic_analysis.get_symbol_usage());
+
         let mut intrinsic_checker: LLVMIntrinsicChecker<'_> =
             LLVMIntrinsicChecker::new(ast, file, self.options);
 
@@ -599,6 +909,21 @@ impl<'thrustc> ThrustCompiler<'thrustc> {
             return interrupt::archive_compilation_unit_jit(self, file, file_time);
         }
 
+        // Every unit goes through the MIR builder and its verifier before codegen, even
+        // though the LLVM backend itself still lowers from the AST.
+        let mut mir_builder: MirBuilder<'_> = MirBuilder::new(ast, file, self.options);
+        let mir_builder_result: bool = mir_builder.build();
+
+        self.update_thrustc_frontend_time(frontend_time.elapsed());
+
+        if mir_builder_result {
+            return interrupt::archive_compilation_unit_jit(self, file, file_time);
+        }
+
+        if print::mir(self, file, mir_builder.get_bodies()) {
+            return finisher::archive_compilation_module_jit(self, file_time, file);
+        }
+
         if print::frontend_after(self, file, Emited::Ast(ast)) {
             return finisher::archive_compilation_module_jit(self, file_time, file);
         }
@@ -770,11 +1095,21 @@ impl ThrustCompiler<'_> {
     pub fn get_compilation_options(&self) -> &CompilerOptions {
         self.options
     }
+
+    #[inline]
+    pub fn get_symbol_usages(&self) -> &[(&CompilationUnit, LinterSymbolUsage)] {
+        &self.symbol_usages
+    }
 }
 
-impl ThrustCompiler<'_> {
+impl<'thrustc> ThrustCompiler<'thrustc> {
     #[inline]
     pub fn add_compiled_unit(&mut self, path: std::path::PathBuf) {
         self.ready.push(path);
     }
+
+    #[inline]
+    pub fn add_symbol_usage(&mut self, file: &'thrustc CompilationUnit, usage: LinterSymbolUsage) {
+        self.symbol_usages.push((file, usage));
+    }
 }
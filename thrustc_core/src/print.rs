@@ -22,6 +22,7 @@
 use inkwell::module::Module;
 use inkwell::targets::TargetMachine;
 
+use thrustc_mir::body::MirBody;
 use thrustc_options::{CompilationUnit, CompilerOptions, Emited, PrintableUnit};
 
 use crate::{ThrustCompiler, interrupt, printers};
@@ -153,3 +154,18 @@ pub fn frontend_after(
 
     false
 }
+
+#[inline]
+pub fn mir(compiler: &mut ThrustCompiler, file: &CompilationUnit, bodies: &[MirBody]) -> bool {
+    let options: &CompilerOptions = compiler.get_compilation_options();
+
+    if options.contains_printable(PrintableUnit::Mir) {
+        if printers::mir::print_to_stdout_pretty(options, bodies, file.get_name()).is_err() {
+            return false;
+        }
+
+        return true;
+    }
+
+    false
+}
@@ -20,4 +20,5 @@
 pub mod assembler;
 pub mod ast;
 pub mod llvmir;
+pub mod mir;
 pub mod tokens;
@@ -17,6 +17,16 @@
 
 */
 
+use thrustc_options::{CompilationUnit, CompilerOptions};
+
+pub fn seed_compilation_unit(options: &CompilerOptions, file: &CompilationUnit) {
+    if options.is_deterministic() {
+        fastrand::seed(thrustc_utils::get_content_hash(
+            file.get_unit_content().as_bytes(),
+        ));
+    }
+}
+
 pub fn generate_random_string(max: usize) -> String {
     let length: usize = fastrand::usize(5..=max);
 
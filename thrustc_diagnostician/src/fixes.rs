@@ -0,0 +1,179 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+use thrustc_errors::CompilationEdit;
+
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+static PENDING_EDITS: Mutex<Vec<(PathBuf, CompilationEdit)>> = Mutex::new(Vec::new());
+
+#[inline]
+pub(crate) fn record(path: &Path, edits: &[CompilationEdit]) {
+    if let Ok(mut pending) = PENDING_EDITS.lock() {
+        pending.extend(edits.iter().map(|edit| (path.to_path_buf(), edit.clone())));
+    }
+}
+
+pub fn take_edits(path: &Path) -> Vec<CompilationEdit> {
+    let Ok(mut pending) = PENDING_EDITS.lock() else {
+        return Vec::new();
+    };
+
+    let (taken, remaining): (Vec<_>, Vec<_>) = pending
+        .drain(..)
+        .partition(|(edit_path, _)| edit_path == path);
+
+    *pending = remaining;
+
+    taken.into_iter().map(|(_, edit)| edit).collect()
+}
+
+pub fn apply_edits(code: &str, edits: &[CompilationEdit]) -> (String, usize) {
+    let line_offsets: Vec<usize> = std::iter::once(0)
+        .chain(code.match_indices('\n').map(|(idx, _)| idx + 1))
+        .collect();
+
+    let mut resolved: Vec<(usize, usize, &str)> = edits
+        .iter()
+        .filter_map(|edit| {
+            let line: usize = edit.get_span().get_line().try_into().ok()?;
+            let line_start: usize = *line_offsets.get(line.checked_sub(1)?)?;
+
+            let line_end: usize = line_offsets
+                .get(line)
+                .map_or(code.len(), |next| next.saturating_sub(1));
+
+            let line_code: &str = &code[line_start..line_end];
+
+            let start: usize = self::get_byte_offset(line_code, edit.get_span().get_span_start())?;
+            let end: usize = self::get_byte_offset(line_code, edit.get_span().get_span_end())?;
+
+            if start > end {
+                return None;
+            }
+
+            Some((line_start + start, line_start + end, edit.get_replacement()))
+        })
+        .collect();
+
+    resolved.sort_unstable();
+    resolved.dedup();
+
+    let mut accepted: Vec<(usize, usize, &str)> = Vec::with_capacity(resolved.len());
+
+    for edit in resolved {
+        let overlaps: bool = accepted
+            .last()
+            .is_some_and(|last| edit.0 < last.1 || (edit.0 == last.0 && edit.0 == edit.1));
+
+        if !overlaps {
+            accepted.push(edit);
+        }
+    }
+
+    let mut fixed: String = code.to_string();
+
+    for (start, end, replacement) in accepted.iter().rev() {
+        fixed.replace_range(*start..*end, replacement);
+    }
+
+    (fixed, accepted.len())
+}
+
+fn get_byte_offset(line: &str, column: u32) -> Option<usize> {
+    let column: usize = column.try_into().ok()?;
+
+    line.char_indices()
+        .map(|(idx, _)| idx)
+        .chain(std::iter::once(line.len()))
+        .nth(column)
+}
+
+#[cfg(test)]
+mod tests {
+    use thrustc_errors::CompilationEdit;
+    use thrustc_span::Span;
+
+    use super::apply_edits;
+
+    fn replace(line: u32, start: u32, end: u32, text: &str) -> CompilationEdit {
+        CompilationEdit::new(Span::new((line, (start, end))), text.into())
+    }
+
+    #[test]
+    fn replaces_a_span() {
+        let edits: Vec<CompilationEdit> = vec![self::replace(1, 4, 5, "y")];
+
+        assert_eq!(
+            apply_edits("var x: s32 = 1;\n", &edits),
+            ("var y: s32 = 1;\n".into(), 1)
+        );
+    }
+
+    #[test]
+    fn inserts_and_removes_on_later_lines() {
+        let edits: Vec<CompilationEdit> = vec![
+            CompilationEdit::insertion(2, 0, "// ".into()),
+            CompilationEdit::removal(Span::new((3, (0, 4)))),
+        ];
+
+        assert_eq!(
+            apply_edits("a\nb\nc = d\n", &edits),
+            ("a\n// b\nd\n".into(), 2)
+        );
+    }
+
+    #[test]
+    fn applies_several_edits_on_the_same_line() {
+        let edits: Vec<CompilationEdit> =
+            vec![self::replace(1, 4, 7, "yz"), self::replace(1, 0, 3, "x")];
+
+        assert_eq!(apply_edits("abc def", &edits), ("x yz".into(), 2));
+    }
+
+    #[test]
+    fn skips_overlapping_and_duplicated_edits() {
+        let edits: Vec<CompilationEdit> = vec![
+            self::replace(1, 0, 3, "x"),
+            self::replace(1, 0, 3, "x"),
+            self::replace(1, 2, 5, "y"),
+        ];
+
+        assert_eq!(apply_edits("abcdef", &edits), ("xdef".into(), 1));
+    }
+
+    #[test]
+    fn ignores_edits_outside_of_the_source() {
+        let edits: Vec<CompilationEdit> = vec![
+            self::replace(0, 0, 1, "x"),
+            self::replace(5, 0, 1, "x"),
+            self::replace(1, 2, 9, "x"),
+            self::replace(1, 2, 1, "x"),
+        ];
+
+        assert_eq!(apply_edits("abc\n", &edits), ("abc\n".into(), 0));
+    }
+
+    #[test]
+    fn counts_columns_in_characters() {
+        let edits: Vec<CompilationEdit> = vec![self::replace(1, 1, 2, "b")];
+
+        assert_eq!(apply_edits("éa = 1", &edits), ("éb = 1".into(), 1));
+    }
+}
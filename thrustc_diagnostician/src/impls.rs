@@ -26,6 +26,7 @@ impl std::fmt::Display for Notificator {
         match self {
             Notificator::Error => write!(f, "{}: ", "ERROR".bright_red().bold().underline()),
             Notificator::Warning => write!(f, "{}: ", "WARNING".bright_yellow().bold().underline()),
+            Notificator::Note => write!(f, "{}: ", "NOTE".bright_blue().bold().underline()),
             Notificator::CompilerFrontendBug | Self::CompilerBackendBug => {
                 write!(f, "{}: ", "INFO".bright_green().bold().underline())
             }
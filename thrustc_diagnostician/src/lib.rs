@@ -20,28 +20,39 @@
 pub mod config;
 pub mod diagnostic;
 pub mod errors;
+pub mod fixes;
 mod impls;
 pub mod position;
 pub mod printers;
 mod traits;
+pub mod warnings;
 
+use thrustc_ast::Ast;
 use thrustc_errors::CompilationIssue;
+use thrustc_errors::CompilationIssueCode;
+use thrustc_errors::CompilationLabel;
 use thrustc_logging::LoggingType;
 use thrustc_logging::OutputIn;
 use thrustc_options::CompilationUnit;
 use thrustc_options::CompilerOptions;
+use thrustc_options::warnings::{WarningControl, WarningLevel};
+use thrustc_span::Span;
 
 use crate::config::DiagnosticianConfig;
 use crate::diagnostic::Diagnostic;
+use crate::warnings::AllowedWarnings;
 
+use std::borrow::Cow;
 use std::fs::OpenOptions;
 use std::io::Write;
+use std::path::Path;
 use std::path::PathBuf;
 
 #[derive(Debug, Clone, Copy)]
 enum Notificator {
     Error,
     Warning,
+    Note,
     CompilerFrontendBug,
     CompilerBackendBug,
 }
@@ -60,6 +71,10 @@ pub struct Diagnostician {
     base_name: String,
     code: String,
     config: DiagnosticianConfig,
+    warning_control: WarningControl,
+    allowed_warnings: AllowedWarnings,
+    denied_warnings: bool,
+    apply_fixes: bool,
 }
 
 impl Diagnostician {
@@ -74,12 +89,51 @@ impl Diagnostician {
                 options.get_export_compiler_error_diagnostics(),
                 options.get_export_compiler_warning_diagnostics(),
             ),
+            warning_control: options.get_warning_control().clone(),
+            allowed_warnings: AllowedWarnings::default(),
+            denied_warnings: false,
+            apply_fixes: options.need_apply_fixes(),
         }
     }
+
+    #[inline]
+    pub fn with_allowed_warnings(
+        file: &CompilationUnit,
+        options: &CompilerOptions,
+        ast: &[Ast],
+    ) -> Self {
+        Self::with_allowed_warnings_set(
+            file,
+            options,
+            AllowedWarnings::new(file.get_path(), ast),
+        )
+    }
+
+    #[inline]
+    pub fn with_allowed_warnings_set(
+        file: &CompilationUnit,
+        options: &CompilerOptions,
+        allowed_warnings: AllowedWarnings,
+    ) -> Self {
+        let mut diagnostician: Diagnostician = Self::new(file, options);
+        diagnostician.allowed_warnings = allowed_warnings;
+
+        diagnostician
+    }
 }
 
 impl Diagnostician {
+    #[inline]
     pub fn dispatch_diagnostic(&mut self, error: &CompilationIssue, logging_type: LoggingType) {
+        self.dispatch_with_labels(error, logging_type, &[]);
+    }
+
+    fn dispatch_with_labels(
+        &mut self,
+        error: &CompilationIssue,
+        logging_type: LoggingType,
+        labels: &[CompilationLabel],
+    ) {
         match error {
             CompilationIssue::Error(title, message, help, note, span) => {
                 let diagnostic: Diagnostic = diagnostic::build(
@@ -92,7 +146,7 @@ impl Diagnostician {
                     logging_type,
                 );
 
-                let generated_diagnostic: String = printers::print_to_string(
+                let mut generated_diagnostic: String = printers::print_to_string(
                     &diagnostic,
                     (
                         &title.to_title(),
@@ -102,6 +156,8 @@ impl Diagnostician {
                     ),
                 );
 
+                generated_diagnostic.push_str(&self.render_labels(labels, *span, logging_type));
+
                 if self.get_config().export_errors() {
                     let base_path: PathBuf = self.get_config().export_path().join("errors");
 
@@ -126,21 +182,42 @@ impl Diagnostician {
             }
 
             CompilationIssue::Warning(title, message, span) => {
+                let (diagnostic_type, notificator, logging_type): (
+                    DiagnosticType,
+                    Notificator,
+                    LoggingType,
+                ) = match self.get_warning_level(*title, *span) {
+                    WarningLevel::Allow => return,
+                    WarningLevel::Warn => {
+                        (DiagnosticType::Warning, Notificator::Warning, logging_type)
+                    }
+                    WarningLevel::Deny => {
+                        self.denied_warnings = true;
+                        (
+                            DiagnosticType::Error,
+                            Notificator::Error,
+                            LoggingType::Error,
+                        )
+                    }
+                };
+
                 let diagnostic: Diagnostic = diagnostic::build(
                     &self.code,
                     *span,
                     message,
                     "",
-                    DiagnosticType::Warning,
-                    Notificator::Warning,
+                    diagnostic_type,
+                    notificator,
                     logging_type,
                 );
 
-                let generated_diagnostic: String = printers::print_to_string(
+                let mut generated_diagnostic: String = printers::print_to_string(
                     &diagnostic,
                     (&title.to_title(), &self.path, None, logging_type),
                 );
 
+                generated_diagnostic.push_str(&self.render_labels(labels, *span, logging_type));
+
                 if self.get_config().export_warnings() {
                     let base_path: PathBuf = self.get_config().export_path().join("warnings");
 
@@ -164,6 +241,27 @@ impl Diagnostician {
                 thrustc_logging::write(OutputIn::Stderr, &generated_diagnostic);
             }
 
+            CompilationIssue::Fixable(issue, edits) => {
+                if let CompilationIssue::Warning(title, _, span) = &**issue {
+                    if let WarningLevel::Allow = self.get_warning_level(*title, *span) {
+                        return;
+                    }
+                }
+
+                if self.apply_fixes {
+                    fixes::record(&self.path, edits);
+                }
+
+                self.dispatch_with_labels(issue, logging_type, labels);
+            }
+
+            CompilationIssue::Labeled(issue, issue_labels) => {
+                let labels: Vec<CompilationLabel> =
+                    labels.iter().chain(issue_labels.iter()).cloned().collect();
+
+                self.dispatch_with_labels(issue, logging_type, &labels);
+            }
+
             CompilationIssue::FrontEndBug(title, message, span, position, path, line) => {
                 let diagnostic: Diagnostic = diagnostic::build(
                     &self.code,
@@ -179,6 +277,8 @@ impl Diagnostician {
                     &diagnostic,
                     (title, *position, logging_type, &self.path, path, *line),
                 );
+
+                thrustc_ice::report(message);
             }
 
             CompilationIssue::BackenEndBug(title, message, span, position, path, line) => {
@@ -196,11 +296,71 @@ impl Diagnostician {
                     &diagnostic,
                     (title, *position, logging_type, &self.path, path, *line),
                 );
+
+                thrustc_ice::report(message);
             }
         };
     }
 }
 
+impl Diagnostician {
+    fn render_labels(
+        &self,
+        labels: &[CompilationLabel],
+        span: Span,
+        logging_type: LoggingType,
+    ) -> String {
+        let mut rendered: String = String::new();
+
+        let expansion: Option<CompilationLabel> = span
+            .get_expansion()
+            .map(|call_site| CompilationLabel::new(call_site, "In this macro invocation.".into()));
+
+        for label in labels.iter().chain(expansion.iter()) {
+            let path: &Path = label.get_path().unwrap_or(&self.path);
+
+            let code: Cow<str> = if path == self.path {
+                Cow::Borrowed(&self.code)
+            } else if let Ok(code) = std::fs::read_to_string(path) {
+                Cow::Owned(code)
+            } else {
+                continue;
+            };
+
+            let diagnostic: Diagnostic = diagnostic::build(
+                &code,
+                label.get_span(),
+                label.get_message(),
+                "",
+                DiagnosticType::Warning,
+                Notificator::Note,
+                logging_type,
+            );
+
+            rendered.push_str(&printers::print_label_to_string(
+                &diagnostic,
+                path,
+                logging_type,
+            ));
+        }
+
+        rendered
+    }
+
+    fn get_warning_level(&self, code: CompilationIssueCode, span: Span) -> WarningLevel {
+        if self.allowed_warnings.contains(code, &self.path, span) {
+            return WarningLevel::Allow;
+        }
+
+        self.warning_control.get_level(code)
+    }
+
+    #[inline]
+    pub fn has_denied_warnings(&self) -> bool {
+        self.denied_warnings
+    }
+}
+
 impl Diagnostician {
     #[inline]
     pub fn get_file_path(&self) -> PathBuf {
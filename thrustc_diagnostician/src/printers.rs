@@ -76,6 +76,32 @@ pub fn print_to_string(diagnostic: &Diagnostic, error: Error<'_>) -> String {
     buffer
 }
 
+pub fn print_label_to_string(
+    diagnostic: &Diagnostic,
+    path: &Path,
+    logging_type: LoggingType,
+) -> String {
+    let code: &str = diagnostic.get_code();
+    let signaler: &str = diagnostic.get_signaler();
+
+    let line: u32 = diagnostic.get_span().get_line();
+    let start: u32 = diagnostic.get_span().get_span_start();
+
+    format!(
+        "{}:{}:{}:\n\n{}\n{}",
+        format_args!(
+            "{}",
+            logging_type
+                .text_with_color(path.to_string_lossy().as_ref())
+                .underline()
+        ),
+        logging_type.text_with_color(&line.to_string()),
+        logging_type.text_with_color(&start.to_string()),
+        code,
+        signaler
+    )
+}
+
 pub fn print_compiler_frontend_bug(diagnostic: &Diagnostic, error: FrontendError<'_>) {
     let title: &str = error.get_title();
     let position: CompilationPosition = error.get_position();
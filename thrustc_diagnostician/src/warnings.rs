@@ -0,0 +1,367 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+
+use std::path::{Path, PathBuf};
+
+use thrustc_ast::{Ast, traits::AstCodeLocation};
+use thrustc_attributes::ThrustAttribute;
+use thrustc_errors::CompilationIssueCode;
+use thrustc_span::Span;
+use thrustc_token::{Token, traits::TokenExtensions};
+use thrustc_token_type::TokenType;
+
+#[derive(Debug, Clone)]
+struct AllowedRegion {
+    path: PathBuf,
+    start: u32,
+    end: u32,
+    codes: Vec<CompilationIssueCode>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct AllowedWarnings {
+    regions: Vec<AllowedRegion>,
+}
+
+impl AllowedWarnings {
+    pub fn new(path: &Path, ast: &[Ast]) -> Self {
+        let mut regions: Vec<AllowedRegion> = Vec::with_capacity(u8::MAX as usize);
+
+        for (idx, node) in ast.iter().enumerate() {
+            let codes: Vec<CompilationIssueCode> = self::get_allowed_codes(node);
+
+            if codes.is_empty() {
+                continue;
+            }
+
+            let start: u32 = node.get_span().get_line();
+
+            let end: u32 = ast
+                .get(idx + 1)
+                .map_or(u32::MAX, |next| {
+                    next.get_span().get_line().saturating_sub(1)
+                })
+                .max(start);
+
+            regions.push(AllowedRegion {
+                path: path.to_path_buf(),
+                start,
+                end,
+                codes,
+            });
+        }
+
+        Self { regions }
+    }
+
+    /// Builds the regions straight from the tokens, for the passes that run before parsing.
+    /// A region covers the whole statement that carries the '@allow(...)' attribute.
+    pub fn from_tokens(path: &Path, tokens: &[Token]) -> Self {
+        let mut regions: Vec<AllowedRegion> = Vec::with_capacity(u8::MAX as usize);
+
+        let mut depth: u32 = 0;
+        let mut start: Option<u32> = None;
+
+        for (idx, token) in tokens.iter().enumerate() {
+            let line: u32 = token.get_span().get_line();
+
+            if depth == 0 && start.is_none() {
+                start = Some(line);
+            }
+
+            match token.get_type() {
+                TokenType::LBrace => depth += 1,
+                TokenType::RBrace => {
+                    depth = depth.saturating_sub(1);
+
+                    if depth == 0 {
+                        start = None;
+                    }
+                }
+                TokenType::SemiColon if depth == 0 => start = None,
+
+                TokenType::Allow => {
+                    let codes: Vec<CompilationIssueCode> =
+                        self::get_allowed_token_codes(&tokens[idx + 1..]);
+
+                    if codes.is_empty() {
+                        continue;
+                    }
+
+                    let region_start: u32 = start.unwrap_or(line);
+                    let region_end: u32 =
+                        self::get_statement_end(&tokens[idx..], depth).max(region_start);
+
+                    regions.push(AllowedRegion {
+                        path: path.to_path_buf(),
+                        start: region_start,
+                        end: region_end,
+                        codes,
+                    });
+                }
+
+                _ => (),
+            }
+        }
+
+        Self { regions }
+    }
+}
+
+impl AllowedWarnings {
+    #[inline]
+    pub fn contains(&self, code: CompilationIssueCode, path: &Path, span: Span) -> bool {
+        let line: u32 = span.get_line();
+
+        self.regions.iter().any(|region| {
+            line >= region.start
+                && line <= region.end
+                && region.codes.contains(&code)
+                && region.path == path
+        })
+    }
+}
+
+fn get_allowed_token_codes(tokens: &[Token]) -> Vec<CompilationIssueCode> {
+    if !tokens
+        .first()
+        .is_some_and(|token| token.get_type() == TokenType::LParen)
+    {
+        return Vec::new();
+    }
+
+    tokens
+        .iter()
+        .skip(1)
+        .take_while(|token| token.get_type() != TokenType::RParen)
+        .filter(|token| token.get_type() == TokenType::Identifier)
+        .filter_map(|token| CompilationIssueCode::from_warning(token.get_lexeme()))
+        .collect()
+}
+
+fn get_statement_end(tokens: &[Token], mut depth: u32) -> u32 {
+    let outer: u32 = depth;
+
+    for token in tokens.iter() {
+        match token.get_type() {
+            TokenType::LBrace => depth += 1,
+            TokenType::RBrace => {
+                depth = depth.saturating_sub(1);
+
+                if depth == outer {
+                    return token.get_span().get_line();
+                }
+            }
+            TokenType::SemiColon if depth == outer => return token.get_span().get_line(),
+
+            _ => (),
+        }
+    }
+
+    u32::MAX
+}
+
+fn get_allowed_codes(node: &Ast) -> Vec<CompilationIssueCode> {
+    let attributes: &[ThrustAttribute] = match node {
+        Ast::Function { attributes, .. }
+        | Ast::AssemblerFunction { attributes, .. }
+        | Ast::Intrinsic { attributes, .. }
+        | Ast::Struct { attributes, .. }
+        | Ast::Enum { attributes, .. }
+        | Ast::Static { attributes, .. }
+        | Ast::Const { attributes, .. }
+        | Ast::Import { attributes, .. } => attributes,
+
+        _ => return Vec::new(),
+    };
+
+    attributes
+        .iter()
+        .filter_map(|attribute| match attribute {
+            ThrustAttribute::Allow(codes, ..) => Some(codes.iter().copied()),
+            _ => None,
+        })
+        .flatten()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use thrustc_ast::{Ast, NodeId};
+    use thrustc_attributes::ThrustAttribute;
+    use thrustc_errors::CompilationIssueCode;
+    use thrustc_span::Span;
+    use thrustc_token::Token;
+    use thrustc_token_type::TokenType;
+    use thrustc_typesystem::Type;
+
+    use super::AllowedWarnings;
+
+    const FILE: &str = "main.thrust";
+
+    fn line(line: u32) -> Span {
+        Span::new((line, (0, 0)))
+    }
+
+    fn enumeration(at: u32, attributes: Vec<ThrustAttribute>) -> Ast<'static> {
+        Ast::Enum {
+            name: "Example",
+            data: Vec::new(),
+            attributes,
+            kind: Type::Void(self::line(at)),
+            span: self::line(at),
+            id: NodeId::new(),
+        }
+    }
+
+    fn token(kind: TokenType, lexeme: &str, at: u32) -> Token {
+        Token {
+            lexeme: lexeme.into(),
+            ascii: lexeme.into(),
+            kind,
+            span: self::line(at),
+        }
+    }
+
+    fn allow(codes: Vec<CompilationIssueCode>, at: u32) -> Vec<ThrustAttribute> {
+        vec![ThrustAttribute::Allow(codes, self::line(at))]
+    }
+
+    #[test]
+    fn region_ends_before_the_next_declaration() {
+        let ast: Vec<Ast> = vec![
+            self::enumeration(1, self::allow(vec![CompilationIssueCode::W0001], 1)),
+            self::enumeration(10, Vec::new()),
+        ];
+
+        let allowed: AllowedWarnings = AllowedWarnings::new(Path::new(FILE), &ast);
+
+        assert!(allowed.contains(CompilationIssueCode::W0001, Path::new(FILE), self::line(1)));
+        assert!(allowed.contains(CompilationIssueCode::W0001, Path::new(FILE), self::line(9)));
+        assert!(!allowed.contains(CompilationIssueCode::W0001, Path::new(FILE), self::line(10)));
+    }
+
+    #[test]
+    fn last_region_extends_to_the_end_of_the_file() {
+        let ast: Vec<Ast> = vec![
+            self::enumeration(1, Vec::new()),
+            self::enumeration(5, self::allow(vec![CompilationIssueCode::W0001], 5)),
+        ];
+
+        let allowed: AllowedWarnings = AllowedWarnings::new(Path::new(FILE), &ast);
+
+        assert!(!allowed.contains(CompilationIssueCode::W0001, Path::new(FILE), self::line(4)));
+        assert!(allowed.contains(CompilationIssueCode::W0001, Path::new(FILE), self::line(5)));
+        assert!(allowed.contains(
+            CompilationIssueCode::W0001,
+            Path::new(FILE),
+            self::line(u32::MAX)
+        ));
+    }
+
+    #[test]
+    fn only_the_listed_codes_are_allowed() {
+        let ast: Vec<Ast> = vec![self::enumeration(
+            1,
+            self::allow(vec![CompilationIssueCode::W0001], 1),
+        )];
+
+        let allowed: AllowedWarnings = AllowedWarnings::new(Path::new(FILE), &ast);
+
+        assert!(allowed.contains(CompilationIssueCode::W0001, Path::new(FILE), self::line(2)));
+        assert!(!allowed.contains(CompilationIssueCode::W0002, Path::new(FILE), self::line(2)));
+    }
+
+    #[test]
+    fn declarations_without_allow_have_no_region() {
+        let ast: Vec<Ast> = vec![self::enumeration(1, Vec::new())];
+
+        let allowed: AllowedWarnings = AllowedWarnings::new(Path::new(FILE), &ast);
+
+        assert!(!allowed.contains(CompilationIssueCode::W0001, Path::new(FILE), self::line(1)));
+    }
+
+    #[test]
+    fn regions_only_apply_to_their_own_file() {
+        let ast: Vec<Ast> = vec![self::enumeration(
+            1,
+            self::allow(vec![CompilationIssueCode::W0001], 1),
+        )];
+
+        let allowed: AllowedWarnings = AllowedWarnings::new(Path::new(FILE), &ast);
+
+        assert!(allowed.contains(CompilationIssueCode::W0001, Path::new(FILE), self::line(2)));
+        assert!(!allowed.contains(
+            CompilationIssueCode::W0001,
+            Path::new("other.thrust"),
+            self::line(2)
+        ));
+    }
+
+    #[test]
+    fn token_region_covers_the_annotated_statement() {
+        let tokens: Vec<Token> = vec![
+            self::token(TokenType::Import, "import", 1),
+            self::token(TokenType::CString, "a.thrust", 1),
+            self::token(TokenType::SemiColon, ";", 1),
+            self::token(TokenType::Import, "import", 2),
+            self::token(TokenType::CString, "b.thrust", 2),
+            self::token(TokenType::Allow, "@allow", 2),
+            self::token(TokenType::LParen, "(", 2),
+            self::token(TokenType::Identifier, "W0027", 2),
+            self::token(TokenType::RParen, ")", 2),
+            self::token(TokenType::SemiColon, ";", 2),
+            self::token(TokenType::Import, "import", 3),
+            self::token(TokenType::CString, "c.thrust", 3),
+            self::token(TokenType::SemiColon, ";", 3),
+        ];
+
+        let allowed: AllowedWarnings = AllowedWarnings::from_tokens(Path::new(FILE), &tokens);
+
+        assert!(!allowed.contains(CompilationIssueCode::W0027, Path::new(FILE), self::line(1)));
+        assert!(allowed.contains(CompilationIssueCode::W0027, Path::new(FILE), self::line(2)));
+        assert!(!allowed.contains(CompilationIssueCode::W0027, Path::new(FILE), self::line(3)));
+    }
+
+    #[test]
+    fn token_region_spans_a_function_body() {
+        let tokens: Vec<Token> = vec![
+            self::token(TokenType::Fn, "fn", 1),
+            self::token(TokenType::Identifier, "foo", 1),
+            self::token(TokenType::LParen, "(", 1),
+            self::token(TokenType::RParen, ")", 1),
+            self::token(TokenType::Allow, "@allow", 1),
+            self::token(TokenType::LParen, "(", 1),
+            self::token(TokenType::Identifier, "W0028", 1),
+            self::token(TokenType::RParen, ")", 1),
+            self::token(TokenType::LBrace, "{", 1),
+            self::token(TokenType::SemiColon, ";", 2),
+            self::token(TokenType::RBrace, "}", 3),
+            self::token(TokenType::Fn, "fn", 4),
+        ];
+
+        let allowed: AllowedWarnings = AllowedWarnings::from_tokens(Path::new(FILE), &tokens);
+
+        assert!(allowed.contains(CompilationIssueCode::W0028, Path::new(FILE), self::line(1)));
+        assert!(allowed.contains(CompilationIssueCode::W0028, Path::new(FILE), self::line(3)));
+        assert!(!allowed.contains(CompilationIssueCode::W0028, Path::new(FILE), self::line(4)));
+    }
+}
@@ -18,7 +18,7 @@
 */
 
 use thrustc_ast::{
-    data::EnumData,
+    data::{EnumData, InterfaceData},
     metadata::{FunctionParameterMetadata, LocalMetadata, StaticMetadata},
 };
 use thrustc_attributes::ThrustAttributes;
@@ -29,7 +29,7 @@ use ahash::AHashMap as HashMap;
 
 pub type Struct<'parser> = (
     &'parser str,
-    Vec<(&'parser str, Type, u32, Span)>,
+    Vec<(&'parser str, Type, u32, Span, Option<u8>)>,
     ThrustAttributes,
     StructureTypeModificator,
     Span,
@@ -62,6 +62,8 @@ pub type FoundSymbolId<'parser> = (
 
 pub type CustomTypeSymbol<'ctx> = (Type, ThrustAttributes);
 pub type EnumSymbol<'ctx> = (EnumData<'ctx>, ThrustAttributes);
+pub type InterfaceSymbol<'parser> = (InterfaceData<'parser>, Span);
+pub type ImplementationSymbol<'parser> = (Vec<&'parser str>, Span);
 pub type StaticSymbol<'parser> = (Type, StaticMetadata, ThrustAttributes);
 pub type ConstantSymbol<'parser> = (Type, ThrustAttributes);
 
@@ -82,7 +84,11 @@ pub type LocalConstants<'parser> = Vec<HashMap<&'parser str, ConstantSymbol<'par
 pub type GlobalConstants<'parser> = HashMap<&'parser str, ConstantSymbol<'parser>>;
 
 pub type GlobalEnums<'parser> = HashMap<&'parser str, EnumSymbol<'parser>>;
-pub type LocalEnums<'parser> = Vec<HashMap<&'parser str, EnumSymbol<'parser>>>;
+pub type LocalEnums<'parser> = Vec<HashMap<&'parser str, (EnumSymbol<'parser>, Span)>>;
+
+pub type Interfaces<'parser> = HashMap<&'parser str, InterfaceSymbol<'parser>>;
+pub type Implementations<'parser> =
+    HashMap<(&'parser str, &'parser str), ImplementationSymbol<'parser>>;
 
 pub type Parameters<'parser> = HashMap<&'parser str, ParameterSymbol<'parser>>;
 
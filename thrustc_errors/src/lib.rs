@@ -44,15 +44,128 @@ pub enum CompilationIssue {
         std::path::PathBuf,
         u32,
     ),
+
+    Fixable(Box<CompilationIssue>, Vec<CompilationEdit>),
+    Labeled(Box<CompilationIssue>, Vec<CompilationLabel>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompilationLabel {
+    span: Span,
+    message: String,
+    path: Option<std::path::PathBuf>,
+}
+
+impl CompilationLabel {
+    #[inline]
+    pub fn new(span: Span, message: String) -> Self {
+        Self {
+            span,
+            message,
+            path: None,
+        }
+    }
+
+    #[inline]
+    pub fn in_file(path: std::path::PathBuf, span: Span, message: String) -> Self {
+        Self {
+            span,
+            message,
+            path: Some(path),
+        }
+    }
+}
+
+impl CompilationLabel {
+    #[inline]
+    pub fn get_span(&self) -> Span {
+        self.span
+    }
+
+    #[inline]
+    pub fn get_message(&self) -> &str {
+        &self.message
+    }
+
+    #[inline]
+    pub fn get_path(&self) -> Option<&std::path::Path> {
+        self.path.as_deref()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompilationEdit {
+    span: Span,
+    replacement: String,
+}
+
+impl CompilationEdit {
+    #[inline]
+    pub fn new(span: Span, replacement: String) -> Self {
+        Self { span, replacement }
+    }
+
+    #[inline]
+    pub fn insertion(line: u32, column: u32, text: String) -> Self {
+        Self::new(Span::new((line, (column, column))), text)
+    }
+
+    #[inline]
+    pub fn removal(span: Span) -> Self {
+        Self::new(span, String::new())
+    }
+}
+
+impl CompilationEdit {
+    #[inline]
+    pub fn get_span(&self) -> Span {
+        self.span
+    }
+
+    #[inline]
+    pub fn get_replacement(&self) -> &str {
+        &self.replacement
+    }
 }
 
 impl CompilationIssue {
     #[inline]
     pub fn is_bug(&self) -> bool {
-        matches!(
-            self,
-            CompilationIssue::FrontEndBug(..) | CompilationIssue::BackenEndBug(..)
-        )
+        match self {
+            CompilationIssue::FrontEndBug(..) | CompilationIssue::BackenEndBug(..) => true,
+            CompilationIssue::Fixable(issue, ..) | CompilationIssue::Labeled(issue, ..) => {
+                issue.is_bug()
+            }
+
+            _ => false,
+        }
+    }
+
+    #[inline]
+    pub fn with_edits(self, edits: Vec<CompilationEdit>) -> Self {
+        if edits.is_empty() {
+            return self;
+        }
+
+        CompilationIssue::Fixable(self.into(), edits)
+    }
+
+    #[inline]
+    pub fn with_labels(self, labels: Vec<CompilationLabel>) -> Self {
+        if labels.is_empty() {
+            return self;
+        }
+
+        CompilationIssue::Labeled(self.into(), labels)
+    }
+
+    #[inline]
+    pub fn get_edits(&self) -> &[CompilationEdit] {
+        match self {
+            CompilationIssue::Fixable(_, edits) => edits,
+            CompilationIssue::Labeled(issue, ..) => issue.get_edits(),
+            _ => &[],
+        }
     }
 }
 
@@ -92,6 +205,10 @@ lazy_static::lazy_static! {
         explanations.insert(CompilationIssueCode::E0031, r#""#);
         explanations.insert(CompilationIssueCode::E0032, r#""#);
         explanations.insert(CompilationIssueCode::E0033, r#""#);
+        explanations.insert(CompilationIssueCode::E0040, r#""#);
+        explanations.insert(CompilationIssueCode::E0041, r#""#);
+        explanations.insert(CompilationIssueCode::E0042, r#""#);
+        explanations.insert(CompilationIssueCode::E0043, r#""#);
 
         explanations.insert(CompilationIssueCode::W0001, r#""#);
         explanations.insert(CompilationIssueCode::W0002, r#""#);
@@ -109,6 +226,16 @@ lazy_static::lazy_static! {
         explanations.insert(CompilationIssueCode::W0015, r#""#);
         explanations.insert(CompilationIssueCode::W0016, r#""#);
         explanations.insert(CompilationIssueCode::W0017, r#""#);
+        explanations.insert(CompilationIssueCode::W0019, r#""#);
+        explanations.insert(CompilationIssueCode::W0020, r#""#);
+        explanations.insert(CompilationIssueCode::W0021, r#""#);
+        explanations.insert(CompilationIssueCode::W0022, r#""#);
+        explanations.insert(CompilationIssueCode::W0023, r#""#);
+        explanations.insert(CompilationIssueCode::W0024, r#""#);
+        explanations.insert(CompilationIssueCode::W0025, r#""#);
+        explanations.insert(CompilationIssueCode::W0026, r#""#);
+        explanations.insert(CompilationIssueCode::W0027, r#""#);
+        explanations.insert(CompilationIssueCode::W0028, r#""#);
 
         explanations
     };
@@ -154,6 +281,10 @@ pub enum CompilationIssueCode {
     E0037, // Too many depth,
     E0038, // Not Mutable
     E0039, // Unsupported Native Type
+    E0040, // Escaping Stack Address
+    E0041, // Unhandled Error Union
+    E0042, // Unimplemented Interface
+    E0043, // Macro Expansion
 
     W0001, // Irrelevant Attribute
     W0002, // Unknown Call Convention
@@ -172,6 +303,16 @@ pub enum CompilationIssueCode {
     W0016, // Structure Field not Used,
     W0017, // Function not used
     W0018, // Circular Import
+    W0019, // Possibly Escaping Stack Address
+    W0020, // Redundant Cast
+    W0021, // Shadowed Symbol
+    W0022, // Lossy Cast
+    W0023, // Constant Condition
+    W0024, // Self Assignment
+    W0025, // Useless Comparison
+    W0026, // Empty Loop Body
+    W0027, // Unused Import
+    W0028, // Dead Public Symbol
 }
 
 impl CompilationIssueCode {
@@ -279,6 +420,18 @@ impl CompilationIssueCode {
             CompilationIssueCode::E0039 => {
                 format!("UNSUPPORTED NATIVE TYPE - {}", "E0039".bright_red())
             }
+            CompilationIssueCode::E0040 => {
+                format!("ESCAPING STACK ADDRESS - {}", "E0040".bright_red())
+            }
+            CompilationIssueCode::E0041 => {
+                format!("UNHANDLED ERROR UNION - {}", "E0041".bright_red())
+            }
+            CompilationIssueCode::E0042 => {
+                format!("UNIMPLEMENTED INTERFACE - {}", "E0042".bright_red())
+            }
+            CompilationIssueCode::E0043 => {
+                format!("MACRO EXPANSION ERROR - {}", "E0043".bright_red())
+            }
             CompilationIssueCode::W0001 => {
                 format!("IRRELEVANT ATTRIBUTE - {}", "W0001".bright_yellow())
             }
@@ -320,6 +473,107 @@ impl CompilationIssueCode {
             CompilationIssueCode::W0018 => {
                 format!("CIRCULAR IMPORT - {}", "W0018".bright_yellow())
             }
+            CompilationIssueCode::W0019 => {
+                format!(
+                    "POSSIBLY ESCAPING STACK ADDRESS - {}",
+                    "W0019".bright_yellow()
+                )
+            }
+            CompilationIssueCode::W0020 => {
+                format!("REDUNDANT CAST - {}", "W0020".bright_yellow())
+            }
+            CompilationIssueCode::W0021 => {
+                format!("SHADOWED SYMBOL - {}", "W0021".bright_yellow())
+            }
+            CompilationIssueCode::W0022 => {
+                format!("LOSSY CAST - {}", "W0022".bright_yellow())
+            }
+            CompilationIssueCode::W0023 => {
+                format!("CONSTANT CONDITION - {}", "W0023".bright_yellow())
+            }
+            CompilationIssueCode::W0024 => {
+                format!("SELF ASSIGNMENT - {}", "W0024".bright_yellow())
+            }
+            CompilationIssueCode::W0025 => {
+                format!("USELESS COMPARISON - {}", "W0025".bright_yellow())
+            }
+            CompilationIssueCode::W0026 => {
+                format!("EMPTY LOOP BODY - {}", "W0026".bright_yellow())
+            }
+            CompilationIssueCode::W0027 => {
+                format!("UNUSED IMPORT - {}", "W0027".bright_yellow())
+            }
+            CompilationIssueCode::W0028 => {
+                format!("DEAD PUBLIC SYMBOL - {}", "W0028".bright_yellow())
+            }
+        }
+    }
+
+    #[inline]
+    pub fn is_warning(&self) -> bool {
+        matches!(
+            self,
+            CompilationIssueCode::W0001
+                | CompilationIssueCode::W0002
+                | CompilationIssueCode::W0003
+                | CompilationIssueCode::W0004
+                | CompilationIssueCode::W0005
+                | CompilationIssueCode::W0007
+                | CompilationIssueCode::W0008
+                | CompilationIssueCode::W0009
+                | CompilationIssueCode::W0010
+                | CompilationIssueCode::W0011
+                | CompilationIssueCode::W0012
+                | CompilationIssueCode::W0013
+                | CompilationIssueCode::W0014
+                | CompilationIssueCode::W0015
+                | CompilationIssueCode::W0016
+                | CompilationIssueCode::W0017
+                | CompilationIssueCode::W0018
+                | CompilationIssueCode::W0019
+                | CompilationIssueCode::W0020
+                | CompilationIssueCode::W0021
+                | CompilationIssueCode::W0022
+                | CompilationIssueCode::W0023
+                | CompilationIssueCode::W0024
+                | CompilationIssueCode::W0025
+                | CompilationIssueCode::W0026
+                | CompilationIssueCode::W0027
+                | CompilationIssueCode::W0028
+        )
+    }
+
+    pub fn from_warning(code: &str) -> Option<Self> {
+        match code {
+            "W0001" => Some(CompilationIssueCode::W0001),
+            "W0002" => Some(CompilationIssueCode::W0002),
+            "W0003" => Some(CompilationIssueCode::W0003),
+            "W0004" => Some(CompilationIssueCode::W0004),
+            "W0005" => Some(CompilationIssueCode::W0005),
+            "W0007" => Some(CompilationIssueCode::W0007),
+            "W0008" => Some(CompilationIssueCode::W0008),
+            "W0009" => Some(CompilationIssueCode::W0009),
+            "W0010" => Some(CompilationIssueCode::W0010),
+            "W0011" => Some(CompilationIssueCode::W0011),
+            "W0012" => Some(CompilationIssueCode::W0012),
+            "W0013" => Some(CompilationIssueCode::W0013),
+            "W0014" => Some(CompilationIssueCode::W0014),
+            "W0015" => Some(CompilationIssueCode::W0015),
+            "W0016" => Some(CompilationIssueCode::W0016),
+            "W0017" => Some(CompilationIssueCode::W0017),
+            "W0018" => Some(CompilationIssueCode::W0018),
+            "W0019" => Some(CompilationIssueCode::W0019),
+            "W0020" => Some(CompilationIssueCode::W0020),
+            "W0021" => Some(CompilationIssueCode::W0021),
+            "W0022" => Some(CompilationIssueCode::W0022),
+            "W0023" => Some(CompilationIssueCode::W0023),
+            "W0024" => Some(CompilationIssueCode::W0024),
+            "W0025" => Some(CompilationIssueCode::W0025),
+            "W0026" => Some(CompilationIssueCode::W0026),
+            "W0027" => Some(CompilationIssueCode::W0027),
+            "W0028" => Some(CompilationIssueCode::W0028),
+
+            _ => None,
         }
     }
 
@@ -372,6 +626,10 @@ impl From<&str> for CompilationIssueCode {
             "E0031" => CompilationIssueCode::E0031,
             "E0032" => CompilationIssueCode::E0032,
             "E0033" => CompilationIssueCode::E0033,
+            "E0040" => CompilationIssueCode::E0040,
+            "E0041" => CompilationIssueCode::E0041,
+            "E0042" => CompilationIssueCode::E0042,
+            "E0043" => CompilationIssueCode::E0043,
 
             "W0001" => CompilationIssueCode::W0001,
             "W0002" => CompilationIssueCode::W0002,
@@ -389,6 +647,17 @@ impl From<&str> for CompilationIssueCode {
             "W0015" => CompilationIssueCode::W0015,
             "W0016" => CompilationIssueCode::W0016,
             "W0017" => CompilationIssueCode::W0017,
+            "W0018" => CompilationIssueCode::W0018,
+            "W0019" => CompilationIssueCode::W0019,
+            "W0020" => CompilationIssueCode::W0020,
+            "W0021" => CompilationIssueCode::W0021,
+            "W0022" => CompilationIssueCode::W0022,
+            "W0023" => CompilationIssueCode::W0023,
+            "W0024" => CompilationIssueCode::W0024,
+            "W0025" => CompilationIssueCode::W0025,
+            "W0026" => CompilationIssueCode::W0026,
+            "W0027" => CompilationIssueCode::W0027,
+            "W0028" => CompilationIssueCode::W0028,
 
             unknown => thrustc_logging::print_critical_error(
                 LoggingType::Error,
@@ -408,7 +677,9 @@ pub enum CompilationPosition {
     TypeChecker,
     Analyzer,
     Linter,
+    MirBuilder,
     LLVMBackend,
+    CBackend,
 }
 
 impl std::fmt::Display for CompilationPosition {
@@ -419,7 +690,9 @@ impl std::fmt::Display for CompilationPosition {
             Self::TypeChecker => write!(f, "{}", "Type Checker".bright_yellow().bold()),
             Self::Analyzer => write!(f, "{}", "Analyzer".bright_blue().bold()),
             Self::Linter => write!(f, "{}", "Linter".bright_magenta().bold()),
+            Self::MirBuilder => write!(f, "{}", "MIR Builder".bright_cyan().bold()),
             Self::LLVMBackend => write!(f, "{}", "LLVMBackend".bright_red().bold()),
+            Self::CBackend => write!(f, "{}", "CBackend".bright_red().bold()),
         }
     }
 }
@@ -0,0 +1,185 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+
+use colored::Colorize;
+
+use thrustc_options::{CompilationUnit, CompilerOptions};
+
+use std::backtrace::Backtrace;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, MutexGuard};
+
+static CONTEXT: Mutex<Option<IceContext>> = Mutex::new(None);
+static REPORTED: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug)]
+struct IceContext {
+    command_line: Vec<String>,
+    options: String,
+    target_triple: String,
+    build_dir: PathBuf,
+    sources: Vec<PathBuf>,
+}
+
+pub fn install(options: &CompilerOptions) {
+    let context: IceContext = IceContext {
+        command_line: std::env::args().collect(),
+        options: format!("{:#?}", options),
+        target_triple: options
+            .get_llvm_backend()
+            .get_target()
+            .get_target_triple()
+            .as_str()
+            .to_string_lossy()
+            .to_string(),
+        build_dir: options.get_build_dir().clone(),
+        sources: options
+            .get_files()
+            .iter()
+            .map(|file: &CompilationUnit| file.get_path().to_path_buf())
+            .collect(),
+    };
+
+    *self::lock_context() = Some(context);
+
+    std::panic::set_hook(Box::new(|info| {
+        self::report(&info.to_string());
+    }));
+}
+
+pub fn register_source(path: &Path) {
+    if let Some(context) = self::lock_context().as_mut() {
+        if !context.sources.iter().any(|source| source == path) {
+            context.sources.push(path.to_path_buf());
+        }
+    }
+}
+
+pub fn report(reason: &str) {
+    if REPORTED.swap(true, Ordering::SeqCst) {
+        return;
+    }
+
+    let backtrace: Backtrace = Backtrace::force_capture();
+    let context: MutexGuard<Option<IceContext>> = self::lock_context();
+
+    let mut stderr: std::io::Stderr = std::io::stderr();
+
+    let _ = stderr.write_all(
+        format!(
+            "\n{} {}\n",
+            "internal compiler error:".bold().bright_red(),
+            reason
+        )
+        .as_bytes(),
+    );
+
+    let _ = stderr.write_all(
+        format!(
+            "\nThis is a bug in the compiler, not in your code. Report it in: '{}'.\n",
+            concat!(env!("CARGO_PKG_REPOSITORY"), "/issues/")
+                .bold()
+                .bright_red()
+                .underline()
+        )
+        .as_bytes(),
+    );
+
+    let Some(context) = context.as_ref() else {
+        return;
+    };
+
+    match self::write_bundle(context, reason, &backtrace) {
+        Ok(bundle) => {
+            let _ = stderr.write_all(
+                format!(
+                    "A reproducer bundle was written to '{}'. Attach it to the issue.\n\n",
+                    bundle.display()
+                )
+                .as_bytes(),
+            );
+        }
+
+        Err(error) => {
+            let _ = stderr.write_all(
+                format!(
+                    "The reproducer bundle couldn't be written, because: '{}'.\n\n",
+                    error
+                )
+                .as_bytes(),
+            );
+        }
+    }
+}
+
+fn write_bundle(
+    context: &IceContext,
+    reason: &str,
+    backtrace: &Backtrace,
+) -> Result<PathBuf, std::io::Error> {
+    let timestamp: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default();
+
+    let name: String = format!("{}-{}", timestamp, std::process::id());
+    let bundle: PathBuf = context.build_dir.join("ice").join(name);
+
+    let sources: PathBuf = bundle.join("sources");
+
+    std::fs::create_dir_all(&sources)?;
+
+    std::fs::write(
+        bundle.join("command-line.txt"),
+        context.command_line.join(" "),
+    )?;
+    std::fs::write(bundle.join("options.txt"), &context.options)?;
+    std::fs::write(bundle.join("target.txt"), &context.target_triple)?;
+    std::fs::write(
+        bundle.join("backtrace.txt"),
+        format!("{}\n\n{}", reason, backtrace),
+    )?;
+
+    let mut manifest: String = String::with_capacity(u8::MAX as usize);
+
+    for (idx, source) in context.sources.iter().enumerate() {
+        let file_name: String = source
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        let copied: String = format!("{}-{}", idx, file_name);
+
+        if std::fs::copy(source, sources.join(&copied)).is_ok() {
+            manifest.push_str(&format!("{} -> {}\n", copied, source.display()));
+        }
+    }
+
+    std::fs::write(sources.join("manifest.txt"), manifest)?;
+
+    Ok(bundle)
+}
+
+fn lock_context() -> MutexGuard<'static, Option<IceContext>> {
+    CONTEXT
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
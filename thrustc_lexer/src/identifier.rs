@@ -17,7 +17,8 @@
 
 */
 
-use thrustc_errors::CompilationIssue;
+use thrustc_errors::{CompilationIssue, CompilationIssueCode};
+use thrustc_span::Span;
 use thrustc_token_type::TokenType;
 
 use crate::Lexer;
@@ -82,6 +83,9 @@ lazy_static! {
         attributes.insert("@thunk", TokenType::Thunk);
         attributes.insert("@constructor", TokenType::Constructor);
         attributes.insert("@destructor", TokenType::Destructor);
+        attributes.insert("@allow", TokenType::Allow);
+        attributes.insert("@format", TokenType::Format);
+        attributes.insert("@cfg", TokenType::Cfg);
 
         attributes
     };
@@ -97,6 +101,13 @@ lazy_static! {
         builtins.insert("memset", TokenType::MemSet);
         builtins.insert("memmove", TokenType::MemMove);
         builtins.insert("memcpy", TokenType::MemCpy);
+        builtins.insert("splat", TokenType::Splat);
+        builtins.insert("shuffle", TokenType::Shuffle);
+        builtins.insert("vaStart", TokenType::VaStart);
+        builtins.insert("vaArg", TokenType::VaArg);
+        builtins.insert("vaEnd", TokenType::VaEnd);
+        builtins.insert("vaCopy", TokenType::VaCopy);
+        builtins.insert("print", TokenType::Print);
         builtins.insert("alignOf", TokenType::AlignOf);
         builtins.insert("abiSizeOf", TokenType::AbiSizeOf);
         builtins.insert("bitSizeOf", TokenType::BitSizeOf);
@@ -114,6 +125,7 @@ lazy_static! {
         types.insert("s16", TokenType::S16);
         types.insert("s32", TokenType::S32);
         types.insert("s64", TokenType::S64);
+        types.insert("s128", TokenType::S128);
         types.insert("ssize", TokenType::Ssize);
         types.insert("u8", TokenType::U8);
         types.insert("u16", TokenType::U16);
@@ -121,6 +133,8 @@ lazy_static! {
         types.insert("u64", TokenType::U64);
         types.insert("u128", TokenType::U128);
         types.insert("usize", TokenType::Usize);
+        types.insert("f16", TokenType::F16);
+        types.insert("bf16", TokenType::BF16);
         types.insert("f32", TokenType::F32);
         types.insert("f64", TokenType::F64);
         types.insert("f128", TokenType::F128);
@@ -130,6 +144,7 @@ lazy_static! {
         types.insert("char", TokenType::Char);
         types.insert("ptr", TokenType::Ptr);
         types.insert("array", TokenType::Array);
+        types.insert("vec", TokenType::Vector);
         types.insert("void", TokenType::Void);
         types.insert("Fn", TokenType::FnRef);
 
@@ -156,12 +171,15 @@ lazy_static! {
         keywords.insert("and", TokenType::And);
         keywords.insert("const", TokenType::Const);
         keywords.insert("struct", TokenType::Struct);
+        keywords.insert("union", TokenType::Union);
         keywords.insert("return", TokenType::Return);
         keywords.insert("break", TokenType::Break);
         keywords.insert("continue", TokenType::Continue);
         keywords.insert("breakall", TokenType::BreakAll);
         keywords.insert("continueall", TokenType::ContinueAll);
         keywords.insert("defer", TokenType::Defer);
+        keywords.insert("raise", TokenType::Raise);
+        keywords.insert("catch", TokenType::Catch);
         keywords.insert("pass", TokenType::Pass);
         keywords.insert("nullptr", TokenType::NullPtr);
         keywords.insert("as", TokenType::As);
@@ -171,6 +189,10 @@ lazy_static! {
         keywords.insert("deref", TokenType::Deref);
         keywords.insert("type", TokenType::Type);
         keywords.insert("enum", TokenType::Enum);
+        keywords.insert("errset", TokenType::ErrSet);
+        keywords.insert("interface", TokenType::Interface);
+        keywords.insert("impl", TokenType::Impl);
+        keywords.insert("macro", TokenType::Macro);
         keywords.insert("alloc", TokenType::Alloc);
         keywords.insert("address", TokenType::Address);
         keywords.insert("addr", TokenType::Addr);
@@ -208,9 +230,74 @@ pub fn lex(lexer: &mut Lexer) -> Result<(), CompilationIssue> {
         lexer.make(*builtin);
     } else if let Some(r#type) = TYPES.get(lexem.as_str()) {
         lexer.make(*r#type);
+    } else if lexem.starts_with('@') {
+        lexer.end_span();
+
+        let span: Span = Span::new(lexer.span());
+
+        return Err(CompilationIssue::Error(
+            CompilationIssueCode::E0012,
+            format!("Unknown attribute '{}'", lexem),
+            "You should use an attribute available in the language grammar.".into(),
+            thrustc_utils::did_you_mean(&lexem, ATTRIBUTES.keys().copied()),
+            span,
+        ));
     } else {
         lexer.make(TokenType::Identifier);
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use thrustc_diagnostician::Diagnostician;
+    use thrustc_errors::{CompilationIssue, CompilationIssueCode};
+    use thrustc_options::{CompilationUnit, CompilerOptions};
+
+    use crate::Lexer;
+
+    fn lex(code: &str) -> Result<(), CompilationIssue> {
+        let options: CompilerOptions = CompilerOptions::new();
+        let file: CompilationUnit = CompilationUnit::new(
+            "test".into(),
+            PathBuf::from("test.thrust"),
+            code.into(),
+            "test".into(),
+        );
+
+        let mut lexer: Lexer = Lexer {
+            tokens: Vec::new(),
+            errors: Vec::new(),
+            code: code.chars().collect(),
+            column: 0,
+            start: 0,
+            current: 0,
+            line: 1,
+            span: (0, 0),
+            diagnostician: Diagnostician::new(&file, &options),
+        };
+
+        lexer.start_span();
+
+        crate::lex::analyze(&mut lexer)
+    }
+
+    #[test]
+    fn suggests_the_closest_attribute_for_an_unknown_one() {
+        let Err(CompilationIssue::Error(code, title, _, note, _)) = self::lex("@inlin") else {
+            panic!("an unknown attribute should be rejected");
+        };
+
+        assert_eq!(code, CompilationIssueCode::E0012);
+        assert_eq!(title, "Unknown attribute '@inlin'");
+        assert_eq!(note, Some("Did you mean '@inline'?".into()));
+    }
+
+    #[test]
+    fn known_attributes_are_not_reported() {
+        assert!(self::lex("@inline").is_ok());
+    }
+}
@@ -39,6 +39,7 @@ pub fn analyze(lexer: &mut Lexer) -> Result<(), CompilationIssue> {
         '*' => lexer.make(TokenType::Star),
         '^' => lexer.make(TokenType::Xor),
         '~' => lexer.make(TokenType::Not),
+        '?' => lexer.make(TokenType::Question),
         '/' if lexer.char_match('/') => loop {
             if lexer.peek() == '\n' || lexer.is_eof() {
                 break;
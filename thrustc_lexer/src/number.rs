@@ -17,6 +17,8 @@
 
 */
 
+use std::num::IntErrorKind;
+
 use thrustc_errors::{CompilationIssue, CompilationIssueCode};
 use thrustc_span::Span;
 use thrustc_token::Token;
@@ -52,16 +54,14 @@ pub fn check_float_format(lexer: &Lexer, lexeme: &str) -> Result<(), Compilation
     ))
 }
 
-const I8_MIN: isize = -128;
-const I8_MAX: isize = 127;
-const I16_MIN: isize = -32768;
-const I16_MAX: isize = 32767;
-const I32_MIN: isize = -2147483648;
-const I32_MAX: isize = 2147483647;
+const I8_MIN: i128 = -128;
+const I8_MAX: i128 = 127;
+const I16_MIN: i128 = -32768;
+const I16_MAX: i128 = 32767;
+const I32_MIN: i128 = -2147483648;
+const I32_MAX: i128 = 2147483647;
 
-const U8_MAX: usize = 255;
-const U16_MAX: usize = 65535;
-const U32_MAX: usize = 4294967295;
+const F16_MAX: f64 = 65504.0;
 
 #[inline]
 pub fn check_integer_format(lexer: &Lexer, lexeme: &str) -> Result<(), CompilationIssue> {
@@ -81,21 +81,14 @@ pub fn check_integer_format(lexer: &Lexer, lexeme: &str) -> Result<(), Compilati
 
     let cleaned: String = lexeme.replace('_', "");
 
-    match cleaned.parse::<usize>() {
-        Ok(num) if num <= U8_MAX || num <= U16_MAX || num <= U32_MAX || num < usize::MAX => Ok(()),
-        Ok(_) => Err(CompilationIssue::Error(
-            CompilationIssueCode::E0001,
-            "Literal is too large to be represented in a integer type.".into(),
-            "You can shorten it.".into(),
-            None,
-            span,
-        )),
-        Err(_) => match cleaned.parse::<isize>() {
+    match cleaned.parse::<u128>() {
+        Ok(_) => Ok(()),
+        Err(_) => match cleaned.parse::<i128>() {
             Ok(num)
                 if (I8_MIN..=I8_MAX).contains(&num)
                     || (I16_MIN..=I16_MAX).contains(&num)
                     || (I32_MIN..=I32_MAX).contains(&num)
-                    || (isize::MIN..=isize::MAX).contains(&num) =>
+                    || (i128::MIN..=i128::MAX).contains(&num) =>
             {
                 Ok(())
             }
@@ -132,12 +125,12 @@ fn check_integer_radix_format(
         "binary"
     };
 
-    match isize::from_str_radix(&cleaned, radix) {
+    match i128::from_str_radix(&cleaned, radix) {
         Ok(num)
             if (I8_MIN..=I8_MAX).contains(&num)
                 || (I16_MIN..=I16_MAX).contains(&num)
                 || (I32_MIN..=I32_MAX).contains(&num)
-                || (isize::MIN..=isize::MAX).contains(&num) =>
+                || (i128::MIN..=i128::MAX).contains(&num) =>
         {
             Ok(())
         }
@@ -148,17 +141,17 @@ fn check_integer_radix_format(
             None,
             span,
         )),
-        Err(_) => match usize::from_str_radix(&cleaned, radix) {
-            Ok(num) if num <= U8_MAX || num <= U16_MAX || num <= U32_MAX || num < usize::MAX => {
-                Ok(())
+        Err(_) => match u128::from_str_radix(&cleaned, radix) {
+            Ok(_) => Ok(()),
+            Err(error) if *error.kind() == IntErrorKind::PosOverflow => {
+                Err(CompilationIssue::Error(
+                    CompilationIssueCode::E0001,
+                    format!("Integer out of bounds unsigned {} format.", prefix_name),
+                    "You can shorten it.".into(),
+                    None,
+                    span,
+                ))
             }
-            Ok(_) => Err(CompilationIssue::Error(
-                CompilationIssueCode::E0001,
-                format!("Integer out of bounds unsigned {} format.", prefix_name),
-                "You can shorten it.".into(),
-                None,
-                span,
-            )),
             Err(_) => Err(CompilationIssue::Error(
                 CompilationIssueCode::E0001,
                 format!("Integer invalid {} format.", prefix_name),
@@ -170,6 +163,150 @@ fn check_integer_radix_format(
     }
 }
 
+fn check_suffixed_format(
+    lexer: &Lexer,
+    number: &str,
+    suffix: &str,
+) -> Result<TokenType, CompilationIssue> {
+    let span: Span = Span::new(lexer.span());
+
+    let radix: u32 = if number.starts_with("0x") {
+        16
+    } else if number.starts_with("0b") {
+        2
+    } else if number.starts_with("0o") {
+        8
+    } else {
+        10
+    };
+
+    let float_max: Option<f64> = match suffix {
+        "f16" => Some(F16_MAX),
+        "bf16" | "f32" => Some(f32::MAX as f64),
+        "f64" | "f128" => Some(f64::MAX),
+        _ => None,
+    };
+
+    if let Some(float_max) = float_max {
+        if radix != 10 {
+            return Err(CompilationIssue::Error(
+                CompilationIssueCode::E0001,
+                format!(
+                    "Floating-point suffix '{}' cannot be used on a prefixed integer literal.",
+                    suffix
+                ),
+                "You should write the value as a decimal number.".into(),
+                None,
+                span,
+            ));
+        }
+
+        let cleaned: String = number.replace('_', "");
+
+        self::check_float_format(lexer, &cleaned)?;
+
+        let value: f64 = cleaned.parse::<f64>().unwrap_or(f64::INFINITY);
+
+        if value > float_max {
+            return Err(CompilationIssue::Error(
+                CompilationIssueCode::E0001,
+                format!("Literal is out of range for the '{}' type.", suffix),
+                "You can shorten it or use a wider suffix.".into(),
+                None,
+                span,
+            ));
+        }
+
+        return Ok(TokenType::Float);
+    }
+
+    let (integer_max, signed): (u128, bool) = match suffix {
+        "s8" => (i8::MAX as u128, true),
+        "s16" => (i16::MAX as u128, true),
+        "s32" => (i32::MAX as u128, true),
+        "s64" | "ssize" => (i64::MAX as u128, true),
+        "s128" => (i128::MAX as u128, true),
+        "u8" => (u8::MAX as u128, false),
+        "u16" => (u16::MAX as u128, false),
+        "u32" => (u32::MAX as u128, false),
+        "u64" | "usize" => (u64::MAX as u128, false),
+        "u128" => (u128::MAX, false),
+
+        _ => {
+            return Err(CompilationIssue::Error(
+                CompilationIssueCode::E0001,
+                format!("Unknown literal suffix '{}'.", suffix),
+                "You should use a primitive numeric type as suffix, for example 'u8' or 'f64'."
+                    .into(),
+                None,
+                span,
+            ));
+        }
+    };
+
+    // The minus sign is its own token, so '-128s8' reaches the lexer as '128s8'.
+    let integer_max: u128 = if signed && self::is_negated(lexer) {
+        integer_max + 1
+    } else {
+        integer_max
+    };
+
+    if number.contains('.') {
+        return Err(CompilationIssue::Error(
+            CompilationIssueCode::E0001,
+            format!(
+                "Integer suffix '{}' cannot be used on a floating-point literal.",
+                suffix
+            ),
+            "You should use a floating-point suffix, for example 'f32' or 'f64'.".into(),
+            None,
+            span,
+        ));
+    }
+
+    let digits: &str = if radix != 10 { &number[2..] } else { number };
+
+    match u128::from_str_radix(&digits.replace('_', ""), radix) {
+        Ok(value) if value <= integer_max => Ok(TokenType::Integer),
+        _ => Err(CompilationIssue::Error(
+            CompilationIssueCode::E0001,
+            format!("Literal is out of range for the '{}' type.", suffix),
+            "You can shorten it or use a wider suffix.".into(),
+            None,
+            span,
+        )),
+    }
+}
+
+fn is_negated(lexer: &Lexer) -> bool {
+    let previous: Vec<TokenType> = lexer
+        .tokens
+        .iter()
+        .rev()
+        .take(2)
+        .map(|token| token.kind)
+        .collect();
+
+    match previous.as_slice() {
+        [TokenType::Minus] => true,
+        [TokenType::Minus, before] => !matches!(
+            before,
+            TokenType::Identifier
+                | TokenType::Integer
+                | TokenType::Float
+                | TokenType::Char
+                | TokenType::CString
+                | TokenType::CNString
+                | TokenType::True
+                | TokenType::False
+                | TokenType::NullPtr
+                | TokenType::RParen
+                | TokenType::RBracket
+        ),
+        _ => false,
+    }
+}
+
 pub fn lex(lexer: &mut Lexer) -> Result<(), CompilationIssue> {
     let mut is_hexadecimal: bool = false;
     let mut is_binary: bool = false;
@@ -227,6 +364,10 @@ pub fn lex(lexer: &mut Lexer) -> Result<(), CompilationIssue> {
             break;
         }
 
+        if !is_hexadecimal && lexer.peek() == 'b' && lexer.peek_next() == 'f' {
+            break;
+        }
+
         if lexer.peek() == 'x' && lexer.peek_next().is_ascii_alphanumeric() {
             is_hexadecimal = true;
         }
@@ -242,12 +383,33 @@ pub fn lex(lexer: &mut Lexer) -> Result<(), CompilationIssue> {
         let _ = lexer.advance();
     }
 
+    if !is_hexadecimal && lexer.peek().is_ascii_alphabetic() {
+        while lexer.peek().is_ascii_alphanumeric() {
+            let _ = lexer.advance();
+        }
+    }
+
     lexer.end_span();
 
     let span: Span = Span::new(lexer.span());
 
     let lexeme: String = lexer.lexeme();
 
+    let (number, suffix) = thrustc_utils::split_literal_suffix(&lexeme);
+
+    if !suffix.is_empty() {
+        let kind: TokenType = self::check_suffixed_format(lexer, number, suffix)?;
+
+        lexer.tokens.push(Token {
+            lexeme,
+            ascii: String::default(),
+            kind,
+            span,
+        });
+
+        return Ok(());
+    }
+
     if lexeme.contains(".") {
         self::check_float_format(lexer, &lexeme)?;
 
@@ -272,3 +434,101 @@ pub fn lex(lexer: &mut Lexer) -> Result<(), CompilationIssue> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use thrustc_options::{CompilationUnit, CompilerOptions};
+    use thrustc_token::Token;
+    use thrustc_token_type::TokenType;
+
+    use crate::Lexer;
+
+    fn lex(code: &str) -> Result<Vec<Token>, ()> {
+        let options: CompilerOptions = CompilerOptions::new();
+        let file: CompilationUnit = CompilationUnit::new(
+            "test".into(),
+            PathBuf::from("test.thrust"),
+            code.into(),
+            "test".into(),
+        );
+
+        Lexer::lex(&file, &options)
+    }
+
+    fn lexes_as(code: &str, kind: TokenType) -> bool {
+        self::lex(code).is_ok_and(|tokens| tokens.first().is_some_and(|token| token.kind == kind))
+    }
+
+    #[test]
+    fn accepts_the_full_unsigned_128_bit_range() {
+        assert!(self::lexes_as("0", TokenType::Integer));
+        assert!(self::lexes_as(
+            "340282366920938463463374607431768211455",
+            TokenType::Integer
+        ));
+        assert!(self::lexes_as(
+            "0xffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff",
+            TokenType::Integer
+        ));
+        assert!(self::lexes_as(
+            &format!("0b{}", "1".repeat(128)),
+            TokenType::Integer
+        ));
+    }
+
+    #[test]
+    fn accepts_literals_wider_than_64_bits() {
+        assert!(self::lexes_as("18446744073709551616", TokenType::Integer));
+        assert!(self::lexes_as(
+            "0o4000000000000000000000",
+            TokenType::Integer
+        ));
+    }
+
+    #[test]
+    fn rejects_literals_wider_than_128_bits() {
+        assert!(self::lex("340282366920938463463374607431768211456").is_err());
+        assert!(self::lex("0x1_0000_0000_0000_0000_0000_0000_0000_0000").is_err());
+        assert!(self::lex(&format!("0b{}", "1".repeat(129))).is_err());
+    }
+
+    #[test]
+    fn accepts_suffixed_literals_up_to_their_maximum() {
+        assert!(self::lexes_as("127s8", TokenType::Integer));
+        assert!(self::lexes_as("255u8", TokenType::Integer));
+        assert!(self::lexes_as("0xffu8", TokenType::Integer));
+        assert!(self::lexes_as(
+            "18446744073709551615u64",
+            TokenType::Integer
+        ));
+        assert!(self::lexes_as("1.5f32", TokenType::Float));
+    }
+
+    #[test]
+    fn rejects_suffixed_literals_out_of_range() {
+        assert!(self::lex("128s8").is_err());
+        assert!(self::lex("256u8").is_err());
+        assert!(self::lex("0x100u8").is_err());
+        assert!(self::lex("70000.0f16").is_err());
+        assert!(self::lex("1u7").is_err());
+    }
+
+    #[test]
+    fn accepts_the_signed_minimum_when_negated() {
+        assert!(self::lex("-128s8").is_ok());
+        assert!(self::lex("-32768s16").is_ok());
+        assert!(self::lex("-9223372036854775808s64").is_ok());
+        assert!(self::lex("-170141183460469231731687303715884105728s128").is_ok());
+        assert!(self::lex("(-128s8)").is_ok());
+    }
+
+    #[test]
+    fn rejects_one_past_the_signed_maximum_when_subtracted() {
+        assert!(self::lex("-129s8").is_err());
+        assert!(self::lex("a - 128s8").is_err());
+        assert!(self::lex("(a) - 128s8").is_err());
+        assert!(self::lex("-256u8").is_err());
+    }
+}
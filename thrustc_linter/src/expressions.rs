@@ -17,12 +17,18 @@
 
 */
 
-use thrustc_ast::{Ast, builtins::AstBuiltin, traits::AstCodeLocation};
-use thrustc_errors::{CompilationIssue, CompilationPosition};
+use thrustc_ast::{
+    Ast,
+    builtins::AstBuiltin,
+    traits::{AstCodeLocation, AstGetType},
+};
+use thrustc_errors::{
+    CompilationEdit, CompilationIssue, CompilationIssueCode, CompilationPosition,
+};
 use thrustc_span::Span;
 use thrustc_token_type::traits::TokenTypeExtensions;
 
-use crate::Linter;
+use crate::{Linter, lints};
 
 pub fn analyze<'linter>(linter: &mut Linter<'linter>, expr: &'linter Ast) {
     match expr {
@@ -30,7 +36,28 @@ pub fn analyze<'linter>(linter: &mut Linter<'linter>, expr: &'linter Ast) {
             linter.analyze_expr(node);
         }
 
-        Ast::BinaryOp { left, right, .. } => {
+        Ast::Try { expression, .. } => {
+            linter.analyze_expr(expression);
+        }
+
+        Ast::Catch {
+            expression,
+            fallback,
+            ..
+        } => {
+            linter.analyze_expr(expression);
+            linter.analyze_expr(fallback);
+        }
+
+        Ast::BinaryOp {
+            left,
+            operator,
+            right,
+            span,
+            ..
+        } => {
+            lints::check_unsigned_comparison(linter, left, *operator, right, *span);
+
             linter.analyze_expr(left);
             linter.analyze_expr(right);
         }
@@ -93,9 +120,23 @@ pub fn analyze<'linter>(linter: &mut Linter<'linter>, expr: &'linter Ast) {
             }
         }
 
+        Ast::DynamicCall { object, args, .. } => {
+            linter.analyze_expr(object);
+
+            {
+                for argument in args.iter() {
+                    linter.analyze_expr(argument);
+                }
+            }
+        }
+
+        Ast::InterfaceObject { value, .. } => linter.analyze_expr(value),
+
         Ast::Call {
             name, span, args, ..
         } => {
+            linter.referenced.insert(name);
+
             if let Some(function) = linter.get_mut_symbols().get_function_info(name) {
                 function.1 = true;
 
@@ -209,14 +250,52 @@ pub fn analyze<'linter>(linter: &mut Linter<'linter>, expr: &'linter Ast) {
                 linter.analyze_expr(new_size);
                 linter.analyze_expr(size);
             }
+            AstBuiltin::Splat { value, .. } => {
+                linter.analyze_expr(value);
+            }
+            AstBuiltin::Shuffle { left, right, .. } => {
+                linter.analyze_expr(left);
+                linter.analyze_expr(right);
+            }
+            AstBuiltin::VaArg { list, .. }
+            | AstBuiltin::VaEnd { list, .. }
+            | AstBuiltin::VaCopy { list, .. } => {
+                linter.analyze_expr(list);
+            }
+            AstBuiltin::Print { args, .. } => {
+                for arg in args.iter() {
+                    linter.analyze_expr(arg);
+                }
+            }
             AstBuiltin::Halloc { .. }
+            | AstBuiltin::VaStart { .. }
             | AstBuiltin::AlignOf { .. }
             | AstBuiltin::SizeOf { .. }
             | AstBuiltin::AbiSizeOf { .. }
             | AstBuiltin::BitSizeOf { .. }
             | AstBuiltin::AbiAlignOf { .. } => (),
         },
-        Ast::As { from, .. } => {
+        Ast::As {
+            from, cast, span, ..
+        } => {
+            if from
+                .get_value_type()
+                .is_ok_and(|from_type| from_type == cast)
+            {
+                linter.add_warning(
+                    CompilationIssue::Warning(
+                        CompilationIssueCode::W0020,
+                        format!("Redundant cast to '{}'.", cast),
+                        *span,
+                    )
+                    .with_edits(vec![CompilationEdit::removal(
+                        self::get_cast_removal_span(from, *span),
+                    )]),
+                );
+            } else {
+                lints::check_lossy_cast(linter, from, cast, *span);
+            }
+
             linter.analyze_expr(from);
         }
         Ast::Deref { value, .. } => {
@@ -248,3 +327,28 @@ pub fn analyze<'linter>(linter: &mut Linter<'linter>, expr: &'linter Ast) {
         }
     }
 }
+
+fn get_cast_removal_span(from: &Ast, span: Span) -> Span {
+    let from_span: Span = from.get_span();
+
+    let is_single_token: bool = matches!(
+        from,
+        Ast::Reference { .. }
+            | Ast::Integer { .. }
+            | Ast::Float { .. }
+            | Ast::Boolean { .. }
+            | Ast::Char { .. }
+    );
+
+    if is_single_token
+        && from_span.get_line() == span.get_line()
+        && from_span.get_span_end() <= span.get_span_start()
+    {
+        return Span::new((
+            span.get_line(),
+            (from_span.get_span_end(), span.get_span_end()),
+        ));
+    }
+
+    span
+}
@@ -20,16 +20,18 @@
 use thrustc_ast::{Ast, traits::AstCodeLocation};
 use thrustc_attributes::traits::ThrustAttributesExtensions;
 use thrustc_diagnostician::Diagnostician;
-use thrustc_errors::{CompilationIssue, CompilationIssueCode};
+use thrustc_errors::{CompilationEdit, CompilationIssue, CompilationIssueCode};
 use thrustc_options::{CompilationUnit, CompilerOptions};
 use thrustc_span::Span;
 
-use ahash::AHashMap as HashMap;
+use ahash::{AHashMap as HashMap, AHashSet as HashSet};
 
-use crate::table::LinterSymbolsTable;
+use crate::{table::LinterSymbolsTable, usage::LinterSymbolUsage};
 
 mod expressions;
+mod lints;
 mod table;
+pub mod usage;
 
 #[derive(Debug)]
 pub struct Linter<'linter> {
@@ -41,6 +43,7 @@ pub struct Linter<'linter> {
     diagnostician: Diagnostician,
 
     symbols: LinterSymbolsTable<'linter>,
+    referenced: HashSet<&'linter str>,
 }
 
 impl<'linter> Linter<'linter> {
@@ -54,14 +57,15 @@ impl<'linter> Linter<'linter> {
             warnings: Vec::with_capacity(u8::MAX as usize),
             bugs: Vec::with_capacity(u8::MAX as usize),
 
-            diagnostician: Diagnostician::new(file, options),
+            diagnostician: Diagnostician::with_allowed_warnings(file, options, ast),
             symbols: LinterSymbolsTable::new(),
+            referenced: HashSet::with_capacity(u8::MAX as usize),
         }
     }
 }
 
 impl<'linter> Linter<'linter> {
-    pub fn check(&mut self) {
+    pub fn check(&mut self) -> bool {
         self.declare_forward();
 
         {
@@ -85,6 +89,8 @@ impl<'linter> Linter<'linter> {
                     .dispatch_diagnostic(warning, thrustc_logging::LoggingType::Warning);
             }
         }
+
+        self.diagnostician.has_denied_warnings()
     }
 }
 
@@ -128,6 +134,15 @@ impl<'linter> Linter<'linter> {
 
                 self.generate_scoped_function_warnings();
             }
+            Ast::Implementation { methods, .. } => {
+                for method in methods.iter() {
+                    self.referenced.insert(method);
+
+                    if let Some(function) = self.symbols.get_function_info(method) {
+                        function.1 = true;
+                    }
+                }
+            }
 
             _ => (),
         }
@@ -142,6 +157,8 @@ impl<'linter> Linter<'linter> {
                 metadata,
                 ..
             } => {
+                lints::check_shadowing(self, name, *span);
+
                 self.symbols
                     .new_local(name, (*span, false, !metadata.is_mutable()));
 
@@ -203,6 +220,8 @@ impl<'linter> Linter<'linter> {
                 block,
                 ..
             } => {
+                lints::check_empty_loop_body(self, block);
+
                 self.analyze_stmt(local);
                 self.analyze_expr(actions);
                 self.analyze_expr(condition);
@@ -214,6 +233,9 @@ impl<'linter> Linter<'linter> {
                 block,
                 ..
             } => {
+                lints::check_constant_condition(self, condition);
+                lints::check_empty_loop_body(self, block);
+
                 if let Some(node) = variable {
                     self.analyze_stmt(node);
                 }
@@ -222,6 +244,7 @@ impl<'linter> Linter<'linter> {
                 self.analyze_stmt(block);
             }
             Ast::Loop { block, .. } => {
+                lints::check_empty_loop_body(self, block);
                 self.analyze_stmt(block);
             }
 
@@ -237,6 +260,8 @@ impl<'linter> Linter<'linter> {
                 else_branch,
                 ..
             } => {
+                lints::check_constant_condition(self, condition);
+
                 self.analyze_expr(condition);
                 self.analyze_stmt(then_branch);
 
@@ -253,6 +278,8 @@ impl<'linter> Linter<'linter> {
             Ast::Elif {
                 condition, block, ..
             } => {
+                lints::check_constant_condition(self, condition);
+
                 self.analyze_expr(condition);
                 self.analyze_stmt(block);
             }
@@ -260,7 +287,14 @@ impl<'linter> Linter<'linter> {
                 self.analyze_stmt(block);
             }
 
-            Ast::Mut { source, value, .. } => {
+            Ast::Mut {
+                source,
+                value,
+                span,
+                ..
+            } => {
+                lints::check_self_assignment(self, source, value, *span);
+
                 if let Ast::Reference { name, .. } = &**source {
                     self::mark_as_used(self, name);
                     self::mark_as_mutated(self, name);
@@ -276,6 +310,10 @@ impl<'linter> Linter<'linter> {
                 }
             }
 
+            Ast::Raise { expression, .. } => {
+                self.analyze_expr(expression);
+            }
+
             expr => self.analyze_expr(expr),
         }
     }
@@ -311,7 +349,7 @@ impl Linter<'_> {
                     let mut converted_fields: HashMap<&str, (Span, bool)> =
                         HashMap::with_capacity(100);
 
-                    for (field_name, _, _, span) in data.1.iter() {
+                    for (field_name, _, _, span, _) in data.1.iter() {
                         converted_fields.insert(field_name, (*span, false));
                     }
 
@@ -382,12 +420,19 @@ impl Linter<'_> {
                 let span: Span = info.0;
                 let used: bool = info.1;
 
-                if !used {
-                    warnings.push(CompilationIssue::Warning(
-                        CompilationIssueCode::W0005,
-                        format!("'{}' not used.", name),
-                        span,
-                    ));
+                if !used && !name.starts_with('_') {
+                    warnings.push(
+                        CompilationIssue::Warning(
+                            CompilationIssueCode::W0005,
+                            format!("'{}' not used.", name),
+                            span,
+                        )
+                        .with_edits(vec![CompilationEdit::insertion(
+                            span.get_line(),
+                            span.get_span_start(),
+                            "_".into(),
+                        )]),
+                    );
                 }
             }
         }
@@ -447,12 +492,19 @@ impl Linter<'_> {
             let span: Span = info.0;
             let used: bool = info.1;
 
-            if !used {
-                warnings.push(CompilationIssue::Warning(
-                    CompilationIssueCode::W0008,
-                    format!("'{}' not used.", name),
-                    span,
-                ));
+            if !used && !name.starts_with('_') {
+                warnings.push(
+                    CompilationIssue::Warning(
+                        CompilationIssueCode::W0008,
+                        format!("'{}' not used.", name),
+                        span,
+                    )
+                    .with_edits(vec![CompilationEdit::insertion(
+                        span.get_line(),
+                        span.get_span_start(),
+                        "_".into(),
+                    )]),
+                );
             }
         }
 
@@ -592,6 +644,11 @@ impl Linter<'_> {
     pub fn add_bulk_warnings(&mut self, warnings: Vec<CompilationIssue>) {
         self.warnings.extend(warnings);
     }
+
+    #[inline]
+    pub fn add_warning(&mut self, warning: CompilationIssue) {
+        self.warnings.push(warning);
+    }
 }
 
 impl Linter<'_> {
@@ -613,6 +670,17 @@ impl<'linter> Linter<'linter> {
     }
 }
 
+impl Linter<'_> {
+    #[inline]
+    pub fn get_symbol_usage(&self) -> LinterSymbolUsage {
+        LinterSymbolUsage::new(
+            self.ast,
+            &self.referenced,
+            &self.diagnostician.get_file_path(),
+        )
+    }
+}
+
 impl<'linter> Linter<'linter> {
     #[inline]
     pub fn get_mut_symbols(&mut self) -> &mut LinterSymbolsTable<'linter> {
@@ -643,6 +711,8 @@ pub fn mark_as_mutated<'linter>(linter: &mut Linter<'linter>, name: &'linter str
 
 #[inline]
 pub fn mark_as_used<'linter>(linter: &mut Linter<'linter>, name: &'linter str) {
+    linter.referenced.insert(name);
+
     if let Some(local) = linter.symbols.get_local_info(name) {
         local.1 = true;
     }
@@ -0,0 +1,204 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+use thrustc_ast::{Ast, traits::AstGetType};
+use thrustc_errors::{CompilationIssue, CompilationIssueCode, CompilationLabel};
+use thrustc_span::Span;
+use thrustc_token_type::TokenType;
+use thrustc_typesystem::{
+    Type,
+    traits::{ConstantTypeExtensions, TypeIsExtensions},
+};
+
+use crate::Linter;
+
+pub fn check_shadowing<'linter>(linter: &mut Linter<'linter>, name: &'linter str, span: Span) {
+    if name.starts_with('_') {
+        return;
+    }
+
+    let local_span: Option<Span> = linter
+        .get_mut_symbols()
+        .get_local_info(name)
+        .map(|local| local.0);
+
+    let parameter_span: Option<Span> = linter
+        .get_mut_symbols()
+        .get_parameter_info(name)
+        .map(|parameter| parameter.0);
+
+    let shadowed: Option<(Span, &str)> = local_span
+        .map(|span| (span, "local"))
+        .or_else(|| parameter_span.map(|span| (span, "parameter")));
+
+    let Some((shadowed_span, kind)) = shadowed else {
+        return;
+    };
+
+    linter.add_warning(
+        CompilationIssue::Warning(
+            CompilationIssueCode::W0021,
+            format!(
+                "'{}' shadows a {} with the same name. You should rename one of them.",
+                name, kind
+            ),
+            span,
+        )
+        .with_labels(vec![CompilationLabel::new(
+            shadowed_span,
+            format!("The shadowed {} is declared here.", kind),
+        )]),
+    );
+}
+
+pub fn check_lossy_cast(linter: &mut Linter, from: &Ast, cast: &Type, span: Span) {
+    if matches!(from, Ast::Integer { .. }) {
+        return;
+    }
+
+    let Ok(from_type) = from.get_value_type() else {
+        return;
+    };
+
+    let from_type: Type = from_type.remove_all_constant_type();
+    let cast: Type = cast.remove_all_constant_type();
+
+    let (Some(from_bits), Some(cast_bits)) = (
+        self::get_integer_bit_size(&from_type),
+        self::get_integer_bit_size(&cast),
+    ) else {
+        return;
+    };
+
+    let from_signed: bool = from_type.is_signed_integer_type();
+    let cast_signed: bool = cast.is_signed_integer_type();
+
+    let reason: &str = if cast_bits < from_bits {
+        "truncates"
+    } else if from_signed && !cast_signed {
+        "drops the sign of"
+    } else if !from_signed && cast_signed && cast_bits == from_bits {
+        "may change the sign of"
+    } else {
+        return;
+    };
+
+    linter.add_warning(CompilationIssue::Warning(
+        CompilationIssueCode::W0022,
+        format!(
+            "Casting '{}' to '{}' {} the value. You should make the conversion explicit.",
+            from_type, cast, reason
+        ),
+        span,
+    ));
+}
+
+pub fn check_constant_condition(linter: &mut Linter, condition: &Ast) {
+    if let Ast::Boolean { value, span, .. } = condition {
+        linter.add_warning(CompilationIssue::Warning(
+            CompilationIssueCode::W0023,
+            format!(
+                "This condition is always '{}'. You should remove it or the branch it guards.",
+                *value != 0
+            ),
+            *span,
+        ));
+    }
+}
+
+pub fn check_self_assignment(linter: &mut Linter, source: &Ast, value: &Ast, span: Span) {
+    if let (Ast::Reference { name: target, .. }, Ast::Reference { name, .. }) = (source, value) {
+        if target == name {
+            linter.add_warning(CompilationIssue::Warning(
+                CompilationIssueCode::W0024,
+                format!(
+                    "'{}' is assigned to itself. You should remove the assignment.",
+                    name
+                ),
+                span,
+            ));
+        }
+    }
+}
+
+pub fn check_unsigned_comparison(
+    linter: &mut Linter,
+    left: &Ast,
+    operator: TokenType,
+    right: &Ast,
+    span: Span,
+) {
+    let outcome: Option<bool> = match (operator, self::is_zero(left), self::is_zero(right)) {
+        (TokenType::Less, false, true) if self::is_unsigned(left) => Some(false),
+        (TokenType::GreaterEq, false, true) if self::is_unsigned(left) => Some(true),
+        (TokenType::Greater, true, false) if self::is_unsigned(right) => Some(false),
+        (TokenType::LessEq, true, false) if self::is_unsigned(right) => Some(true),
+
+        _ => None,
+    };
+
+    if let Some(outcome) = outcome {
+        linter.add_warning(CompilationIssue::Warning(
+            CompilationIssueCode::W0025,
+            format!(
+                "Comparing an unsigned value against zero this way is always '{}'.",
+                outcome
+            ),
+            span,
+        ));
+    }
+}
+
+pub fn check_empty_loop_body(linter: &mut Linter, block: &Ast) {
+    if let Ast::Block {
+        nodes, post, span, ..
+    } = block
+    {
+        if nodes.is_empty() && post.is_empty() {
+            linter.add_warning(CompilationIssue::Warning(
+                CompilationIssueCode::W0026,
+                "This loop has an empty body. You should add a statement or remove the loop."
+                    .into(),
+                *span,
+            ));
+        }
+    }
+}
+
+fn get_integer_bit_size(kind: &Type) -> Option<u32> {
+    match kind {
+        Type::U8 { .. } | Type::S8 { .. } => Some(8),
+        Type::U16 { .. } | Type::S16 { .. } => Some(16),
+        Type::U32 { .. } | Type::S32 { .. } => Some(32),
+        Type::U64 { .. } | Type::S64 { .. } => Some(64),
+        Type::U128 { .. } | Type::S128 { .. } => Some(128),
+
+        _ => None,
+    }
+}
+
+#[inline]
+fn is_zero(node: &Ast) -> bool {
+    matches!(node, Ast::Integer { value: 0, .. })
+}
+
+#[inline]
+fn is_unsigned(node: &Ast) -> bool {
+    node.get_value_type()
+        .is_ok_and(|kind| kind.remove_all_constant_type().is_unsigned_integer_type())
+}
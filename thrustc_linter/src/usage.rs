@@ -0,0 +1,123 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+use std::path::Path;
+
+use thrustc_ast::Ast;
+use thrustc_attributes::{ThrustAttribute, traits::ThrustAttributesExtensions};
+use thrustc_diagnostician::warnings::AllowedWarnings;
+use thrustc_span::Span;
+
+use ahash::AHashSet as HashSet;
+
+#[derive(Debug, Default)]
+pub struct LinterSymbolUsage {
+    definitions: Vec<(String, Span)>,
+    references: HashSet<String>,
+    allowed_warnings: AllowedWarnings,
+}
+
+impl LinterSymbolUsage {
+    pub fn new(ast: &[Ast], referenced: &HashSet<&str>, path: &Path) -> Self {
+        let mut definitions: Vec<(String, Span)> = Vec::with_capacity(u8::MAX as usize);
+        let mut references: HashSet<String> = HashSet::with_capacity(referenced.len());
+
+        for node in ast.iter() {
+            match node {
+                Ast::Function {
+                    name,
+                    body,
+                    attributes,
+                    span,
+                    ..
+                } => {
+                    let linkage_name: &str = self::get_linkage_name(name, attributes);
+
+                    if referenced.contains(name) {
+                        references.insert(linkage_name.to_string());
+                    }
+
+                    if body.is_some()
+                        && attributes.has_public_attribute()
+                        && !attributes.has_constructor_attribute()
+                        && !attributes.has_destructor_attribute()
+                        && *name != "main"
+                    {
+                        definitions.push((linkage_name.to_string(), *span));
+                    }
+                }
+
+                Ast::AssemblerFunction {
+                    name,
+                    attributes,
+                    span,
+                    ..
+                } => {
+                    let linkage_name: &str = self::get_linkage_name(name, attributes);
+
+                    if referenced.contains(name) {
+                        references.insert(linkage_name.to_string());
+                    }
+
+                    if attributes.has_public_attribute()
+                        && !attributes.has_constructor_attribute()
+                        && !attributes.has_destructor_attribute()
+                    {
+                        definitions.push((linkage_name.to_string(), *span));
+                    }
+                }
+
+                _ => (),
+            }
+        }
+
+        Self {
+            definitions,
+            references,
+            allowed_warnings: AllowedWarnings::new(path, ast),
+        }
+    }
+}
+
+impl LinterSymbolUsage {
+    #[inline]
+    pub fn get_public_definitions(&self) -> &[(String, Span)] {
+        &self.definitions
+    }
+
+    #[inline]
+    pub fn is_referenced(&self, name: &str) -> bool {
+        self.references.contains(name)
+    }
+
+    #[inline]
+    pub fn get_allowed_warnings(&self) -> &AllowedWarnings {
+        &self.allowed_warnings
+    }
+}
+
+/// Public symbols are matched across compilation units by their linkage name.
+fn get_linkage_name<'a>(name: &'a str, attributes: &'a [ThrustAttribute]) -> &'a str {
+    attributes
+        .iter()
+        .find_map(|attr| match attr {
+            ThrustAttribute::Extern(external_name, ..) => Some(external_name.as_str()),
+            _ => None,
+        })
+        .unwrap_or(name)
+}
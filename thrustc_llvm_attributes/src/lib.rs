@@ -191,8 +191,8 @@ pub enum LLVMAttributeComparator {
 }
 
 #[inline]
-pub fn into_llvm_attribute(attribute: &ThrustAttribute) -> LLVMAttribute<'_> {
-    match attribute {
+pub fn into_llvm_attribute(attribute: &ThrustAttribute) -> Option<LLVMAttribute<'_>> {
+    Some(match attribute {
         ThrustAttribute::Extern(external_name, ..) => LLVMAttribute::Extern(external_name),
         ThrustAttribute::Linkage(linkage, ..) => LLVMAttribute::Linkage(linkage.get_llvm_linkage()),
         ThrustAttribute::Convention(name, ..) => LLVMAttribute::Convention(
@@ -223,14 +223,18 @@ pub fn into_llvm_attribute(attribute: &ThrustAttribute) -> LLVMAttribute<'_> {
         ThrustAttribute::Thunk(..) => LLVMAttribute::Thunk,
         ThrustAttribute::Constructor(..) => LLVMAttribute::Constructor,
         ThrustAttribute::Destructor(..) => LLVMAttribute::Destructor,
-    }
+
+        ThrustAttribute::Allow(..) | ThrustAttribute::Format(..) => return None,
+    })
 }
 
 pub fn into_llvm_attributes(thrust_attributes: &ThrustAttributes) -> Vec<LLVMAttribute<'_>> {
     let mut llvm_attributes: Vec<LLVMAttribute<'_>> = Vec::with_capacity(thrust_attributes.len());
 
     for attribute in thrust_attributes.iter() {
-        llvm_attributes.push(self::into_llvm_attribute(attribute));
+        if let Some(llvm_attribute) = self::into_llvm_attribute(attribute) {
+            llvm_attributes.push(llvm_attribute);
+        }
     }
 
     llvm_attributes
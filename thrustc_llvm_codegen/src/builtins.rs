@@ -18,18 +18,30 @@
 */
 
 use inkwell::{
-    builder::Builder,
-    types::BasicTypeEnum,
-    values::{BasicValueEnum, IntValue, PointerValue},
+    AddressSpace, IntPredicate,
+    basic_block::BasicBlock,
+    builder::{Builder, BuilderError},
+    context::Context,
+    module::Module,
+    types::{ArrayType, BasicMetadataTypeEnum, BasicTypeEnum, FunctionType, IntType, VectorType},
+    values::{
+        BasicMetadataValueEnum, BasicValueEnum, FunctionValue, IntValue, PhiValue, PointerValue,
+        VectorValue,
+    },
 };
 use thrustc_ast::traits::AstCodeLocation;
+use thrustc_llvm_target_triple::LLVMTargetTriple;
 use thrustc_span::Span;
-use thrustc_typesystem::Type;
+use thrustc_typesystem::{Type, traits::TypeVectorExtensions};
 
 use thrustc_ast::{Ast, builtins::AstBuiltin};
 
 use crate::{
-    abort, cast, codegen, context::LLVMCodeGenContext, traits::AstLLVMGetType, typegeneration,
+    abort, block, cast, codegen,
+    context::LLVMCodeGenContext,
+    expressions,
+    traits::{AstLLVMGetType, LLVMFunctionExtensions},
+    typegeneration,
 };
 
 #[derive(Debug, Clone)]
@@ -56,6 +68,38 @@ pub enum LLVMBuiltin<'ctx> {
         size: &'ctx Ast<'ctx>,
         span: Span,
     },
+    Splat {
+        of: &'ctx Type,
+        value: &'ctx Ast<'ctx>,
+        span: Span,
+    },
+    Shuffle {
+        left: &'ctx Ast<'ctx>,
+        right: &'ctx Ast<'ctx>,
+        mask: &'ctx [u32],
+        span: Span,
+    },
+    VaStart {
+        span: Span,
+    },
+    VaArg {
+        list: &'ctx Ast<'ctx>,
+        of: &'ctx Type,
+        span: Span,
+    },
+    VaEnd {
+        list: &'ctx Ast<'ctx>,
+        span: Span,
+    },
+    VaCopy {
+        list: &'ctx Ast<'ctx>,
+        span: Span,
+    },
+    Print {
+        pieces: &'ctx [String],
+        args: &'ctx [Ast<'ctx>],
+        span: Span,
+    },
     AbiSizeOf {
         of: &'ctx Type,
         span: Span,
@@ -114,6 +158,35 @@ pub fn into_llvm_builtin<'ctx>(ast_builtin: &'ctx AstBuiltin) -> LLVMBuiltin<'ct
             size,
             span: *span,
         },
+        AstBuiltin::Splat { of, value, span } => LLVMBuiltin::Splat {
+            of,
+            value,
+            span: *span,
+        },
+        AstBuiltin::Shuffle {
+            left,
+            right,
+            mask,
+            span,
+        } => LLVMBuiltin::Shuffle {
+            left,
+            right,
+            mask,
+            span: *span,
+        },
+        AstBuiltin::VaStart { span } => LLVMBuiltin::VaStart { span: *span },
+        AstBuiltin::VaArg { list, of, span } => LLVMBuiltin::VaArg {
+            list,
+            of,
+            span: *span,
+        },
+        AstBuiltin::VaEnd { list, span } => LLVMBuiltin::VaEnd { list, span: *span },
+        AstBuiltin::VaCopy { list, span } => LLVMBuiltin::VaCopy { list, span: *span },
+        AstBuiltin::Print { pieces, args, span } => LLVMBuiltin::Print {
+            pieces,
+            args,
+            span: *span,
+        },
         AstBuiltin::AlignOf { of, span } => LLVMBuiltin::AlignOf { of, span: *span },
         AstBuiltin::SizeOf { of, span } => LLVMBuiltin::SizeOf { of, span: *span },
         AstBuiltin::BitSizeOf { of, span } => LLVMBuiltin::BitSizeOf { of, span: *span },
@@ -262,6 +335,146 @@ pub fn compile<'ctx>(
                 })
                 .into()
         }
+        LLVMBuiltin::Splat { of, value, span } => {
+            let element_type: Type = of.get_vector_base_type();
+            let lanes: u32 = of.get_vector_lanes();
+
+            let vector_type: VectorType =
+                typegeneration::generate_vector_type(context, &element_type, lanes);
+
+            let element: BasicValueEnum =
+                codegen::compile_as_value(context, value, Some(&element_type));
+
+            let llvm_context: &Context = context.get_llvm_context();
+            let llvm_builder: &Builder = context.get_llvm_builder();
+
+            let first_lane: IntValue = llvm_context.i32_type().const_zero();
+            let mask: VectorValue = llvm_context.i32_type().vec_type(lanes).const_zero();
+
+            let splatted: Result<VectorValue, BuilderError> = llvm_builder
+                .build_insert_element(vector_type.get_poison(), element, first_lane, "")
+                .and_then(|vector| {
+                    llvm_builder.build_shuffle_vector(vector, vector_type.get_poison(), mask, "")
+                });
+
+            splatted
+                .unwrap_or_else(|_| {
+                    abort::abort_codegen(
+                        context,
+                        "Failed to compile 'splat' builtin!",
+                        span,
+                        std::path::PathBuf::from(file!()),
+                        line!(),
+                    )
+                })
+                .into()
+        }
+        LLVMBuiltin::Shuffle {
+            left,
+            right,
+            mask,
+            span,
+        } => {
+            let left: VectorValue =
+                codegen::compile_as_value(context, left, None).into_vector_value();
+            let right: VectorValue =
+                codegen::compile_as_value(context, right, None).into_vector_value();
+
+            let llvm_context: &Context = context.get_llvm_context();
+            let llvm_builder: &Builder = context.get_llvm_builder();
+
+            let lanes: Vec<IntValue> = mask
+                .iter()
+                .map(|lane| llvm_context.i32_type().const_int((*lane).into(), false))
+                .collect();
+
+            let mask: VectorValue = VectorType::const_vector(&lanes);
+
+            llvm_builder
+                .build_shuffle_vector(left, right, mask, "")
+                .unwrap_or_else(|_| {
+                    abort::abort_codegen(
+                        context,
+                        "Failed to compile 'shuffle' builtin!",
+                        span,
+                        std::path::PathBuf::from(file!()),
+                        line!(),
+                    )
+                })
+                .into()
+        }
+        LLVMBuiltin::VaStart { span } => {
+            let list: PointerValue = self::alloc_va_list(context, span);
+
+            self::call_va_intrinsic(context, "llvm.va_start", &[list], span);
+
+            list.into()
+        }
+        LLVMBuiltin::VaArg { list, of, span } => {
+            let list: PointerValue =
+                codegen::compile_as_value(context, list, None).into_pointer_value();
+
+            let llvm_type: BasicTypeEnum = typegeneration::generate_type(context, of);
+
+            context
+                .get_llvm_builder()
+                .build_va_arg(list, llvm_type, "")
+                .unwrap_or_else(|_| {
+                    abort::abort_codegen(
+                        context,
+                        "Failed to compile 'vaArg' builtin!",
+                        span,
+                        std::path::PathBuf::from(file!()),
+                        line!(),
+                    )
+                })
+        }
+        LLVMBuiltin::VaEnd { list, span } => {
+            let list: PointerValue =
+                codegen::compile_as_value(context, list, None).into_pointer_value();
+
+            self::call_va_intrinsic(context, "llvm.va_end", &[list], span);
+
+            context
+                .get_llvm_context()
+                .ptr_type(AddressSpace::default())
+                .const_null()
+                .into()
+        }
+        LLVMBuiltin::VaCopy { list, span } => {
+            let source: PointerValue =
+                codegen::compile_as_value(context, list, None).into_pointer_value();
+
+            let destination: PointerValue = self::alloc_va_list(context, span);
+
+            self::call_va_intrinsic(context, "llvm.va_copy", &[destination, source], span);
+
+            destination.into()
+        }
+        LLVMBuiltin::Print { pieces, args, span } => {
+            let printed: Result<(), BuilderError> =
+                if context.get_compiler_options().is_freestanding() {
+                    self::compile_freestanding_print(context, pieces, args, span)
+                } else {
+                    self::compile_print(context, pieces, args, span)
+                };
+
+            if printed.is_err() {
+                abort::abort_codegen(
+                    context,
+                    "Failed to compile 'print' builtin!",
+                    span,
+                    std::path::PathBuf::from(file!()),
+                    line!(),
+                );
+            }
+
+            context
+                .get_llvm_context()
+                .ptr_type(AddressSpace::default())
+                .const_null()
+                .into()
+        }
         LLVMBuiltin::Malloc { of, span } => context
             .get_llvm_builder()
             .build_malloc(typegeneration::generate_type(context, of), "")
@@ -361,3 +574,571 @@ pub fn compile<'ctx>(
         }
     }
 }
+
+fn alloc_va_list<'ctx>(
+    context: &mut LLVMCodeGenContext<'_, 'ctx>,
+    span: Span,
+) -> PointerValue<'ctx> {
+    let llvm_context: &Context = context.get_llvm_context();
+    let llvm_builder: &Builder = context.get_llvm_builder();
+
+    // Large enough to hold the 'va_list' of every supported target.
+    let va_list_type: ArrayType = llvm_context.i64_type().array_type(4);
+
+    let Ok(list) = llvm_builder.build_alloca(va_list_type, "") else {
+        abort::abort_codegen(
+            context,
+            "Failed to allocate a variable argument list!",
+            span,
+            std::path::PathBuf::from(file!()),
+            line!(),
+        );
+    };
+
+    if let Some(instruction) = list.as_instruction() {
+        instruction.set_alignment(8).unwrap_or_else(|_| {
+            abort::abort_codegen(
+                context,
+                "Failed to set type alignment!",
+                span,
+                std::path::PathBuf::from(file!()),
+                line!(),
+            );
+        });
+    }
+
+    list
+}
+
+fn call_va_intrinsic<'ctx>(
+    context: &mut LLVMCodeGenContext<'_, 'ctx>,
+    name: &str,
+    args: &[PointerValue<'ctx>],
+    span: Span,
+) {
+    let llvm_context: &Context = context.get_llvm_context();
+    let llvm_module: &Module = context.get_llvm_module();
+    let llvm_builder: &Builder = context.get_llvm_builder();
+
+    let intrinsic: FunctionValue = llvm_module.get_function(name).unwrap_or_else(|| {
+        let ptr_type: BasicTypeEnum = llvm_context.ptr_type(AddressSpace::default()).into();
+        let parameters: Vec<BasicMetadataTypeEnum> = args.iter().map(|_| ptr_type.into()).collect();
+
+        let intrinsic_type: FunctionType = llvm_context.void_type().fn_type(&parameters, false);
+
+        llvm_module.add_function(name, intrinsic_type, None)
+    });
+
+    let args: Vec<BasicMetadataValueEnum> = args.iter().map(|arg| (*arg).into()).collect();
+
+    if llvm_builder.build_call(intrinsic, &args, "").is_err() {
+        abort::abort_codegen(
+            context,
+            &format!("Failed to compile '{}' intrinsic!", name),
+            span,
+            std::path::PathBuf::from(file!()),
+            line!(),
+        );
+    }
+}
+
+fn compile_print<'ctx>(
+    context: &mut LLVMCodeGenContext<'_, 'ctx>,
+    pieces: &[String],
+    args: &'ctx [Ast<'ctx>],
+    span: Span,
+) -> Result<(), BuilderError> {
+    let llvm_context: &Context = context.get_llvm_context();
+    let llvm_module: &Module = context.get_llvm_module();
+    let llvm_builder: &Builder = context.get_llvm_builder();
+
+    let mut format: String = String::with_capacity(u8::MAX as usize);
+    let mut values: Vec<BasicMetadataValueEnum> = Vec::with_capacity(args.len() + 1);
+
+    for (position, piece) in pieces.iter().enumerate() {
+        format.push_str(&piece.replace('%', "%%"));
+
+        if let Some(arg) = args.get(position) {
+            let (specifier, value): (&str, BasicValueEnum) =
+                self::compile_print_argument(context, arg, span)?;
+
+            format.push_str(specifier);
+            values.push(value.into());
+        }
+    }
+
+    let format: PointerValue = llvm_builder
+        .build_global_string_ptr(&format, "")?
+        .as_pointer_value();
+
+    values.insert(0, format.into());
+
+    let ptr_type: BasicMetadataTypeEnum = llvm_context.ptr_type(AddressSpace::default()).into();
+    let printf_type: FunctionType = llvm_context.i32_type().fn_type(&[ptr_type], true);
+
+    // A user declaration of 'printf' may not be variadic, so it is always called through its address.
+    let printf: FunctionValue = llvm_module
+        .get_function("printf")
+        .unwrap_or_else(|| llvm_module.add_function("printf", printf_type, None));
+
+    llvm_builder.build_indirect_call(
+        printf_type,
+        printf.as_global_value().as_pointer_value(),
+        &values,
+        "",
+    )?;
+
+    Ok(())
+}
+
+fn compile_print_argument<'ctx>(
+    context: &mut LLVMCodeGenContext<'_, 'ctx>,
+    arg: &'ctx Ast<'ctx>,
+    span: Span,
+) -> Result<(&'static str, BasicValueEnum<'ctx>), BuilderError> {
+    let llvm_context: &Context = context.get_llvm_context();
+    let llvm_builder: &Builder = context.get_llvm_builder();
+
+    let size_type: IntType = llvm_context.ptr_sized_int_type(context.get_target_data(), None);
+
+    let kind: &Type = self::get_print_type(arg.get_type_for_llvm());
+
+    let integer: Option<(&'static str, IntType, bool)> = match kind {
+        Type::S8 { .. } | Type::S16 { .. } | Type::S32 { .. } => {
+            Some(("%d", llvm_context.i32_type(), true))
+        }
+        Type::U8 { .. } | Type::U16 { .. } | Type::U32 { .. } => {
+            Some(("%u", llvm_context.i32_type(), false))
+        }
+        Type::S64 { .. } => Some(("%lld", llvm_context.i64_type(), true)),
+        Type::U64 { .. } => Some(("%llu", llvm_context.i64_type(), false)),
+        Type::SSize { .. } => Some(("%zd", size_type, true)),
+        Type::USize { .. } => Some(("%zu", size_type, false)),
+        Type::Char(..) => Some(("%c", llvm_context.i32_type(), false)),
+
+        _ => None,
+    };
+
+    if let Some((specifier, integer_type, signed)) = integer {
+        let value: IntValue = codegen::compile_as_value(context, arg, None).into_int_value();
+
+        return Ok((
+            specifier,
+            llvm_builder
+                .build_int_cast_sign_flag(value, integer_type, signed, "")?
+                .into(),
+        ));
+    }
+
+    match kind {
+        Type::F16 { .. } | Type::BF16 { .. } | Type::F32 { .. } | Type::F64 { .. } => {
+            let value: BasicValueEnum = codegen::compile_as_value(context, arg, None);
+
+            Ok((
+                "%g",
+                llvm_builder
+                    .build_float_cast(value.into_float_value(), llvm_context.f64_type(), "")?
+                    .into(),
+            ))
+        }
+        Type::Bool(..) => {
+            let value: IntValue = codegen::compile_as_value(context, arg, None).into_int_value();
+
+            let true_string: PointerValue =
+                expressions::string::compile(context, b"true", true, span);
+            let false_string: PointerValue =
+                expressions::string::compile(context, b"false", true, span);
+
+            Ok((
+                "%s",
+                llvm_builder.build_select(value, true_string, false_string, "")?,
+            ))
+        }
+
+        _ => Ok((
+            "%s",
+            self::compile_print_string(context, arg, kind, span).into(),
+        )),
+    }
+}
+
+fn compile_freestanding_print<'ctx>(
+    context: &mut LLVMCodeGenContext<'_, 'ctx>,
+    pieces: &'ctx [String],
+    args: &'ctx [Ast<'ctx>],
+    span: Span,
+) -> Result<(), BuilderError> {
+    let llvm_context: &Context = context.get_llvm_context();
+    let size_type: IntType = llvm_context.ptr_sized_int_type(context.get_target_data(), None);
+
+    for (position, piece) in pieces.iter().enumerate() {
+        if !piece.is_empty() {
+            let buffer: PointerValue =
+                expressions::string::compile(context, piece.as_bytes(), false, span);
+            let length: IntValue = size_type.const_int(piece.len() as u64, false);
+
+            self::compile_write(context, buffer, length)?;
+        }
+
+        if let Some(arg) = args.get(position) {
+            let (buffer, length): (PointerValue, IntValue) =
+                self::compile_freestanding_print_argument(context, arg, span)?;
+
+            self::compile_write(context, buffer, length)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn compile_freestanding_print_argument<'ctx>(
+    context: &mut LLVMCodeGenContext<'_, 'ctx>,
+    arg: &'ctx Ast<'ctx>,
+    span: Span,
+) -> Result<(PointerValue<'ctx>, IntValue<'ctx>), BuilderError> {
+    let llvm_context: &Context = context.get_llvm_context();
+    let llvm_builder: &Builder = context.get_llvm_builder();
+
+    let size_type: IntType = llvm_context.ptr_sized_int_type(context.get_target_data(), None);
+
+    let kind: &Type = self::get_print_type(arg.get_type_for_llvm());
+
+    match kind {
+        Type::S8 { .. }
+        | Type::S16 { .. }
+        | Type::S32 { .. }
+        | Type::S64 { .. }
+        | Type::SSize { .. } => {
+            let value: IntValue = codegen::compile_as_value(context, arg, None).into_int_value();
+            let value: IntValue =
+                llvm_builder.build_int_cast_sign_flag(value, llvm_context.i64_type(), true, "")?;
+
+            self::compile_integer_digits(context, value, true, span)
+        }
+        Type::U8 { .. }
+        | Type::U16 { .. }
+        | Type::U32 { .. }
+        | Type::U64 { .. }
+        | Type::USize { .. } => {
+            let value: IntValue = codegen::compile_as_value(context, arg, None).into_int_value();
+            let value: IntValue =
+                llvm_builder.build_int_cast_sign_flag(value, llvm_context.i64_type(), false, "")?;
+
+            self::compile_integer_digits(context, value, false, span)
+        }
+        Type::Char(..) => {
+            let value: IntValue = codegen::compile_as_value(context, arg, None).into_int_value();
+
+            let buffer: PointerValue = llvm_builder.build_alloca(llvm_context.i8_type(), "")?;
+            llvm_builder.build_store(buffer, value)?;
+
+            Ok((buffer, size_type.const_int(1, false)))
+        }
+        Type::Bool(..) => {
+            let value: IntValue = codegen::compile_as_value(context, arg, None).into_int_value();
+
+            let true_string: PointerValue =
+                expressions::string::compile(context, b"true", false, span);
+            let false_string: PointerValue =
+                expressions::string::compile(context, b"false", false, span);
+
+            let buffer: PointerValue = llvm_builder
+                .build_select(value, true_string, false_string, "")?
+                .into_pointer_value();
+
+            let length: IntValue = llvm_builder
+                .build_select(
+                    value,
+                    size_type.const_int(4, false),
+                    size_type.const_int(5, false),
+                    "",
+                )?
+                .into_int_value();
+
+            Ok((buffer, length))
+        }
+        Type::F16 { .. } | Type::BF16 { .. } | Type::F32 { .. } | Type::F64 { .. } => {
+            abort::abort_codegen(
+                context,
+                "Failed to format a floating point value in a freestanding build!",
+                arg.get_span(),
+                std::path::PathBuf::from(file!()),
+                line!(),
+            )
+        }
+
+        _ => {
+            let buffer: PointerValue = self::compile_print_string(context, arg, kind, span);
+            let length: IntValue = self::compile_string_length(context, buffer, span)?;
+
+            Ok((buffer, length))
+        }
+    }
+}
+
+fn compile_integer_digits<'ctx>(
+    context: &mut LLVMCodeGenContext<'_, 'ctx>,
+    value: IntValue<'ctx>,
+    signed: bool,
+    span: Span,
+) -> Result<(PointerValue<'ctx>, IntValue<'ctx>), BuilderError> {
+    let llvm_context: &Context = context.get_llvm_context();
+    let llvm_builder: &Builder = context.get_llvm_builder();
+    let llvm_function: FunctionValue = context.get_current_function(span).get_value();
+
+    let i8_type: IntType = llvm_context.i8_type();
+    let i64_type: IntType = llvm_context.i64_type();
+    let size_type: IntType = llvm_context.ptr_sized_int_type(context.get_target_data(), None);
+
+    // Enough room for the 20 digits of 'u64::MAX', or 19 digits and a sign.
+    let buffer_size: IntValue = i64_type.const_int(21, false);
+    let buffer: PointerValue = llvm_builder.build_alloca(i8_type.array_type(21), "")?;
+
+    let zero: IntValue = i64_type.const_zero();
+    let one: IntValue = i64_type.const_int(1, false);
+    let ten: IntValue = i64_type.const_int(10, false);
+
+    let is_negative: IntValue = if signed {
+        llvm_builder.build_int_compare(IntPredicate::SLT, value, zero, "")?
+    } else {
+        llvm_context.bool_type().const_zero()
+    };
+
+    // 'i64::MIN' negates to itself, which is still the right magnitude once divided as unsigned.
+    let magnitude: IntValue = if signed {
+        let negated: IntValue = llvm_builder.build_int_sub(zero, value, "")?;
+
+        llvm_builder
+            .build_select(is_negative, negated, value, "")?
+            .into_int_value()
+    } else {
+        value
+    };
+
+    let entry_block: BasicBlock = context.get_last_builder_block(span);
+    let digits_block: BasicBlock = block::append_block(context, llvm_function);
+    let end_block: BasicBlock = block::append_block(context, llvm_function);
+
+    llvm_builder.build_unconditional_branch(digits_block)?;
+    llvm_builder.position_at_end(digits_block);
+
+    let remaining: PhiValue = llvm_builder.build_phi(i64_type, "")?;
+    let position: PhiValue = llvm_builder.build_phi(i64_type, "")?;
+
+    let remaining_value: IntValue = remaining.as_basic_value().into_int_value();
+    let position_value: IntValue = position.as_basic_value().into_int_value();
+
+    let digit: IntValue = llvm_builder.build_int_unsigned_rem(remaining_value, ten, "")?;
+    let digit: IntValue = llvm_builder.build_int_truncate(digit, i8_type, "")?;
+    let digit: IntValue =
+        llvm_builder.build_int_add(digit, i8_type.const_int(u64::from(b'0'), false), "")?;
+
+    let next_position: IntValue = llvm_builder.build_int_sub(position_value, one, "")?;
+    let slot: PointerValue =
+        unsafe { llvm_builder.build_in_bounds_gep(i8_type, buffer, &[next_position], "")? };
+
+    llvm_builder.build_store(slot, digit)?;
+
+    let next_remaining: IntValue = llvm_builder.build_int_unsigned_div(remaining_value, ten, "")?;
+    let has_digits: IntValue =
+        llvm_builder.build_int_compare(IntPredicate::NE, next_remaining, zero, "")?;
+
+    llvm_builder.build_conditional_branch(has_digits, digits_block, end_block)?;
+
+    remaining.add_incoming(&[(&magnitude, entry_block), (&next_remaining, digits_block)]);
+    position.add_incoming(&[(&buffer_size, entry_block), (&next_position, digits_block)]);
+
+    llvm_builder.position_at_end(end_block);
+
+    let mut start: IntValue = next_position;
+
+    if signed {
+        let sign_position: IntValue = llvm_builder.build_int_sub(next_position, one, "")?;
+        let sign_slot: PointerValue =
+            unsafe { llvm_builder.build_in_bounds_gep(i8_type, buffer, &[sign_position], "")? };
+
+        llvm_builder.build_store(sign_slot, i8_type.const_int(u64::from(b'-'), false))?;
+
+        start = llvm_builder
+            .build_select(is_negative, sign_position, next_position, "")?
+            .into_int_value();
+    }
+
+    let digits: PointerValue =
+        unsafe { llvm_builder.build_in_bounds_gep(i8_type, buffer, &[start], "")? };
+
+    let length: IntValue = llvm_builder.build_int_sub(buffer_size, start, "")?;
+    let length: IntValue = llvm_builder.build_int_cast_sign_flag(length, size_type, false, "")?;
+
+    Ok((digits, length))
+}
+
+fn compile_string_length<'ctx>(
+    context: &mut LLVMCodeGenContext<'_, 'ctx>,
+    string: PointerValue<'ctx>,
+    span: Span,
+) -> Result<IntValue<'ctx>, BuilderError> {
+    let llvm_context: &Context = context.get_llvm_context();
+    let llvm_builder: &Builder = context.get_llvm_builder();
+    let llvm_function: FunctionValue = context.get_current_function(span).get_value();
+
+    let i8_type: IntType = llvm_context.i8_type();
+    let size_type: IntType = llvm_context.ptr_sized_int_type(context.get_target_data(), None);
+
+    let entry_block: BasicBlock = context.get_last_builder_block(span);
+    let scan_block: BasicBlock = block::append_block(context, llvm_function);
+    let end_block: BasicBlock = block::append_block(context, llvm_function);
+
+    llvm_builder.build_unconditional_branch(scan_block)?;
+    llvm_builder.position_at_end(scan_block);
+
+    let index: PhiValue = llvm_builder.build_phi(size_type, "")?;
+    let index_value: IntValue = index.as_basic_value().into_int_value();
+
+    let slot: PointerValue =
+        unsafe { llvm_builder.build_in_bounds_gep(i8_type, string, &[index_value], "")? };
+
+    let byte: IntValue = llvm_builder.build_load(i8_type, slot, "")?.into_int_value();
+    let is_end: IntValue =
+        llvm_builder.build_int_compare(IntPredicate::EQ, byte, i8_type.const_zero(), "")?;
+
+    let next_index: IntValue =
+        llvm_builder.build_int_add(index_value, size_type.const_int(1, false), "")?;
+
+    llvm_builder.build_conditional_branch(is_end, end_block, scan_block)?;
+
+    index.add_incoming(&[
+        (&size_type.const_zero(), entry_block),
+        (&next_index, scan_block),
+    ]);
+
+    llvm_builder.position_at_end(end_block);
+
+    Ok(index_value)
+}
+
+fn compile_write<'ctx>(
+    context: &mut LLVMCodeGenContext<'_, 'ctx>,
+    buffer: PointerValue<'ctx>,
+    length: IntValue<'ctx>,
+) -> Result<(), BuilderError> {
+    let llvm_context: &Context = context.get_llvm_context();
+    let llvm_module: &Module = context.get_llvm_module();
+    let llvm_builder: &Builder = context.get_llvm_builder();
+
+    let size_type: IntType = llvm_context.ptr_sized_int_type(context.get_target_data(), None);
+    let ptr_type: BasicMetadataTypeEnum = llvm_context.ptr_type(AddressSpace::default()).into();
+
+    let target_triple: LLVMTargetTriple = LLVMTargetTriple::new(
+        context
+            .get_target_triple()
+            .as_str()
+            .to_string_lossy()
+            .to_string(),
+    );
+
+    let syscall: Option<(&str, &str, u64)> = match target_triple.get_os() {
+        "linux" if target_triple.is_x86_64_arch() => Some((
+            "syscall",
+            "={rax},{rax},{rdi},{rsi},{rdx},~{rcx},~{r11},~{memory}",
+            1,
+        )),
+        "linux" if target_triple.is_aarch64_arch() => {
+            Some(("svc #0", "={x0},{x8},{x0},{x1},{x2},~{memory}", 64))
+        }
+
+        _ => None,
+    };
+
+    if let Some((assembly, constraints, number)) = syscall {
+        let syscall_type: FunctionType = size_type.fn_type(
+            &[
+                size_type.into(),
+                size_type.into(),
+                ptr_type,
+                size_type.into(),
+            ],
+            false,
+        );
+
+        let syscall: PointerValue = llvm_context.create_inline_asm(
+            syscall_type,
+            assembly.to_string(),
+            constraints.to_string(),
+            true,
+            false,
+            None,
+            false,
+        );
+
+        llvm_builder.build_indirect_call(
+            syscall_type,
+            syscall,
+            &[
+                size_type.const_int(number, false).into(),
+                size_type.const_int(1, false).into(),
+                buffer.into(),
+                length.into(),
+            ],
+            "",
+        )?;
+
+        return Ok(());
+    }
+
+    // Without a known system call convention, the target has to provide 'write'.
+    let write_type: FunctionType = size_type.fn_type(
+        &[llvm_context.i32_type().into(), ptr_type, size_type.into()],
+        false,
+    );
+
+    let write: FunctionValue = llvm_module
+        .get_function("write")
+        .unwrap_or_else(|| llvm_module.add_function("write", write_type, None));
+
+    llvm_builder.build_indirect_call(
+        write_type,
+        write.as_global_value().as_pointer_value(),
+        &[
+            llvm_context.i32_type().const_int(1, false).into(),
+            buffer.into(),
+            length.into(),
+        ],
+        "",
+    )?;
+
+    Ok(())
+}
+
+fn compile_print_string<'ctx>(
+    context: &mut LLVMCodeGenContext<'_, 'ctx>,
+    arg: &'ctx Ast<'ctx>,
+    kind: &Type,
+    span: Span,
+) -> PointerValue<'ctx> {
+    let value: BasicValueEnum = if matches!(kind, Type::Ptr(..)) {
+        codegen::compile_as_value(context, arg, None)
+    } else {
+        codegen::compile_as_ptr_value(context, arg, None)
+    };
+
+    if !value.is_pointer_value() {
+        abort::abort_codegen(
+            context,
+            "Failed to compile a string argument of 'print' builtin!",
+            span,
+            std::path::PathBuf::from(file!()),
+            line!(),
+        );
+    }
+
+    value.into_pointer_value()
+}
+
+fn get_print_type(kind: &Type) -> &Type {
+    if let Type::Const(subtype, ..) = kind {
+        return self::get_print_type(subtype);
+    }
+
+    kind
+}
@@ -20,6 +20,7 @@
 #![allow(unnecessary_transmutes)]
 
 use inkwell::builder::Builder;
+use inkwell::builder::BuilderError;
 use inkwell::targets::TargetData;
 use inkwell::types::BasicTypeEnum;
 use inkwell::types::FloatType;
@@ -133,8 +134,6 @@ pub fn compile_float_cast<'ctx>(
     from_value: BasicValueEnum<'ctx>,
     span: Span,
 ) -> Option<BasicValueEnum<'ctx>> {
-    let llvm_builder: &Builder = context.get_llvm_builder();
-
     let from_type: Type = from_type.remove_all_constant_type();
     let target_type: Type = target_type.remove_all_constant_type();
 
@@ -151,8 +150,7 @@ pub fn compile_float_cast<'ctx>(
     let float_value: FloatValue<'_> = from_value.into_float_value();
     let float_type: FloatType<'_> = cast_type.into_float_type();
 
-    let casted_value: FloatValue<'_> = llvm_builder
-        .build_float_cast(float_value, float_type, "")
+    let casted_value: FloatValue<'_> = self::build_float_cast(context, float_value, float_type)
         .unwrap_or_else(|_| {
             abort::abort_codegen(
                 context,
@@ -166,6 +164,28 @@ pub fn compile_float_cast<'ctx>(
     Some(casted_value.into())
 }
 
+#[inline]
+fn build_float_cast<'ctx>(
+    context: &LLVMCodeGenContext<'_, 'ctx>,
+    value: FloatValue<'ctx>,
+    cast_type: FloatType<'ctx>,
+) -> Result<FloatValue<'ctx>, BuilderError> {
+    let llvm_builder: &Builder = context.get_llvm_builder();
+    let target_data: &TargetData = context.get_target_data();
+
+    let value_type: FloatType = value.get_type();
+
+    // 'f16' and 'bf16' have the same width, a plain 'fpcast' between them is a bitcast.
+    if value_type != cast_type && target_data.get_bit_size(&value_type) == 16 {
+        let f32_type: FloatType = context.get_llvm_context().f32_type();
+        let extended: FloatValue = llvm_builder.build_float_ext(value, f32_type, "")?;
+
+        return llvm_builder.build_float_cast(extended, cast_type, "");
+    }
+
+    llvm_builder.build_float_cast(value, cast_type, "")
+}
+
 /* ######################################################################
 
 
@@ -270,9 +290,8 @@ pub fn compile_type_cast<'ctx>(
             let float_value: FloatValue<'_> = value.into_float_value();
             let cast_type: FloatType<'_> = cast.into_float_type();
 
-            let casted_value: FloatValue<'_> = llvm_builder
-                .build_float_cast(float_value, cast_type, "")
-                .unwrap_or_else(|_| {
+            let casted_value: FloatValue<'_> =
+                self::build_float_cast(context, float_value, cast_type).unwrap_or_else(|_| {
                     abort::abort_codegen(
                         context,
                         &format!(
@@ -766,8 +785,6 @@ pub fn compile_float_together_cast<'ctx>(
     right: FloatValue<'ctx>,
     span: Span,
 ) -> (FloatValue<'ctx>, FloatValue<'ctx>) {
-    let llvm_builder: &Builder = context.get_llvm_builder();
-
     let left_type: FloatType = left.get_type();
     let right_type: FloatType = right.get_type();
 
@@ -776,33 +793,29 @@ pub fn compile_float_together_cast<'ctx>(
     }
 
     let new_left: FloatValue = if left_type != right_type {
-        llvm_builder
-            .build_float_cast(left, right_type, "")
-            .unwrap_or_else(|_| {
-                abort::abort_codegen(
-                    context,
-                    "Failed to cast floats together!",
-                    span,
-                    std::path::PathBuf::from(file!()),
-                    line!(),
-                )
-            })
+        self::build_float_cast(context, left, right_type).unwrap_or_else(|_| {
+            abort::abort_codegen(
+                context,
+                "Failed to cast floats together!",
+                span,
+                std::path::PathBuf::from(file!()),
+                line!(),
+            )
+        })
     } else {
         left
     };
 
     let new_right: FloatValue = if right_type != left_type {
-        llvm_builder
-            .build_float_cast(right, left_type, "")
-            .unwrap_or_else(|_| {
-                abort::abort_codegen(
-                    context,
-                    "Failed to cast floats together!",
-                    span,
-                    std::path::PathBuf::from(file!()),
-                    line!(),
-                )
-            })
+        self::build_float_cast(context, right, left_type).unwrap_or_else(|_| {
+            abort::abort_codegen(
+                context,
+                "Failed to cast floats together!",
+                span,
+                std::path::PathBuf::from(file!()),
+                line!(),
+            )
+        })
     } else {
         right
     };
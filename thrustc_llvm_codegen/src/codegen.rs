@@ -24,7 +24,7 @@ use inkwell::basic_block::BasicBlock;
 use inkwell::context::Context;
 use inkwell::module::{Linkage, Module};
 use inkwell::types::{ArrayType, BasicTypeEnum, StructType};
-use inkwell::values::{GlobalValue, PointerValue, StructValue};
+use inkwell::values::{GlobalValue, IntValue, PointerValue, StructValue};
 use inkwell::{builder::Builder, values::BasicValueEnum};
 use thrustc_ast::metadata::{ConstantMetadata, LocalMetadata, StaticMetadata};
 use thrustc_attributes::ThrustAttributes;
@@ -37,12 +37,13 @@ use thrustc_span::Span;
 use crate::anchor::PointerAnchor;
 use crate::builtins::LLVMBuiltin;
 use crate::context::LLVMCodeGenContext;
-use crate::declarations::{asmfunction, function, intrinsic};
-use crate::expressions::unaryop;
+use crate::declarations::{asmfunction, function, intrinsic, vtable};
+use crate::expressions::{errorunion, unaryop};
 use crate::memory::SymbolAllocated;
 use crate::metadata::LLVMMetadata;
 use crate::statements::{conditional, forloop, infloop, whileloop};
 use crate::traits::{AstLLVMGetType, LLVMFunctionExtensions};
+use crate::types::LLVMErrorExit;
 use crate::{
     abort, block, builtins, cast, codegen, expressions, memory, stack, r#static, typegeneration,
 };
@@ -51,18 +52,25 @@ use thrustc_ast::Ast;
 use thrustc_ast::traits::AstCodeLocation;
 use thrustc_typesystem::Type;
 use thrustc_typesystem::traits::{
-    DereferenceExtensions, TypeIsExtensions, TypePointerExtensions, TypeStructExtensions,
+    DereferenceExtensions, TypeErrorUnionExtensions, TypeIsExtensions, TypePointerExtensions,
+    TypeStructExtensions,
 };
 
 #[derive(Debug)]
 pub struct LLVMCodegen<'a, 'ctx> {
     context: &'a mut LLVMCodeGenContext<'a, 'ctx>,
     ast: &'ctx [Ast<'ctx>],
+    defers: Vec<&'ctx [Ast<'ctx>]>,
 }
 
 impl<'a, 'ctx> LLVMCodegen<'a, 'ctx> {
     pub fn codegen(context: &'a mut LLVMCodeGenContext<'a, 'ctx>, ast: &'ctx [Ast<'ctx>]) {
-        Self { context, ast }.compile();
+        Self {
+            context,
+            ast,
+            defers: Vec::with_capacity(u8::MAX as usize),
+        }
+        .compile();
     }
 }
 
@@ -106,6 +114,12 @@ impl<'a, 'ctx> LLVMCodegen<'a, 'ctx> {
                         self.context,
                         thrustc_entities::function_from_ast(node),
                     ),
+                    Ast::Implementation {
+                        interface,
+                        target,
+                        methods,
+                        ..
+                    } => vtable::compile(self.context, interface, target, methods),
                     Ast::Const { .. } => {
                         self.get_mut_context()
                             .get_mut_expressions_optimizations()
@@ -272,6 +286,7 @@ impl<'a, 'ctx> LLVMCodegen<'a, 'ctx> {
                 self.get_mut_context().add_dbg_block_data(*span);
 
                 self.context.begin_scope();
+                self.defers.push(post);
 
                 {
                     let nodes_size: usize = nodes.len();
@@ -283,12 +298,18 @@ impl<'a, 'ctx> LLVMCodegen<'a, 'ctx> {
                             for postnode in post.iter() {
                                 self.codegen_post_executation(postnode);
                             }
+
+                            if let Some(defers) = self.defers.last_mut() {
+                                *defers = &[];
+                            }
                         }
 
                         self.codegen_block(node);
+                        self.codegen_error_exits();
                     }
                 }
 
+                self.defers.pop();
                 self.context.end_scope();
 
                 block::move_terminator_to_end(self.get_mut_context(), *span);
@@ -302,6 +323,56 @@ impl<'a, 'ctx> LLVMCodegen<'a, 'ctx> {
         self.codegen_post_executation(node);
     }
 
+    fn codegen_error_exits(&mut self) {
+        let error_exits: Vec<LLVMErrorExit> = self.context.take_error_exits();
+
+        if error_exits.is_empty() {
+            return;
+        }
+
+        let llvm_builder: &Builder = self.context.get_llvm_builder();
+        let insert_block: Option<BasicBlock> = llvm_builder.get_insert_block();
+
+        for (error_block, code, span) in error_exits {
+            llvm_builder.position_at_end(error_block);
+
+            let defers: Vec<&'ctx [Ast<'ctx>]> = self.defers.clone();
+
+            for postnode in defers.iter().rev().flat_map(|post| post.iter()) {
+                self.codegen_post_executation(postnode);
+            }
+
+            let compiler_options: &CompilerOptions = self.get_context().get_compiler_options();
+            let llvm_backend: &LLVMBackend = compiler_options.get_llvm_backend();
+
+            if llvm_backend.needs_stack_protector() {
+                function::emit_stack_protector_epilogue(self.context, span);
+            }
+
+            let return_type: &Type = self
+                .get_mut_context()
+                .get_current_function(span)
+                .get_return_type();
+
+            let error_value: BasicValueEnum =
+                errorunion::compile_error_exit_value(self.context, return_type, code, span);
+
+            if llvm_builder.build_return(Some(&error_value)).is_err() {
+                abort::abort_codegen(
+                    self.context,
+                    "Failed to compile the error propagation!",
+                    span,
+                    std::path::PathBuf::from(file!()),
+                    line!(),
+                );
+            }
+        }
+
+        if let Some(insert_block) = insert_block {
+            llvm_builder.position_at_end(insert_block);
+        }
+    }
+
     fn codegen_post_executation(&mut self, node: &'ctx Ast) {
         match node {
             Ast::Defer { node, .. } => {
@@ -660,6 +731,50 @@ impl<'a, 'ctx> LLVMCodegen<'a, 'ctx> {
 
                 let llvm_builder: &Builder = self.context.get_llvm_builder();
 
+                let return_type: &Type = self
+                    .get_mut_context()
+                    .get_current_function(*span)
+                    .get_return_type();
+
+                if return_type.is_error_union_type() {
+                    let success_value: BasicValueEnum = match expression {
+                        Some(expr) if expr.get_type_for_llvm().is_error_union_type() => {
+                            self::compile_as_value(self.context, expr, Some(return_type))
+                        }
+                        Some(expr) => {
+                            let value_type: &Type = return_type.get_error_union_value_type();
+
+                            let value: BasicValueEnum =
+                                self::compile_as_value(self.context, expr, Some(value_type));
+
+                            errorunion::compile_success_value(
+                                self.context,
+                                return_type,
+                                Some(value),
+                                *span,
+                            )
+                        }
+                        None => errorunion::compile_success_value(
+                            self.context,
+                            return_type,
+                            None,
+                            *span,
+                        ),
+                    };
+
+                    if llvm_builder.build_return(Some(&success_value)).is_err() {
+                        abort::abort_codegen(
+                            self.context,
+                            "Failed to compile a function terminator!",
+                            *span,
+                            std::path::PathBuf::from(file!()),
+                            line!(),
+                        );
+                    }
+
+                    return;
+                }
+
                 if expression.is_none() {
                     if llvm_builder.build_return(None).is_err() {
                         abort::abort_codegen(
@@ -693,6 +808,49 @@ impl<'a, 'ctx> LLVMCodegen<'a, 'ctx> {
                 }
             }
 
+            Ast::Raise {
+                expression, span, ..
+            } => {
+                let code: IntValue = self::compile_as_value(
+                    self.context,
+                    expression,
+                    Some(&Type::U32 { span: *span }),
+                )
+                .into_int_value();
+
+                let compiler_options: &CompilerOptions = self.get_context().get_compiler_options();
+                let llvm_backend: &LLVMBackend = compiler_options.get_llvm_backend();
+
+                if llvm_backend.needs_stack_protector() {
+                    function::emit_stack_protector_epilogue(self.context, *span);
+                }
+
+                self.get_mut_context().mark_dbg_location(*span);
+
+                let return_type: &Type = self
+                    .get_mut_context()
+                    .get_current_function(*span)
+                    .get_return_type();
+
+                let error_value: BasicValueEnum =
+                    errorunion::compile_error_exit_value(self.context, return_type, code, *span);
+
+                if self
+                    .context
+                    .get_llvm_builder()
+                    .build_return(Some(&error_value))
+                    .is_err()
+                {
+                    abort::abort_codegen(
+                        self.context,
+                        "Failed to compile a function terminator!",
+                        *span,
+                        std::path::PathBuf::from(file!()),
+                        line!(),
+                    );
+                }
+            }
+
             node => self.expressions(node),
         }
     }
@@ -738,6 +896,12 @@ impl<'a, 'ctx> LLVMCodegen<'a, 'ctx> {
                         (left, operator, right, *span),
                         None,
                     );
+                } else if kind.is_vector_type() {
+                    expressions::binaryop::vector::compile(
+                        self.context,
+                        (left, operator, right, *span),
+                        None,
+                    );
                 } else {
                     abort::abort_codegen(
                         self.context,
@@ -762,6 +926,20 @@ impl<'a, 'ctx> LLVMCodegen<'a, 'ctx> {
                 let source_type: &Type = source.get_type_for_llvm();
                 let cast_type: Type = source_type.dereference_until_value();
 
+                if expressions::property::is_bitfield_property(source) {
+                    let value: BasicValueEnum =
+                        codegen::compile_as_value(self.context, value, Some(&cast_type));
+
+                    expressions::property::compile_bitfield_store(
+                        self.context,
+                        source,
+                        value,
+                        *span,
+                    );
+
+                    return;
+                }
+
                 let ptr: BasicValueEnum = self::compile_as_ptr_value(self.context, source, None);
                 let value: BasicValueEnum =
                     codegen::compile_as_value(self.context, value, Some(&cast_type));
@@ -781,6 +959,10 @@ impl<'a, 'ctx> LLVMCodegen<'a, 'ctx> {
                 self::compile_as_value(self.context, node, None);
             }
 
+            Ast::DynamicCall { .. } => {
+                self::compile_as_value(self.context, node, None);
+            }
+
             Ast::AsmValue { .. } => {
                 self::compile_as_value(self.context, node, None);
             }
@@ -797,6 +979,10 @@ impl<'a, 'ctx> LLVMCodegen<'a, 'ctx> {
                 let _ = self.context.get_llvm_builder().build_unreachable();
             }
 
+            Ast::Try { .. } | Ast::Catch { .. } => {
+                self::compile_as_value(self.context, node, None);
+            }
+
             _ => (),
         }
     }
@@ -903,6 +1089,35 @@ pub fn compile_as_value<'ctx>(
             cast_type,
         ),
 
+        // Interfaces
+        // Compiles a call through an interface object vtable
+        Ast::DynamicCall {
+            object,
+            index,
+            function_type,
+            args,
+            span,
+            ..
+        } => expressions::interface::compile_dynamic_call(
+            context,
+            object,
+            *index,
+            function_type,
+            args,
+            *span,
+            cast_type,
+        ),
+
+        // Interfaces
+        // Compiles a fat pointer made of an object and its vtable
+        Ast::InterfaceObject {
+            value,
+            interface,
+            target,
+            span,
+            ..
+        } => expressions::interface::compile_object(context, value, interface, target, *span),
+
         // Expressions
         // Compiles a grouped expression (e.g., parenthesized)
         Ast::Group { node, .. } => self::compile_as_value(context, node, cast_type),
@@ -930,6 +1145,11 @@ pub fn compile_as_value<'ctx>(
                 (left, operator, right, *span),
                 cast_type,
             ),
+            t if t.is_vector_type() => expressions::binaryop::vector::compile(
+                context,
+                (left, operator, right, *span),
+                cast_type,
+            ),
 
             _ => {
                 abort::abort_codegen(
@@ -973,6 +1193,13 @@ pub fn compile_as_value<'ctx>(
         } => {
             let value_type: &Type = value.get_type_for_llvm();
 
+            if value_type.is_ptr_like_type() && expressions::property::is_bitfield_property(value) {
+                let bitfield: BasicValueEnum =
+                    expressions::property::compile_bitfield_load(context, value, *span);
+
+                return cast::try_smart_cast(context, cast_type, kind, bitfield, *span);
+            }
+
             if value_type.is_ptr_like_type() {
                 let value: BasicValueEnum = self::compile_as_ptr_value(context, value, Some(kind));
 
@@ -1053,6 +1280,26 @@ pub fn compile_as_value<'ctx>(
             builtins::compile(context, llvm_builtin, cast_type)
         }
 
+        // Error Unions
+        // Propagates the error to the caller or unwraps the value
+        Ast::Try {
+            expression,
+            kind,
+            span,
+            ..
+        } => expressions::errorunion::compile_try(context, expression, kind, *span, cast_type),
+
+        // Unwraps the value or evaluates the fallback on error
+        Ast::Catch {
+            expression,
+            fallback,
+            kind,
+            span,
+            ..
+        } => expressions::errorunion::compile_catch(
+            context, expression, fallback, kind, *span, cast_type,
+        ),
+
         // Fallback, Unknown expressions or statements
         what => {
             abort::abort_codegen(
@@ -48,6 +48,7 @@ use crate::table::LLVMSymbolsTable;
 use crate::types::LLVMCtors;
 use crate::types::LLVMDBGFunction;
 use crate::types::LLVMDtors;
+use crate::types::LLVMErrorExit;
 use crate::types::LLVMFunction;
 use crate::types::LLVMStackProtectorPointer;
 
@@ -71,6 +72,7 @@ pub struct LLVMCodeGenContext<'a, 'ctx> {
 
     current_function: Option<LLVMFunction<'ctx>>,
     function_stack_protector_ptr: Option<LLVMStackProtectorPointer<'ctx>>,
+    error_exits: Vec<LLVMErrorExit<'ctx>>,
 
     expression_optimizations: LLVMExpressionOptimization,
 
@@ -125,6 +127,7 @@ impl<'a, 'ctx> LLVMCodeGenContext<'a, 'ctx> {
 
             current_function: None,
             function_stack_protector_ptr: None,
+            error_exits: Vec::with_capacity(u8::MAX as usize),
 
             expression_optimizations: LLVMExpressionOptimization::new(),
 
@@ -265,6 +268,16 @@ impl<'ctx> LLVMCodeGenContext<'_, 'ctx> {
         self.function_stack_protector_ptr = None;
     }
 
+    #[inline]
+    pub fn add_error_exit(&mut self, exit: LLVMErrorExit<'ctx>) {
+        self.error_exits.push(exit);
+    }
+
+    #[inline]
+    pub fn take_error_exits(&mut self) -> Vec<LLVMErrorExit<'ctx>> {
+        std::mem::take(&mut self.error_exits)
+    }
+
     #[inline]
     pub fn add_ctor(&mut self, ctor: PointerValue<'ctx>) {
         let last: Option<&(PointerValue, u32)> = self.ctors.iter().last();
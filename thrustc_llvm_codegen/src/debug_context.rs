@@ -17,6 +17,8 @@
 
 */
 
+use std::path::PathBuf;
+
 use inkwell::builder::Builder;
 use inkwell::context::Context;
 use inkwell::debug_info::AsDIScope;
@@ -59,6 +61,8 @@ pub struct LLVMDebugContext<'a, 'ctx> {
     subprograms: Vec<DISubprogram<'ctx>>,
     lexical_blocks: Vec<DILexicalBlock<'ctx>>,
     debug_locations: Vec<DILocation<'ctx>>,
+    file_path: PathBuf,
+    deterministic: bool,
 }
 
 impl<'a, 'ctx> LLVMDebugContext<'a, 'ctx> {
@@ -106,6 +110,8 @@ impl<'a, 'ctx> LLVMDebugContext<'a, 'ctx> {
             subprograms: Vec::with_capacity(u8::MAX as usize),
             lexical_blocks: Vec::with_capacity(u8::MAX as usize),
             debug_locations: Vec::with_capacity(u8::MAX as usize),
+            file_path: unit.get_path().to_path_buf(),
+            deterministic: options.is_deterministic(),
         }
     }
 }
@@ -289,6 +295,16 @@ impl<'a, 'ctx> LLVMDebugContext<'a, 'ctx> {
     pub fn get_target_data(&self) -> TargetData {
         self.target_machine.get_target_data()
     }
+
+    #[inline]
+    pub fn get_file_path(&self) -> &PathBuf {
+        &self.file_path
+    }
+
+    #[inline]
+    pub fn is_deterministic(&self) -> bool {
+        self.deterministic
+    }
 }
 
 impl<'a, 'ctx> LLVMDebugContext<'a, 'ctx> {
@@ -22,6 +22,7 @@ use inkwell::IntPredicate;
 use inkwell::context::Context;
 use inkwell::targets::TargetData;
 use inkwell::values::BasicValue;
+use inkwell::values::BasicValueEnum;
 use inkwell::values::IntValue;
 use inkwell::values::PointerValue;
 use thrustc_ast::Ast;
@@ -34,6 +35,7 @@ use thrustc_llvm_attributes::traits::LLVMAttributesExtensions;
 use thrustc_llvm_callconventions::LLVMCallConvention;
 use thrustc_span::Span;
 use thrustc_typesystem::Type;
+use thrustc_typesystem::traits::TypeErrorUnionExtensions;
 use thrustc_typesystem::traits::TypeIsExtensions;
 
 use crate::abort;
@@ -42,6 +44,7 @@ use crate::attributebuilder::LLVMAttributeApplicant;
 use crate::block;
 use crate::codegen::LLVMCodegen;
 use crate::context::LLVMCodeGenContext;
+use crate::expressions::errorunion;
 use crate::traits::LLVMFunctionExtensions;
 use crate::typegeneration;
 use crate::types::LLVMDBGFunction;
@@ -198,6 +201,20 @@ pub fn compile_down<'ctx>(codegen: &mut LLVMCodegen<'_, 'ctx>, function: Functio
             if function_type.is_void_type() && !function_body.has_terminator() {
                 let _ = llvm_builder.build_return(None);
             }
+
+            if function_type.is_error_union_type()
+                && function_type.get_error_union_value_type().is_void_type()
+                && !function_body.has_terminator()
+            {
+                let success: BasicValueEnum = errorunion::compile_success_value(
+                    codegen.get_mut_context(),
+                    function_type,
+                    None,
+                    span,
+                );
+
+                let _ = llvm_builder.build_return(Some(&success));
+            }
         }
     }
 
@@ -20,3 +20,4 @@
 pub mod asmfunction;
 pub mod function;
 pub mod intrinsic;
+pub mod vtable;
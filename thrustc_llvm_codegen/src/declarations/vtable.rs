@@ -0,0 +1,62 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+
+use crate::context::LLVMCodeGenContext;
+use crate::types::LLVMFunction;
+
+use inkwell::AddressSpace;
+use inkwell::module::{Linkage, Module};
+use inkwell::types::{ArrayType, PointerType};
+use inkwell::values::{GlobalValue, PointerValue};
+
+pub fn compile<'ctx>(
+    context: &mut LLVMCodeGenContext<'_, 'ctx>,
+    interface: &str,
+    target: &str,
+    methods: &[&str],
+) {
+    let llvm_module: &Module = context.get_llvm_module();
+    let ptr_type: PointerType = context.get_llvm_context().ptr_type(AddressSpace::default());
+
+    let entries: Vec<PointerValue> = methods
+        .iter()
+        .map(|method| {
+            let function: LLVMFunction = context.get_table().get_function(method);
+            function.0.as_global_value().as_pointer_value()
+        })
+        .collect();
+
+    let vtable_type: ArrayType = ptr_type.array_type(entries.len() as u32);
+
+    let vtable: GlobalValue = llvm_module.add_global(
+        vtable_type,
+        Some(AddressSpace::default()),
+        &self::generate_name(target, interface),
+    );
+
+    vtable.set_linkage(Linkage::Private);
+    vtable.set_constant(true);
+    vtable.set_unnamed_addr(true);
+    vtable.set_initializer(&ptr_type.const_array(&entries));
+}
+
+#[inline]
+pub fn generate_name(target: &str, interface: &str) -> String {
+    format!("__thrust_vtable_{}_{}", target, interface)
+}
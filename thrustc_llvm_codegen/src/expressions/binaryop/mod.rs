@@ -21,3 +21,4 @@
 pub mod boolean;
 pub mod float;
 pub mod integer;
+pub mod vector;
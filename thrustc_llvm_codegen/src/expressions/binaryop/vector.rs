@@ -0,0 +1,181 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+
+use crate::abort;
+use crate::cast;
+use crate::codegen;
+use crate::context::LLVMCodeGenContext;
+use crate::memory;
+use crate::predicates;
+use crate::traits::AstLLVMGetType;
+
+use either::Either;
+
+use thrustc_ast::Ast;
+use thrustc_ast::traits::AstMemoryExtensions;
+use thrustc_entities::BinaryOperation;
+use thrustc_span::Span;
+use thrustc_token_type::TokenType;
+use thrustc_token_type::traits::TokenTypeExtensions;
+use thrustc_typesystem::Type;
+use thrustc_typesystem::traits::TypeIsExtensions;
+use thrustc_typesystem::traits::TypeVectorExtensions;
+
+use inkwell::FloatPredicate;
+use inkwell::IntPredicate;
+use inkwell::builder::Builder;
+use inkwell::builder::BuilderError;
+use inkwell::values::BasicValueEnum;
+use inkwell::values::PointerValue;
+use inkwell::values::VectorValue;
+
+pub fn compile<'ctx>(
+    context: &mut LLVMCodeGenContext<'_, 'ctx>,
+    binary: BinaryOperation<'ctx>,
+    cast_type: Option<&Type>,
+) -> BasicValueEnum<'ctx> {
+    let (lhs, operator, rhs, span) = binary;
+
+    let vector_type: &Type = lhs.get_type_for_llvm();
+    let element_type: Type = vector_type.get_vector_base_type();
+
+    let lhs_value: VectorValue = codegen::compile_as_value(context, lhs, None).into_vector_value();
+    let rhs_value: VectorValue = codegen::compile_as_value(context, rhs, None).into_vector_value();
+
+    let value: BasicValueEnum = self::compile_vector_value_operation(
+        context,
+        lhs_value,
+        rhs_value,
+        &element_type,
+        operator,
+        span,
+    );
+
+    if let TokenType::PlusEq | TokenType::MinusEq = operator {
+        self::store_compound_assignment(context, lhs, value, span);
+    }
+
+    cast::try_smart_cast(context, cast_type, vector_type, value, span)
+}
+
+fn store_compound_assignment<'ctx>(
+    context: &mut LLVMCodeGenContext<'_, 'ctx>,
+    lhs: &'ctx Ast,
+    value: BasicValueEnum<'ctx>,
+    span: Span,
+) {
+    if !lhs.is_memory_assigned_reference() {
+        return;
+    }
+
+    let reference: BasicValueEnum = codegen::compile_as_ptr_value(context, lhs, None);
+
+    if !reference.is_pointer_value() {
+        abort::abort_codegen(
+            context,
+            "Failed to compile vector compound assignment!",
+            span,
+            std::path::PathBuf::from(file!()),
+            line!(),
+        );
+    }
+
+    let ptr: PointerValue = reference.into_pointer_value();
+
+    memory::store_anon(context, ptr, value, span);
+}
+
+pub fn compile_vector_value_operation<'ctx>(
+    context: &mut LLVMCodeGenContext<'_, 'ctx>,
+    lhs: VectorValue<'ctx>,
+    rhs: VectorValue<'ctx>,
+    element_type: &Type,
+    operator: &TokenType,
+    span: Span,
+) -> BasicValueEnum<'ctx> {
+    let is_float: bool = element_type.is_float_type();
+    let is_signed: bool = element_type.is_signed_integer_type();
+
+    let predicate: Option<Either<FloatPredicate, IntPredicate>> = if !operator.is_logical_operator()
+    {
+        None
+    } else if is_float {
+        Some(Either::Left(predicates::get_float_predicate(
+            context, operator, span,
+        )))
+    } else {
+        Some(Either::Right(predicates::get_integer_predicate(
+            context, operator, is_signed, is_signed, span,
+        )))
+    };
+
+    let llvm_builder: &Builder = context.get_llvm_builder();
+
+    let value: Result<VectorValue, BuilderError> = match (operator, predicate) {
+        (_, Some(Either::Left(predicate))) => {
+            llvm_builder.build_float_compare(predicate, lhs, rhs, "")
+        }
+        (_, Some(Either::Right(predicate))) => {
+            llvm_builder.build_int_compare(predicate, lhs, rhs, "")
+        }
+
+        (TokenType::Plus | TokenType::PlusEq, _) if is_float => {
+            llvm_builder.build_float_add(lhs, rhs, "")
+        }
+        (TokenType::Minus | TokenType::MinusEq, _) if is_float => {
+            llvm_builder.build_float_sub(lhs, rhs, "")
+        }
+        (TokenType::Star, _) if is_float => llvm_builder.build_float_mul(lhs, rhs, ""),
+        (TokenType::Slash, _) if is_float => llvm_builder.build_float_div(lhs, rhs, ""),
+        (TokenType::Arith, _) if is_float => llvm_builder.build_float_rem(lhs, rhs, ""),
+
+        (TokenType::Plus | TokenType::PlusEq, _) => llvm_builder.build_int_add(lhs, rhs, ""),
+        (TokenType::Minus | TokenType::MinusEq, _) => llvm_builder.build_int_sub(lhs, rhs, ""),
+        (TokenType::Star, _) => llvm_builder.build_int_mul(lhs, rhs, ""),
+        (TokenType::Slash, _) if is_signed => llvm_builder.build_int_signed_div(lhs, rhs, ""),
+        (TokenType::Slash, _) => llvm_builder.build_int_unsigned_div(lhs, rhs, ""),
+        (TokenType::Arith, _) if is_signed => llvm_builder.build_int_signed_rem(lhs, rhs, ""),
+        (TokenType::Arith, _) => llvm_builder.build_int_unsigned_rem(lhs, rhs, ""),
+        (TokenType::LShift, _) => llvm_builder.build_left_shift(lhs, rhs, ""),
+        (TokenType::RShift, _) => llvm_builder.build_right_shift(lhs, rhs, is_signed, ""),
+        (TokenType::Xor, _) => llvm_builder.build_xor(lhs, rhs, ""),
+        (TokenType::Bor, _) => llvm_builder.build_or(lhs, rhs, ""),
+        (TokenType::BAnd, _) => llvm_builder.build_and(lhs, rhs, ""),
+
+        _ => abort::abort_codegen(
+            context,
+            "Failed to compile without a valid operator!",
+            span,
+            std::path::PathBuf::from(file!()),
+            line!(),
+        ),
+    };
+
+    value
+        .unwrap_or_else(|_| {
+            abort::abort_codegen(
+                context,
+                &format!("Failed to compile '{}' vector operation!", operator),
+                span,
+                std::path::PathBuf::from(file!()),
+                line!(),
+            )
+        })
+        .into()
+}
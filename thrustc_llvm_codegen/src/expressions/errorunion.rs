@@ -0,0 +1,294 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+
+use inkwell::IntPredicate;
+use inkwell::basic_block::BasicBlock;
+use inkwell::builder::Builder;
+use inkwell::types::BasicTypeEnum;
+use inkwell::values::{BasicValueEnum, FunctionValue, IntValue, PhiValue, StructValue};
+use thrustc_ast::Ast;
+use thrustc_span::Span;
+use thrustc_typesystem::Type;
+use thrustc_typesystem::traits::TypeIsExtensions;
+
+use crate::context::LLVMCodeGenContext;
+use crate::traits::LLVMFunctionExtensions;
+use crate::{abort, block, cast, codegen, typegeneration};
+
+pub fn compile_try<'ctx>(
+    context: &mut LLVMCodeGenContext<'_, 'ctx>,
+    expression: &'ctx Ast,
+    kind: &Type,
+    span: Span,
+    cast_type: Option<&Type>,
+) -> BasicValueEnum<'ctx> {
+    let llvm_builder: &Builder = context.get_llvm_builder();
+    let llvm_function: FunctionValue = context.get_current_function(span).get_value();
+
+    let union_value: StructValue = self::compile_error_union(context, expression, span);
+    let code: IntValue = self::extract_error_code(context, union_value, span);
+
+    let error_block: BasicBlock = block::append_block(context, llvm_function);
+    let ok_block: BasicBlock = block::append_block(context, llvm_function);
+
+    let is_error: IntValue = self::compare_error_code(context, code, span);
+
+    llvm_builder
+        .build_conditional_branch(is_error, error_block, ok_block)
+        .unwrap_or_else(|_| {
+            abort::abort_codegen(
+                context,
+                "Failed to compile the error propagation!",
+                span,
+                std::path::PathBuf::from(file!()),
+                line!(),
+            )
+        });
+
+    // The early return is emitted once the enclosing statement finishes, where
+    // the pending defers are known.
+    context.add_error_exit((error_block, code, span));
+
+    llvm_builder.position_at_end(ok_block);
+
+    if kind.is_void_type() {
+        return code.into();
+    }
+
+    let value: BasicValueEnum = self::extract_error_union_value(context, union_value, span);
+
+    cast::try_smart_cast(context, cast_type, kind, value, span)
+}
+
+pub fn compile_catch<'ctx>(
+    context: &mut LLVMCodeGenContext<'_, 'ctx>,
+    expression: &'ctx Ast,
+    fallback: &'ctx Ast,
+    kind: &Type,
+    span: Span,
+    cast_type: Option<&Type>,
+) -> BasicValueEnum<'ctx> {
+    let llvm_builder: &Builder = context.get_llvm_builder();
+    let llvm_function: FunctionValue = context.get_current_function(span).get_value();
+
+    let union_value: StructValue = self::compile_error_union(context, expression, span);
+    let code: IntValue = self::extract_error_code(context, union_value, span);
+
+    let value: Option<BasicValueEnum> = if kind.is_void_type() {
+        None
+    } else {
+        Some(self::extract_error_union_value(context, union_value, span))
+    };
+
+    let ok_block: BasicBlock = context.get_last_builder_block(span);
+    let fallback_block: BasicBlock = block::append_block(context, llvm_function);
+    let merge_block: BasicBlock = block::append_block(context, llvm_function);
+
+    let is_error: IntValue = self::compare_error_code(context, code, span);
+
+    llvm_builder
+        .build_conditional_branch(is_error, fallback_block, merge_block)
+        .unwrap_or_else(|_| {
+            abort::abort_codegen(
+                context,
+                "Failed to compile the error handling!",
+                span,
+                std::path::PathBuf::from(file!()),
+                line!(),
+            )
+        });
+
+    llvm_builder.position_at_end(fallback_block);
+
+    let fallback_value: BasicValueEnum = if kind.is_void_type() {
+        codegen::compile_as_value(context, fallback, None)
+    } else {
+        codegen::compile_as_value(context, fallback, Some(kind))
+    };
+
+    let fallback_end_block: BasicBlock = context.get_last_builder_block(span);
+
+    llvm_builder
+        .build_unconditional_branch(merge_block)
+        .unwrap_or_else(|_| {
+            abort::abort_codegen(
+                context,
+                "Failed to compile the error handling!",
+                span,
+                std::path::PathBuf::from(file!()),
+                line!(),
+            )
+        });
+
+    llvm_builder.position_at_end(merge_block);
+
+    let Some(value) = value else {
+        return code.into();
+    };
+
+    let llvm_type: BasicTypeEnum = typegeneration::generate_type(context, kind);
+
+    let phi: PhiValue = llvm_builder.build_phi(llvm_type, "").unwrap_or_else(|_| {
+        abort::abort_codegen(
+            context,
+            "Failed to compile the error handling!",
+            span,
+            std::path::PathBuf::from(file!()),
+            line!(),
+        )
+    });
+
+    phi.add_incoming(&[(&value, ok_block), (&fallback_value, fallback_end_block)]);
+
+    cast::try_smart_cast(context, cast_type, kind, phi.as_basic_value(), span)
+}
+
+pub fn compile_error_exit_value<'ctx>(
+    context: &mut LLVMCodeGenContext<'_, 'ctx>,
+    return_type: &Type,
+    code: IntValue<'ctx>,
+    span: Span,
+) -> BasicValueEnum<'ctx> {
+    let llvm_type: BasicTypeEnum = typegeneration::generate_type(context, return_type);
+
+    context
+        .get_llvm_builder()
+        .build_insert_value(llvm_type.into_struct_type().get_undef(), code, 0, "")
+        .unwrap_or_else(|_| {
+            abort::abort_codegen(
+                context,
+                "Failed to compile the error union value!",
+                span,
+                std::path::PathBuf::from(file!()),
+                line!(),
+            )
+        })
+        .into_struct_value()
+        .into()
+}
+
+pub fn compile_success_value<'ctx>(
+    context: &mut LLVMCodeGenContext<'_, 'ctx>,
+    return_type: &Type,
+    value: Option<BasicValueEnum<'ctx>>,
+    span: Span,
+) -> BasicValueEnum<'ctx> {
+    let llvm_type: BasicTypeEnum = typegeneration::generate_type(context, return_type);
+
+    let success: BasicValueEnum = llvm_type.into_struct_type().const_zero().into();
+
+    let Some(value) = value else {
+        return success;
+    };
+
+    context
+        .get_llvm_builder()
+        .build_insert_value(success.into_struct_value(), value, 1, "")
+        .unwrap_or_else(|_| {
+            abort::abort_codegen(
+                context,
+                "Failed to compile the error union value!",
+                span,
+                std::path::PathBuf::from(file!()),
+                line!(),
+            )
+        })
+        .into_struct_value()
+        .into()
+}
+
+fn compile_error_union<'ctx>(
+    context: &mut LLVMCodeGenContext<'_, 'ctx>,
+    expression: &'ctx Ast,
+    span: Span,
+) -> StructValue<'ctx> {
+    let value: BasicValueEnum = codegen::compile_as_value(context, expression, None);
+
+    if !value.is_struct_value() {
+        abort::abort_codegen(
+            context,
+            "Failed to compile the error union value!",
+            span,
+            std::path::PathBuf::from(file!()),
+            line!(),
+        );
+    }
+
+    value.into_struct_value()
+}
+
+fn extract_error_code<'ctx>(
+    context: &mut LLVMCodeGenContext<'_, 'ctx>,
+    union_value: StructValue<'ctx>,
+    span: Span,
+) -> IntValue<'ctx> {
+    context
+        .get_llvm_builder()
+        .build_extract_value(union_value, 0, "")
+        .unwrap_or_else(|_| {
+            abort::abort_codegen(
+                context,
+                "Failed to extract the error code!",
+                span,
+                std::path::PathBuf::from(file!()),
+                line!(),
+            )
+        })
+        .into_int_value()
+}
+
+fn extract_error_union_value<'ctx>(
+    context: &mut LLVMCodeGenContext<'_, 'ctx>,
+    union_value: StructValue<'ctx>,
+    span: Span,
+) -> BasicValueEnum<'ctx> {
+    context
+        .get_llvm_builder()
+        .build_extract_value(union_value, 1, "")
+        .unwrap_or_else(|_| {
+            abort::abort_codegen(
+                context,
+                "Failed to extract the error union value!",
+                span,
+                std::path::PathBuf::from(file!()),
+                line!(),
+            )
+        })
+}
+
+fn compare_error_code<'ctx>(
+    context: &mut LLVMCodeGenContext<'_, 'ctx>,
+    code: IntValue<'ctx>,
+    span: Span,
+) -> IntValue<'ctx> {
+    let success: IntValue = code.get_type().const_zero();
+
+    context
+        .get_llvm_builder()
+        .build_int_compare(IntPredicate::NE, code, success, "")
+        .unwrap_or_else(|_| {
+            abort::abort_codegen(
+                context,
+                "Failed to compare the error code!",
+                span,
+                std::path::PathBuf::from(file!()),
+                line!(),
+            )
+        })
+}
@@ -22,7 +22,7 @@ use inkwell::{context::Context, values::FloatValue};
 use thrustc_span::Span;
 use thrustc_typesystem::Type;
 
-use crate::{abort, context::LLVMCodeGenContext};
+use crate::{abort, context::LLVMCodeGenContext, typegeneration};
 
 pub fn compile<'ctx>(
     context: &mut LLVMCodeGenContext<'_, 'ctx>,
@@ -34,6 +34,12 @@ pub fn compile<'ctx>(
     let llvm_context: &Context = context.get_llvm_context();
 
     match kind {
+        Type::F16 { .. } if signed => llvm_context.f16_type().const_float(-value),
+        Type::F16 { .. } => llvm_context.f16_type().const_float(value),
+        Type::BF16 { .. } if signed => {
+            typegeneration::generate_bf16_type(llvm_context).const_float(-value)
+        }
+        Type::BF16 { .. } => typegeneration::generate_bf16_type(llvm_context).const_float(value),
         Type::F32 { .. } if signed => llvm_context.f32_type().const_float(-value),
         Type::F32 { .. } => llvm_context.f32_type().const_float(value),
         Type::F64 { .. } if signed => llvm_context.f64_type().const_float(-value),
@@ -17,7 +17,7 @@
 
 */
 
-use inkwell::values::{BasicValueEnum, IntValue, PointerValue};
+use inkwell::values::{BasicValueEnum, IntValue, PointerValue, VectorValue};
 use thrustc_ast::{Ast, traits::AstCodeLocation};
 use thrustc_span::Span;
 use thrustc_typesystem::{
@@ -25,7 +25,9 @@ use thrustc_typesystem::{
     traits::{InfererTypeExtensions, TypePointerExtensions},
 };
 
-use crate::{codegen, context::LLVMCodeGenContext, expressions, memory, traits::AstLLVMGetType};
+use crate::{
+    abort, codegen, context::LLVMCodeGenContext, expressions, memory, traits::AstLLVMGetType,
+};
 
 pub fn compile<'ctx>(
     context: &mut LLVMCodeGenContext<'_, 'ctx>,
@@ -33,6 +35,11 @@ pub fn compile<'ctx>(
     index: &'ctx Ast<'ctx>,
 ) -> BasicValueEnum<'ctx> {
     let source_value: BasicValueEnum<'_> = codegen::compile_as_ptr_value(context, source, None);
+
+    if source_value.is_vector_value() {
+        return self::compile_vector_lane(context, source_value.into_vector_value(), index);
+    }
+
     let ptr_value: PointerValue<'_> = source_value.into_pointer_value();
 
     let mut ptr_type: &Type = source.get_type_for_llvm();
@@ -96,3 +103,28 @@ pub fn compile<'ctx>(
     )
     .into()
 }
+
+fn compile_vector_lane<'ctx>(
+    context: &mut LLVMCodeGenContext<'_, 'ctx>,
+    vector: VectorValue<'ctx>,
+    index: &'ctx Ast<'ctx>,
+) -> BasicValueEnum<'ctx> {
+    let span: Span = index.get_span();
+    let lane_type: Type = Type::U32 { span };
+
+    let lane: IntValue =
+        codegen::compile_as_value(context, index, Some(&lane_type)).into_int_value();
+
+    context
+        .get_llvm_builder()
+        .build_extract_element(vector, lane, "")
+        .unwrap_or_else(|_| {
+            abort::abort_codegen(
+                context,
+                "Failed to extract a vector lane!",
+                span,
+                std::path::PathBuf::from(file!()),
+                line!(),
+            )
+        })
+}
@@ -26,32 +26,37 @@ use crate::{abort, context::LLVMCodeGenContext};
 pub fn compile<'ctx>(
     context: &mut LLVMCodeGenContext<'_, 'ctx>,
     kind: &Type,
-    value: u64,
+    value: u128,
     signed: bool,
     span: Span,
 ) -> IntValue<'ctx> {
     let llvm_context: &Context = context.get_llvm_context();
 
+    let low: u64 = value as u64;
+    let words: [u64; 2] = [low, (value >> 64) as u64];
+
     match kind {
-        Type::Char(..) => llvm_context.i8_type().const_int(value, signed).const_neg(),
-        Type::S8 { .. } if signed => llvm_context.i8_type().const_int(value, signed).const_neg(),
-        Type::S8 { .. } => llvm_context.i8_type().const_int(value, signed),
-        Type::S16 { .. } if signed => llvm_context.i16_type().const_int(value, signed).const_neg(),
-        Type::S16 { .. } => llvm_context.i16_type().const_int(value, signed),
-        Type::S32 { .. } if signed => llvm_context.i32_type().const_int(value, signed).const_neg(),
-        Type::S32 { .. } => llvm_context.i32_type().const_int(value, signed),
-        Type::S64 { .. } if signed => llvm_context.i64_type().const_int(value, signed).const_neg(),
-        Type::S64 { .. } => llvm_context.i64_type().const_int(value, signed),
-        Type::U8 { .. } => llvm_context.i8_type().const_int(value, false),
-        Type::U16 { .. } => llvm_context.i16_type().const_int(value, false),
-        Type::U32 { .. } => llvm_context.i32_type().const_int(value, false),
-        Type::U64 { .. } => llvm_context.i64_type().const_int(value, false),
-        Type::U128 { .. } if signed => llvm_context
+        Type::Char(..) => llvm_context.i8_type().const_int(low, signed).const_neg(),
+        Type::S8 { .. } if signed => llvm_context.i8_type().const_int(low, signed).const_neg(),
+        Type::S8 { .. } => llvm_context.i8_type().const_int(low, signed),
+        Type::S16 { .. } if signed => llvm_context.i16_type().const_int(low, signed).const_neg(),
+        Type::S16 { .. } => llvm_context.i16_type().const_int(low, signed),
+        Type::S32 { .. } if signed => llvm_context.i32_type().const_int(low, signed).const_neg(),
+        Type::S32 { .. } => llvm_context.i32_type().const_int(low, signed),
+        Type::S64 { .. } if signed => llvm_context.i64_type().const_int(low, signed).const_neg(),
+        Type::S64 { .. } => llvm_context.i64_type().const_int(low, signed),
+        Type::U8 { .. } => llvm_context.i8_type().const_int(low, false),
+        Type::U16 { .. } => llvm_context.i16_type().const_int(low, false),
+        Type::U32 { .. } => llvm_context.i32_type().const_int(low, false),
+        Type::U64 { .. } => llvm_context.i64_type().const_int(low, false),
+        Type::S128 { .. } | Type::U128 { .. } if signed => llvm_context
             .i128_type()
-            .const_int(value, signed)
+            .const_int_arbitrary_precision(&words)
             .const_neg(),
-        Type::U128 { .. } => llvm_context.i128_type().const_int(value, signed),
-        Type::Bool(..) => llvm_context.bool_type().const_int(value, false),
+        Type::S128 { .. } | Type::U128 { .. } => llvm_context
+            .i128_type()
+            .const_int_arbitrary_precision(&words),
+        Type::Bool(..) => llvm_context.bool_type().const_int(low, false),
 
         what => abort::abort_codegen(
             context,
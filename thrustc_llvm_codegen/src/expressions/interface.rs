@@ -0,0 +1,232 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+
+use inkwell::AddressSpace;
+use inkwell::builder::Builder;
+use inkwell::types::{BasicTypeEnum, FunctionType, PointerType};
+use inkwell::values::{
+    BasicMetadataValueEnum, BasicValueEnum, IntValue, PointerValue, StructValue,
+};
+
+use thrustc_ast::Ast;
+use thrustc_span::Span;
+use thrustc_typesystem::Type;
+use thrustc_typesystem::traits::TypeIsExtensions;
+
+use crate::context::LLVMCodeGenContext;
+use crate::declarations::vtable;
+use crate::{abort, cast, codegen, typegeneration};
+
+pub fn compile_object<'ctx>(
+    context: &mut LLVMCodeGenContext<'_, 'ctx>,
+    value: &'ctx Ast,
+    interface: &str,
+    target: &str,
+    span: Span,
+) -> BasicValueEnum<'ctx> {
+    let llvm_builder: &Builder = context.get_llvm_builder();
+
+    let data: BasicValueEnum = codegen::compile_as_value(context, value, None);
+
+    let Some(vtable) = context
+        .get_llvm_module()
+        .get_global(&vtable::generate_name(target, interface))
+    else {
+        abort::abort_codegen(
+            context,
+            &format!(
+                "Failed to find the vtable of '{}' for '{}'!",
+                target, interface
+            ),
+            span,
+            std::path::PathBuf::from(file!()),
+            line!(),
+        )
+    };
+
+    let object_type: BasicTypeEnum =
+        typegeneration::generate_type(context, &Type::Interface(interface.to_string(), span));
+
+    let object: StructValue = object_type.into_struct_type().get_undef();
+
+    let object: StructValue = llvm_builder
+        .build_insert_value(object, data, 0, "")
+        .unwrap_or_else(|_| {
+            abort::abort_codegen(
+                context,
+                "Failed to compile the interface object!",
+                span,
+                std::path::PathBuf::from(file!()),
+                line!(),
+            )
+        })
+        .into_struct_value();
+
+    let object: StructValue = llvm_builder
+        .build_insert_value(object, vtable.as_pointer_value(), 1, "")
+        .unwrap_or_else(|_| {
+            abort::abort_codegen(
+                context,
+                "Failed to compile the interface object!",
+                span,
+                std::path::PathBuf::from(file!()),
+                line!(),
+            )
+        })
+        .into_struct_value();
+
+    object.into()
+}
+
+pub fn compile_dynamic_call<'ctx>(
+    context: &mut LLVMCodeGenContext<'_, 'ctx>,
+    object: &'ctx Ast,
+    index: u32,
+    function_type: &Type,
+    args: &'ctx [Ast],
+    span: Span,
+    cast_type: Option<&Type>,
+) -> BasicValueEnum<'ctx> {
+    let llvm_builder: &Builder = context.get_llvm_builder();
+    let ptr_type: PointerType = context.get_llvm_context().ptr_type(AddressSpace::default());
+
+    let Type::Fn(parameter_types, kind, ..) = function_type else {
+        abort::abort_codegen(
+            context,
+            "Failed to compile the dynamic call!",
+            span,
+            std::path::PathBuf::from(file!()),
+            line!(),
+        )
+    };
+
+    let object: StructValue = self::compile_interface_object(context, object, span);
+
+    let (data, vtable): (BasicValueEnum, BasicValueEnum) = match (
+        llvm_builder.build_extract_value(object, 0, ""),
+        llvm_builder.build_extract_value(object, 1, ""),
+    ) {
+        (Ok(data), Ok(vtable)) => (data, vtable),
+        _ => abort::abort_codegen(
+            context,
+            "Failed to compile the dynamic call!",
+            span,
+            std::path::PathBuf::from(file!()),
+            line!(),
+        ),
+    };
+
+    let index: IntValue = context
+        .get_llvm_context()
+        .i32_type()
+        .const_int(index as u64, false);
+
+    let function_ptr: PointerValue = unsafe {
+        llvm_builder.build_in_bounds_gep(ptr_type, vtable.into_pointer_value(), &[index], "")
+    }
+    .and_then(|slot| llvm_builder.build_load(ptr_type, slot, ""))
+    .unwrap_or_else(|_| {
+        abort::abort_codegen(
+            context,
+            "Failed to compile the dynamic call!",
+            span,
+            std::path::PathBuf::from(file!()),
+            line!(),
+        )
+    })
+    .into_pointer_value();
+
+    let llvm_function_type: FunctionType =
+        typegeneration::generate_type_function_type_to_function_type(
+            context,
+            kind,
+            parameter_types,
+            false,
+        );
+
+    let mut compiled_args: Vec<BasicMetadataValueEnum> = Vec::with_capacity(args.len() + 1);
+
+    compiled_args.push(data.into());
+
+    for (index, expr) in args.iter().enumerate() {
+        let cast_type: Option<&Type> = parameter_types.get(index + 1);
+        compiled_args.push(codegen::compile_as_value(context, expr, cast_type).into());
+    }
+
+    let value: BasicValueEnum = match llvm_builder.build_indirect_call(
+        llvm_function_type,
+        function_ptr,
+        &compiled_args,
+        "",
+    ) {
+        Ok(call) if !kind.is_void_type() => call.try_as_basic_value().left().unwrap_or_else(|| {
+            abort::abort_codegen(
+                context,
+                "Failed to compile the dynamic call!",
+                span,
+                std::path::PathBuf::from(file!()),
+                line!(),
+            )
+        }),
+        Ok(_) => ptr_type.const_null().into(),
+        Err(_) => abort::abort_codegen(
+            context,
+            "Failed to compile the dynamic call!",
+            span,
+            std::path::PathBuf::from(file!()),
+            line!(),
+        ),
+    };
+
+    cast::try_smart_cast(context, cast_type, kind, value, span)
+}
+
+fn compile_interface_object<'ctx>(
+    context: &mut LLVMCodeGenContext<'_, 'ctx>,
+    object: &'ctx Ast,
+    span: Span,
+) -> StructValue<'ctx> {
+    let value: BasicValueEnum = codegen::compile_as_value(context, object, None);
+
+    if value.is_struct_value() {
+        return value.into_struct_value();
+    }
+
+    // Interface objects reached through a pointer are loaded first.
+    let ptr_type: PointerType = context.get_llvm_context().ptr_type(AddressSpace::default());
+
+    let object_type: BasicTypeEnum = context
+        .get_llvm_context()
+        .struct_type(&[ptr_type.into(), ptr_type.into()], false)
+        .into();
+
+    match context
+        .get_llvm_builder()
+        .build_load(object_type, value.into_pointer_value(), "")
+    {
+        Ok(object) => object.into_struct_value(),
+        Err(_) => abort::abort_codegen(
+            context,
+            "Failed to load the interface object!",
+            span,
+            std::path::PathBuf::from(file!()),
+            line!(),
+        ),
+    }
+}
@@ -20,12 +20,14 @@
 pub mod array;
 pub mod binaryop;
 pub mod call;
+pub mod errorunion;
 pub mod farray;
 pub mod floatingpoint;
 pub mod index;
 pub mod indirectcall;
 pub mod inlineasm;
 pub mod integer;
+pub mod interface;
 pub mod property;
 pub mod string;
 pub mod structure;
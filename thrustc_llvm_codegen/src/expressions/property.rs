@@ -17,21 +17,28 @@
 
 */
 
-use inkwell::types::BasicTypeEnum;
-use inkwell::values::PointerValue;
+use inkwell::builder::BuilderError;
+use inkwell::context::Context;
+use inkwell::targets::ByteOrdering;
+use inkwell::types::{BasicTypeEnum, IntType};
+use inkwell::values::{BasicValue, InstructionValue, IntValue, PointerValue};
 use inkwell::{builder::Builder, values::BasicValueEnum};
 use thrustc_ast::Ast;
-use thrustc_ast::data::PropertyData;
+use thrustc_ast::data::{PropertyData, PropertyDataField};
 use thrustc_ast::traits::AstMemoryExtensions;
 use thrustc_ast::traits::{
     AstCodeLocation, AstPropertyDataExtensions, AstPropertyDataFieldExtensions,
 };
 use thrustc_span::Span;
 use thrustc_typesystem::Type;
+use thrustc_typesystem::traits::TypeExtensions;
 use thrustc_typesystem::traits::TypeIsExtensions;
 use thrustc_typesystem::traits::TypePointerExtensions;
+use thrustc_typesystem::traits::{DereferenceExtensions, TypeStructExtensions};
+use thrustc_typesystem::type_layout::{StructMemberStorage, StructStorageLayout};
 
 use crate::context::LLVMCodeGenContext;
+use crate::memory::LLVMAllocationSite;
 use crate::traits::AstLLVMGetType;
 use crate::{abort, codegen, memory, typegeneration};
 
@@ -53,7 +60,20 @@ pub fn compile<'ctx>(
     });
 
     if (is_allocated && source_type.is_struct_type()) || source_type.is_ptr_composite_type() {
-        self::compile_gep_property(context, source, data)
+        if data
+            .last()
+            .is_some_and(|field| self::get_bitfield_width(field).is_some())
+        {
+            abort::abort_codegen(
+                context,
+                "Bitfield members cannot be addressed!",
+                source.get_span(),
+                std::path::PathBuf::from(file!()),
+                line!(),
+            )
+        }
+
+        self::compile_gep_property(context, source, data).into()
     } else {
         self::compile_extract_property(context, source, data)
     }
@@ -71,34 +91,57 @@ fn compile_extract_property<'ctx>(
     let mut property: BasicValueEnum = {
         let value: BasicValueEnum = codegen::compile_as_value(context, source, None);
 
-        let index: u32 = data
-            .get_first_property()
-            .unwrap_or_else(|| {
-                abort::abort_codegen(
-                    context,
-                    "Failed to compile the property!",
-                    span,
-                    std::path::PathBuf::from(file!()),
-                    line!(),
-                )
-            })
-            .get_index();
+        let first: &PropertyDataField = data.get_first_property().unwrap_or_else(|| {
+            abort::abort_codegen(
+                context,
+                "Failed to compile the property!",
+                span,
+                std::path::PathBuf::from(file!()),
+                line!(),
+            )
+        });
 
-        llvm_builder
-            .build_extract_value(value.into_struct_value(), index, "")
-            .unwrap_or_else(|_| {
-                abort::abort_codegen(
-                    context,
-                    "Failed to compile the property!",
-                    span,
-                    std::path::PathBuf::from(file!()),
-                    line!(),
-                )
-            })
+        if first.get_base_type().is_union_type() {
+            self::extract_union_field(context, value, first, span)
+        } else if self::get_bitfield_width(first).is_some() {
+            self::extract_bitfield(context, value, first, span)
+        } else {
+            let index: u32 = typegeneration::get_struct_storage_index(
+                context,
+                &first.get_base_type(),
+                first.get_index(),
+            );
+
+            llvm_builder
+                .build_extract_value(value.into_struct_value(), index, "")
+                .unwrap_or_else(|_| {
+                    abort::abort_codegen(
+                        context,
+                        "Failed to compile the property!",
+                        span,
+                        std::path::PathBuf::from(file!()),
+                        line!(),
+                    )
+                })
+        }
     };
 
     for field in data.iter().skip(1) {
-        let index: u32 = field.get_index();
+        if field.get_base_type().is_union_type() {
+            property = self::extract_union_field(context, property, field, span);
+            continue;
+        }
+
+        if self::get_bitfield_width(field).is_some() {
+            property = self::extract_bitfield(context, property, field, span);
+            continue;
+        }
+
+        let index: u32 = typegeneration::get_struct_storage_index(
+            context,
+            &field.get_base_type(),
+            field.get_index(),
+        );
 
         property = llvm_builder
             .build_extract_value(property.into_struct_value(), index, "")
@@ -116,39 +159,379 @@ fn compile_extract_property<'ctx>(
     property
 }
 
-fn compile_gep_property<'ctx>(
+fn extract_union_field<'ctx>(
     context: &mut LLVMCodeGenContext<'_, 'ctx>,
-    source: &'ctx Ast<'ctx>,
-    data: &'ctx PropertyData,
+    value: BasicValueEnum<'ctx>,
+    field: &PropertyDataField,
+    span: Span,
+) -> BasicValueEnum<'ctx> {
+    let union_type: Type = field.get_base_type();
+    let field_type: Type = field.get_property_type();
+
+    let storage: PointerValue =
+        memory::alloc_anon(context, LLVMAllocationSite::Stack, &union_type, span);
+
+    memory::store_anon(context, storage, value, span);
+    memory::load_anon(context, storage, &field_type, span)
+}
+
+fn extract_bitfield<'ctx>(
+    context: &mut LLVMCodeGenContext<'_, 'ctx>,
+    value: BasicValueEnum<'ctx>,
+    field: &PropertyDataField,
+    span: Span,
 ) -> BasicValueEnum<'ctx> {
     let llvm_builder: &Builder = context.get_llvm_builder();
 
-    let span: Span = source.get_span();
+    let struct_type: Type = field.get_base_type();
 
-    let source_value: BasicValueEnum<'_> = codegen::compile_as_ptr_value(context, source, None);
+    let storage: PointerValue =
+        memory::alloc_anon(context, LLVMAllocationSite::Stack, &struct_type, span);
 
-    let ptr_value: PointerValue = source_value.into_pointer_value();
-    let ptr_type: &Type = source.get_type_for_llvm();
+    memory::store_anon(context, storage, value, span);
 
-    let index: u32 = data
-        .get_first_property()
-        .unwrap_or_else(|| {
+    let index: u32 =
+        typegeneration::get_struct_storage_index(context, &struct_type, field.get_index());
+
+    let struct_llvm_type: BasicTypeEnum = typegeneration::generate_type(context, &struct_type);
+
+    let unit_ptr: PointerValue = llvm_builder
+        .build_struct_gep(struct_llvm_type, storage, index, "")
+        .unwrap_or_else(|_| {
             abort::abort_codegen(
                 context,
-                "Failed to compile the property!",
+                "Failed to gep a value from pointer!",
                 span,
                 std::path::PathBuf::from(file!()),
                 line!(),
             )
+        });
+
+    self::load_bitfield(context, unit_ptr, field, span)
+}
+
+pub fn is_bitfield_property(property: &Ast) -> bool {
+    match property {
+        Ast::Property { data, .. } => data
+            .last()
+            .is_some_and(|field| self::get_bitfield_width(field).is_some()),
+
+        _ => false,
+    }
+}
+
+pub fn compile_bitfield_load<'ctx>(
+    context: &mut LLVMCodeGenContext<'_, 'ctx>,
+    property: &'ctx Ast<'ctx>,
+    span: Span,
+) -> BasicValueEnum<'ctx> {
+    let Ast::Property { source, data, .. } = property else {
+        abort::abort_codegen(
+            context,
+            "Failed to compile the bitfield!",
+            span,
+            std::path::PathBuf::from(file!()),
+            line!(),
+        )
+    };
+
+    let field: &PropertyDataField = self::get_last_property(context, data, span);
+    let unit_ptr: PointerValue = self::compile_gep_property(context, source, data);
+
+    self::load_bitfield(context, unit_ptr, field, span)
+}
+
+pub fn compile_bitfield_store<'ctx>(
+    context: &mut LLVMCodeGenContext<'_, 'ctx>,
+    property: &'ctx Ast<'ctx>,
+    value: BasicValueEnum<'ctx>,
+    span: Span,
+) {
+    let Ast::Property { source, data, .. } = property else {
+        abort::abort_codegen(
+            context,
+            "Failed to compile the bitfield!",
+            span,
+            std::path::PathBuf::from(file!()),
+            line!(),
+        )
+    };
+
+    let field: &PropertyDataField = self::get_last_property(context, data, span);
+    let unit_ptr: PointerValue = self::compile_gep_property(context, source, data);
+
+    let struct_type: Type = field.get_base_type();
+
+    self::store_bitfield(
+        context,
+        unit_ptr,
+        &struct_type,
+        field.get_index(),
+        value,
+        span,
+    );
+}
+
+pub fn store_bitfield<'ctx>(
+    context: &mut LLVMCodeGenContext<'_, 'ctx>,
+    unit_ptr: PointerValue<'ctx>,
+    struct_type: &Type,
+    index: u32,
+    value: BasicValueEnum<'ctx>,
+    span: Span,
+) {
+    let llvm_context: &Context = context.get_llvm_context();
+    let llvm_builder: &Builder = context.get_llvm_builder();
+
+    let (unit_width, width, shift) = self::get_bitfield_storage(context, struct_type, index, span);
+
+    let unit_type: IntType = llvm_context.custom_width_int_type(unit_width);
+    let field_type: IntType = llvm_context.custom_width_int_type(width);
+
+    let unit: IntValue = self::load_bitfield_unit(context, unit_ptr, unit_type, span);
+
+    let shift: IntValue = unit_type.const_int(u64::from(shift), false);
+
+    let encoded: Result<IntValue, BuilderError> = llvm_builder
+        .build_int_truncate_or_bit_cast(value.into_int_value(), field_type, "")
+        .and_then(|value| llvm_builder.build_int_z_extend_or_bit_cast(value, unit_type, ""))
+        .and_then(|value| llvm_builder.build_left_shift(value, shift, ""));
+
+    let mask: Result<IntValue, BuilderError> = llvm_builder
+        .build_int_z_extend_or_bit_cast(field_type.const_all_ones(), unit_type, "")
+        .and_then(|mask| llvm_builder.build_left_shift(mask, shift, ""))
+        .and_then(|mask| llvm_builder.build_not(mask, ""));
+
+    let updated: IntValue = encoded
+        .and_then(|encoded| {
+            mask.and_then(|mask| llvm_builder.build_and(unit, mask, ""))
+                .and_then(|cleared| llvm_builder.build_or(cleared, encoded, ""))
         })
-        .get_index();
+        .unwrap_or_else(|_| {
+            abort::abort_codegen(
+                context,
+                "Failed to compile the bitfield!",
+                span,
+                std::path::PathBuf::from(file!()),
+                line!(),
+            )
+        });
 
-    let mut property_value: PointerValue =
-        memory::gep_struct_anon(context, ptr_value, ptr_type, index, span);
+    let store: Result<InstructionValue, BuilderError> = llvm_builder.build_store(unit_ptr, updated);
+
+    if let Ok(instruction) = store {
+        context.mark_dbg_location(span);
+        let _ = instruction.set_alignment(1);
+    }
+}
+
+fn load_bitfield<'ctx>(
+    context: &mut LLVMCodeGenContext<'_, 'ctx>,
+    unit_ptr: PointerValue<'ctx>,
+    field: &PropertyDataField,
+    span: Span,
+) -> BasicValueEnum<'ctx> {
+    let llvm_context: &Context = context.get_llvm_context();
+    let llvm_builder: &Builder = context.get_llvm_builder();
+
+    let struct_type: Type = field.get_base_type();
+
+    let (unit_width, width, shift) =
+        self::get_bitfield_storage(context, &struct_type, field.get_index(), span);
+
+    let property_type: Type = field.get_property_type().dereference_until_value();
+
+    let unit_type: IntType = llvm_context.custom_width_int_type(unit_width);
+    let field_type: IntType = llvm_context.custom_width_int_type(width);
+
+    let target_type: IntType =
+        typegeneration::generate_type(context, &property_type).into_int_type();
+
+    let unit: IntValue = self::load_bitfield_unit(context, unit_ptr, unit_type, span);
+
+    let shift: IntValue = unit_type.const_int(u64::from(shift), false);
+
+    llvm_builder
+        .build_right_shift(unit, shift, false, "")
+        .and_then(|value| llvm_builder.build_int_truncate_or_bit_cast(value, field_type, ""))
+        .and_then(|value| {
+            if property_type.is_signed_integer_type() {
+                llvm_builder.build_int_s_extend_or_bit_cast(value, target_type, "")
+            } else {
+                llvm_builder.build_int_z_extend_or_bit_cast(value, target_type, "")
+            }
+        })
+        .unwrap_or_else(|_| {
+            abort::abort_codegen(
+                context,
+                "Failed to compile the bitfield!",
+                span,
+                std::path::PathBuf::from(file!()),
+                line!(),
+            )
+        })
+        .into()
+}
+
+fn load_bitfield_unit<'ctx>(
+    context: &mut LLVMCodeGenContext<'_, 'ctx>,
+    unit_ptr: PointerValue<'ctx>,
+    unit_type: IntType<'ctx>,
+    span: Span,
+) -> IntValue<'ctx> {
+    let llvm_builder: &Builder = context.get_llvm_builder();
+
+    let unit: BasicValueEnum = llvm_builder
+        .build_load(unit_type, unit_ptr, "")
+        .unwrap_or_else(|_| {
+            abort::abort_codegen(
+                context,
+                "Failed to load the bitfield storage!",
+                span,
+                std::path::PathBuf::from(file!()),
+                line!(),
+            )
+        });
+
+    context.mark_dbg_location(span);
+
+    if let Some(instruction) = unit.as_instruction_value() {
+        let _ = instruction.set_alignment(1);
+    }
+
+    unit.into_int_value()
+}
+
+// Returns the storage unit width, the field width and the shift of the
+// bitfield inside its unit, bits are numbered from the first byte in memory.
+fn get_bitfield_storage(
+    context: &mut LLVMCodeGenContext<'_, '_>,
+    struct_type: &Type,
+    index: u32,
+    span: Span,
+) -> (u32, u32, u32) {
+    let struct_type: Type = struct_type.dereference_until_value();
+
+    let Type::Struct {
+        fields,
+        bitfields,
+        modifier,
+        ..
+    } = &struct_type
+    else {
+        abort::abort_codegen(
+            context,
+            "Failed to compile the bitfield!",
+            span,
+            std::path::PathBuf::from(file!()),
+            line!(),
+        )
+    };
+
+    let storage: StructStorageLayout = context.get_mut_target_info().get_struct_storage_layout(
+        fields,
+        bitfields,
+        modifier.llvm().is_packed(),
+    );
+
+    let member: Option<StructMemberStorage> = storage.members.get(index as usize).copied();
+
+    let Some(StructMemberStorage::BitField {
+        unit,
+        offset,
+        width,
+    }) = member
+    else {
+        abort::abort_codegen(
+            context,
+            "Failed to compile the bitfield!",
+            span,
+            std::path::PathBuf::from(file!()),
+            line!(),
+        )
+    };
+
+    let unit_width: u32 = storage
+        .units
+        .get(unit as usize)
+        .and_then(|unit| unit.get_bit_width())
+        .unwrap_or(width);
+
+    let shift: u32 = match context.get_target_data().get_byte_ordering() {
+        ByteOrdering::BigEndian => unit_width.saturating_sub(offset).saturating_sub(width),
+        ByteOrdering::LittleEndian => offset,
+    };
+
+    (unit_width, width, shift)
+}
+
+fn get_bitfield_width(field: &PropertyDataField) -> Option<u8> {
+    field
+        .get_base_type()
+        .dereference_until_value()
+        .get_struct_bitfield_width(field.get_index())
+}
+
+fn get_last_property<'ctx>(
+    context: &mut LLVMCodeGenContext<'_, 'ctx>,
+    data: &'ctx PropertyData,
+    span: Span,
+) -> &'ctx PropertyDataField {
+    data.last().unwrap_or_else(|| {
+        abort::abort_codegen(
+            context,
+            "Failed to compile the property!",
+            span,
+            std::path::PathBuf::from(file!()),
+            line!(),
+        )
+    })
+}
+
+fn compile_gep_property<'ctx>(
+    context: &mut LLVMCodeGenContext<'_, 'ctx>,
+    source: &'ctx Ast<'ctx>,
+    data: &'ctx PropertyData,
+) -> PointerValue<'ctx> {
+    let llvm_builder: &Builder = context.get_llvm_builder();
+
+    let span: Span = source.get_span();
+
+    let source_value: BasicValueEnum<'_> = codegen::compile_as_ptr_value(context, source, None);
+
+    let ptr_value: PointerValue = source_value.into_pointer_value();
+    let ptr_type: &Type = source.get_type_for_llvm();
+
+    let first: &PropertyDataField = data.get_first_property().unwrap_or_else(|| {
+        abort::abort_codegen(
+            context,
+            "Failed to compile the property!",
+            span,
+            std::path::PathBuf::from(file!()),
+            line!(),
+        )
+    });
+
+    // Every field of a union lives at offset zero, with opaque pointers the
+    // union address is already the field address.
+    let mut property_value: PointerValue = if first.get_base_type().is_union_type() {
+        ptr_value
+    } else {
+        let index: u32 =
+            typegeneration::get_struct_storage_index(context, ptr_type, first.get_index());
+
+        memory::gep_struct_anon(context, ptr_value, ptr_type, index, span)
+    };
 
     for field in data.iter().skip(1) {
         let base_type: Type = field.get_base_type();
-        let index: u32 = field.get_index();
+
+        if base_type.get_type_with_depth(1).is_union_type() {
+            continue;
+        }
+
+        let index: u32 =
+            typegeneration::get_struct_storage_index(context, &base_type, field.get_index());
 
         let property_type: BasicTypeEnum =
             typegeneration::generate_pointer_arithmetic_type(context, &base_type);
@@ -166,5 +549,5 @@ fn compile_gep_property<'ctx>(
             });
     }
 
-    property_value.into()
+    property_value
 }
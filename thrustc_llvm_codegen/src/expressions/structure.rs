@@ -23,10 +23,11 @@ use inkwell::values::{BasicValueEnum, PointerValue};
 use thrustc_ast::data::ConstructorData;
 use thrustc_span::Span;
 use thrustc_typesystem::Type;
-use thrustc_typesystem::traits::TypeStructExtensions;
+use thrustc_typesystem::traits::{TypeIsExtensions, TypeStructExtensions};
 
 use crate::anchor::PointerAnchor;
 use crate::context::LLVMCodeGenContext;
+use crate::expressions::property;
 use crate::memory::LLVMAllocationSite;
 use crate::{abort, codegen, memory, typegeneration};
 
@@ -56,6 +57,16 @@ fn compile_with_anchor<'ctx>(
     let ptr_type: BasicTypeEnum<'_> = typegeneration::generate_type(context, struct_type);
     let ptr_value: PointerValue<'_> = anchor.get_pointer();
 
+    if struct_type.is_union_type() {
+        self::store_union_field(context, data, ptr_value, span);
+
+        return context
+            .get_llvm_context()
+            .ptr_type(AddressSpace::default())
+            .const_null()
+            .into();
+    }
+
     let fields_types: &[Type] = struct_type.get_struct_fields();
 
     let fields: Vec<_> = data
@@ -66,6 +77,12 @@ fn compile_with_anchor<'ctx>(
         })
         .collect();
 
+    // Bitfields are written with read-modify-write sequences, so their storage
+    // units start zeroed.
+    if !struct_type.get_struct_bitfields().is_empty() {
+        memory::store_anon(context, ptr_value, ptr_type.const_zero(), span);
+    }
+
     for (idx, value) in fields.iter().enumerate() {
         let index: u32 = idx.try_into().unwrap_or_else(|_| {
             abort::abort_codegen(
@@ -77,9 +94,12 @@ fn compile_with_anchor<'ctx>(
             )
         });
 
+        let storage_index: u32 =
+            typegeneration::get_struct_storage_index(context, struct_type, index);
+
         let field_ptr_value: PointerValue<'_> = context
             .get_llvm_builder()
-            .build_struct_gep(ptr_type, ptr_value, index, "")
+            .build_struct_gep(ptr_type, ptr_value, storage_index, "")
             .unwrap_or_else(|_| {
                 abort::abort_codegen(
                     context,
@@ -90,6 +110,11 @@ fn compile_with_anchor<'ctx>(
                 )
             });
 
+        if struct_type.get_struct_bitfield_width(index).is_some() {
+            property::store_bitfield(context, field_ptr_value, struct_type, index, *value, span);
+            continue;
+        }
+
         memory::store_anon(context, field_ptr_value, *value, span);
     }
 
@@ -110,6 +135,11 @@ fn compile_without_anchor<'ctx>(
     let ptr_value: PointerValue<'_> =
         memory::alloc_anon(context, LLVMAllocationSite::Stack, struct_type, span);
 
+    if struct_type.is_union_type() {
+        self::store_union_field(context, data, ptr_value, span);
+        return memory::load_anon(context, ptr_value, struct_type, span);
+    }
+
     let fields_types: &[Type] = struct_type.get_struct_fields();
 
     let fields: Vec<_> = data
@@ -120,6 +150,12 @@ fn compile_without_anchor<'ctx>(
         })
         .collect();
 
+    // Bitfields are written with read-modify-write sequences, so their storage
+    // units start zeroed.
+    if !struct_type.get_struct_bitfields().is_empty() {
+        memory::store_anon(context, ptr_value, ptr_type.const_zero(), span);
+    }
+
     for (idx, value) in fields.iter().enumerate() {
         let index: u32 = idx.try_into().unwrap_or_else(|_| {
             abort::abort_codegen(
@@ -131,9 +167,12 @@ fn compile_without_anchor<'ctx>(
             )
         });
 
+        let storage_index: u32 =
+            typegeneration::get_struct_storage_index(context, struct_type, index);
+
         let field_ptr_value: PointerValue<'_> = context
             .get_llvm_builder()
-            .build_struct_gep(ptr_type, ptr_value, index, "")
+            .build_struct_gep(ptr_type, ptr_value, storage_index, "")
             .unwrap_or_else(|_| {
                 abort::abort_codegen(
                     context,
@@ -144,8 +183,25 @@ fn compile_without_anchor<'ctx>(
                 )
             });
 
+        if struct_type.get_struct_bitfield_width(index).is_some() {
+            property::store_bitfield(context, field_ptr_value, struct_type, index, *value, span);
+            continue;
+        }
+
         memory::store_anon(context, field_ptr_value, *value, span);
     }
 
     memory::load_anon(context, ptr_value, struct_type, span)
 }
+
+fn store_union_field<'ctx>(
+    context: &mut LLVMCodeGenContext<'_, 'ctx>,
+    data: &'ctx ConstructorData,
+    ptr_value: PointerValue<'ctx>,
+    span: Span,
+) {
+    if let Some((_, field, field_type, _)) = data.first() {
+        let value: BasicValueEnum = codegen::compile_as_value(context, field, Some(field_type));
+        memory::store_anon(context, ptr_value, value, span);
+    }
+}
@@ -91,6 +91,10 @@ impl AstLLVMGetType for Ast<'_> {
             Ast::Group { kind, .. } => kind,
             Ast::Index { kind, .. } => kind,
 
+            // Error unions
+            Ast::Try { kind, .. } => kind,
+            Ast::Catch { kind, .. } => kind,
+
             // Type operations
             Ast::As { cast: kind, .. } => kind,
 
@@ -102,6 +106,7 @@ impl AstLLVMGetType for Ast<'_> {
 
             // Indirect Call
             Ast::IndirectCall { kind, .. } => kind,
+            Ast::DynamicCall { kind, .. } => kind,
 
             // Intrinsic
             Ast::Intrinsic {
@@ -130,11 +135,17 @@ impl AstLLVMGetType for Ast<'_> {
             Ast::Function { return_type, .. } => return_type,
             Ast::AssemblerFunction { return_type, .. } => return_type,
             Ast::Return { kind, .. } => kind,
+            Ast::Raise { kind, .. } => kind,
 
             // Composite type definitions
             Ast::Struct { kind, .. } => kind,
             Ast::Enum { kind, .. } => kind,
 
+            // Interfaces
+            Ast::Interface { kind, .. } => kind,
+            Ast::Implementation { kind, .. } => kind,
+            Ast::InterfaceObject { kind, .. } => kind,
+
             // Constants
             Ast::Const { kind, .. } => kind,
 
@@ -659,10 +659,15 @@ impl<'a, 'ctx> LLVMMetadata<'a, 'ctx> {
                 llvm_major, llvm_minor, llvm_patch
             ));
 
+        let timestamp: MetadataValue = self
+            .get_codegen_context()
+            .get_llvm_context()
+            .metadata_string(&options.get_build_timestamp().to_string());
+
         let node: MetadataValue = self
             .get_codegen_context()
             .get_llvm_context()
-            .metadata_node(&[build_id.into(), llvm_v.into()]);
+            .metadata_node(&[build_id.into(), llvm_v.into(), timestamp.into()]);
 
         self.get_codegen_context()
             .get_llvm_module()
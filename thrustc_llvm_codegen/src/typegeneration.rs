@@ -18,21 +18,28 @@
 */
 
 use inkwell::AddressSpace;
+use inkwell::context::AsContextRef;
 use inkwell::context::Context;
 use inkwell::debug_info::AsDIScope;
 use inkwell::debug_info::DIFlagsConstants;
 use inkwell::debug_info::DIType;
+use inkwell::llvm_sys::core::LLVMBFloatTypeInContext;
 use inkwell::targets::TargetData;
 use inkwell::types::BasicMetadataTypeEnum;
 use inkwell::types::BasicType;
 use inkwell::types::BasicTypeEnum;
+use inkwell::types::FloatType;
 use inkwell::types::FunctionType;
+use inkwell::types::VectorType;
 
 use thrustc_ast::Ast;
 use thrustc_typesystem::Type;
 use thrustc_typesystem::traits::TypeCodeLocation;
+use thrustc_typesystem::traits::TypeErrorUnionExtensions;
+use thrustc_typesystem::traits::TypeInterfaceExtensions;
 use thrustc_typesystem::traits::TypeIsExtensions;
 use thrustc_typesystem::traits::TypePointerExtensions;
+use thrustc_typesystem::type_layout::{StructStorageLayout, StructStorageUnit};
 
 use std::path::PathBuf;
 
@@ -100,6 +107,11 @@ pub fn generate_type_function_type_to_function_type<'ctx>(
     }
 }
 
+#[inline]
+pub fn generate_bf16_type(llvm_context: &Context) -> FloatType<'_> {
+    unsafe { FloatType::new(LLVMBFloatTypeInContext(llvm_context.as_ctx_ref())) }
+}
+
 pub fn generate_type<'ctx>(
     context: &mut LLVMCodeGenContext<'_, 'ctx>,
     kind: &Type,
@@ -112,7 +124,7 @@ pub fn generate_type<'ctx>(
             Type::S16 { .. } | Type::U16 { .. } => llvm_context.i16_type().into(),
             Type::S32 { .. } | Type::U32 { .. } => llvm_context.i32_type().into(),
             Type::S64 { .. } | Type::U64 { .. } => llvm_context.i64_type().into(),
-            Type::U128 { .. } => llvm_context.i128_type().into(),
+            Type::S128 { .. } | Type::U128 { .. } => llvm_context.i128_type().into(),
             Type::USize { .. } | Type::SSize { .. } => llvm_context
                 .ptr_sized_int_type(context.get_target_data(), None)
                 .into(),
@@ -130,6 +142,8 @@ pub fn generate_type<'ctx>(
         },
 
         t if t.is_float_type() => match kind {
+            Type::F16 { .. } => llvm_context.f16_type().into(),
+            Type::BF16 { .. } => self::generate_bf16_type(llvm_context).into(),
             Type::F32 { .. } => llvm_context.f32_type().into(),
             Type::F64 { .. } => llvm_context.f64_type().into(),
             Type::F128 { .. } => llvm_context.f128_type().into(),
@@ -157,7 +171,10 @@ pub fn generate_type<'ctx>(
         Type::Const(subtype, ..) => self::generate_type(context, subtype),
 
         Type::Struct {
-            fields, modifier, ..
+            fields,
+            bitfields,
+            modifier,
+            ..
         } => {
             let mut field_types: Vec<BasicTypeEnum> = Vec::with_capacity(u8::MAX as usize);
 
@@ -169,6 +186,20 @@ pub fn generate_type<'ctx>(
                 }
             }
 
+            if modifier.is_union() {
+                return self::generate_union_type(context, &field_types, packed);
+            }
+
+            if !bitfields.is_empty() {
+                return self::generate_bitfield_struct_type(
+                    context,
+                    fields,
+                    bitfields,
+                    &field_types,
+                    packed,
+                );
+            }
+
             llvm_context.struct_type(&field_types, packed).into()
         }
 
@@ -177,6 +208,28 @@ pub fn generate_type<'ctx>(
             array_type.array_type(*size).into()
         }
 
+        Type::Vector(type_, lanes, ..) => self::generate_vector_type(context, type_, *lanes).into(),
+
+        Type::ErrorUnion(..) => {
+            let field_types: Vec<BasicTypeEnum> = kind
+                .get_error_union_fields()
+                .iter()
+                .map(|field_type| self::generate_type(context, field_type))
+                .collect();
+
+            llvm_context.struct_type(&field_types, false).into()
+        }
+
+        Type::Interface(..) => {
+            let field_types: Vec<BasicTypeEnum> = kind
+                .get_interface_fields()
+                .iter()
+                .map(|field_type| self::generate_type(context, field_type))
+                .collect();
+
+            llvm_context.struct_type(&field_types, false).into()
+        }
+
         any => abort::abort_codegen(
             context,
             &format!("Failed to compile '{}' as a type!", any),
@@ -200,7 +253,7 @@ pub fn generate_load_type<'ctx>(
             Type::S16 { .. } | Type::U16 { .. } => llvm_context.i16_type().into(),
             Type::S32 { .. } | Type::U32 { .. } => llvm_context.i32_type().into(),
             Type::S64 { .. } | Type::U64 { .. } => llvm_context.i64_type().into(),
-            Type::U128 { .. } => llvm_context.i128_type().into(),
+            Type::S128 { .. } | Type::U128 { .. } => llvm_context.i128_type().into(),
             Type::USize { .. } | Type::SSize { .. } => llvm_context
                 .ptr_sized_int_type(context.get_target_data(), None)
                 .into(),
@@ -218,6 +271,8 @@ pub fn generate_load_type<'ctx>(
         },
 
         t if t.is_float_type() => match kind {
+            Type::F16 { .. } => llvm_context.f16_type().into(),
+            Type::BF16 { .. } => self::generate_bf16_type(llvm_context).into(),
             Type::F32 { .. } => llvm_context.f32_type().into(),
             Type::F64 { .. } => llvm_context.f64_type().into(),
             Type::F128 { .. } => llvm_context.f128_type().into(),
@@ -240,7 +295,10 @@ pub fn generate_load_type<'ctx>(
         Type::Const(subtype, ..) => self::generate_load_type(context, subtype),
 
         Type::Struct {
-            fields, modifier, ..
+            fields,
+            bitfields,
+            modifier,
+            ..
         } => {
             let mut field_types: Vec<BasicTypeEnum> = Vec::with_capacity(u8::MAX as usize);
 
@@ -252,6 +310,20 @@ pub fn generate_load_type<'ctx>(
                 }
             }
 
+            if modifier.is_union() {
+                return self::generate_union_type(context, &field_types, packed);
+            }
+
+            if !bitfields.is_empty() {
+                return self::generate_bitfield_struct_type(
+                    context,
+                    fields,
+                    bitfields,
+                    &field_types,
+                    packed,
+                );
+            }
+
             llvm_context.struct_type(&field_types, packed).into()
         }
 
@@ -260,6 +332,30 @@ pub fn generate_load_type<'ctx>(
             array_type.array_type(*size).into()
         }
 
+        Type::Vector(subtype, lanes, ..) => {
+            self::generate_vector_type(context, subtype, *lanes).into()
+        }
+
+        Type::ErrorUnion(..) => {
+            let field_types: Vec<BasicTypeEnum> = kind
+                .get_error_union_fields()
+                .iter()
+                .map(|field_type| self::generate_load_type(context, field_type))
+                .collect();
+
+            llvm_context.struct_type(&field_types, false).into()
+        }
+
+        Type::Interface(..) => {
+            let field_types: Vec<BasicTypeEnum> = kind
+                .get_interface_fields()
+                .iter()
+                .map(|field_type| self::generate_load_type(context, field_type))
+                .collect();
+
+            llvm_context.struct_type(&field_types, false).into()
+        }
+
         any => abort::abort_codegen(
             context,
             &format!("Failed to compile '{}' as a type!", any),
@@ -270,6 +366,126 @@ pub fn generate_load_type<'ctx>(
     }
 }
 
+pub fn generate_vector_type<'ctx>(
+    context: &mut LLVMCodeGenContext<'_, 'ctx>,
+    element_type: &Type,
+    lanes: u32,
+) -> VectorType<'ctx> {
+    match self::generate_type(context, element_type) {
+        BasicTypeEnum::IntType(int_type) => int_type.vec_type(lanes),
+        BasicTypeEnum::FloatType(float_type) => float_type.vec_type(lanes),
+
+        _ => abort::abort_codegen(
+            context,
+            &format!(
+                "Failed to compile '{}' as a vector element type!",
+                element_type
+            ),
+            element_type.get_span(),
+            PathBuf::from(file!()),
+            line!(),
+        ),
+    }
+}
+
+fn generate_union_type<'ctx>(
+    context: &LLVMCodeGenContext<'_, 'ctx>,
+    field_types: &[BasicTypeEnum<'ctx>],
+    packed: bool,
+) -> BasicTypeEnum<'ctx> {
+    let llvm_context: &Context = context.get_llvm_context();
+    let target_data: &TargetData = context.get_target_data();
+
+    let size: u64 = field_types
+        .iter()
+        .map(|field_type| target_data.get_abi_size(field_type))
+        .max()
+        .unwrap_or_default();
+
+    let align: u32 = if packed {
+        1
+    } else {
+        field_types
+            .iter()
+            .map(|field_type| target_data.get_abi_alignment(field_type))
+            .max()
+            .unwrap_or(1)
+            .max(1)
+    };
+
+    let element_count: u32 = u32::try_from(size.div_ceil(u64::from(align))).unwrap_or(u32::MAX);
+
+    let storage_type: BasicTypeEnum = llvm_context
+        .custom_width_int_type(align.saturating_mul(8))
+        .array_type(element_count)
+        .into();
+
+    llvm_context.struct_type(&[storage_type], packed).into()
+}
+
+fn generate_bitfield_struct_type<'ctx>(
+    context: &mut LLVMCodeGenContext<'_, 'ctx>,
+    fields: &[Type],
+    bitfields: &[(u32, u8)],
+    field_types: &[BasicTypeEnum<'ctx>],
+    packed: bool,
+) -> BasicTypeEnum<'ctx> {
+    let llvm_context: &Context = context.get_llvm_context();
+
+    let storage: StructStorageLayout = context
+        .get_mut_target_info()
+        .get_struct_storage_layout(fields, bitfields, packed);
+
+    let unit_types: Vec<BasicTypeEnum> = storage
+        .units
+        .iter()
+        .filter_map(|unit| match unit {
+            StructStorageUnit::Field(index) => field_types.get(*index as usize).copied(),
+            StructStorageUnit::Integer(bits) => {
+                Some(llvm_context.custom_width_int_type(*bits).into())
+            }
+            StructStorageUnit::Bytes(bytes) => {
+                Some(llvm_context.i8_type().array_type(*bytes).into())
+            }
+        })
+        .collect();
+
+    llvm_context.struct_type(&unit_types, packed).into()
+}
+
+pub fn get_struct_storage_index(
+    context: &mut LLVMCodeGenContext<'_, '_>,
+    struct_type: &Type,
+    index: u32,
+) -> u32 {
+    let struct_type: &Type = match struct_type {
+        Type::Ptr(Some(inner), ..) | Type::Const(inner, ..) => inner,
+        _ => struct_type,
+    };
+
+    let Type::Struct {
+        fields,
+        bitfields,
+        modifier,
+        ..
+    } = struct_type
+    else {
+        return index;
+    };
+
+    if bitfields.is_empty() {
+        return index;
+    }
+
+    context
+        .get_mut_target_info()
+        .get_struct_storage_layout(fields, bitfields, modifier.llvm().is_packed())
+        .members
+        .get(index as usize)
+        .map(|member| member.get_unit())
+        .unwrap_or(index)
+}
+
 pub fn compile_as_dbg_type<'ctx>(
     context: &mut LLVMDebugContext<'_, 'ctx>,
     from_type: &Type,
@@ -310,6 +526,24 @@ pub fn compile_as_dbg_type<'ctx>(
                 .as_type()
         }
 
+        BasicTypeEnum::VectorType(vector_ty) => {
+            let size: i64 = i64::from(vector_ty.get_size().saturating_sub(1));
+            let subscript: std::ops::Range<i64> = 0..size;
+
+            let inner_type: DIType<'_> =
+                self::compile_as_dbg_type(context, from_type, vector_ty.get_element_type());
+
+            context
+                .get_debug_builder()
+                .create_array_type(
+                    inner_type,
+                    target_data.get_bit_size(&vector_ty),
+                    target_data.get_abi_alignment(&vector_ty),
+                    std::slice::from_ref(&subscript),
+                )
+                .as_type()
+        }
+
         BasicTypeEnum::FloatType(fp_ty) => context
             .get_debug_builder()
             .create_basic_type(
@@ -370,6 +604,19 @@ pub fn compile_as_dbg_type<'ctx>(
                 .collect();
 
             let line: u32 = from_type.get_span().get_line();
+            let name: String = format!("{}", from_type);
+
+            let unique_id: uuid::Uuid = if context.is_deterministic() {
+                let qualified_name: String =
+                    format!("{}::{}", context.get_file_path().display(), name);
+
+                uuid::Uuid::from_u64_pair(
+                    thrustc_utils::get_content_hash(qualified_name.as_bytes()),
+                    thrustc_utils::get_content_hash(&line.to_le_bytes()),
+                )
+            } else {
+                uuid::Uuid::new_v4()
+            };
 
             context
                 .get_debug_builder()
@@ -385,18 +632,10 @@ pub fn compile_as_dbg_type<'ctx>(
                     &elements,
                     0,
                     None,
-                    &uuid::Uuid::new_v4().to_string(),
+                    &unique_id.to_string(),
                 )
                 .as_type()
         }
-
-        _ => abort::abort_codegen_dbg(
-            context,
-            &format!("Failed to compile '{}' as a debuggable type!", from_type),
-            from_type.get_span(),
-            PathBuf::from(file!()),
-            line!(),
-        ),
     }
 }
 
@@ -19,7 +19,8 @@
 
 
 use ahash::{AHashMap as HashMap, AHashSet as HashSet};
-use inkwell::values::{FunctionValue, PointerValue};
+use inkwell::basic_block::BasicBlock;
+use inkwell::values::{FunctionValue, IntValue, PointerValue};
 use thrustc_span::Span;
 use thrustc_typesystem::Type;
 
@@ -50,3 +51,4 @@ pub type LLVMFunctionsParameters<'ctx> = HashMap<&'ctx str, SymbolAllocated<'ctx
 pub type LLVMCtors<'ctx> = HashSet<(PointerValue<'ctx>, u32)>;
 pub type LLVMDtors<'ctx> = HashSet<(PointerValue<'ctx>, u32)>;
 pub type LLVMStackProtectorPointer<'ctx> = PointerValue<'ctx>;
+pub type LLVMErrorExit<'ctx> = (BasicBlock<'ctx>, IntValue<'ctx>, Span);
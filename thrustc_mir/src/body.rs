@@ -0,0 +1,363 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+
+use thrustc_span::Span;
+use thrustc_token_type::TokenType;
+use thrustc_typesystem::Type;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Local(pub u32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BasicBlock(pub u32);
+
+impl Local {
+    pub const RETURN_PLACE: Local = Local(0);
+
+    #[inline]
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl BasicBlock {
+    pub const START: BasicBlock = BasicBlock(0);
+
+    #[inline]
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MirBody {
+    name: String,
+    arg_count: usize,
+    locals: Vec<LocalDecl>,
+    blocks: Vec<BasicBlockData>,
+    span: Span,
+}
+
+#[derive(Debug, Clone)]
+pub struct LocalDecl {
+    name: Option<String>,
+    kind: Type,
+    mutable: bool,
+    span: Span,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct BasicBlockData {
+    statements: Vec<Statement>,
+    terminator: Option<Terminator>,
+}
+
+#[derive(Debug, Clone)]
+pub enum PlaceBase {
+    Local(Local),
+    Static(String),
+}
+
+#[derive(Debug, Clone)]
+pub enum Projection {
+    Deref,
+    Field(u32, Type),
+    Index(Operand),
+}
+
+#[derive(Debug, Clone)]
+pub struct Place {
+    base: PlaceBase,
+    projections: Vec<Projection>,
+}
+
+#[derive(Debug, Clone)]
+pub enum Constant {
+    Integer { value: u128, signed: bool },
+    Float { value: f64, signed: bool },
+    Boolean(bool),
+    Char(u64),
+    Bytes(Vec<u8>),
+    NullPtr,
+    Function(String),
+    SizeOf(Type),
+    BitSizeOf(Type),
+    AlignOf(Type),
+    Assembler(String),
+}
+
+#[derive(Debug, Clone)]
+pub enum Operand {
+    Copy(Place),
+    Constant(Constant, Type),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AggregateKind {
+    Array,
+    Struct,
+}
+
+#[derive(Debug, Clone)]
+pub enum Rvalue {
+    Use(Operand),
+    Ref(Place),
+    Binary(TokenType, Operand, Operand),
+    Unary(TokenType, Operand),
+    Cast(Operand, Type),
+    Aggregate(AggregateKind, Type, Vec<Operand>),
+    HeapAlloc(Type),
+}
+
+#[derive(Debug, Clone)]
+pub enum Statement {
+    Assign(Place, Box<Rvalue>, Span),
+    StorageLive(Local),
+    StorageDead(Local),
+    InlineAsm {
+        assembler: String,
+        constraints: String,
+        args: Vec<Operand>,
+        destination: Place,
+        span: Span,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub enum Terminator {
+    Goto(BasicBlock),
+    SwitchBool {
+        condition: Operand,
+        then_block: BasicBlock,
+        else_block: BasicBlock,
+    },
+    Call {
+        callee: Operand,
+        args: Vec<Operand>,
+        destination: Place,
+        target: BasicBlock,
+        span: Span,
+    },
+    Return,
+    Unreachable,
+}
+
+impl MirBody {
+    #[inline]
+    pub fn new(name: String, return_type: Type, span: Span) -> Self {
+        let mut locals: Vec<LocalDecl> = Vec::with_capacity(u8::MAX as usize);
+        locals.push(LocalDecl::new(None, return_type, true, span));
+
+        Self {
+            name,
+            arg_count: 0,
+            locals,
+            blocks: Vec::with_capacity(u8::MAX as usize),
+            span,
+        }
+    }
+}
+
+impl MirBody {
+    #[inline]
+    pub fn push_argument(&mut self, decl: LocalDecl) -> Local {
+        self.arg_count += 1;
+        self.push_local(decl)
+    }
+
+    #[inline]
+    pub fn push_local(&mut self, decl: LocalDecl) -> Local {
+        self.locals.push(decl);
+        Local((self.locals.len() - 1) as u32)
+    }
+
+    #[inline]
+    pub fn push_block(&mut self) -> BasicBlock {
+        self.blocks.push(BasicBlockData::default());
+        BasicBlock((self.blocks.len() - 1) as u32)
+    }
+
+    #[inline]
+    pub fn push_statement(&mut self, block: BasicBlock, statement: Statement) {
+        if let Some(data) = self.blocks.get_mut(block.index()) {
+            data.statements.push(statement);
+        }
+    }
+
+    #[inline]
+    pub fn set_terminator(&mut self, block: BasicBlock, terminator: Terminator) {
+        if let Some(data) = self.blocks.get_mut(block.index()) {
+            data.terminator = Some(terminator);
+        }
+    }
+}
+
+impl MirBody {
+    #[inline]
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    #[inline]
+    pub fn get_arg_count(&self) -> usize {
+        self.arg_count
+    }
+
+    #[inline]
+    pub fn get_locals(&self) -> &[LocalDecl] {
+        &self.locals
+    }
+
+    #[inline]
+    pub fn get_local(&self, local: Local) -> Option<&LocalDecl> {
+        self.locals.get(local.index())
+    }
+
+    #[inline]
+    pub fn get_blocks(&self) -> &[BasicBlockData] {
+        &self.blocks
+    }
+
+    #[inline]
+    pub fn get_block(&self, block: BasicBlock) -> Option<&BasicBlockData> {
+        self.blocks.get(block.index())
+    }
+
+    #[inline]
+    pub fn get_return_type(&self) -> &Type {
+        &self.locals[Local::RETURN_PLACE.index()].kind
+    }
+
+    #[inline]
+    pub fn get_span(&self) -> Span {
+        self.span
+    }
+
+    #[inline]
+    pub fn is_terminated(&self, block: BasicBlock) -> bool {
+        self.blocks
+            .get(block.index())
+            .is_some_and(|data| data.terminator.is_some())
+    }
+}
+
+impl LocalDecl {
+    #[inline]
+    pub fn new(name: Option<String>, kind: Type, mutable: bool, span: Span) -> Self {
+        Self {
+            name,
+            kind,
+            mutable,
+            span,
+        }
+    }
+}
+
+impl LocalDecl {
+    #[inline]
+    pub fn get_name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    #[inline]
+    pub fn get_type(&self) -> &Type {
+        &self.kind
+    }
+
+    #[inline]
+    pub fn is_mutable(&self) -> bool {
+        self.mutable
+    }
+
+    #[inline]
+    pub fn get_span(&self) -> Span {
+        self.span
+    }
+}
+
+impl BasicBlockData {
+    #[inline]
+    pub fn get_statements(&self) -> &[Statement] {
+        &self.statements
+    }
+
+    #[inline]
+    pub fn get_terminator(&self) -> Option<&Terminator> {
+        self.terminator.as_ref()
+    }
+}
+
+impl Place {
+    #[inline]
+    pub fn local(local: Local) -> Self {
+        Self {
+            base: PlaceBase::Local(local),
+            projections: Vec::new(),
+        }
+    }
+
+    #[inline]
+    pub fn global(name: String) -> Self {
+        Self {
+            base: PlaceBase::Static(name),
+            projections: Vec::new(),
+        }
+    }
+
+    #[inline]
+    pub fn project(mut self, projection: Projection) -> Self {
+        self.projections.push(projection);
+        self
+    }
+}
+
+impl Place {
+    #[inline]
+    pub fn get_base(&self) -> &PlaceBase {
+        &self.base
+    }
+
+    #[inline]
+    pub fn get_projections(&self) -> &[Projection] {
+        &self.projections
+    }
+
+    #[inline]
+    pub fn as_local(&self) -> Option<Local> {
+        match self.base {
+            PlaceBase::Local(local) if self.projections.is_empty() => Some(local),
+            _ => None,
+        }
+    }
+}
+
+impl Terminator {
+    pub fn successors(&self) -> Vec<BasicBlock> {
+        match self {
+            Terminator::Goto(target) => vec![*target],
+            Terminator::SwitchBool {
+                then_block,
+                else_block,
+                ..
+            } => vec![*then_block, *else_block],
+            Terminator::Call { target, .. } => vec![*target],
+            Terminator::Return | Terminator::Unreachable => Vec::new(),
+        }
+    }
+}
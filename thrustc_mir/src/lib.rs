@@ -19,4 +19,7 @@
 
 
 pub mod atomicord;
+pub mod body;
+mod pretty;
 pub mod threadmode;
+pub mod verifier;
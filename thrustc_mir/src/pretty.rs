@@ -0,0 +1,243 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+
+use crate::body::{
+    AggregateKind, BasicBlock, Constant, Local, MirBody, Operand, Place, PlaceBase, Projection,
+    Rvalue, Statement, Terminator,
+};
+
+impl std::fmt::Display for Local {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "_{}", self.0)
+    }
+}
+
+impl std::fmt::Display for BasicBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "bb{}", self.0)
+    }
+}
+
+impl std::fmt::Display for Place {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut place: String = match self.get_base() {
+            PlaceBase::Local(local) => local.to_string(),
+            PlaceBase::Static(name) => format!("static {}", name),
+        };
+
+        for projection in self.get_projections() {
+            place = match projection {
+                Projection::Deref => format!("(*{})", place),
+                Projection::Field(index, kind) => format!("{}.{}: {}", place, index, kind),
+                Projection::Index(index) => format!("{}[{}]", place, index),
+            };
+        }
+
+        write!(f, "{}", place)
+    }
+}
+
+impl std::fmt::Display for Constant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Constant::Integer { value, signed } => {
+                if *signed {
+                    write!(f, "-{}", value)
+                } else {
+                    write!(f, "{}", value)
+                }
+            }
+            Constant::Float { value, signed } => {
+                if *signed {
+                    write!(f, "-{}", value)
+                } else {
+                    write!(f, "{}", value)
+                }
+            }
+            Constant::Boolean(value) => write!(f, "{}", value),
+            Constant::Char(byte) => write!(f, "'\\x{:02x}'", byte),
+            Constant::Bytes(bytes) => write!(f, "{:?}", String::from_utf8_lossy(bytes)),
+            Constant::NullPtr => write!(f, "nullptr"),
+            Constant::Function(name) => write!(f, "fn {}", name),
+            Constant::SizeOf(kind) => write!(f, "sizeof({})", kind),
+            Constant::BitSizeOf(kind) => write!(f, "bitsizeof({})", kind),
+            Constant::AlignOf(kind) => write!(f, "alignof({})", kind),
+            Constant::Assembler(asm) => write!(f, "asm {:?}", asm),
+        }
+    }
+}
+
+impl std::fmt::Display for Operand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Operand::Copy(place) => write!(f, "copy {}", place),
+            Operand::Constant(constant, kind) => write!(f, "const {}_{}", constant, kind),
+        }
+    }
+}
+
+impl std::fmt::Display for Rvalue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Rvalue::Use(operand) => write!(f, "{}", operand),
+            Rvalue::Ref(place) => write!(f, "&{}", place),
+            Rvalue::Binary(operator, left, right) => {
+                write!(f, "{} {} {}", left, operator, right)
+            }
+            Rvalue::Unary(operator, operand) => write!(f, "{}{}", operator, operand),
+            Rvalue::Cast(operand, kind) => write!(f, "{} as {}", operand, kind),
+            Rvalue::Aggregate(aggregate, kind, operands) => {
+                let operands: String = operands
+                    .iter()
+                    .map(|operand| operand.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ");
+
+                match aggregate {
+                    AggregateKind::Array => write!(f, "[{}]: {}", operands, kind),
+                    AggregateKind::Struct => write!(f, "{} {{ {} }}", kind, operands),
+                }
+            }
+            Rvalue::HeapAlloc(kind) => write!(f, "halloc {}", kind),
+        }
+    }
+}
+
+impl std::fmt::Display for Statement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Statement::Assign(place, rvalue, _) => write!(f, "{} = {};", place, rvalue),
+            Statement::StorageLive(local) => write!(f, "StorageLive({});", local),
+            Statement::StorageDead(local) => write!(f, "StorageDead({});", local),
+            Statement::InlineAsm {
+                assembler,
+                constraints,
+                args,
+                destination,
+                ..
+            } => {
+                let args: String = args
+                    .iter()
+                    .map(|arg| arg.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ");
+
+                write!(
+                    f,
+                    "{} = asm({:?}, {:?}, [{}]);",
+                    destination, assembler, constraints, args
+                )
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Terminator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Terminator::Goto(target) => write!(f, "goto -> {};", target),
+            Terminator::SwitchBool {
+                condition,
+                then_block,
+                else_block,
+            } => write!(
+                f,
+                "switchBool({}) -> [true: {}, false: {}];",
+                condition, then_block, else_block
+            ),
+            Terminator::Call {
+                callee,
+                args,
+                destination,
+                target,
+                ..
+            } => {
+                let args: String = args
+                    .iter()
+                    .map(|arg| arg.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ");
+
+                write!(
+                    f,
+                    "{} = call {}({}) -> {};",
+                    destination, callee, args, target
+                )
+            }
+            Terminator::Return => write!(f, "return;"),
+            Terminator::Unreachable => write!(f, "unreachable;"),
+        }
+    }
+}
+
+impl std::fmt::Display for MirBody {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let arguments: String = self
+            .get_locals()
+            .iter()
+            .enumerate()
+            .skip(1)
+            .take(self.get_arg_count())
+            .map(|(index, decl)| format!("_{}: {}", index, decl.get_type()))
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        writeln!(
+            f,
+            "fn {}({}) -> {} {{",
+            self.get_name(),
+            arguments,
+            self.get_return_type()
+        )?;
+
+        for (index, decl) in self.get_locals().iter().enumerate() {
+            let mutability: &str = if decl.is_mutable() { "mut " } else { "" };
+
+            if let Some(name) = decl.get_name() {
+                writeln!(
+                    f,
+                    "    let {}_{}: {}; // {}",
+                    mutability,
+                    index,
+                    decl.get_type(),
+                    name
+                )?;
+            } else {
+                writeln!(f, "    let {}_{}: {};", mutability, index, decl.get_type())?;
+            }
+        }
+
+        for (index, block) in self.get_blocks().iter().enumerate() {
+            writeln!(f)?;
+            writeln!(f, "    bb{}: {{", index)?;
+
+            for statement in block.get_statements() {
+                writeln!(f, "        {}", statement)?;
+            }
+
+            if let Some(terminator) = block.get_terminator() {
+                writeln!(f, "        {}", terminator)?;
+            }
+
+            writeln!(f, "    }}")?;
+        }
+
+        write!(f, "}}")
+    }
+}
@@ -0,0 +1,311 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+
+use thrustc_span::Span;
+
+use crate::body::{
+    BasicBlock, Local, MirBody, Operand, Place, PlaceBase, Projection, Rvalue, Statement,
+    Terminator,
+};
+
+#[derive(Debug, Clone)]
+pub struct MirVerifierIssue {
+    message: String,
+    span: Span,
+}
+
+impl MirVerifierIssue {
+    #[inline]
+    pub fn get_message(&self) -> &str {
+        &self.message
+    }
+
+    #[inline]
+    pub fn get_span(&self) -> Span {
+        self.span
+    }
+}
+
+#[derive(Debug)]
+pub struct MirVerifier<'mir> {
+    body: &'mir MirBody,
+    issues: Vec<MirVerifierIssue>,
+}
+
+impl<'mir> MirVerifier<'mir> {
+    #[inline]
+    pub fn new(body: &'mir MirBody) -> Self {
+        Self {
+            body,
+            issues: Vec::with_capacity(u8::MAX as usize),
+        }
+    }
+}
+
+impl MirVerifier<'_> {
+    pub fn verify(mut self) -> Result<(), Vec<MirVerifierIssue>> {
+        if self.body.get_blocks().is_empty() {
+            self.report(format!(
+                "Function '{}' has no basic blocks.",
+                self.body.get_name()
+            ));
+        }
+
+        if self.body.get_arg_count() >= self.body.get_locals().len() {
+            self.report(format!(
+                "Function '{}' declares more arguments than locals.",
+                self.body.get_name()
+            ));
+        }
+
+        for (index, block) in self.body.get_blocks().iter().enumerate() {
+            let current: BasicBlock = BasicBlock(index as u32);
+
+            for statement in block.get_statements() {
+                self.verify_statement(current, statement);
+            }
+
+            match block.get_terminator() {
+                Some(terminator) => self.verify_terminator(current, terminator),
+                None => self.report(format!("Basic block '{}' is not terminated.", current)),
+            }
+        }
+
+        if self.issues.is_empty() {
+            Ok(())
+        } else {
+            Err(self.issues)
+        }
+    }
+}
+
+impl MirVerifier<'_> {
+    fn verify_statement(&mut self, block: BasicBlock, statement: &Statement) {
+        match statement {
+            Statement::Assign(place, rvalue, _) => {
+                self.verify_place(block, place);
+                self.verify_rvalue(block, rvalue);
+            }
+            Statement::StorageLive(local) | Statement::StorageDead(local) => {
+                self.verify_local(block, *local);
+            }
+            Statement::InlineAsm {
+                args, destination, ..
+            } => {
+                args.iter().for_each(|arg| self.verify_operand(block, arg));
+                self.verify_place(block, destination);
+            }
+        }
+    }
+
+    fn verify_terminator(&mut self, block: BasicBlock, terminator: &Terminator) {
+        match terminator {
+            Terminator::SwitchBool { condition, .. } => self.verify_operand(block, condition),
+            Terminator::Call {
+                callee,
+                args,
+                destination,
+                ..
+            } => {
+                self.verify_operand(block, callee);
+                args.iter().for_each(|arg| self.verify_operand(block, arg));
+                self.verify_place(block, destination);
+            }
+            Terminator::Goto(..) | Terminator::Return | Terminator::Unreachable => (),
+        }
+
+        for target in terminator.successors() {
+            if self.body.get_block(target).is_none() {
+                self.report(format!(
+                    "Basic block '{}' jumps to the undeclared basic block '{}'.",
+                    block, target
+                ));
+            }
+        }
+    }
+
+    fn verify_rvalue(&mut self, block: BasicBlock, rvalue: &Rvalue) {
+        match rvalue {
+            Rvalue::Use(operand) | Rvalue::Unary(_, operand) | Rvalue::Cast(operand, _) => {
+                self.verify_operand(block, operand)
+            }
+            Rvalue::Ref(place) => self.verify_place(block, place),
+            Rvalue::Binary(_, left, right) => {
+                self.verify_operand(block, left);
+                self.verify_operand(block, right);
+            }
+            Rvalue::Aggregate(_, _, operands) => operands
+                .iter()
+                .for_each(|operand| self.verify_operand(block, operand)),
+            Rvalue::HeapAlloc(..) => (),
+        }
+    }
+
+    fn verify_operand(&mut self, block: BasicBlock, operand: &Operand) {
+        if let Operand::Copy(place) = operand {
+            self.verify_place(block, place);
+        }
+    }
+
+    fn verify_place(&mut self, block: BasicBlock, place: &Place) {
+        if let PlaceBase::Local(local) = place.get_base() {
+            self.verify_local(block, *local);
+        }
+
+        for projection in place.get_projections() {
+            if let Projection::Index(index) = projection {
+                self.verify_operand(block, index);
+            }
+        }
+    }
+
+    fn verify_local(&mut self, block: BasicBlock, local: Local) {
+        if self.body.get_local(local).is_none() {
+            self.report(format!(
+                "Basic block '{}' uses the undeclared local '{}'.",
+                block, local
+            ));
+        }
+    }
+}
+
+impl MirVerifier<'_> {
+    #[inline]
+    fn report(&mut self, message: String) {
+        self.issues.push(MirVerifierIssue {
+            message,
+            span: self.body.get_span(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use thrustc_span::Span;
+    use thrustc_typesystem::Type;
+
+    use crate::body::{
+        BasicBlock, Constant, Local, LocalDecl, MirBody, Operand, Place, Rvalue, Statement,
+        Terminator,
+    };
+    use crate::verifier::MirVerifier;
+
+    fn s32() -> Type {
+        Type::S32 {
+            span: Span::nothing(),
+        }
+    }
+
+    fn body_with_argument() -> (MirBody, Local) {
+        let mut body: MirBody = MirBody::new("main".into(), s32(), Span::nothing());
+
+        let argument: Local = body.push_argument(LocalDecl::new(
+            Some("x".into()),
+            s32(),
+            false,
+            Span::nothing(),
+        ));
+
+        (body, argument)
+    }
+
+    #[test]
+    fn accepts_well_formed_body() {
+        let (mut body, argument) = body_with_argument();
+
+        let entry: BasicBlock = body.push_block();
+        let exit: BasicBlock = body.push_block();
+
+        body.push_statement(
+            entry,
+            Statement::Assign(
+                Place::local(Local::RETURN_PLACE),
+                Box::new(Rvalue::Use(Operand::Copy(Place::local(argument)))),
+                Span::nothing(),
+            ),
+        );
+
+        body.set_terminator(entry, Terminator::Goto(exit));
+        body.set_terminator(exit, Terminator::Return);
+
+        assert!(MirVerifier::new(&body).verify().is_ok());
+    }
+
+    #[test]
+    fn rejects_body_without_blocks() {
+        let (body, _) = body_with_argument();
+
+        assert!(MirVerifier::new(&body).verify().is_err());
+    }
+
+    #[test]
+    fn rejects_unterminated_block() {
+        let (mut body, _) = body_with_argument();
+        body.push_block();
+
+        let issues = MirVerifier::new(&body).verify().unwrap_err();
+
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].get_message().contains("is not terminated"));
+    }
+
+    #[test]
+    fn rejects_jump_to_undeclared_block() {
+        let (mut body, _) = body_with_argument();
+
+        let entry: BasicBlock = body.push_block();
+        body.set_terminator(entry, Terminator::Goto(BasicBlock(7)));
+
+        let issues = MirVerifier::new(&body).verify().unwrap_err();
+
+        assert!(
+            issues[0]
+                .get_message()
+                .contains("undeclared basic block 'bb7'")
+        );
+    }
+
+    #[test]
+    fn rejects_undeclared_local() {
+        let (mut body, _) = body_with_argument();
+
+        let entry: BasicBlock = body.push_block();
+
+        body.push_statement(
+            entry,
+            Statement::Assign(
+                Place::local(Local(9)),
+                Box::new(Rvalue::Use(Operand::Constant(
+                    Constant::Integer {
+                        value: 1,
+                        signed: true,
+                    },
+                    s32(),
+                ))),
+                Span::nothing(),
+            ),
+        );
+
+        body.set_terminator(entry, Terminator::Return);
+
+        let issues = MirVerifier::new(&body).verify().unwrap_err();
+
+        assert!(issues[0].get_message().contains("undeclared local '_9'"));
+    }
+}
@@ -0,0 +1,264 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+
+use ahash::{AHashMap as HashMap, AHashSet as HashSet};
+
+use thrustc_errors::{CompilationIssue, CompilationPosition};
+use thrustc_mir::body::{
+    BasicBlock, Local, LocalDecl, MirBody, Operand, Place, Rvalue, Statement, Terminator,
+};
+use thrustc_span::Span;
+use thrustc_typesystem::Type;
+
+#[derive(Debug, Clone)]
+pub enum MirBinding {
+    Local(Local),
+    Global(String),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct MirLoopScope {
+    continue_block: BasicBlock,
+    break_block: BasicBlock,
+}
+
+#[derive(Debug)]
+pub struct MirBuilderContext<'ctx> {
+    body: MirBody,
+    current: BasicBlock,
+
+    scopes: Vec<Vec<(&'ctx str, MirBinding)>>,
+    loops: Vec<MirLoopScope>,
+
+    functions: &'ctx HashSet<&'ctx str>,
+    globals: &'ctx HashMap<&'ctx str, String>,
+}
+
+impl<'ctx> MirBuilderContext<'ctx> {
+    #[inline]
+    pub fn new(
+        name: &str,
+        return_type: Type,
+        span: Span,
+        functions: &'ctx HashSet<&'ctx str>,
+        globals: &'ctx HashMap<&'ctx str, String>,
+    ) -> Self {
+        let mut body: MirBody = MirBody::new(name.to_string(), return_type, span);
+        let current: BasicBlock = body.push_block();
+
+        Self {
+            body,
+            current,
+
+            scopes: vec![Vec::with_capacity(u8::MAX as usize)],
+            loops: Vec::with_capacity(u8::MAX as usize),
+
+            functions,
+            globals,
+        }
+    }
+}
+
+impl<'ctx> MirBuilderContext<'ctx> {
+    #[inline]
+    pub fn push_argument(&mut self, name: &'ctx str, kind: Type, mutable: bool, span: Span) {
+        let local: Local =
+            self.body
+                .push_argument(LocalDecl::new(Some(name.to_string()), kind, mutable, span));
+
+        self.bind(name, MirBinding::Local(local));
+    }
+
+    #[inline]
+    pub fn new_local(&mut self, name: &'ctx str, kind: Type, mutable: bool, span: Span) -> Local {
+        let local: Local =
+            self.body
+                .push_local(LocalDecl::new(Some(name.to_string()), kind, mutable, span));
+
+        self.bind(name, MirBinding::Local(local));
+        self.push_statement(Statement::StorageLive(local));
+
+        local
+    }
+
+    #[inline]
+    pub fn new_temporary(&mut self, kind: Type, span: Span) -> Local {
+        self.body.push_local(LocalDecl::new(None, kind, true, span))
+    }
+
+    #[inline]
+    pub fn bind(&mut self, name: &'ctx str, binding: MirBinding) {
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.push((name, binding));
+        }
+    }
+
+    pub fn resolve(&self, name: &str) -> Option<MirBinding> {
+        for scope in self.scopes.iter().rev() {
+            if let Some((_, binding)) = scope.iter().rev().find(|(symbol, _)| *symbol == name) {
+                return Some(binding.clone());
+            }
+        }
+
+        if let Some(symbol) = self.globals.get(name) {
+            return Some(MirBinding::Global(symbol.clone()));
+        }
+
+        None
+    }
+
+    #[inline]
+    pub fn is_function(&self, name: &str) -> bool {
+        self.functions.contains(name)
+    }
+}
+
+impl MirBuilderContext<'_> {
+    #[inline]
+    pub fn begin_scope(&mut self) {
+        self.scopes.push(Vec::with_capacity(u8::MAX as usize));
+    }
+
+    pub fn end_scope(&mut self) {
+        let Some(scope) = self.scopes.pop() else {
+            return;
+        };
+
+        for (_, binding) in scope.iter().rev() {
+            if let MirBinding::Local(local) = binding {
+                self.push_statement(Statement::StorageDead(*local));
+            }
+        }
+    }
+
+    #[inline]
+    pub fn begin_loop(&mut self, continue_block: BasicBlock, break_block: BasicBlock) {
+        self.loops.push(MirLoopScope {
+            continue_block,
+            break_block,
+        });
+    }
+
+    #[inline]
+    pub fn end_loop(&mut self) {
+        self.loops.pop();
+    }
+
+    #[inline]
+    pub fn get_innermost_loop(&self) -> Option<MirLoopScope> {
+        self.loops.last().copied()
+    }
+
+    #[inline]
+    pub fn get_outermost_loop(&self) -> Option<MirLoopScope> {
+        self.loops.first().copied()
+    }
+}
+
+impl MirBuilderContext<'_> {
+    #[inline]
+    pub fn new_block(&mut self) -> BasicBlock {
+        self.body.push_block()
+    }
+
+    #[inline]
+    pub fn switch_to(&mut self, block: BasicBlock) {
+        self.current = block;
+    }
+
+    #[inline]
+    pub fn push_statement(&mut self, statement: Statement) {
+        self.body.push_statement(self.current, statement);
+    }
+
+    #[inline]
+    pub fn assign(&mut self, place: Place, rvalue: Rvalue, span: Span) {
+        self.push_statement(Statement::Assign(place, Box::new(rvalue), span));
+    }
+
+    #[inline]
+    pub fn assign_temporary(&mut self, kind: Type, rvalue: Rvalue, span: Span) -> Operand {
+        let temporary: Local = self.new_temporary(kind, span);
+        self.assign(Place::local(temporary), rvalue, span);
+
+        Operand::Copy(Place::local(temporary))
+    }
+
+    #[inline]
+    pub fn terminate(&mut self, terminator: Terminator) {
+        if !self.body.is_terminated(self.current) {
+            self.body.set_terminator(self.current, terminator);
+        }
+    }
+
+    #[inline]
+    pub fn terminate_and_continue(&mut self, terminator: Terminator) {
+        self.terminate(terminator);
+
+        let unreachable: BasicBlock = self.new_block();
+        self.switch_to(unreachable);
+    }
+
+    #[inline]
+    pub fn goto(&mut self, target: BasicBlock) {
+        self.terminate(Terminator::Goto(target));
+    }
+}
+
+impl MirBuilderContext<'_> {
+    #[inline]
+    pub fn get_return_type(&self) -> &Type {
+        self.body.get_return_type()
+    }
+
+    #[inline]
+    pub fn finish(self) -> MirBody {
+        self.body
+    }
+}
+
+impl MirLoopScope {
+    #[inline]
+    pub fn get_continue_block(&self) -> BasicBlock {
+        self.continue_block
+    }
+
+    #[inline]
+    pub fn get_break_block(&self) -> BasicBlock {
+        self.break_block
+    }
+}
+
+#[inline]
+pub fn bug(
+    title: &str,
+    description: &str,
+    span: Span,
+    file: std::path::PathBuf,
+    line: u32,
+) -> CompilationIssue {
+    CompilationIssue::FrontEndBug(
+        title.into(),
+        description.into(),
+        span,
+        CompilationPosition::MirBuilder,
+        file,
+        line,
+    )
+}
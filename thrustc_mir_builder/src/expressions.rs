@@ -0,0 +1,970 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+
+use thrustc_ast::{
+    Ast, ModuleExpressionValues,
+    builtins::AstBuiltin,
+    data::{ConstructorData, PropertyData},
+    traits::{AstCodeLocation, AstGetType, AstMemoryExtensions, AstPropertyDataFieldExtensions},
+};
+use thrustc_errors::CompilationIssue;
+use thrustc_mir::body::{
+    AggregateKind, BasicBlock, Constant, Local, Operand, Place, Projection, Rvalue, Statement,
+    Terminator,
+};
+use thrustc_span::Span;
+use thrustc_token_type::TokenType;
+use thrustc_typesystem::{
+    Type,
+    traits::{
+        InfererTypeExtensions, TypeIsExtensions, TypePointerExtensions, TypeVectorExtensions,
+    },
+};
+
+use crate::context::{self, MirBinding, MirBuilderContext};
+
+pub fn lower_operand<'ctx>(
+    ctx: &mut MirBuilderContext<'ctx>,
+    expr: &'ctx Ast<'ctx>,
+) -> Result<Operand, CompilationIssue> {
+    match expr {
+        // Literals
+        Ast::Integer {
+            kind,
+            value,
+            signed,
+            ..
+        } => Ok(Operand::Constant(
+            Constant::Integer {
+                value: *value,
+                signed: *signed,
+            },
+            kind.clone(),
+        )),
+
+        Ast::Float {
+            kind,
+            value,
+            signed,
+            ..
+        } => Ok(Operand::Constant(
+            Constant::Float {
+                value: *value,
+                signed: *signed,
+            },
+            kind.clone(),
+        )),
+
+        Ast::Boolean { kind, value, .. } => Ok(Operand::Constant(
+            Constant::Boolean(*value != 0),
+            kind.clone(),
+        )),
+
+        Ast::Char { kind, byte, .. } => Ok(Operand::Constant(Constant::Char(*byte), kind.clone())),
+
+        Ast::CString { bytes, kind, .. } | Ast::CNString { bytes, kind, .. } => Ok(
+            Operand::Constant(Constant::Bytes(bytes.clone()), kind.clone()),
+        ),
+
+        Ast::NullPtr { kind, .. } => Ok(Operand::Constant(Constant::NullPtr, kind.clone())),
+
+        // Symbols
+        Ast::Reference {
+            name, kind, span, ..
+        } => self::lower_symbol(ctx, name, kind, *span),
+
+        Ast::DirectRef {
+            expr: inner,
+            kind,
+            span,
+            ..
+        } => match &**inner {
+            Ast::Reference { .. } => {
+                let place: Place = self::lower_place(ctx, inner)?;
+                Ok(ctx.assign_temporary(kind.clone(), Rvalue::Ref(place), *span))
+            }
+
+            _ => self::lower_operand(ctx, inner),
+        },
+
+        Ast::EnumValue { value, .. } => self::lower_operand(ctx, value),
+
+        // Memory Access Operations
+        Ast::Property {
+            source,
+            data,
+            kind,
+            span,
+            ..
+        } => {
+            let source_type: &Type = self::get_type(source)?;
+            let is_allocated: bool = source.is_memory_assigned_value()?;
+
+            let is_address: bool = (is_allocated && source_type.is_struct_type())
+                || source_type.is_ptr_composite_type();
+
+            let place: Place = self::lower_property_place(ctx, source, data)?;
+
+            if is_address {
+                Ok(ctx.assign_temporary(kind.clone(), Rvalue::Ref(place), *span))
+            } else {
+                Ok(Operand::Copy(place))
+            }
+        }
+
+        Ast::Index {
+            source,
+            index,
+            kind,
+            span,
+        } => {
+            let place: Place = self::lower_index_place(ctx, source, index)?;
+            Ok(ctx.assign_temporary(kind.clone(), Rvalue::Ref(place), *span))
+        }
+
+        Ast::Address {
+            source,
+            indexes,
+            kind,
+            span,
+            ..
+        } => {
+            let mut place: Place = self::lower_pointee(ctx, source)?;
+
+            for index in indexes.iter() {
+                let index: Operand = self::lower_operand(ctx, index)?;
+                place = place.project(Projection::Index(index));
+            }
+
+            Ok(ctx.assign_temporary(kind.clone(), Rvalue::Ref(place), *span))
+        }
+
+        Ast::Deref { value, .. } => {
+            if !self::get_type(value)?.is_ptr_like_type() {
+                return self::lower_operand(ctx, value);
+            }
+
+            Ok(Operand::Copy(self::lower_pointee(ctx, value)?))
+        }
+
+        Ast::Load { source, .. } => Ok(Operand::Copy(self::lower_pointee(ctx, source)?)),
+
+        Ast::Write {
+            source,
+            write_value,
+            span,
+            ..
+        } => {
+            let value: Operand = self::lower_operand(ctx, write_value)?;
+            let place: Place = self::lower_pointee(ctx, source)?;
+
+            ctx.assign(place, Rvalue::Use(value.clone()), *span);
+
+            Ok(value)
+        }
+
+        Ast::Mut {
+            source,
+            value,
+            span,
+            ..
+        } => {
+            let value: Operand = self::lower_operand(ctx, value)?;
+            let place: Place = self::lower_lvalue(ctx, source)?;
+
+            ctx.assign(place, Rvalue::Use(value.clone()), *span);
+
+            Ok(value)
+        }
+
+        // Composite Values
+        Ast::Constructor {
+            data, kind, span, ..
+        } => {
+            let fields: Vec<Operand> = self::lower_constructor(ctx, data)?;
+
+            Ok(ctx.assign_temporary(
+                kind.clone(),
+                Rvalue::Aggregate(AggregateKind::Struct, kind.clone(), fields),
+                *span,
+            ))
+        }
+
+        Ast::FixedArray {
+            items, kind, span, ..
+        }
+        | Ast::Array {
+            items, kind, span, ..
+        } => {
+            let items: Vec<Operand> = self::lower_operands(ctx, items)?;
+
+            Ok(ctx.assign_temporary(
+                kind.clone(),
+                Rvalue::Aggregate(AggregateKind::Array, kind.clone(), items),
+                *span,
+            ))
+        }
+
+        // Calls
+        Ast::Call {
+            name,
+            args,
+            kind,
+            span,
+            ..
+        } => {
+            let callee: Operand = self::lower_symbol(ctx, name, kind, *span)?;
+            self::lower_call(ctx, callee, args, kind, *span)
+        }
+
+        Ast::IndirectCall {
+            function,
+            args,
+            kind,
+            span,
+            ..
+        } => {
+            let callee: Operand = self::lower_operand(ctx, function)?;
+            self::lower_call(ctx, callee, args, kind, *span)
+        }
+
+        Ast::DynamicCall {
+            object,
+            index,
+            args,
+            kind,
+            span,
+            ..
+        } => {
+            let object: Place = match object.as_ref() {
+                Ast::Property { .. } => self::lower_place(ctx, object)?,
+                _ if self::get_type(object)?.is_ptr_type() => self::lower_pointee(ctx, object)?,
+                _ => self::lower_place(ctx, object)?,
+            };
+
+            let data: Operand = Operand::Copy(
+                object
+                    .clone()
+                    .project(Projection::Field(0, Type::Ptr(None, *span))),
+            );
+
+            let callee: Operand = Operand::Copy(
+                object
+                    .project(Projection::Field(1, Type::Ptr(None, *span)))
+                    .project(Projection::Deref)
+                    .project(Projection::Index(Operand::Constant(
+                        Constant::Integer {
+                            value: u128::from(*index),
+                            signed: false,
+                        },
+                        Type::U32 { span: *span },
+                    ))),
+            );
+
+            let mut operands: Vec<Operand> = Vec::with_capacity(args.len() + 1);
+
+            operands.push(data);
+            operands.extend(self::lower_operands(ctx, args)?);
+
+            self::emit_call(ctx, callee, operands, kind, *span)
+        }
+
+        Ast::InterfaceObject {
+            value,
+            interface,
+            target,
+            kind,
+            span,
+            ..
+        } => {
+            let data: Operand = self::lower_operand(ctx, value)?;
+            let vtable: Operand = ctx.assign_temporary(
+                Type::Ptr(None, *span),
+                Rvalue::Ref(Place::global(format!(
+                    "__thrust_vtable_{}_{}",
+                    target, interface
+                ))),
+                *span,
+            );
+
+            Ok(ctx.assign_temporary(
+                kind.clone(),
+                Rvalue::Aggregate(AggregateKind::Struct, kind.clone(), vec![data, vtable]),
+                *span,
+            ))
+        }
+
+        Ast::ModuleExpression {
+            data, values, span, ..
+        } => {
+            let kind: Type = self::get_type(expr)?.clone();
+
+            match values {
+                ModuleExpressionValues::Call { arguments, .. } => {
+                    let callee: Operand =
+                        Operand::Constant(Constant::Function(data.name.clone()), kind.clone());
+
+                    self::lower_call(ctx, callee, arguments, &kind, *span)
+                }
+
+                ModuleExpressionValues::Reference { name, .. } => {
+                    Ok(Operand::Copy(Place::global(name.clone())))
+                }
+            }
+        }
+
+        Ast::AsmValue {
+            assembler,
+            constraints,
+            args,
+            kind,
+            span,
+            ..
+        } => {
+            let args: Vec<Operand> = self::lower_operands(ctx, args)?;
+            let destination: Local = ctx.new_temporary(kind.clone(), *span);
+
+            ctx.push_statement(Statement::InlineAsm {
+                assembler: assembler.clone(),
+                constraints: constraints.clone(),
+                args,
+                destination: Place::local(destination),
+                span: *span,
+            });
+
+            Ok(Operand::Copy(Place::local(destination)))
+        }
+
+        // Operators
+        Ast::BinaryOp {
+            left,
+            operator,
+            right,
+            kind,
+            span,
+            ..
+        } => match operator {
+            TokenType::And | TokenType::Or => {
+                self::lower_short_circuit(ctx, left, operator, right, kind, *span)
+            }
+
+            TokenType::PlusEq | TokenType::MinusEq => {
+                let value: Operand = self::lower_operand(ctx, right)?;
+                let place: Place = self::lower_lvalue(ctx, left)?;
+
+                let operator: TokenType = if *operator == TokenType::PlusEq {
+                    TokenType::Plus
+                } else {
+                    TokenType::Minus
+                };
+
+                ctx.assign(
+                    place.clone(),
+                    Rvalue::Binary(operator, Operand::Copy(place.clone()), value),
+                    *span,
+                );
+
+                Ok(Operand::Copy(place))
+            }
+
+            _ => {
+                let left: Operand = self::lower_operand(ctx, left)?;
+                let right: Operand = self::lower_operand(ctx, right)?;
+
+                Ok(ctx.assign_temporary(
+                    kind.clone(),
+                    Rvalue::Binary(*operator, left, right),
+                    *span,
+                ))
+            }
+        },
+
+        Ast::UnaryOp {
+            operator,
+            node,
+            before,
+            kind,
+            span,
+            ..
+        } => match operator {
+            TokenType::PlusPlus | TokenType::MinusMinus => {
+                let place: Place = self::lower_place(ctx, node)?;
+
+                let old_value: Operand = ctx.assign_temporary(
+                    kind.clone(),
+                    Rvalue::Use(Operand::Copy(place.clone())),
+                    *span,
+                );
+
+                let operator: TokenType = if *operator == TokenType::PlusPlus {
+                    TokenType::Plus
+                } else {
+                    TokenType::Minus
+                };
+
+                let one: Operand = Operand::Constant(
+                    Constant::Integer {
+                        value: 1,
+                        signed: false,
+                    },
+                    kind.clone(),
+                );
+
+                ctx.assign(
+                    place.clone(),
+                    Rvalue::Binary(operator, old_value.clone(), one),
+                    *span,
+                );
+
+                if *before {
+                    Ok(Operand::Copy(place))
+                } else {
+                    Ok(old_value)
+                }
+            }
+
+            _ => {
+                let value: Operand = self::lower_operand(ctx, node)?;
+                Ok(ctx.assign_temporary(kind.clone(), Rvalue::Unary(*operator, value), *span))
+            }
+        },
+
+        Ast::Group { node, .. } => self::lower_operand(ctx, node),
+
+        Ast::As {
+            from, cast, span, ..
+        } => {
+            let value: Operand = self::lower_operand(ctx, from)?;
+            Ok(ctx.assign_temporary(cast.clone(), Rvalue::Cast(value, cast.clone()), *span))
+        }
+
+        // Builtins
+        Ast::Builtin { builtin, kind, .. } => self::lower_builtin(ctx, builtin, kind),
+
+        Ast::Unreachable { kind, span, .. } => {
+            ctx.terminate_and_continue(Terminator::Unreachable);
+
+            let unit: Local = ctx.new_temporary(kind.clone(), *span);
+            Ok(Operand::Copy(Place::local(unit)))
+        }
+
+        // Error Unions
+        Ast::Try {
+            expression,
+            kind,
+            span,
+            ..
+        } => {
+            let (union, code, is_error): (Place, Operand, Operand) =
+                self::lower_error_code(ctx, expression, *span)?;
+
+            let error_block: BasicBlock = ctx.new_block();
+            let ok_block: BasicBlock = ctx.new_block();
+
+            ctx.terminate(Terminator::SwitchBool {
+                condition: is_error,
+                then_block: error_block,
+                else_block: ok_block,
+            });
+
+            ctx.switch_to(error_block);
+            ctx.assign(
+                Place::local(Local::RETURN_PLACE)
+                    .project(Projection::Field(0, Type::U32 { span: *span })),
+                Rvalue::Use(code),
+                *span,
+            );
+            ctx.terminate(Terminator::Return);
+
+            ctx.switch_to(ok_block);
+
+            if kind.is_void_type() {
+                let unit: Local = ctx.new_temporary(kind.clone(), *span);
+                return Ok(Operand::Copy(Place::local(unit)));
+            }
+
+            Ok(Operand::Copy(
+                union.project(Projection::Field(1, kind.clone())),
+            ))
+        }
+
+        Ast::Catch {
+            expression,
+            fallback,
+            kind,
+            span,
+            ..
+        } => {
+            let result: Local = ctx.new_temporary(kind.clone(), *span);
+
+            let (union, _, is_error): (Place, Operand, Operand) =
+                self::lower_error_code(ctx, expression, *span)?;
+
+            let fallback_block: BasicBlock = ctx.new_block();
+            let value_block: BasicBlock = ctx.new_block();
+            let join_block: BasicBlock = ctx.new_block();
+
+            ctx.terminate(Terminator::SwitchBool {
+                condition: is_error,
+                then_block: fallback_block,
+                else_block: value_block,
+            });
+
+            ctx.switch_to(fallback_block);
+            let fallback: Operand = self::lower_operand(ctx, fallback)?;
+
+            if !kind.is_void_type() {
+                ctx.assign(Place::local(result), Rvalue::Use(fallback), *span);
+            }
+
+            ctx.goto(join_block);
+
+            ctx.switch_to(value_block);
+
+            if !kind.is_void_type() {
+                let value: Operand =
+                    Operand::Copy(union.project(Projection::Field(1, kind.clone())));
+
+                ctx.assign(Place::local(result), Rvalue::Use(value), *span);
+            }
+
+            ctx.goto(join_block);
+
+            ctx.switch_to(join_block);
+
+            Ok(Operand::Copy(Place::local(result)))
+        }
+
+        what => Err(context::bug(
+            "Expression not lowered",
+            "Expression could not be lowered into MIR.",
+            what.get_span(),
+            std::path::PathBuf::from(file!()),
+            line!(),
+        )),
+    }
+}
+
+pub fn lower_place<'ctx>(
+    ctx: &mut MirBuilderContext<'ctx>,
+    expr: &'ctx Ast<'ctx>,
+) -> Result<Place, CompilationIssue> {
+    match expr {
+        Ast::Reference {
+            name, kind, span, ..
+        } => match ctx.resolve(name) {
+            Some(MirBinding::Local(local)) => Ok(Place::local(local)),
+            Some(MirBinding::Global(symbol)) => Ok(Place::global(symbol)),
+            None => {
+                let operand: Operand = self::lower_symbol(ctx, name, kind, *span)?;
+                Ok(self::operand_to_place(ctx, operand, kind, *span))
+            }
+        },
+
+        Ast::Property { source, data, .. } => self::lower_property_place(ctx, source, data),
+
+        Ast::Deref { value, .. }
+            if self::get_type(value).is_ok_and(|kind| kind.is_ptr_like_type()) =>
+        {
+            self::lower_pointee(ctx, value)
+        }
+
+        Ast::Group { node, .. } => self::lower_place(ctx, node),
+
+        _ => {
+            let kind: Type = self::get_type(expr)?.clone();
+            let operand: Operand = self::lower_operand(ctx, expr)?;
+
+            Ok(self::operand_to_place(ctx, operand, &kind, expr.get_span()))
+        }
+    }
+}
+
+fn lower_lvalue<'ctx>(
+    ctx: &mut MirBuilderContext<'ctx>,
+    source: &'ctx Ast<'ctx>,
+) -> Result<Place, CompilationIssue> {
+    match source {
+        Ast::Reference { .. } => self::lower_place(ctx, source),
+        _ => self::lower_pointee(ctx, source),
+    }
+}
+
+fn lower_pointee<'ctx>(
+    ctx: &mut MirBuilderContext<'ctx>,
+    pointer: &'ctx Ast<'ctx>,
+) -> Result<Place, CompilationIssue> {
+    let kind: Type = self::get_type(pointer)?.clone();
+    let operand: Operand = self::lower_operand(ctx, pointer)?;
+
+    Ok(self::operand_to_place(ctx, operand, &kind, pointer.get_span()).project(Projection::Deref))
+}
+
+fn lower_property_place<'ctx>(
+    ctx: &mut MirBuilderContext<'ctx>,
+    source: &'ctx Ast<'ctx>,
+    data: &PropertyData,
+) -> Result<Place, CompilationIssue> {
+    let mut place: Place = if self::get_type(source)?.is_ptr_composite_type() {
+        self::lower_pointee(ctx, source)?
+    } else {
+        self::lower_place(ctx, source)?
+    };
+
+    for field in data.iter() {
+        place = place.project(Projection::Field(
+            field.get_index(),
+            field.get_property_type(),
+        ));
+    }
+
+    Ok(place)
+}
+
+fn lower_index_place<'ctx>(
+    ctx: &mut MirBuilderContext<'ctx>,
+    source: &'ctx Ast<'ctx>,
+    index: &'ctx Ast<'ctx>,
+) -> Result<Place, CompilationIssue> {
+    let mut source_type: Type = self::get_type(source)?.clone();
+
+    if source_type.has_inferer_inner_type() && source_type.is_inferer_inner_type_valid() {
+        source_type = source_type.get_inferer_inner_type();
+    }
+
+    let place: Place = if source_type.is_ptr_type() {
+        self::lower_pointee(ctx, source)?
+    } else {
+        self::lower_place(ctx, source)?
+    };
+
+    let index: Operand = self::lower_operand(ctx, index)?;
+
+    Ok(place.project(Projection::Index(index)))
+}
+
+fn lower_symbol(
+    ctx: &mut MirBuilderContext,
+    name: &str,
+    kind: &Type,
+    span: Span,
+) -> Result<Operand, CompilationIssue> {
+    match ctx.resolve(name) {
+        Some(MirBinding::Local(local)) => Ok(Operand::Copy(Place::local(local))),
+        Some(MirBinding::Global(symbol)) => Ok(Operand::Copy(Place::global(symbol))),
+
+        None if ctx.is_function(name) => Ok(Operand::Constant(
+            Constant::Function(name.to_string()),
+            kind.clone(),
+        )),
+
+        None => Err(context::bug(
+            "Symbol not found",
+            &format!(
+                "The symbol '{}' could not be resolved while building MIR.",
+                name
+            ),
+            span,
+            std::path::PathBuf::from(file!()),
+            line!(),
+        )),
+    }
+}
+
+fn lower_call<'ctx>(
+    ctx: &mut MirBuilderContext<'ctx>,
+    callee: Operand,
+    args: &'ctx [Ast<'ctx>],
+    kind: &Type,
+    span: Span,
+) -> Result<Operand, CompilationIssue> {
+    let args: Vec<Operand> = self::lower_operands(ctx, args)?;
+
+    self::emit_call(ctx, callee, args, kind, span)
+}
+
+fn emit_call(
+    ctx: &mut MirBuilderContext,
+    callee: Operand,
+    args: Vec<Operand>,
+    kind: &Type,
+    span: Span,
+) -> Result<Operand, CompilationIssue> {
+    let destination: Local = ctx.new_temporary(kind.clone(), span);
+    let target: BasicBlock = ctx.new_block();
+
+    ctx.terminate(Terminator::Call {
+        callee,
+        args,
+        destination: Place::local(destination),
+        target,
+        span,
+    });
+
+    ctx.switch_to(target);
+
+    Ok(Operand::Copy(Place::local(destination)))
+}
+
+fn lower_short_circuit<'ctx>(
+    ctx: &mut MirBuilderContext<'ctx>,
+    left: &'ctx Ast<'ctx>,
+    operator: &TokenType,
+    right: &'ctx Ast<'ctx>,
+    kind: &Type,
+    span: Span,
+) -> Result<Operand, CompilationIssue> {
+    let result: Local = ctx.new_temporary(kind.clone(), span);
+
+    let left: Operand = self::lower_operand(ctx, left)?;
+
+    let right_block: BasicBlock = ctx.new_block();
+    let short_block: BasicBlock = ctx.new_block();
+    let join_block: BasicBlock = ctx.new_block();
+
+    let is_and: bool = *operator == TokenType::And;
+
+    let (then_block, else_block): (BasicBlock, BasicBlock) = if is_and {
+        (right_block, short_block)
+    } else {
+        (short_block, right_block)
+    };
+
+    ctx.terminate(Terminator::SwitchBool {
+        condition: left,
+        then_block,
+        else_block,
+    });
+
+    ctx.switch_to(short_block);
+    ctx.assign(
+        Place::local(result),
+        Rvalue::Use(Operand::Constant(Constant::Boolean(!is_and), kind.clone())),
+        span,
+    );
+    ctx.goto(join_block);
+
+    ctx.switch_to(right_block);
+    let right: Operand = self::lower_operand(ctx, right)?;
+    ctx.assign(Place::local(result), Rvalue::Use(right), span);
+    ctx.goto(join_block);
+
+    ctx.switch_to(join_block);
+
+    Ok(Operand::Copy(Place::local(result)))
+}
+
+fn lower_builtin<'ctx>(
+    ctx: &mut MirBuilderContext<'ctx>,
+    builtin: &'ctx AstBuiltin<'ctx>,
+    kind: &Type,
+) -> Result<Operand, CompilationIssue> {
+    match builtin {
+        AstBuiltin::Halloc { of, span } => {
+            Ok(ctx.assign_temporary(kind.clone(), Rvalue::HeapAlloc(of.clone()), *span))
+        }
+
+        AstBuiltin::MemCpy {
+            src,
+            dst,
+            size,
+            span,
+        } => self::lower_builtin_call(ctx, "memcpy", [&**dst, &**src, &**size], kind, *span),
+
+        AstBuiltin::MemMove {
+            src,
+            dst,
+            size,
+            span,
+        } => self::lower_builtin_call(ctx, "memmove", [&**dst, &**src, &**size], kind, *span),
+
+        AstBuiltin::MemSet {
+            dst,
+            new_size,
+            size,
+            span,
+        } => self::lower_builtin_call(ctx, "memset", [&**dst, &**new_size, &**size], kind, *span),
+
+        AstBuiltin::Splat { value, span, .. } => {
+            let value: Operand = self::lower_operand(ctx, value)?;
+            let lanes: usize = kind.get_vector_lanes() as usize;
+
+            Ok(ctx.assign_temporary(
+                kind.clone(),
+                Rvalue::Aggregate(AggregateKind::Array, kind.clone(), vec![value; lanes]),
+                *span,
+            ))
+        }
+
+        AstBuiltin::Shuffle {
+            left, right, span, ..
+        } => self::lower_builtin_call(ctx, "shuffle", [&**left, &**right], kind, *span),
+
+        AstBuiltin::VaStart { span } => self::lower_builtin_call(ctx, "va_start", [], kind, *span),
+
+        AstBuiltin::VaArg { list, span, .. } => {
+            self::lower_builtin_call(ctx, "va_arg", [&**list], kind, *span)
+        }
+
+        AstBuiltin::VaEnd { list, span } => {
+            self::lower_builtin_call(ctx, "va_end", [&**list], kind, *span)
+        }
+
+        AstBuiltin::VaCopy { list, span } => {
+            self::lower_builtin_call(ctx, "va_copy", [&**list], kind, *span)
+        }
+
+        AstBuiltin::Print { args, span, .. } => {
+            self::lower_builtin_call(ctx, "print", args.iter(), kind, *span)
+        }
+
+        AstBuiltin::SizeOf { of, .. } | AstBuiltin::AbiSizeOf { of, .. } => Ok(Operand::Constant(
+            Constant::SizeOf(of.clone()),
+            kind.clone(),
+        )),
+
+        AstBuiltin::BitSizeOf { of, .. } => Ok(Operand::Constant(
+            Constant::BitSizeOf(of.clone()),
+            kind.clone(),
+        )),
+
+        AstBuiltin::AlignOf { of, .. } | AstBuiltin::AbiAlignOf { of, .. } => Ok(
+            Operand::Constant(Constant::AlignOf(of.clone()), kind.clone()),
+        ),
+    }
+}
+
+fn lower_builtin_call<'ctx, I>(
+    ctx: &mut MirBuilderContext<'ctx>,
+    name: &str,
+    args: I,
+    kind: &Type,
+    span: Span,
+) -> Result<Operand, CompilationIssue>
+where
+    I: IntoIterator<Item = &'ctx Ast<'ctx>>,
+{
+    let mut operands: Vec<Operand> = Vec::with_capacity(u8::MAX as usize);
+
+    for arg in args {
+        operands.push(self::lower_operand(ctx, arg)?);
+    }
+
+    let callee: Operand = Operand::Constant(Constant::Function(name.to_string()), kind.clone());
+
+    let destination: Local = ctx.new_temporary(kind.clone(), span);
+    let target: BasicBlock = ctx.new_block();
+
+    ctx.terminate(Terminator::Call {
+        callee,
+        args: operands,
+        destination: Place::local(destination),
+        target,
+        span,
+    });
+
+    ctx.switch_to(target);
+
+    Ok(Operand::Copy(Place::local(destination)))
+}
+
+fn lower_constructor<'ctx>(
+    ctx: &mut MirBuilderContext<'ctx>,
+    data: &'ctx ConstructorData<'ctx>,
+) -> Result<Vec<Operand>, CompilationIssue> {
+    let mut fields: Vec<Operand> = Vec::with_capacity(data.len());
+
+    for (_, value, ..) in data.iter() {
+        fields.push(self::lower_operand(ctx, value)?);
+    }
+
+    Ok(fields)
+}
+
+fn lower_error_code<'ctx>(
+    ctx: &mut MirBuilderContext<'ctx>,
+    expression: &'ctx Ast<'ctx>,
+    span: Span,
+) -> Result<(Place, Operand, Operand), CompilationIssue> {
+    let union_type: &Type = self::get_type(expression)?;
+
+    let value: Operand = self::lower_operand(ctx, expression)?;
+    let union: Place = self::operand_to_place(ctx, value, union_type, span);
+
+    let code_type: Type = Type::U32 { span };
+
+    let code: Operand = Operand::Copy(
+        union
+            .clone()
+            .project(Projection::Field(0, code_type.clone())),
+    );
+
+    let is_error: Operand = ctx.assign_temporary(
+        Type::Bool(span),
+        Rvalue::Binary(
+            TokenType::BangEq,
+            code.clone(),
+            Operand::Constant(
+                Constant::Integer {
+                    value: 0,
+                    signed: false,
+                },
+                code_type,
+            ),
+        ),
+        span,
+    );
+
+    Ok((union, code, is_error))
+}
+
+fn lower_operands<'ctx>(
+    ctx: &mut MirBuilderContext<'ctx>,
+    exprs: &'ctx [Ast<'ctx>],
+) -> Result<Vec<Operand>, CompilationIssue> {
+    let mut operands: Vec<Operand> = Vec::with_capacity(exprs.len());
+
+    for expr in exprs.iter() {
+        operands.push(self::lower_operand(ctx, expr)?);
+    }
+
+    Ok(operands)
+}
+
+fn operand_to_place(
+    ctx: &mut MirBuilderContext,
+    operand: Operand,
+    kind: &Type,
+    span: Span,
+) -> Place {
+    match operand {
+        Operand::Copy(place) => place,
+        constant => {
+            let temporary: Local = ctx.new_temporary(kind.clone(), span);
+            ctx.assign(Place::local(temporary), Rvalue::Use(constant), span);
+
+            Place::local(temporary)
+        }
+    }
+}
+
+#[inline]
+pub fn get_type<'ctx>(expr: &'ctx Ast) -> Result<&'ctx Type, CompilationIssue> {
+    expr.get_any_type()
+}
@@ -0,0 +1,187 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+
+use ahash::{AHashMap as HashMap, AHashSet as HashSet};
+
+use thrustc_ast::Ast;
+use thrustc_diagnostician::Diagnostician;
+use thrustc_errors::CompilationIssue;
+use thrustc_mir::{
+    body::{MirBody, Terminator},
+    verifier::{MirVerifier, MirVerifierIssue},
+};
+use thrustc_options::{CompilationUnit, CompilerOptions};
+use thrustc_typesystem::traits::TypeIsExtensions;
+
+use crate::context::MirBuilderContext;
+
+mod context;
+mod expressions;
+mod statements;
+
+#[derive(Debug)]
+pub struct MirBuilder<'mir> {
+    ast: &'mir [Ast<'mir>],
+    bodies: Vec<MirBody>,
+
+    bugs: Vec<CompilationIssue>,
+
+    diagnostician: Diagnostician,
+}
+
+impl<'mir> MirBuilder<'mir> {
+    #[inline]
+    pub fn new(
+        ast: &'mir [Ast<'mir>],
+        file: &'mir CompilationUnit,
+        options: &CompilerOptions,
+    ) -> Self {
+        Self {
+            ast,
+            bodies: Vec::with_capacity(u8::MAX as usize),
+
+            bugs: Vec::with_capacity(u8::MAX as usize),
+
+            diagnostician: Diagnostician::new(file, options),
+        }
+    }
+}
+
+impl<'mir> MirBuilder<'mir> {
+    pub fn build(&mut self) -> bool {
+        let ast: &'mir [Ast<'mir>] = self.ast;
+
+        let mut functions: HashSet<&str> = HashSet::with_capacity(u8::MAX as usize);
+        let mut globals: HashMap<&str, String> = HashMap::with_capacity(u8::MAX as usize);
+
+        for node in ast.iter() {
+            match node {
+                Ast::Function { name, .. }
+                | Ast::AssemblerFunction { name, .. }
+                | Ast::Intrinsic { name, .. } => {
+                    functions.insert(*name);
+                }
+
+                Ast::Static {
+                    name, ascii_name, ..
+                }
+                | Ast::Const {
+                    name, ascii_name, ..
+                } => {
+                    globals.insert(*name, ascii_name.to_string());
+                }
+
+                _ => (),
+            }
+        }
+
+        for node in ast.iter() {
+            if let Err(error) = self.build_function(node, &functions, &globals) {
+                self.bugs.push(error);
+            }
+        }
+
+        self.check()
+    }
+}
+
+impl<'mir> MirBuilder<'mir> {
+    fn build_function(
+        &mut self,
+        node: &'mir Ast<'mir>,
+        functions: &HashSet<&str>,
+        globals: &HashMap<&str, String>,
+    ) -> Result<(), CompilationIssue> {
+        let Ast::Function {
+            name,
+            parameters,
+            body: Some(body),
+            return_type,
+            span,
+            ..
+        } = node
+        else {
+            return Ok(());
+        };
+
+        let mut ctx: MirBuilderContext<'_> =
+            MirBuilderContext::new(name, return_type.clone(), *span, functions, globals);
+
+        for parameter in parameters.iter() {
+            if let Ast::FunctionParameter {
+                name,
+                kind,
+                metadata,
+                span,
+                ..
+            } = parameter
+            {
+                ctx.push_argument(name, kind.clone(), metadata.is_mutable(), *span);
+            }
+        }
+
+        statements::lower_block(&mut ctx, body)?;
+
+        if ctx.get_return_type().is_void_type() {
+            ctx.terminate(Terminator::Return);
+        } else {
+            ctx.terminate(Terminator::Unreachable);
+        }
+
+        let body: MirBody = ctx.finish();
+
+        if let Err(issues) = MirVerifier::new(&body).verify() {
+            issues.iter().for_each(|issue: &MirVerifierIssue| {
+                self.bugs.push(context::bug(
+                    "Invalid MIR",
+                    issue.get_message(),
+                    issue.get_span(),
+                    std::path::PathBuf::from(file!()),
+                    line!(),
+                ));
+            });
+        }
+
+        self.bodies.push(body);
+
+        Ok(())
+    }
+}
+
+impl MirBuilder<'_> {
+    fn check(&mut self) -> bool {
+        if !self.bugs.is_empty() {
+            self.bugs.iter().for_each(|bug| {
+                self.diagnostician
+                    .dispatch_diagnostic(bug, thrustc_logging::LoggingType::Bug);
+            });
+
+            return true;
+        }
+
+        false
+    }
+}
+
+impl MirBuilder<'_> {
+    #[inline]
+    pub fn get_bodies(&self) -> &[MirBody] {
+        &self.bodies
+    }
+}
@@ -0,0 +1,423 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+
+use thrustc_ast::Ast;
+use thrustc_errors::CompilationIssue;
+use thrustc_mir::body::{
+    BasicBlock, Constant, Local, Operand, Place, Projection, Rvalue, Terminator,
+};
+use thrustc_span::Span;
+use thrustc_typesystem::{
+    Type,
+    traits::{TypeErrorUnionExtensions, TypeIsExtensions},
+};
+
+use crate::{
+    context::{self, MirBinding, MirBuilderContext, MirLoopScope},
+    expressions,
+};
+
+pub fn lower_block<'ctx>(
+    ctx: &mut MirBuilderContext<'ctx>,
+    node: &'ctx Ast<'ctx>,
+) -> Result<(), CompilationIssue> {
+    match node {
+        Ast::Block { nodes, post, .. } => {
+            ctx.begin_scope();
+
+            let nodes_size: usize = nodes.len();
+
+            for (idx, node) in nodes.iter().enumerate() {
+                let is_final_node: bool = idx == nodes_size.saturating_sub(1);
+
+                if is_final_node {
+                    for postnode in post.iter() {
+                        self::lower_statement(ctx, postnode)?;
+                    }
+                }
+
+                self::lower_statement(ctx, node)?;
+            }
+
+            ctx.end_scope();
+
+            Ok(())
+        }
+
+        node => self::lower_statement(ctx, node),
+    }
+}
+
+pub fn lower_statement<'ctx>(
+    ctx: &mut MirBuilderContext<'ctx>,
+    node: &'ctx Ast<'ctx>,
+) -> Result<(), CompilationIssue> {
+    match node {
+        Ast::Block { .. } => self::lower_block(ctx, node),
+
+        Ast::Defer { node, .. } => self::lower_statement(ctx, node),
+
+        // Conditionals
+        Ast::If {
+            condition,
+            then_branch,
+            else_if_branch,
+            else_branch,
+            ..
+        } => {
+            let join_block: BasicBlock = ctx.new_block();
+
+            self::lower_conditional(ctx, condition, then_branch, join_block)?;
+
+            for elif in else_if_branch.iter() {
+                if let Ast::Elif {
+                    condition, block, ..
+                } = elif
+                {
+                    self::lower_conditional(ctx, condition, block, join_block)?;
+                }
+            }
+
+            if let Some(Ast::Else { block, .. }) = else_branch.as_deref() {
+                self::lower_block(ctx, block)?;
+            }
+
+            ctx.goto(join_block);
+            ctx.switch_to(join_block);
+
+            Ok(())
+        }
+
+        // Loops
+        Ast::While {
+            variable,
+            condition,
+            block,
+            ..
+        } => {
+            ctx.begin_scope();
+
+            if let Some(variable) = variable {
+                self::lower_statement(ctx, variable)?;
+            }
+
+            let header_block: BasicBlock = ctx.new_block();
+            let exit_block: BasicBlock = ctx.new_block();
+
+            ctx.goto(header_block);
+            ctx.switch_to(header_block);
+
+            self::lower_loop_condition(ctx, condition, exit_block)?;
+            self::lower_loop_body(ctx, block, header_block, exit_block)?;
+
+            ctx.goto(header_block);
+            ctx.switch_to(exit_block);
+            ctx.end_scope();
+
+            Ok(())
+        }
+
+        Ast::For {
+            local,
+            condition,
+            actions,
+            block,
+            ..
+        } => {
+            ctx.begin_scope();
+
+            self::lower_statement(ctx, local)?;
+
+            let header_block: BasicBlock = ctx.new_block();
+            let latch_block: BasicBlock = ctx.new_block();
+            let exit_block: BasicBlock = ctx.new_block();
+
+            ctx.goto(header_block);
+            ctx.switch_to(header_block);
+
+            self::lower_loop_condition(ctx, condition, exit_block)?;
+            self::lower_loop_body(ctx, block, latch_block, exit_block)?;
+
+            ctx.goto(latch_block);
+            ctx.switch_to(latch_block);
+
+            expressions::lower_operand(ctx, actions)?;
+
+            ctx.goto(header_block);
+            ctx.switch_to(exit_block);
+            ctx.end_scope();
+
+            Ok(())
+        }
+
+        Ast::Loop { block, .. } => {
+            let body_block: BasicBlock = ctx.new_block();
+            let exit_block: BasicBlock = ctx.new_block();
+
+            ctx.goto(body_block);
+            ctx.switch_to(body_block);
+
+            self::lower_loop_body(ctx, block, body_block, exit_block)?;
+
+            ctx.goto(body_block);
+            ctx.switch_to(exit_block);
+
+            Ok(())
+        }
+
+        // Loop control flow
+        Ast::Break { span, .. } => {
+            let scope: MirLoopScope = self::get_loop(ctx.get_innermost_loop(), *span)?;
+            ctx.terminate_and_continue(Terminator::Goto(scope.get_break_block()));
+
+            Ok(())
+        }
+
+        Ast::Continue { span, .. } => {
+            let scope: MirLoopScope = self::get_loop(ctx.get_innermost_loop(), *span)?;
+            ctx.terminate_and_continue(Terminator::Goto(scope.get_continue_block()));
+
+            Ok(())
+        }
+
+        Ast::BreakAll { span, .. } => {
+            let scope: MirLoopScope = self::get_loop(ctx.get_outermost_loop(), *span)?;
+            ctx.terminate_and_continue(Terminator::Goto(scope.get_break_block()));
+
+            Ok(())
+        }
+
+        Ast::ContinueAll { span, .. } => {
+            let scope: MirLoopScope = self::get_loop(ctx.get_outermost_loop(), *span)?;
+            ctx.terminate_and_continue(Terminator::Goto(scope.get_continue_block()));
+
+            Ok(())
+        }
+
+        // Functions
+        Ast::Return {
+            expression, span, ..
+        } => {
+            let is_error_union: bool = ctx.get_return_type().is_error_union_type();
+
+            match expression {
+                Some(expression)
+                    if is_error_union
+                        && !expressions::get_type(expression)?.is_error_union_type() =>
+                {
+                    let value_type: Type =
+                        ctx.get_return_type().get_error_union_value_type().clone();
+                    let value: Operand = expressions::lower_operand(ctx, expression)?;
+
+                    self::assign_error_code(ctx, 0, *span);
+
+                    ctx.assign(
+                        Place::local(Local::RETURN_PLACE).project(Projection::Field(1, value_type)),
+                        Rvalue::Use(value),
+                        *span,
+                    );
+                }
+
+                Some(expression) => {
+                    let value: Operand = expressions::lower_operand(ctx, expression)?;
+                    ctx.assign(Place::local(Local::RETURN_PLACE), Rvalue::Use(value), *span);
+                }
+
+                None if is_error_union => self::assign_error_code(ctx, 0, *span),
+                None => (),
+            }
+
+            ctx.terminate_and_continue(Terminator::Return);
+
+            Ok(())
+        }
+
+        Ast::Raise {
+            expression, span, ..
+        } => {
+            let code: Operand = expressions::lower_operand(ctx, expression)?;
+
+            ctx.assign(
+                Place::local(Local::RETURN_PLACE)
+                    .project(Projection::Field(0, Type::U32 { span: *span })),
+                Rvalue::Use(code),
+                *span,
+            );
+
+            ctx.terminate_and_continue(Terminator::Return);
+
+            Ok(())
+        }
+
+        // Declarations
+        Ast::Var {
+            name,
+            kind,
+            value,
+            metadata,
+            span,
+            ..
+        } => {
+            let value: Option<Operand> = match value {
+                Some(value) if !metadata.is_undefined() => {
+                    Some(expressions::lower_operand(ctx, value)?)
+                }
+                _ => None,
+            };
+
+            let local: Local = ctx.new_local(name, kind.clone(), metadata.is_mutable(), *span);
+
+            if let Some(value) = value {
+                ctx.assign(Place::local(local), Rvalue::Use(value), *span);
+            }
+
+            Ok(())
+        }
+
+        Ast::Const {
+            name,
+            kind,
+            value,
+            span,
+            ..
+        } => {
+            let value: Operand = expressions::lower_operand(ctx, value)?;
+            let local: Local = ctx.new_local(name, kind.clone(), false, *span);
+
+            ctx.assign(Place::local(local), Rvalue::Use(value), *span);
+
+            Ok(())
+        }
+
+        Ast::Static {
+            name, ascii_name, ..
+        } => {
+            ctx.bind(name, MirBinding::Global(ascii_name.to_string()));
+            Ok(())
+        }
+
+        // Type declarations
+        Ast::Struct { .. }
+        | Ast::Enum { .. }
+        | Ast::CustomType { .. }
+        | Ast::Embedded { .. }
+        | Ast::Import { .. }
+        | Ast::ImportC { .. } => Ok(()),
+
+        Ast::GlobalAssembler { span, .. } => Err(context::bug(
+            "Global assembler inside a function",
+            "Global assembler can't be lowered inside a function body.",
+            *span,
+            std::path::PathBuf::from(file!()),
+            line!(),
+        )),
+
+        // Expressions as statements
+        node => {
+            expressions::lower_operand(ctx, node)?;
+            Ok(())
+        }
+    }
+}
+
+fn lower_conditional<'ctx>(
+    ctx: &mut MirBuilderContext<'ctx>,
+    condition: &'ctx Ast<'ctx>,
+    block: &'ctx Ast<'ctx>,
+    join_block: BasicBlock,
+) -> Result<(), CompilationIssue> {
+    let condition: Operand = expressions::lower_operand(ctx, condition)?;
+
+    let then_block: BasicBlock = ctx.new_block();
+    let else_block: BasicBlock = ctx.new_block();
+
+    ctx.terminate(Terminator::SwitchBool {
+        condition,
+        then_block,
+        else_block,
+    });
+
+    ctx.switch_to(then_block);
+    self::lower_block(ctx, block)?;
+    ctx.goto(join_block);
+
+    ctx.switch_to(else_block);
+
+    Ok(())
+}
+
+fn lower_loop_condition<'ctx>(
+    ctx: &mut MirBuilderContext<'ctx>,
+    condition: &'ctx Ast<'ctx>,
+    exit_block: BasicBlock,
+) -> Result<(), CompilationIssue> {
+    let condition: Operand = expressions::lower_operand(ctx, condition)?;
+    let body_block: BasicBlock = ctx.new_block();
+
+    ctx.terminate(Terminator::SwitchBool {
+        condition,
+        then_block: body_block,
+        else_block: exit_block,
+    });
+
+    ctx.switch_to(body_block);
+
+    Ok(())
+}
+
+fn lower_loop_body<'ctx>(
+    ctx: &mut MirBuilderContext<'ctx>,
+    block: &'ctx Ast<'ctx>,
+    continue_block: BasicBlock,
+    break_block: BasicBlock,
+) -> Result<(), CompilationIssue> {
+    ctx.begin_loop(continue_block, break_block);
+    let result: Result<(), CompilationIssue> = self::lower_block(ctx, block);
+    ctx.end_loop();
+
+    result
+}
+
+fn get_loop(scope: Option<MirLoopScope>, span: Span) -> Result<MirLoopScope, CompilationIssue> {
+    scope.ok_or_else(|| {
+        context::bug(
+            "Loop control flow outside of a loop",
+            "The loop control flow target could not be obtained.",
+            span,
+            std::path::PathBuf::from(file!()),
+            line!(),
+        )
+    })
+}
+
+fn assign_error_code(ctx: &mut MirBuilderContext, code: u128, span: Span) {
+    let code_type: Type = Type::U32 { span };
+
+    ctx.assign(
+        Place::local(Local::RETURN_PLACE).project(Projection::Field(0, code_type.clone())),
+        Rvalue::Use(Operand::Constant(
+            Constant::Integer {
+                value: code,
+                signed: false,
+            },
+            code_type,
+        )),
+        span,
+    );
+}
@@ -18,8 +18,10 @@
 */
 
 pub mod linkage;
+pub mod warnings;
 
 use crate::linkage::LinkingCompilersConfiguration;
+use crate::warnings::WarningControl;
 use thrustc_backends::llvm::LLVMBackend;
 
 use inkwell::targets::CodeModel;
@@ -38,15 +40,18 @@ pub struct CompilerOptions {
     compiler_tools_path: PathBuf,
 
     llvm: bool,
+    c: bool,
     llvm_backend: LLVMBackend,
     files: Vec<CompilationUnit>,
     build_dir: PathBuf,
 
     emit: Vec<EmitableUnit>,
     printable: Vec<PrintableUnit>,
+    cfg: Vec<(String, String)>,
 
     enable_ansi_colors: bool,
     omit_default_optimizations: bool,
+    apply_fixes: bool,
 
     export_diagnostics_path: PathBuf,
     export_compiler_error_diagnostics: bool,
@@ -62,8 +67,12 @@ pub struct CompilerOptions {
     clean_build: bool,
     obfuscate_archive_names: bool,
     obfuscate_ir: bool,
+    deterministic: bool,
+    source_date_epoch: Option<u64>,
+    freestanding: bool,
 
     linking_compilers_config: LinkingCompilersConfiguration,
+    warning_control: WarningControl,
     build_id: uuid::Uuid,
 }
 
@@ -87,6 +96,9 @@ pub enum EmitableUnit {
     UnCheckedAst,
     Ast,
     Tokens,
+    CHeader,
+    CSource,
+    DepInfo,
 }
 
 #[derive(Debug, PartialEq)]
@@ -98,6 +110,7 @@ pub enum PrintableUnit {
     Tokens,
     UnCheckedAst,
     Ast,
+    Mir,
 }
 
 #[derive(Debug)]
@@ -125,16 +138,19 @@ impl CompilerOptions {
             compiler_tools_path: PathBuf::new(),
 
             llvm: true,
+            c: false,
             llvm_backend: LLVMBackend::new(),
             files: Vec::with_capacity(u8::MAX as usize),
 
             emit: Vec::with_capacity(u8::MAX as usize),
             printable: Vec::with_capacity(u8::MAX as usize),
+            cfg: Vec::with_capacity(u8::MAX as usize),
 
             build_dir: "build".into(),
 
             enable_ansi_colors: false,
             omit_default_optimizations: false,
+            apply_fixes: false,
 
             export_diagnostics_path: "diagnostics".into(),
             export_compiler_error_diagnostics: false,
@@ -150,8 +166,12 @@ impl CompilerOptions {
             clean_build: false,
             obfuscate_archive_names: true,
             obfuscate_ir: true,
+            deterministic: std::env::var_os("SOURCE_DATE_EPOCH").is_some(),
+            source_date_epoch: None,
+            freestanding: false,
 
             linking_compilers_config: LinkingCompilersConfiguration::new(),
+            warning_control: WarningControl::new(),
             build_id: uuid::Uuid::new_v4(),
         }
     }
@@ -184,6 +204,11 @@ impl CompilerOptions {
         self.llvm = value;
     }
 
+    #[inline]
+    pub fn set_use_c_backend(&mut self, value: bool) {
+        self.c = value;
+    }
+
     #[inline]
     pub fn set_build_dir(&mut self, build_dir: PathBuf) {
         self.build_dir = build_dir;
@@ -234,11 +259,52 @@ impl CompilerOptions {
         self.obfuscate_ir = false;
     }
 
+    #[inline]
+    pub fn set_deterministic(&mut self) {
+        self.deterministic = true;
+    }
+
+    #[inline]
+    pub fn set_source_date_epoch(&mut self, epoch: u64) {
+        self.deterministic = true;
+        self.source_date_epoch = Some(epoch);
+    }
+
+    #[inline]
+    pub fn set_freestanding(&mut self) {
+        self.freestanding = true;
+    }
+
+    pub fn set_up_deterministic_build_id(&mut self) {
+        if !self.deterministic {
+            return;
+        }
+
+        let mut content: Vec<u8> = Vec::with_capacity(u8::MAX as usize);
+
+        self.files.iter().for_each(|file| {
+            content.extend_from_slice(file.get_name().as_bytes());
+            content.extend_from_slice(file.get_unit_content().as_bytes());
+        });
+
+        content.extend_from_slice(&self.get_build_timestamp().to_le_bytes());
+
+        let high: u64 = thrustc_utils::get_content_hash(&content);
+        let low: u64 = thrustc_utils::get_content_hash(&high.to_le_bytes());
+
+        self.build_id = uuid::Uuid::from_u64_pair(high, low);
+    }
+
     #[inline]
     pub fn set_enable_ansi_colors(&mut self) {
         self.enable_ansi_colors = true;
     }
 
+    #[inline]
+    pub fn set_apply_fixes(&mut self) {
+        self.apply_fixes = true;
+    }
+
     #[inline]
     pub fn set_export_diagnostic_path(&mut self, export_diagnostics_path: PathBuf) {
         self.export_diagnostics_path = export_diagnostics_path;
@@ -278,6 +344,11 @@ impl CompilerOptions {
     pub fn add_print_option(&mut self, printable: PrintableUnit) {
         self.printable.push(printable);
     }
+
+    #[inline]
+    pub fn add_cfg(&mut self, key: String, value: String) {
+        self.cfg.push((key, value));
+    }
 }
 
 impl CompilerOptions {
@@ -286,6 +357,11 @@ impl CompilerOptions {
         self.llvm
     }
 
+    #[inline]
+    pub fn c(&self) -> bool {
+        self.c
+    }
+
     #[inline]
     pub fn get_files(&self) -> &[CompilationUnit] {
         self.files.as_slice()
@@ -355,6 +431,30 @@ impl CompilerOptions {
         self.obfuscate_ir
     }
 
+    #[inline]
+    pub fn is_deterministic(&self) -> bool {
+        self.deterministic
+    }
+
+    pub fn get_build_timestamp(&self) -> u64 {
+        if let Some(epoch) = self.source_date_epoch {
+            return epoch;
+        }
+
+        if self.deterministic {
+            return 0;
+        }
+
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs())
+    }
+
+    #[inline]
+    pub fn is_freestanding(&self) -> bool {
+        self.freestanding
+    }
+
     #[inline]
     pub fn need_ansi_colors(&self) -> bool {
         self.enable_ansi_colors
@@ -365,6 +465,11 @@ impl CompilerOptions {
         self.copy_output_to_clipboard
     }
 
+    #[inline]
+    pub fn need_apply_fixes(&self) -> bool {
+        self.apply_fixes
+    }
+
     #[inline]
     pub fn get_export_diagnostics_path(&self) -> &Path {
         &self.export_diagnostics_path
@@ -415,6 +520,11 @@ impl CompilerOptions {
         &self.linking_compilers_config
     }
 
+    #[inline]
+    pub fn get_warning_control(&self) -> &WarningControl {
+        &self.warning_control
+    }
+
     #[inline]
     pub fn get_build_id(&self) -> &uuid::Uuid {
         &self.build_id
@@ -424,6 +534,11 @@ impl CompilerOptions {
     pub fn it_will_print(&self) -> bool {
         !self.printable.is_empty()
     }
+
+    #[inline]
+    pub fn get_cfg(&self) -> &[(String, String)] {
+        &self.cfg
+    }
 }
 
 impl CompilerOptions {
@@ -438,6 +553,11 @@ impl CompilerOptions {
     ) -> &mut LinkingCompilersConfiguration {
         &mut self.linking_compilers_config
     }
+
+    #[inline]
+    pub fn get_mut_warning_control(&mut self) -> &mut WarningControl {
+        &mut self.warning_control
+    }
 }
 
 impl CompilationUnit {
@@ -126,6 +126,17 @@ impl LinkingCompilersConfiguration {
     pub fn get_use_gcc(&self) -> bool {
         self.use_gcc
     }
+
+    /// Whether the linking compiler produces an executable, rather than objects or a library.
+    pub fn links_executable(&self) -> bool {
+        const NON_EXECUTABLE_OUTPUTS: &[&str] = &["-c", "-S", "-E", "-r", "-shared", "--shared"];
+
+        (self.use_clang || self.use_gcc)
+            && !self
+                .compiler_args
+                .iter()
+                .any(|arg| NON_EXECUTABLE_OUTPUTS.contains(&arg.as_str()))
+    }
 }
 
 impl LinkingCompilersConfiguration {
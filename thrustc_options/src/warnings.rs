@@ -0,0 +1,69 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+
+use thrustc_errors::CompilationIssueCode;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WarningLevel {
+    Allow,
+    Warn,
+    Deny,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct WarningControl {
+    levels: Vec<(CompilationIssueCode, WarningLevel)>,
+    warnings_as_errors: bool,
+}
+
+impl WarningControl {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            levels: Vec::with_capacity(u8::MAX as usize),
+            warnings_as_errors: false,
+        }
+    }
+}
+
+impl WarningControl {
+    #[inline]
+    pub fn set_level(&mut self, code: CompilationIssueCode, level: WarningLevel) {
+        self.levels.push((code, level));
+    }
+
+    #[inline]
+    pub fn set_warnings_as_errors(&mut self) {
+        self.warnings_as_errors = true;
+    }
+}
+
+impl WarningControl {
+    pub fn get_level(&self, code: CompilationIssueCode) -> WarningLevel {
+        if let Some((_, level)) = self.levels.iter().rev().find(|(other, _)| *other == code) {
+            return *level;
+        }
+
+        if self.warnings_as_errors {
+            return WarningLevel::Deny;
+        }
+
+        WarningLevel::Warn
+    }
+}
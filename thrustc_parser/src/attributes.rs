@@ -115,6 +115,32 @@ pub fn build_compiler_attributes<'parser>(
                 ))
             }
 
+            TokenType::Allow => {
+                ctx.consume(
+                    TokenType::Allow,
+                    CompilationIssueCode::E0001,
+                    "Expected '@allow' prologue for an attribute.".into(),
+                )?;
+
+                attributes.push(ThrustAttribute::Allow(
+                    self::build_allow_attribute(ctx)?,
+                    span,
+                ))
+            }
+
+            TokenType::Format => {
+                ctx.consume(
+                    TokenType::Format,
+                    CompilationIssueCode::E0001,
+                    "Expected '@format' prologue for an attribute.".into(),
+                )?;
+
+                attributes.push(ThrustAttribute::Format(
+                    self::build_format_attribute(ctx)?,
+                    span,
+                ))
+            }
+
             tk_type if tk_type.is_attribute() => {
                 if let Some(compiler_attribute) = thrustc_attributes::as_attribute(tk_type, span) {
                     attributes.push(compiler_attribute);
@@ -131,6 +157,113 @@ pub fn build_compiler_attributes<'parser>(
     Ok(attributes)
 }
 
+fn build_allow_attribute<'parser>(
+    ctx: &mut ParserContext<'parser>,
+) -> Result<Vec<CompilationIssueCode>, CompilationIssue> {
+    ctx.consume(
+        TokenType::LParen,
+        CompilationIssueCode::E0001,
+        "Expected '('.".into(),
+    )?;
+
+    let mut codes: Vec<CompilationIssueCode> = Vec::with_capacity(10);
+
+    loop {
+        let code_tk: &Token = ctx.consume(
+            TokenType::Identifier,
+            CompilationIssueCode::E0001,
+            "Expected a warning code.".into(),
+        )?;
+
+        let code_span: Span = code_tk.get_span();
+
+        let Some(code) = CompilationIssueCode::from_warning(code_tk.get_lexeme()) else {
+            return Err(CompilationIssue::Error(
+                CompilationIssueCode::E0012,
+                format!("Unknown warning code '{}'.", code_tk.get_lexeme()),
+                "You should pass a valid warning code, for example 'W0008'.".into(),
+                None,
+                code_span,
+            ));
+        };
+
+        codes.push(code);
+
+        if ctx.check(TokenType::RParen) {
+            break;
+        }
+
+        ctx.consume(
+            TokenType::Comma,
+            CompilationIssueCode::E0001,
+            "Expected ','.".into(),
+        )?;
+    }
+
+    ctx.consume(
+        TokenType::RParen,
+        CompilationIssueCode::E0001,
+        "Expected ')'.".into(),
+    )?;
+
+    Ok(codes)
+}
+
+fn build_format_attribute<'parser>(
+    ctx: &mut ParserContext<'parser>,
+) -> Result<u64, CompilationIssue> {
+    ctx.consume(
+        TokenType::LParen,
+        CompilationIssueCode::E0001,
+        "Expected '('.".into(),
+    )?;
+
+    let archetype_tk: &Token = ctx.consume(
+        TokenType::Identifier,
+        CompilationIssueCode::E0001,
+        "Expected a format archetype.".into(),
+    )?;
+
+    if archetype_tk.get_lexeme() != "printf" {
+        return Err(CompilationIssue::Error(
+            CompilationIssueCode::E0012,
+            format!("Unknown format archetype '{}'.", archetype_tk.get_lexeme()),
+            "You should use the 'printf' archetype.".into(),
+            None,
+            archetype_tk.get_span(),
+        ));
+    }
+
+    ctx.consume(
+        TokenType::Comma,
+        CompilationIssueCode::E0001,
+        "Expected ','.".into(),
+    )?;
+
+    let expr: Ast<'_> = expressions::parse_expr(ctx)?;
+
+    ctx.consume(
+        TokenType::RParen,
+        CompilationIssueCode::E0001,
+        "Expected ')'.".into(),
+    )?;
+
+    match expr {
+        Ast::Integer {
+            value,
+            signed: false,
+            ..
+        } if value != 0 => Ok(u64::try_from(value).unwrap_or(u64::MAX)),
+        _ => Err(CompilationIssue::Error(
+            CompilationIssueCode::E0028,
+            "Expected a literal unsigned integer greater than zero.".into(),
+            "You should pass the position of the format string parameter, starting at 1.".into(),
+            None,
+            expr.get_span(),
+        )),
+    }
+}
+
 fn build_align_attribute<'parser>(
     ctx: &mut ParserContext<'parser>,
 ) -> Result<u64, CompilationIssue> {
@@ -154,7 +287,7 @@ fn build_align_attribute<'parser>(
         ..
     } = expr
     {
-        Ok(value)
+        Ok(u64::try_from(value).unwrap_or(u64::MAX))
     } else {
         Err(CompilationIssue::Error(
             CompilationIssueCode::E0028,
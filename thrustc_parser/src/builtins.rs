@@ -17,12 +17,19 @@
 
 */
 
-use thrustc_ast::{Ast, NodeId, builtins::AstBuiltin};
+use thrustc_ast::{
+    Ast, NodeId,
+    builtins::AstBuiltin,
+    traits::{AstCodeLocation, AstGetType},
+};
 use thrustc_errors::{CompilationIssue, CompilationIssueCode};
 use thrustc_span::Span;
 use thrustc_token::{Token, traits::TokenExtensions};
 use thrustc_token_type::TokenType;
-use thrustc_typesystem::Type;
+use thrustc_typesystem::{
+    Type,
+    traits::{TypeIsExtensions, TypeVectorExtensions},
+};
 
 use crate::{ParserContext, expressions, typegeneration};
 
@@ -37,6 +44,13 @@ pub fn build_builtin<'parser>(
         TokenType::MemSet => self::build_memset(ctx),
         TokenType::MemMove => self::build_memmove(ctx),
         TokenType::MemCpy => self::build_memcpy(ctx),
+        TokenType::Splat => self::build_splat(ctx),
+        TokenType::Shuffle => self::build_shuffle(ctx),
+        TokenType::VaStart => self::build_va_start(ctx),
+        TokenType::VaArg => self::build_va_arg(ctx),
+        TokenType::VaEnd => self::build_va_end(ctx),
+        TokenType::VaCopy => self::build_va_copy(ctx),
+        TokenType::Print => self::build_print(ctx),
         TokenType::AbiSizeOf => self::build_abi_size_of(ctx),
         TokenType::BitSizeOf => self::build_bit_size_of(ctx),
         TokenType::AbiAlignOf => self::build_abi_align_of(ctx),
@@ -257,6 +271,402 @@ pub fn build_memset<'parser>(
     })
 }
 
+pub fn build_splat<'parser>(
+    ctx: &mut ParserContext<'parser>,
+) -> Result<Ast<'parser>, CompilationIssue> {
+    let splat_tk: &Token = ctx.consume(
+        TokenType::Splat,
+        CompilationIssueCode::E0001,
+        "Expected 'splat' keyword.".into(),
+    )?;
+
+    let span: Span = splat_tk.get_span();
+
+    ctx.consume(
+        TokenType::LParen,
+        CompilationIssueCode::E0001,
+        "Expected '('.".into(),
+    )?;
+
+    let of: Type = typegeneration::build_type(ctx, true)?;
+
+    if !of.is_vector_type() {
+        ctx.add_error_report(CompilationIssue::Error(
+            CompilationIssueCode::E0019,
+            format!("Expected vector type, got '{}' type.", of),
+            "You should pass a vector type like 'vec[f32; 4]'.".into(),
+            None,
+            span,
+        ));
+    }
+
+    ctx.consume(
+        TokenType::Comma,
+        CompilationIssueCode::E0001,
+        "Expected ','.".into(),
+    )?;
+
+    let value: Ast = expressions::parse_expr(ctx)?;
+
+    ctx.consume(
+        TokenType::RParen,
+        CompilationIssueCode::E0001,
+        "Expected ')'.".into(),
+    )?;
+
+    Ok(Ast::Builtin {
+        builtin: AstBuiltin::Splat {
+            of: of.clone(),
+            value: value.into(),
+            span,
+        },
+        kind: of,
+        span,
+        id: NodeId::new(),
+    })
+}
+
+pub fn build_shuffle<'parser>(
+    ctx: &mut ParserContext<'parser>,
+) -> Result<Ast<'parser>, CompilationIssue> {
+    let shuffle_tk: &Token = ctx.consume(
+        TokenType::Shuffle,
+        CompilationIssueCode::E0001,
+        "Expected 'shuffle' keyword.".into(),
+    )?;
+
+    let span: Span = shuffle_tk.get_span();
+
+    ctx.consume(
+        TokenType::LParen,
+        CompilationIssueCode::E0001,
+        "Expected '('.".into(),
+    )?;
+
+    let left: Ast = expressions::parse_expr(ctx)?;
+
+    ctx.consume(
+        TokenType::Comma,
+        CompilationIssueCode::E0001,
+        "Expected ','.".into(),
+    )?;
+
+    let right: Ast = expressions::parse_expr(ctx)?;
+
+    ctx.consume(
+        TokenType::Comma,
+        CompilationIssueCode::E0001,
+        "Expected ','.".into(),
+    )?;
+
+    ctx.consume(
+        TokenType::LBracket,
+        CompilationIssueCode::E0001,
+        "Expected '['.".into(),
+    )?;
+
+    let mut mask: Vec<u32> = Vec::with_capacity(u8::MAX as usize);
+
+    loop {
+        if ctx.check(TokenType::RBracket) {
+            break;
+        }
+
+        let lane: Ast = expressions::parse_expr(ctx)?;
+
+        if let Ast::Integer { value, .. } = lane {
+            mask.push(u32::try_from(value).unwrap_or(u32::MAX));
+        } else {
+            ctx.add_error_report(CompilationIssue::Error(
+                CompilationIssueCode::E0001,
+                "Expected literal integer value.".into(),
+                "Shuffle mask lanes must be literal integer values.".into(),
+                None,
+                lane.get_span(),
+            ));
+        }
+
+        if ctx.check(TokenType::RBracket) {
+            break;
+        }
+
+        ctx.consume(
+            TokenType::Comma,
+            CompilationIssueCode::E0001,
+            "Expected ','.".into(),
+        )?;
+    }
+
+    ctx.consume(
+        TokenType::RBracket,
+        CompilationIssueCode::E0001,
+        "Expected ']'.".into(),
+    )?;
+
+    ctx.consume(
+        TokenType::RParen,
+        CompilationIssueCode::E0001,
+        "Expected ')'.".into(),
+    )?;
+
+    let left_type: &Type = left.get_value_type()?;
+
+    let lanes: u32 = u32::try_from(mask.len()).unwrap_or_default();
+    let kind: Type = Type::Vector(left_type.get_vector_base_type().into(), lanes, span);
+
+    Ok(Ast::Builtin {
+        builtin: AstBuiltin::Shuffle {
+            left: left.into(),
+            right: right.into(),
+            mask,
+            span,
+        },
+        kind,
+        span,
+        id: NodeId::new(),
+    })
+}
+
+pub fn build_va_start<'parser>(
+    ctx: &mut ParserContext<'parser>,
+) -> Result<Ast<'parser>, CompilationIssue> {
+    let va_start_tk: &Token = ctx.consume(
+        TokenType::VaStart,
+        CompilationIssueCode::E0001,
+        "Expected 'vaStart' keyword.".into(),
+    )?;
+
+    let span: Span = va_start_tk.get_span();
+
+    ctx.consume(
+        TokenType::LParen,
+        CompilationIssueCode::E0001,
+        "Expected '('.".into(),
+    )?;
+
+    ctx.consume(
+        TokenType::RParen,
+        CompilationIssueCode::E0001,
+        "Expected ')'.".into(),
+    )?;
+
+    Ok(Ast::Builtin {
+        builtin: AstBuiltin::VaStart { span },
+        kind: Type::Ptr(None, span),
+        span,
+        id: NodeId::new(),
+    })
+}
+
+pub fn build_va_arg<'parser>(
+    ctx: &mut ParserContext<'parser>,
+) -> Result<Ast<'parser>, CompilationIssue> {
+    let va_arg_tk: &Token = ctx.consume(
+        TokenType::VaArg,
+        CompilationIssueCode::E0001,
+        "Expected 'vaArg' keyword.".into(),
+    )?;
+
+    let span: Span = va_arg_tk.get_span();
+
+    ctx.consume(
+        TokenType::LParen,
+        CompilationIssueCode::E0001,
+        "Expected '('.".into(),
+    )?;
+
+    let list: Ast = expressions::parse_expr(ctx)?;
+
+    ctx.consume(
+        TokenType::Comma,
+        CompilationIssueCode::E0001,
+        "Expected ','.".into(),
+    )?;
+
+    let of: Type = typegeneration::build_type(ctx, true)?;
+
+    ctx.consume(
+        TokenType::RParen,
+        CompilationIssueCode::E0001,
+        "Expected ')'.".into(),
+    )?;
+
+    Ok(Ast::Builtin {
+        builtin: AstBuiltin::VaArg {
+            list: list.into(),
+            of: of.clone(),
+            span,
+        },
+        kind: of,
+        span,
+        id: NodeId::new(),
+    })
+}
+
+pub fn build_va_end<'parser>(
+    ctx: &mut ParserContext<'parser>,
+) -> Result<Ast<'parser>, CompilationIssue> {
+    let va_end_tk: &Token = ctx.consume(
+        TokenType::VaEnd,
+        CompilationIssueCode::E0001,
+        "Expected 'vaEnd' keyword.".into(),
+    )?;
+
+    let span: Span = va_end_tk.get_span();
+
+    ctx.consume(
+        TokenType::LParen,
+        CompilationIssueCode::E0001,
+        "Expected '('.".into(),
+    )?;
+
+    let list: Ast = expressions::parse_expr(ctx)?;
+
+    ctx.consume(
+        TokenType::RParen,
+        CompilationIssueCode::E0001,
+        "Expected ')'.".into(),
+    )?;
+
+    Ok(Ast::Builtin {
+        builtin: AstBuiltin::VaEnd {
+            list: list.into(),
+            span,
+        },
+        kind: Type::Void(span),
+        span,
+        id: NodeId::new(),
+    })
+}
+
+pub fn build_va_copy<'parser>(
+    ctx: &mut ParserContext<'parser>,
+) -> Result<Ast<'parser>, CompilationIssue> {
+    let va_copy_tk: &Token = ctx.consume(
+        TokenType::VaCopy,
+        CompilationIssueCode::E0001,
+        "Expected 'vaCopy' keyword.".into(),
+    )?;
+
+    let span: Span = va_copy_tk.get_span();
+
+    ctx.consume(
+        TokenType::LParen,
+        CompilationIssueCode::E0001,
+        "Expected '('.".into(),
+    )?;
+
+    let list: Ast = expressions::parse_expr(ctx)?;
+
+    ctx.consume(
+        TokenType::RParen,
+        CompilationIssueCode::E0001,
+        "Expected ')'.".into(),
+    )?;
+
+    Ok(Ast::Builtin {
+        builtin: AstBuiltin::VaCopy {
+            list: list.into(),
+            span,
+        },
+        kind: Type::Ptr(None, span),
+        span,
+        id: NodeId::new(),
+    })
+}
+
+pub fn build_print<'parser>(
+    ctx: &mut ParserContext<'parser>,
+) -> Result<Ast<'parser>, CompilationIssue> {
+    let print_tk: &Token = ctx.consume(
+        TokenType::Print,
+        CompilationIssueCode::E0001,
+        "Expected 'print' keyword.".into(),
+    )?;
+
+    let span: Span = print_tk.get_span();
+
+    ctx.consume(
+        TokenType::LParen,
+        CompilationIssueCode::E0001,
+        "Expected '('.".into(),
+    )?;
+
+    let format_tk: &Token = ctx.consume(
+        TokenType::CString,
+        CompilationIssueCode::E0001,
+        "Expected format string literal.".into(),
+    )?;
+
+    let format: String = format_tk.get_lexeme().to_string();
+    let format_span: Span = format_tk.get_span();
+
+    let pieces: Vec<String> = self::split_print_format(&format, format_span)?;
+
+    let mut args: Vec<Ast> = Vec::with_capacity(u8::MAX as usize);
+
+    while ctx.check(TokenType::Comma) {
+        ctx.consume(
+            TokenType::Comma,
+            CompilationIssueCode::E0001,
+            "Expected ','.".into(),
+        )?;
+
+        args.push(expressions::parse_expr(ctx)?);
+    }
+
+    ctx.consume(
+        TokenType::RParen,
+        CompilationIssueCode::E0001,
+        "Expected ')'.".into(),
+    )?;
+
+    Ok(Ast::Builtin {
+        builtin: AstBuiltin::Print { pieces, args, span },
+        kind: Type::Void(span),
+        span,
+        id: NodeId::new(),
+    })
+}
+
+fn split_print_format(format: &str, span: Span) -> Result<Vec<String>, CompilationIssue> {
+    let mut pieces: Vec<String> = Vec::with_capacity(u8::MAX as usize);
+    let mut piece: String = String::with_capacity(format.len());
+
+    let mut chars: std::iter::Peekable<std::str::Chars> = format.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        match (ch, chars.peek()) {
+            ('{', Some('{')) | ('}', Some('}')) => {
+                chars.next();
+                piece.push(ch);
+            }
+
+            ('{', Some('}')) => {
+                chars.next();
+                pieces.push(std::mem::take(&mut piece));
+            }
+
+            ('{', _) | ('}', _) => {
+                return Err(CompilationIssue::Error(
+                    CompilationIssueCode::E0001,
+                    format!("Unmatched '{}' in format string.", ch),
+                    "Placeholders are written as '{}', use '{{' or '}}' for a literal brace."
+                        .into(),
+                    None,
+                    span,
+                ));
+            }
+
+            _ => piece.push(ch),
+        }
+    }
+
+    pieces.push(piece);
+
+    Ok(pieces)
+}
+
 pub fn build_alignof<'parser>(
     ctx: &mut ParserContext<'parser>,
 ) -> Result<Ast<'parser>, CompilationIssue> {
@@ -44,12 +44,14 @@ pub fn build_embedded<'parser>(
     let lexeme: &str = name_tk.get_lexeme();
     let span: Span = name_tk.get_span();
 
-    ctx.consume_these(
+    let path_tk: &Token = ctx.consume_these(
         &[TokenType::CNString, TokenType::CString],
         CompilationIssueCode::E0001,
         "Expected string literal.".into(),
     )?;
 
+    let literal: &str = path_tk.get_lexeme();
+
     ctx.consume(
         TokenType::SemiColon,
         CompilationIssueCode::E0001,
@@ -58,8 +60,8 @@ pub fn build_embedded<'parser>(
 
     Ok(Ast::Embedded {
         name: lexeme,
-        path: lexeme.into(),
-        literal: lexeme,
+        path: literal.into(),
+        literal,
         kind: Type::Array {
             base_type: Type::U8 { span }.into(),
             infered_type: None,
@@ -50,87 +50,12 @@ pub fn build_function<'parser>(
 
     let span: Span = function_name_tk.get_span();
 
-    ctx.consume(
-        TokenType::LParen,
-        CompilationIssueCode::E0001,
-        "Expected '('.".into(),
-    )?;
-
     let mut parameters: Vec<Ast> = Vec::with_capacity(12);
     let mut parameters_types: Vec<Type> = Vec::with_capacity(12);
-    let mut parameter_position: u32 = 0;
 
-    loop {
-        if ctx.check(TokenType::RParen) {
-            break;
-        }
+    self::build_parameters(ctx, &mut parameters, &mut parameters_types)?;
 
-        let parameter_name_tk: &Token = ctx.consume(
-            TokenType::Identifier,
-            CompilationIssueCode::E0001,
-            "Expected 'identifier'.".into(),
-        )?;
-
-        let name: &str = parameter_name_tk.get_lexeme();
-        let ascii_name: &str = parameter_name_tk.get_ascii_lexeme();
-        let span: Span = parameter_name_tk.get_span();
-
-        ctx.consume(
-            TokenType::Colon,
-            CompilationIssueCode::E0001,
-            "Expected ':'.".into(),
-        )?;
-
-        let kind: Type = typegeneration::build_type(ctx, false)?;
-        let metadata: FunctionParameterMetadata =
-            FunctionParameterMetadata::new(kind.is_ptr_like_type());
-
-        parameters_types.push(kind.clone());
-
-        parameters.push(Ast::FunctionParameter {
-            name,
-            ascii_name,
-            kind,
-            position: parameter_position,
-            metadata,
-            span,
-            id: NodeId::new(),
-        });
-
-        parameter_position = parameter_position.saturating_add(1);
-
-        if ctx.check(TokenType::RParen) {
-            break;
-        } else {
-            ctx.consume(
-                TokenType::Comma,
-                CompilationIssueCode::E0001,
-                "Expected ','.".into(),
-            )?;
-        }
-    }
-
-    ctx.consume(
-        TokenType::RParen,
-        CompilationIssueCode::E0001,
-        "Expected ')'.".into(),
-    )?;
-
-    let return_type: Type = if ctx.check(TokenType::LBrace) || ctx.peek().get_type().is_attribute()
-    {
-        let peeked: &Token = ctx.peek();
-        let peeked_type: TokenType = peeked.get_type();
-
-        let span: Span = if peeked_type.is_attribute() {
-            peeked.get_span()
-        } else {
-            ctx.previous().get_span()
-        };
-
-        Type::Void(span)
-    } else {
-        typegeneration::build_type(ctx, false)?
-    };
+    let return_type: Type = self::build_return_type(ctx)?;
 
     let attributes: ThrustAttributes =
         attributes::build_compiler_attributes(ctx, &[TokenType::SemiColon, TokenType::LBrace])?;
@@ -211,3 +136,100 @@ pub fn build_function<'parser>(
         Ok(proto)
     }
 }
+
+pub fn build_parameters<'parser>(
+    ctx: &mut ParserContext<'parser>,
+    parameters: &mut Vec<Ast<'parser>>,
+    parameters_types: &mut Vec<Type>,
+) -> Result<(), CompilationIssue> {
+    ctx.consume(
+        TokenType::LParen,
+        CompilationIssueCode::E0001,
+        "Expected '('.".into(),
+    )?;
+
+    let mut parameter_position: u32 = u32::try_from(parameters.len()).unwrap_or(u32::MAX);
+
+    loop {
+        if ctx.check(TokenType::RParen) {
+            break;
+        }
+
+        let parameter_name_tk: &Token = ctx.consume(
+            TokenType::Identifier,
+            CompilationIssueCode::E0001,
+            "Expected 'identifier'.".into(),
+        )?;
+
+        let name: &str = parameter_name_tk.get_lexeme();
+        let ascii_name: &str = parameter_name_tk.get_ascii_lexeme();
+        let span: Span = parameter_name_tk.get_span();
+
+        ctx.consume(
+            TokenType::Colon,
+            CompilationIssueCode::E0001,
+            "Expected ':'.".into(),
+        )?;
+
+        let kind: Type = typegeneration::build_type(ctx, false)?;
+        let metadata: FunctionParameterMetadata =
+            FunctionParameterMetadata::new(kind.is_ptr_like_type());
+
+        parameters_types.push(kind.clone());
+
+        parameters.push(Ast::FunctionParameter {
+            name,
+            ascii_name,
+            kind,
+            position: parameter_position,
+            metadata,
+            span,
+            id: NodeId::new(),
+        });
+
+        parameter_position = parameter_position.saturating_add(1);
+
+        if ctx.check(TokenType::RParen) {
+            break;
+        } else {
+            ctx.consume(
+                TokenType::Comma,
+                CompilationIssueCode::E0001,
+                "Expected ','.".into(),
+            )?;
+        }
+    }
+
+    ctx.consume(
+        TokenType::RParen,
+        CompilationIssueCode::E0001,
+        "Expected ')'.".into(),
+    )?;
+
+    Ok(())
+}
+
+pub fn build_return_type(ctx: &mut ParserContext<'_>) -> Result<Type, CompilationIssue> {
+    let return_type: Type = if ctx.check(TokenType::LBrace) || ctx.peek().get_type().is_attribute()
+    {
+        let peeked: &Token = ctx.peek();
+        let peeked_type: TokenType = peeked.get_type();
+
+        let span: Span = if peeked_type.is_attribute() {
+            peeked.get_span()
+        } else {
+            ctx.previous().get_span()
+        };
+
+        Type::Void(span)
+    } else if ctx.match_token(TokenType::Bang)? {
+        let span: Span = ctx.previous().get_span();
+        let value_type: Type = typegeneration::build_type(ctx, false)?;
+
+        Type::ErrorUnion(value_type.into(), span)
+    } else {
+        typegeneration::build_type(ctx, false)?
+    };
+
+    Ok(return_type)
+}
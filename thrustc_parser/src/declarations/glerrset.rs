@@ -0,0 +1,118 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+
+use thrustc_ast::{Ast, NodeId, data::EnumData};
+use thrustc_attributes::ThrustAttributes;
+use thrustc_errors::{CompilationIssue, CompilationIssueCode};
+use thrustc_span::Span;
+use thrustc_token::{Token, traits::TokenExtensions};
+use thrustc_token_type::TokenType;
+use thrustc_typesystem::Type;
+
+use crate::{ParserContext, attributes};
+
+pub fn build_error_set<'parser>(
+    ctx: &mut ParserContext<'parser>,
+    parse_forward: bool,
+) -> Result<Ast<'parser>, CompilationIssue> {
+    ctx.consume(
+        TokenType::ErrSet,
+        CompilationIssueCode::E0001,
+        "Expected 'errset'.".into(),
+    )?;
+
+    let name_tk: &Token = ctx.consume(
+        TokenType::Identifier,
+        CompilationIssueCode::E0001,
+        "Expected identifier.".into(),
+    )?;
+
+    let name: &str = name_tk.get_lexeme();
+    let span: Span = name_tk.get_span();
+
+    let attributes: ThrustAttributes =
+        attributes::build_compiler_attributes(ctx, &[TokenType::LBrace])?;
+
+    ctx.consume(
+        TokenType::LBrace,
+        CompilationIssueCode::E0001,
+        "Expected '{'.".into(),
+    )?;
+
+    let mut data: EnumData = EnumData::with_capacity(u8::MAX as usize);
+    let mut code: u128 = 1;
+
+    loop {
+        if ctx.check(TokenType::RBrace) {
+            break;
+        }
+
+        if ctx.match_token(TokenType::Identifier)? {
+            let error_tk: &Token = ctx.previous();
+
+            let error_name: &str = error_tk.get_lexeme();
+            let error_span: Span = error_tk.get_span();
+
+            ctx.consume(
+                TokenType::SemiColon,
+                CompilationIssueCode::E0001,
+                "Expected ';'.".into(),
+            )?;
+
+            let error_type: Type = Type::U32 { span: error_span };
+            let error_code: Ast = Ast::new_integer(error_type.clone(), code, false, error_span);
+
+            data.push((error_name, error_type, error_code));
+
+            code = code.saturating_add(1);
+        } else {
+            let span: Span = ctx.advance()?.get_span();
+
+            ctx.add_error_report(CompilationIssue::Error(
+                CompilationIssueCode::E0001,
+                "Expected identifier in error set.".into(),
+                "You should make it match.".into(),
+                None,
+                span,
+            ));
+        }
+    }
+
+    ctx.consume(
+        TokenType::RBrace,
+        CompilationIssueCode::E0001,
+        "Expected '}'.".into(),
+    )?;
+
+    if parse_forward {
+        ctx.get_mut_symbols()
+            .new_global_enum(name, (data, attributes))?;
+
+        Ok(Ast::new_nullptr(span))
+    } else {
+        Ok(Ast::Enum {
+            name,
+            data,
+            attributes,
+            kind: Type::Void(span),
+            span,
+            id: NodeId::new(),
+        })
+    }
+}
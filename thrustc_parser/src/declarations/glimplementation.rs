@@ -0,0 +1,336 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+
+use thrustc_ast::{
+    Ast, NodeId,
+    data::{InterfaceData, StructureData},
+    metadata::FunctionParameterMetadata,
+    traits::AstStructFieldsDataExtensions,
+};
+use thrustc_attributes::{ThrustAttributes, traits::ThrustAttributesExtensions};
+use thrustc_entities::parser::{FoundSymbolId, FunctionParametersTypes, Struct};
+use thrustc_errors::{CompilationIssue, CompilationIssueCode};
+use thrustc_parser_table::traits::{
+    FoundSymbolEitherExtensions, FoundSymbolExtensions, StructSymbolExtensions,
+};
+use thrustc_span::Span;
+use thrustc_token::{Token, traits::TokenExtensions};
+use thrustc_token_type::TokenType;
+use thrustc_typesystem::Type;
+
+use crate::{ParserContext, attributes, declarations::function, statements::block};
+
+pub fn build_implementation<'parser>(
+    ctx: &mut ParserContext<'parser>,
+    parse_forward: bool,
+) -> Result<Ast<'parser>, CompilationIssue> {
+    ctx.consume(
+        TokenType::Impl,
+        CompilationIssueCode::E0001,
+        "Expected 'impl'.".into(),
+    )?;
+
+    let interface_tk: &Token = ctx.consume(
+        TokenType::Identifier,
+        CompilationIssueCode::E0001,
+        "Expected identifier.".into(),
+    )?;
+
+    let interface: &str = interface_tk.get_lexeme();
+    let interface_ascii: &str = interface_tk.get_ascii_lexeme();
+
+    ctx.consume(
+        TokenType::For,
+        CompilationIssueCode::E0001,
+        "Expected 'for'.".into(),
+    )?;
+
+    let target_tk: &Token = ctx.consume(
+        TokenType::Identifier,
+        CompilationIssueCode::E0001,
+        "Expected identifier.".into(),
+    )?;
+
+    let target: &str = target_tk.get_lexeme();
+    let target_ascii: &str = target_tk.get_ascii_lexeme();
+    let span: Span = target_tk.get_span();
+
+    let signatures: Result<(InterfaceData, Type), CompilationIssue> = ctx
+        .get_symbols()
+        .get_interface_by_id(interface_tk.get_span(), interface)
+        .and_then(|(data, _)| Ok((data, self::build_target_type(ctx, target, span)?)));
+
+    let (interface_methods, target_type) = match signatures {
+        Ok(signatures) => signatures,
+        Err(error) => {
+            if parse_forward && ctx.check(TokenType::LBrace) {
+                self::skip_block(ctx)?;
+            }
+
+            return Err(error);
+        }
+    };
+
+    let self_type: Type = Type::Ptr(Some(target_type.into()), span);
+
+    ctx.consume(
+        TokenType::LBrace,
+        CompilationIssueCode::E0001,
+        "Expected '{'.".into(),
+    )?;
+
+    let mut methods: Vec<&str> = vec![""; interface_methods.len()];
+    let mut functions: Vec<Ast> = Vec::with_capacity(interface_methods.len());
+    let mut mismatch: Option<CompilationIssue> = None;
+
+    loop {
+        if ctx.check(TokenType::RBrace) {
+            break;
+        }
+
+        ctx.consume(
+            TokenType::Fn,
+            CompilationIssueCode::E0001,
+            "Expected 'fn' keyword.".into(),
+        )?;
+
+        let method_tk: &Token = ctx.consume(
+            TokenType::Identifier,
+            CompilationIssueCode::E0001,
+            "Expected identifier.".into(),
+        )?;
+
+        let method_name: &str = method_tk.get_lexeme();
+        let method_span: Span = method_tk.get_span();
+
+        let mut parameters: Vec<Ast> = Vec::with_capacity(12);
+        let mut parameters_types: Vec<Type> = Vec::with_capacity(12);
+
+        parameters.push(Ast::FunctionParameter {
+            name: "self",
+            ascii_name: "self",
+            kind: self_type.clone(),
+            position: 0,
+            metadata: FunctionParameterMetadata::new(true),
+            span: method_span,
+            id: NodeId::new(),
+        });
+
+        parameters_types.push(self_type.clone());
+
+        function::build_parameters(ctx, &mut parameters, &mut parameters_types)?;
+
+        let return_type: Type = function::build_return_type(ctx)?;
+
+        let attributes: ThrustAttributes =
+            attributes::build_compiler_attributes(ctx, &[TokenType::LBrace])?;
+        let function_has_ignore: bool = attributes.has_ignore_attribute();
+
+        let name: &str = ctx.intern(format!("{}.{}.{}", target, interface, method_name));
+        let ascii_name: &str = ctx.intern(format!(
+            "{}_{}_{}",
+            target_ascii,
+            interface_ascii,
+            method_tk.get_ascii_lexeme()
+        ));
+
+        let position: Option<usize> = interface_methods
+            .iter()
+            .position(|(other, ..)| *other == method_name);
+
+        let issue: Option<CompilationIssue> = match position {
+            Some(index) if !methods[index].is_empty() => Some(CompilationIssue::Error(
+                CompilationIssueCode::E0004,
+                format!("'{}' method was implemented before.", method_name),
+                "You should remove the copy.".into(),
+                None,
+                method_span,
+            )),
+            Some(index) => {
+                let (_, expected_parameters, expected_return, _) = &interface_methods[index];
+
+                if parameters_types[1..] != expected_parameters[..]
+                    || return_type != *expected_return
+                {
+                    Some(CompilationIssue::Error(
+                        CompilationIssueCode::E0042,
+                        format!(
+                            "'{}' does not match its declaration in '{}'.",
+                            method_name, interface
+                        ),
+                        format!(
+                            "You should use the signature 'fn {}({}) {}'.",
+                            method_name,
+                            expected_parameters
+                                .iter()
+                                .map(|kind| kind.to_string())
+                                .collect::<Vec<String>>()
+                                .join(", "),
+                            expected_return
+                        ),
+                        None,
+                        method_span,
+                    ))
+                } else {
+                    methods[index] = name;
+                    None
+                }
+            }
+            None => Some(CompilationIssue::Error(
+                CompilationIssueCode::E0042,
+                format!("'{}' is not a method of '{}'.", method_name, interface),
+                "You should remove it or declare it in the interface.".into(),
+                thrustc_utils::did_you_mean(
+                    method_name,
+                    interface_methods.iter().map(|(other, ..)| *other),
+                ),
+                method_span,
+            )),
+        };
+
+        if issue.is_some() {
+            mismatch = mismatch.or(issue);
+        } else {
+            ctx.get_mut_symbols().new_function(
+                name,
+                (
+                    return_type.clone(),
+                    FunctionParametersTypes(parameters_types.clone()),
+                    function_has_ignore,
+                ),
+            )?;
+        }
+
+        if parse_forward {
+            self::skip_block(ctx)?;
+            continue;
+        }
+
+        ctx.get_mut_symbols().new_parameters(&parameters)?;
+
+        let function_body: Ast = block::parse_code_block_stmt(ctx)?;
+
+        ctx.get_mut_symbols().finish_parameters();
+
+        functions.push(Ast::Function {
+            name,
+            ascii_name,
+            parameters,
+            parameter_types: parameters_types,
+            body: Some(function_body.into()),
+            return_type,
+            attributes,
+            span: method_span,
+            id: NodeId::new(),
+        });
+    }
+
+    ctx.consume(
+        TokenType::RBrace,
+        CompilationIssueCode::E0001,
+        "Expected '}'.".into(),
+    )?;
+
+    if let Some(issue) = mismatch {
+        return Err(issue);
+    }
+
+    if let Some(((missing, ..), _)) = interface_methods
+        .iter()
+        .zip(methods.iter())
+        .find(|(_, method)| method.is_empty())
+    {
+        return Err(CompilationIssue::Error(
+            CompilationIssueCode::E0042,
+            format!(
+                "'{}' does not implement '{}' from '{}'.",
+                target, missing, interface
+            ),
+            "You should implement every method of the interface.".into(),
+            None,
+            span,
+        ));
+    }
+
+    ctx.get_mut_symbols()
+        .new_implementation(target, interface, (methods.clone(), span))?;
+
+    if parse_forward {
+        return Ok(Ast::new_nullptr(span));
+    }
+
+    {
+        for function in functions {
+            ctx.add_ast_node(function);
+        }
+    }
+
+    Ok(Ast::Implementation {
+        interface,
+        target,
+        methods,
+        kind: Type::Void(span),
+        span,
+        id: NodeId::new(),
+    })
+}
+
+fn build_target_type(
+    ctx: &ParserContext<'_>,
+    target: &str,
+    span: Span,
+) -> Result<Type, CompilationIssue> {
+    let object: FoundSymbolId = ctx.get_symbols().get_symbols_id(target, span)?;
+
+    if !object.is_structure() {
+        return Err(CompilationIssue::Error(
+            CompilationIssueCode::E0019,
+            format!("'{}' is not a structure.", target),
+            "Only structures can implement an interface.".into(),
+            None,
+            span,
+        ));
+    }
+
+    let (id, scope_idx) = object.expected_struct(span)?;
+    let structure: Struct = ctx.get_symbols().get_struct_by_id(id, scope_idx, span)?;
+    let data: StructureData = structure.get_data();
+
+    Ok(data.get_type())
+}
+
+fn skip_block(ctx: &mut ParserContext<'_>) -> Result<(), CompilationIssue> {
+    ctx.consume(
+        TokenType::LBrace,
+        CompilationIssueCode::E0001,
+        "Expected '{'.".into(),
+    )?;
+
+    let mut depth: usize = 1;
+
+    while depth > 0 && !ctx.is_eof() {
+        match ctx.advance()?.get_type() {
+            TokenType::LBrace => depth = depth.saturating_add(1),
+            TokenType::RBrace => depth = depth.saturating_sub(1),
+            _ => (),
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,130 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+
+use thrustc_ast::{Ast, NodeId, data::InterfaceData};
+use thrustc_errors::{CompilationIssue, CompilationIssueCode, CompilationLabel};
+use thrustc_span::Span;
+use thrustc_token::{Token, traits::TokenExtensions};
+use thrustc_token_type::TokenType;
+use thrustc_typesystem::Type;
+
+use crate::{ParserContext, declarations::function};
+
+pub fn build_interface<'parser>(
+    ctx: &mut ParserContext<'parser>,
+    parse_forward: bool,
+) -> Result<Ast<'parser>, CompilationIssue> {
+    ctx.consume(
+        TokenType::Interface,
+        CompilationIssueCode::E0001,
+        "Expected 'interface'.".into(),
+    )?;
+
+    let name_tk: &Token = ctx.consume(
+        TokenType::Identifier,
+        CompilationIssueCode::E0001,
+        "Expected identifier.".into(),
+    )?;
+
+    let name: &str = name_tk.get_lexeme();
+    let span: Span = name_tk.get_span();
+
+    ctx.consume(
+        TokenType::LBrace,
+        CompilationIssueCode::E0001,
+        "Expected '{'.".into(),
+    )?;
+
+    let mut data: InterfaceData = InterfaceData::with_capacity(u8::MAX as usize);
+
+    loop {
+        if ctx.check(TokenType::RBrace) {
+            break;
+        }
+
+        ctx.consume(
+            TokenType::Fn,
+            CompilationIssueCode::E0001,
+            "Expected 'fn' keyword.".into(),
+        )?;
+
+        let method_tk: &Token = ctx.consume(
+            TokenType::Identifier,
+            CompilationIssueCode::E0001,
+            "Expected identifier.".into(),
+        )?;
+
+        let method_name: &str = method_tk.get_lexeme();
+        let method_span: Span = method_tk.get_span();
+
+        if let Some((.., previous_span)) = data.iter().find(|(other, ..)| *other == method_name) {
+            return Err(CompilationIssue::Error(
+                CompilationIssueCode::E0004,
+                format!("'{}' method was declared before.", method_name),
+                "You should rename it or remove the copy.".into(),
+                None,
+                method_span,
+            )
+            .with_labels(vec![CompilationLabel::new(
+                *previous_span,
+                format!("'{}' was first declared here.", method_name),
+            )]));
+        }
+
+        let mut parameters: Vec<Ast> = Vec::with_capacity(12);
+        let mut parameters_types: Vec<Type> = Vec::with_capacity(12);
+
+        function::build_parameters(ctx, &mut parameters, &mut parameters_types)?;
+
+        let return_type: Type = if ctx.check(TokenType::SemiColon) {
+            Type::Void(ctx.previous().get_span())
+        } else {
+            function::build_return_type(ctx)?
+        };
+
+        ctx.consume(
+            TokenType::SemiColon,
+            CompilationIssueCode::E0001,
+            "Expected ';'.".into(),
+        )?;
+
+        data.push((method_name, parameters_types, return_type, method_span));
+    }
+
+    ctx.consume(
+        TokenType::RBrace,
+        CompilationIssueCode::E0001,
+        "Expected '}'.".into(),
+    )?;
+
+    ctx.get_mut_symbols()
+        .new_interface(name, (data.clone(), span))?;
+
+    if parse_forward {
+        Ok(Ast::new_nullptr(span))
+    } else {
+        Ok(Ast::Interface {
+            name,
+            data,
+            kind: Type::Void(span),
+            span,
+            id: NodeId::new(),
+        })
+    }
+}
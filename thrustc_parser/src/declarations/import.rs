@@ -19,13 +19,14 @@
 
 
 use thrustc_ast::{Ast, NodeId};
+use thrustc_attributes::ThrustAttributes;
 use thrustc_errors::{CompilationIssue, CompilationIssueCode};
 use thrustc_span::Span;
 use thrustc_token::{Token, traits::TokenExtensions};
 use thrustc_token_type::TokenType;
 use thrustc_typesystem::Type;
 
-use crate::ParserContext;
+use crate::{ParserContext, attributes};
 
 pub fn build_import<'parser>(
     ctx: &mut ParserContext<'parser>,
@@ -44,6 +45,9 @@ pub fn build_import<'parser>(
 
     let span: Span = path_literal_tk.get_span();
 
+    let attributes: ThrustAttributes =
+        attributes::build_compiler_attributes(ctx, &[TokenType::SemiColon])?;
+
     ctx.consume(
         TokenType::SemiColon,
         CompilationIssueCode::E0001,
@@ -51,6 +55,7 @@ pub fn build_import<'parser>(
     )?;
 
     Ok(Ast::Import {
+        attributes,
         span,
         kind: Type::Void(span),
         id: NodeId::new(),
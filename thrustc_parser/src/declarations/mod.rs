@@ -32,6 +32,9 @@ pub mod glasm;
 pub mod glconstant;
 pub mod glcstype;
 pub mod glenum;
+pub mod glerrset;
+pub mod glimplementation;
+pub mod glinterface;
 pub mod glstatic;
 pub mod glstructure;
 pub mod import;
@@ -44,10 +47,13 @@ pub fn parse<'parser>(ctx: &mut ParserContext<'parser>) -> Result<Ast<'parser>,
 
     let declaration: Result<Ast<'parser>, CompilationIssue> = match ctx.peek().get_type() {
         TokenType::Type => Ok(glcstype::build_custom_type(ctx, false)?),
-        TokenType::Struct => Ok(glstructure::build_structure(ctx, false)?),
+        TokenType::Struct | TokenType::Union => Ok(glstructure::build_structure(ctx, false)?),
         TokenType::Const => Ok(glconstant::build_global_const(ctx, false)?),
         TokenType::Static => Ok(glstatic::build_global_static(ctx, false)?),
         TokenType::Enum => Ok(glenum::build_enum(ctx, false)?),
+        TokenType::ErrSet => Ok(glerrset::build_error_set(ctx, false)?),
+        TokenType::Interface => Ok(glinterface::build_interface(ctx, false)?),
+        TokenType::Impl => Ok(glimplementation::build_implementation(ctx, false)?),
         TokenType::Fn => Ok(function::build_function(ctx, false)?),
         TokenType::AsmFn => Ok(asmfn::build_assembler_function(ctx, false)?),
         TokenType::Intrinsic => Ok(intrinsic::build_compiler_intrinsic(ctx, false)?),
@@ -81,7 +87,7 @@ pub fn parse_forward(ctx: &mut ParserContext) {
             TokenType::Type if !at_block => {
                 let _ = glcstype::build_custom_type(ctx, true);
             }
-            TokenType::Struct if !at_block => {
+            TokenType::Struct | TokenType::Union if !at_block => {
                 let _ = glstructure::build_structure(ctx, true);
             }
             TokenType::Static if !at_block => {
@@ -93,6 +99,15 @@ pub fn parse_forward(ctx: &mut ParserContext) {
             TokenType::Enum if !at_block => {
                 let _ = glenum::build_enum(ctx, true);
             }
+            TokenType::ErrSet if !at_block => {
+                let _ = glerrset::build_error_set(ctx, true);
+            }
+            TokenType::Interface if !at_block => {
+                let _ = glinterface::build_interface(ctx, true);
+            }
+            TokenType::Impl if !at_block => {
+                let _ = glimplementation::build_implementation(ctx, true);
+            }
             TokenType::Intrinsic if !at_block => {
                 let _ = intrinsic::build_compiler_intrinsic(ctx, true);
             }
@@ -39,33 +39,7 @@ pub fn build_call<'parser>(
     name: &'parser str,
     span: Span,
 ) -> Result<Ast<'parser>, CompilationIssue> {
-    let mut args: Vec<Ast> = Vec::with_capacity(u8::MAX as usize);
-
-    loop {
-        if ctx.check(TokenType::RParen) {
-            break;
-        }
-
-        let expr: Ast<'_> = expressions::parse_expr(ctx)?;
-
-        args.push(expr);
-
-        if ctx.check(TokenType::RParen) {
-            break;
-        } else {
-            ctx.consume(
-                TokenType::Comma,
-                CompilationIssueCode::E0001,
-                "Expected ','.".into(),
-            )?;
-        }
-    }
-
-    ctx.consume(
-        TokenType::RParen,
-        CompilationIssueCode::E0001,
-        "Expected ')'.".into(),
-    )?;
+    let args: Vec<Ast> = self::build_call_arguments(ctx)?;
 
     let reference: Result<FoundSymbolId, CompilationIssue> =
         ctx.get_symbols().get_symbols_id(name, span);
@@ -141,6 +115,24 @@ pub fn build_anonymous_call<'parser>(
 
     let span: Span = expr.get_span();
 
+    let args: Vec<Ast> = self::build_call_arguments(ctx)?;
+
+    let expr_type: &Type = expr.get_value_type()?;
+    let return_type: Type = expr_type.get_function_reference_return_type();
+
+    Ok(Ast::IndirectCall {
+        function: expr.clone().into(),
+        function_type: expr_type.clone(),
+        args,
+        kind: return_type,
+        span,
+        id: NodeId::new(),
+    })
+}
+
+pub fn build_call_arguments<'parser>(
+    ctx: &mut ParserContext<'parser>,
+) -> Result<Vec<Ast<'parser>>, CompilationIssue> {
     let mut args: Vec<Ast> = Vec::with_capacity(u8::MAX as usize);
 
     loop {
@@ -169,15 +161,5 @@ pub fn build_anonymous_call<'parser>(
         "Expected ')'.".into(),
     )?;
 
-    let expr_type: &Type = expr.get_value_type()?;
-    let return_type: Type = expr_type.get_function_reference_return_type();
-
-    Ok(Ast::IndirectCall {
-        function: expr.clone().into(),
-        function_type: expr_type.clone(),
-        args,
-        kind: return_type,
-        span,
-        id: NodeId::new(),
-    })
+    Ok(args)
 }
@@ -17,13 +17,19 @@
 
 */
 
-use thrustc_ast::{Ast, NodeId, data::ConstructorData, traits::AstStructureDataExtensions};
+use thrustc_ast::{
+    Ast, NodeId,
+    data::ConstructorData,
+    traits::{AstStructFieldsDataExtensions, AstStructureDataExtensions},
+};
 use thrustc_entities::parser::{FoundSymbolId, Struct};
 use thrustc_errors::{CompilationIssue, CompilationIssueCode};
 use thrustc_span::Span;
 use thrustc_token::{Token, traits::TokenExtensions};
 use thrustc_token_type::TokenType;
-use thrustc_typesystem::{Type, type_modificators::StructureTypeModificator};
+use thrustc_typesystem::{
+    Type, traits::TypeStructExtensions, type_modificators::StructureTypeModificator,
+};
 
 use thrustc_parser_table::traits::{
     ConstructorExtensions, FoundSymbolEitherExtensions, StructSymbolExtensions,
@@ -75,7 +81,11 @@ pub fn build_constructor<'parser>(
                         ConstructorData::with_capacity(u8::MAX as usize);
                     let mut count: usize = 0;
 
-                    let required: usize = object.get_data().get_fields().len();
+                    let required: usize = if modificator.is_union() {
+                        1
+                    } else {
+                        object.get_data().get_fields().len()
+                    };
 
                     loop {
                         if ctx.check(TokenType::RBrace) {
@@ -182,7 +192,15 @@ pub fn build_constructor<'parser>(
                         "Expected '}'.".into(),
                     )?;
 
-                    let constructor_type: Type = data.get_type(name, modificator, span);
+                    let structure_type: Type = object.get_data().get_type();
+
+                    let constructor_type: Type = if modificator.is_union()
+                        || !structure_type.get_struct_bitfields().is_empty()
+                    {
+                        structure_type
+                    } else {
+                        data.get_type(name, modificator, span)
+                    };
 
                     Ok(Ast::Constructor {
                         name,
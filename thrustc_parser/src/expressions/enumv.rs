@@ -77,7 +77,10 @@ pub fn build_enum_value<'parser>(
                                 "Unknown field.".into(),
                                 "You should make sure that it existion in the enum definition."
                                     .into(),
-                                None,
+                                thrustc_utils::did_you_mean(
+                                    field_name,
+                                    data.iter().map(|(name, ..)| *name),
+                                ),
                                 field_span,
                             ));
 
@@ -0,0 +1,128 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+
+use thrustc_ast::{Ast, NodeId, traits::AstGetType};
+use thrustc_entities::parser::Function;
+use thrustc_errors::{CompilationIssue, CompilationIssueCode};
+use thrustc_span::Span;
+use thrustc_token_type::TokenType;
+use thrustc_typesystem::{
+    Type,
+    traits::{TypeExtensions, TypeIsExtensions},
+    type_modificators::FunctionReferenceTypeModificator,
+};
+
+use crate::{ParserContext, expressions::call};
+
+pub fn build_method_call<'parser>(
+    ctx: &mut ParserContext<'parser>,
+    receiver: Ast<'parser>,
+    method: &'parser str,
+    span: Span,
+) -> Result<Ast<'parser>, CompilationIssue> {
+    ctx.consume(
+        TokenType::LParen,
+        CompilationIssueCode::E0001,
+        "Expected '('.".into(),
+    )?;
+
+    let mut args: Vec<Ast> = call::build_call_arguments(ctx)?;
+
+    let receiver_type: Type = receiver.get_value_type()?.clone();
+
+    let object_type: &Type = match &receiver_type {
+        Type::Ptr(Some(inner_type), ..) => inner_type,
+        any => any,
+    };
+
+    match object_type {
+        Type::Interface(interface, ..) => {
+            let (methods, _) = ctx.get_symbols().get_interface_by_id(span, interface)?;
+
+            let Some(index) = methods.iter().position(|(name, ..)| *name == method) else {
+                return Err(CompilationIssue::Error(
+                    CompilationIssueCode::E0028,
+                    format!("Method '{}' not found in '{}'.", method, interface),
+                    "You should make sure that it exist in the interface.".into(),
+                    thrustc_utils::did_you_mean(method, methods.iter().map(|(name, ..)| *name)),
+                    span,
+                ));
+            };
+
+            let (_, parameters, return_type, _) = &methods[index];
+
+            let mut function_parameters: Vec<Type> = Vec::with_capacity(parameters.len() + 1);
+
+            function_parameters.push(Type::Ptr(None, span));
+            function_parameters.extend(parameters.iter().cloned());
+
+            Ok(Ast::DynamicCall {
+                object: receiver.into(),
+                index: u32::try_from(index).unwrap_or(u32::MAX),
+                function_type: Type::Fn(
+                    function_parameters,
+                    return_type.clone().into(),
+                    FunctionReferenceTypeModificator::default(),
+                    span,
+                ),
+                args,
+                kind: return_type.clone(),
+                span,
+                id: NodeId::new(),
+            })
+        }
+
+        Type::Struct { name, .. } => {
+            let function_name: &str = ctx
+                .get_symbols()
+                .get_implemented_method(span, name, method)?;
+
+            let function: Function = ctx.get_symbols().get_function_by_id(span, function_name)?;
+
+            let object: Ast = if receiver_type.is_ptr_type() {
+                receiver
+            } else {
+                Ast::DirectRef {
+                    kind: receiver_type.get_type_ref(),
+                    expr: receiver.into(),
+                    span,
+                    id: NodeId::new(),
+                }
+            };
+
+            args.insert(0, object);
+
+            Ok(Ast::Call {
+                name: function_name,
+                args,
+                kind: function.0,
+                span,
+                id: NodeId::new(),
+            })
+        }
+
+        _ => Err(CompilationIssue::Error(
+            CompilationIssueCode::E0019,
+            format!("Type '{}' has no methods.", receiver_type),
+            "You should call methods on a structure or an interface.".into(),
+            None,
+            span,
+        )),
+    }
+}
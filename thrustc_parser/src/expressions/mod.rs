@@ -25,6 +25,7 @@ pub mod deref;
 pub mod enumv;
 pub mod farray;
 pub mod index;
+pub mod method;
 pub mod precedences;
 pub mod property;
 pub mod reference;
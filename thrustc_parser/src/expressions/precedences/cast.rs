@@ -20,13 +20,16 @@
 use thrustc_ast::{
     Ast, NodeId,
     metadata::CastingMetadata,
-    traits::{AstConstantExtensions, AstMemoryExtensions},
+    traits::{AstConstantExtensions, AstGetType, AstMemoryExtensions},
 };
-use thrustc_errors::CompilationIssue;
+use thrustc_errors::{CompilationIssue, CompilationIssueCode};
 use thrustc_span::Span;
 use thrustc_token::traits::TokenExtensions;
 use thrustc_token_type::TokenType;
-use thrustc_typesystem::Type;
+use thrustc_typesystem::{
+    Type,
+    traits::{TypeExtensions, TypeIsExtensions},
+};
 
 use crate::{ParserContext, expressions::precedences, typegeneration};
 
@@ -35,13 +38,32 @@ pub fn cast_precedence<'parser>(
 ) -> Result<Ast<'parser>, CompilationIssue> {
     ctx.enter_expression()?;
 
-    let mut expression: Ast = precedences::index::index_precedence(ctx)?;
+    let mut expression: Ast = precedences::propagation::propagation_precedence(ctx)?;
 
     if ctx.match_token(TokenType::As)? {
-        let span: Span = ctx.previous().get_span();
+        let as_span: Span = ctx.previous().get_span();
 
         let cast: Type = typegeneration::build_type(ctx, false)?;
 
+        let type_span: Span = ctx.previous().get_span();
+
+        let span: Span = if type_span.get_line() == as_span.get_line() {
+            Span::new((
+                as_span.get_line(),
+                (as_span.get_span_start(), type_span.get_span_end()),
+            ))
+        } else {
+            as_span
+        };
+
+        if let Type::Interface(interface, ..) = &cast {
+            expression = self::build_interface_object(ctx, expression, interface, span)?;
+
+            ctx.leave_expression();
+
+            return Ok(expression);
+        }
+
         let is_constant: bool = expression.is_constant_value();
         let is_allocated: bool = expression.is_memory_assigned_value()?;
 
@@ -58,3 +80,70 @@ pub fn cast_precedence<'parser>(
 
     Ok(expression)
 }
+
+fn build_interface_object<'parser>(
+    ctx: &mut ParserContext<'parser>,
+    expression: Ast<'parser>,
+    interface: &str,
+    span: Span,
+) -> Result<Ast<'parser>, CompilationIssue> {
+    let expression_type: Type = expression.get_value_type()?.clone();
+
+    let (target_type, value): (&Type, Ast) = match &expression_type {
+        Type::Ptr(Some(inner_type), ..) if inner_type.is_struct_type() => (inner_type, expression),
+        any if any.is_struct_type() && expression.is_memory_assigned_value()? => (
+            any,
+            Ast::DirectRef {
+                kind: any.get_type_ref(),
+                expr: expression.into(),
+                span,
+                id: NodeId::new(),
+            },
+        ),
+        _ => {
+            return Err(CompilationIssue::Error(
+                CompilationIssueCode::E0032,
+                format!(
+                    "Type '{}' cannot be cast to the interface '{}'.",
+                    expression_type, interface
+                ),
+                "You should cast a pointer to a structure that implements it.".into(),
+                None,
+                span,
+            ));
+        }
+    };
+
+    let Type::Struct { name: target, .. } = target_type else {
+        return Err(CompilationIssue::Error(
+            CompilationIssueCode::E0032,
+            format!("Type '{}' is not a structure.", target_type),
+            "You should cast a pointer to a structure that implements it.".into(),
+            None,
+            span,
+        ));
+    };
+
+    let Some((&(target, interface), _)) = ctx.get_symbols().get_implementation(target, interface)
+    else {
+        return Err(CompilationIssue::Error(
+            CompilationIssueCode::E0042,
+            format!("'{}' does not implement '{}'.", target, interface),
+            format!(
+                "You should add an 'impl {} for {} {{ ... }}' block.",
+                interface, target
+            ),
+            None,
+            span,
+        ));
+    };
+
+    Ok(Ast::InterfaceObject {
+        value: value.into(),
+        interface,
+        target,
+        kind: Type::Interface(interface.to_string(), span),
+        span,
+        id: NodeId::new(),
+    })
+}
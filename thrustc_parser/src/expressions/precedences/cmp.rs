@@ -17,12 +17,12 @@
 
 */
 
-use thrustc_ast::{Ast, NodeId};
+use thrustc_ast::{Ast, NodeId, traits::AstGetType};
 use thrustc_errors::CompilationIssue;
 use thrustc_span::Span;
 use thrustc_token::{Token, traits::TokenExtensions};
 use thrustc_token_type::TokenType;
-use thrustc_typesystem::Type;
+use thrustc_typesystem::{Type, traits::TypeVectorExtensions};
 
 use crate::{ParserContext, expressions::precedences};
 
@@ -45,11 +45,13 @@ pub fn cmp_precedence<'parser>(
 
         let right: Ast = precedences::term::term_precedence(ctx)?;
 
+        let kind: Type = left.get_value_type()?.get_vector_comparison_type(span);
+
         left = Ast::BinaryOp {
             left: left.into(),
             operator,
             right: right.into(),
-            kind: Type::Bool(span),
+            kind,
             span,
             id: NodeId::new(),
         };
@@ -17,12 +17,12 @@
 
 */
 
-use thrustc_ast::{Ast, NodeId};
+use thrustc_ast::{Ast, NodeId, traits::AstGetType};
 use thrustc_errors::CompilationIssue;
 use thrustc_span::Span;
 use thrustc_token::{Token, traits::TokenExtensions};
 use thrustc_token_type::TokenType;
-use thrustc_typesystem::Type;
+use thrustc_typesystem::{Type, traits::TypeVectorExtensions};
 
 use crate::{ParserContext, expressions::precedences};
 
@@ -40,11 +40,13 @@ pub fn equality_precedence<'parser>(
 
         let right: Ast = precedences::cmp::cmp_precedence(ctx)?;
 
+        let kind: Type = left.get_value_type()?.get_vector_comparison_type(span);
+
         left = Ast::BinaryOp {
             left: left.into(),
             operator,
             right: right.into(),
-            kind: Type::Bool(span),
+            kind,
             span,
             id: NodeId::new(),
         }
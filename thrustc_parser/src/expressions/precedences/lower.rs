@@ -218,10 +218,10 @@ pub fn lower_precedence<'parser>(
             let integer: &str = tk.get_lexeme();
             let span: Span = tk.get_span();
 
-            let parsed_integer: (Type, u64) = reinterpret::integer(integer, span)?;
+            let parsed_integer: (Type, u128) = reinterpret::integer(integer, span)?;
 
             let kind: Type = parsed_integer.0;
-            let value: u64 = parsed_integer.1;
+            let value: u128 = parsed_integer.1;
 
             Ast::new_integer(kind, value, false, span)
         }
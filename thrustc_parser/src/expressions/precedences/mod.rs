@@ -28,6 +28,7 @@ pub mod indirect;
 pub mod lower;
 pub mod mutation;
 pub mod or;
+pub mod propagation;
 pub mod property;
 pub mod term;
 pub mod unary;
@@ -32,15 +32,12 @@ use thrustc_typesystem::{Type, traits::TypeCodeLocation};
 
 use thrustc_parser_table::traits::{FoundSymbolEitherExtensions, StructSymbolExtensions};
 
-use crate::ParserContext;
+use crate::{ParserContext, expressions};
 
 pub fn build_property<'parser>(
     ctx: &mut ParserContext<'parser>,
     source: Ast<'parser>,
 ) -> Result<Ast<'parser>, CompilationIssue> {
-    let base_type: &Type = source.get_value_type()?;
-    let metadata: PropertyMetadata = PropertyMetadata::new(source.is_memory_assigned_value()?);
-
     let mut property_names: Vec<&str> = Vec::with_capacity(u8::MAX as usize);
 
     let first: &Token = ctx.consume(
@@ -65,6 +62,30 @@ pub fn build_property<'parser>(
         property_names.push(property.get_lexeme());
     }
 
+    if ctx.check(TokenType::LParen) {
+        let method: &str = property_names.pop().unwrap_or_default();
+
+        let receiver: Ast = if property_names.is_empty() {
+            source
+        } else {
+            self::build_property_access(ctx, source, property_names, span)?
+        };
+
+        return expressions::method::build_method_call(ctx, receiver, method, span);
+    }
+
+    self::build_property_access(ctx, source, property_names, span)
+}
+
+fn build_property_access<'parser>(
+    ctx: &mut ParserContext<'parser>,
+    source: Ast<'parser>,
+    property_names: Vec<&str>,
+    span: Span,
+) -> Result<Ast<'parser>, CompilationIssue> {
+    let base_type: &Type = source.get_value_type()?;
+    let metadata: PropertyMetadata = PropertyMetadata::new(source.is_memory_assigned_value()?);
+
     let properties_result: Result<(Type, PropertyData), CompilationIssue> =
         self::decompose_structure_property(ctx, 0, &source, property_names, base_type, span);
 
@@ -144,9 +165,12 @@ fn decompose_structure_property<'parser>(
         let Some((index, (_, field_type, ..))) = field else {
             return Err(CompilationIssue::Error(
                 CompilationIssueCode::E0028,
-                "Unknown property".into(),
+                format!("Unknown property '{}'", current_property_name),
                 "You should make sure that it exist in the structure type reference.".into(),
-                None,
+                thrustc_utils::did_you_mean(
+                    current_property_name,
+                    data.get_fields().iter().map(|(field_name, ..)| *field_name),
+                ),
                 span,
             ));
         };
@@ -20,7 +20,9 @@
 use thrustc_ast::Ast;
 use thrustc_diagnostician::Diagnostician;
 use thrustc_entities::parser::{AssemblerFunctions, Functions};
-use thrustc_errors::{CompilationIssue, CompilationIssueCode, CompilationPosition};
+use thrustc_errors::{
+    CompilationEdit, CompilationIssue, CompilationIssueCode, CompilationPosition,
+};
 use thrustc_logging::LoggingType;
 use thrustc_options::{CompilationUnit, CompilerOptions};
 use thrustc_parser_context::{ControlContext, TypeContext, traits::ControlContextExtensions};
@@ -128,9 +130,16 @@ impl<'parser> ParserContext<'parser> {
 
         let control_context: ControlContext = ControlContext::new();
 
-        let table: SymbolTable =
+        let mut table: SymbolTable =
             SymbolTable::with_functions(functions, asm_functions, options, file);
 
+        table.add_external_symbols(
+            modules
+                .iter()
+                .flat_map(|module| module.get_symbols())
+                .map(|symbol| symbol.name.as_str()),
+        );
+
         let type_context: TypeContext = TypeContext::new();
 
         Self {
@@ -294,13 +303,20 @@ impl<'parser> ParserContext<'parser> {
             return self.advance();
         }
 
-        Err(CompilationIssue::Error(
-            code,
-            help,
-            "You should make it match.".into(),
-            None,
-            self.previous().get_span(),
-        ))
+        let span: Span = self.previous().get_span();
+
+        let issue: CompilationIssue =
+            CompilationIssue::Error(code, help, "You should make it match.".into(), None, span);
+
+        if kind == TokenType::SemiColon {
+            return Err(issue.with_edits(vec![CompilationEdit::insertion(
+                span.get_line(),
+                span.get_span_end(),
+                ";".into(),
+            )]));
+        }
+
+        Err(issue)
     }
 
     #[inline]
@@ -484,6 +500,13 @@ impl<'parser> ParserContext<'parser> {
     pub fn add_bug_report(&mut self, error: CompilationIssue) {
         self.bugs.push(error);
     }
+
+    #[inline]
+    pub fn intern(&self, name: String) -> &'parser str {
+        // Synthesized names have no backing token, so they are leaked
+        // to live as long as the tokens they sit beside.
+        Box::leak(name.into_boxed_str())
+    }
 }
 
 impl ParserContext<'_> {
@@ -32,12 +32,16 @@ use thrustc_token_type::TokenType;
 use crate::ParserContext;
 
 #[inline]
-pub fn build_structure_modificator(attributes: &ThrustAttributes) -> StructureTypeModificator {
+pub fn build_structure_modificator(
+    attributes: &ThrustAttributes,
+    union: bool,
+) -> StructureTypeModificator {
     let llvm_packed_modificator: bool = attributes.iter().any(|attr| attr.is_packed());
 
     StructureTypeModificator::new(
         LLVMStructureTypeModificator::new(llvm_packed_modificator),
         GCCStructureTypeModificator::new(),
+        union,
     )
 }
 
@@ -32,12 +32,14 @@ use crate::{ParserContext, attributes, modificators, typegeneration};
 pub fn parse_structure_stmt<'parser>(
     ctx: &mut ParserContext<'parser>,
 ) -> Result<Ast<'parser>, CompilationIssue> {
-    ctx.consume(
-        TokenType::Struct,
+    let keyword_tk: &Token = ctx.consume_these(
+        &[TokenType::Struct, TokenType::Union],
         CompilationIssueCode::E0001,
-        "Expected 'struct' keyword.".into(),
+        "Expected 'struct' or 'union' keyword.".into(),
     )?;
 
+    let is_union: bool = keyword_tk.get_type() == TokenType::Union;
+
     let name_tk: &Token = ctx.consume(
         TokenType::Identifier,
         CompilationIssueCode::E0001,
@@ -48,7 +50,7 @@ pub fn parse_structure_stmt<'parser>(
         attributes::build_compiler_attributes(ctx, &[TokenType::LBrace])?;
 
     let modificator: StructureTypeModificator =
-        modificators::build_structure_modificator(&attributes);
+        modificators::build_structure_modificator(&attributes, is_union);
 
     ctx.consume(
         TokenType::LBrace,
@@ -84,9 +86,16 @@ pub fn parse_structure_stmt<'parser>(
             )?;
 
             let field_type: Type = typegeneration::build_type(ctx, false)?;
-
-            data.1
-                .push((field_name, field_type, field_position, field_span));
+            let bit_width: Option<u8> =
+                typegeneration::build_bitfield_width(ctx, &field_type, is_union)?;
+
+            data.1.push((
+                field_name,
+                field_type,
+                field_position,
+                field_span,
+                bit_width,
+            ));
 
             field_position = field_position.saturating_add(1);
 
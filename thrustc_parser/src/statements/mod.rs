@@ -45,9 +45,10 @@ pub fn parse<'parser>(ctx: &mut ParserContext<'parser>) -> Result<Ast<'parser>,
     let statement: Result<Ast<'parser>, CompilationIssue> = match &ctx.peek().get_type() {
         TokenType::LBrace => Ok(block::parse_code_block_stmt(ctx)?),
         TokenType::Return => Ok(terminator::parse_return_stmt(ctx)?),
+        TokenType::Raise => Ok(terminator::parse_raise_stmt(ctx)?),
         TokenType::Static => Ok(lstatic::parse_static_stmt(ctx)?),
         TokenType::Const => Ok(lconstant::parse_constant_stmt(ctx)?),
-        TokenType::Struct => Ok(lstructure::parse_structure_stmt(ctx)?),
+        TokenType::Struct | TokenType::Union => Ok(lstructure::parse_structure_stmt(ctx)?),
         TokenType::Type => Ok(lctype::parse_custom_type_stmt(ctx)?),
         TokenType::Enum => Ok(lenum::parse_enum_stmt(ctx)?),
         TokenType::Var => Ok(var::build_variable_stmt(ctx)?),
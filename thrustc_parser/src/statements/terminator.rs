@@ -62,3 +62,31 @@ pub fn parse_return_stmt<'parser>(
         id: NodeId::new(),
     })
 }
+
+pub fn parse_raise_stmt<'parser>(
+    ctx: &mut ParserContext<'parser>,
+) -> Result<Ast<'parser>, CompilationIssue> {
+    let raise_tk: &Token = ctx.consume(
+        TokenType::Raise,
+        CompilationIssueCode::E0001,
+        "Expected 'raise' keyword.".into(),
+    )?;
+
+    let span: Span = raise_tk.get_span();
+
+    let error: Ast = expressions::parse_expr(ctx)?;
+    let kind: Type = error.get_value_type()?.clone();
+
+    ctx.consume(
+        TokenType::SemiColon,
+        CompilationIssueCode::E0001,
+        "Expected ';'.".into(),
+    )?;
+
+    Ok(Ast::Raise {
+        expression: error.into(),
+        kind,
+        span,
+        id: NodeId::new(),
+    })
+}
@@ -24,11 +24,12 @@ use thrustc_token_type::TokenType;
 
 use crate::{ParserContext, statements::block};
 
-pub const SYNC_STATEMENTS: [TokenType; 16] = [
+pub const SYNC_STATEMENTS: [TokenType; 18] = [
     TokenType::Return,
     TokenType::Static,
     TokenType::Const,
     TokenType::Struct,
+    TokenType::Union,
     TokenType::Type,
     TokenType::Enum,
     TokenType::Var,
@@ -41,14 +42,19 @@ pub const SYNC_STATEMENTS: [TokenType; 16] = [
     TokenType::Break,
     TokenType::BreakAll,
     TokenType::Defer,
+    TokenType::Raise,
 ];
 
-pub const SYNC_DECLARATIONS: [TokenType; 11] = [
+pub const SYNC_DECLARATIONS: [TokenType; 15] = [
     TokenType::Type,
     TokenType::Struct,
+    TokenType::Union,
     TokenType::Const,
     TokenType::Static,
     TokenType::Enum,
+    TokenType::ErrSet,
+    TokenType::Interface,
+    TokenType::Impl,
     TokenType::Fn,
     TokenType::AsmFn,
     TokenType::Intrinsic,
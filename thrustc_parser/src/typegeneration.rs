@@ -60,6 +60,7 @@ pub fn build_type(ctx: &mut ParserContext<'_>, parse_expr: bool) -> Result<Type,
 
             match tk_kind {
                 _ if tk_kind.is_array() => self::parse_array_type(ctx, span),
+                _ if tk_kind.is_vector() => self::parse_vector_type(ctx, span),
                 _ if tk_kind.is_const() => self::parse_constant_type(ctx, span),
                 _ if tk_kind.is_fn_ref() => self::parse_anonymous_function_type(ctx, span),
                 _ => match tk_kind {
@@ -72,6 +73,7 @@ pub fn build_type(ctx: &mut ParserContext<'_>, parse_expr: bool) -> Result<Type,
                     TokenType::S16 => Ok(Type::S16 { span }),
                     TokenType::S32 => Ok(Type::S32 { span }),
                     TokenType::S64 => Ok(Type::S64 { span }),
+                    TokenType::S128 => Ok(Type::S128 { span }),
                     TokenType::Ssize => Ok(Type::SSize { span }),
 
                     TokenType::U8 => Ok(Type::U8 { span }),
@@ -83,6 +85,8 @@ pub fn build_type(ctx: &mut ParserContext<'_>, parse_expr: bool) -> Result<Type,
 
                     TokenType::Bool => Ok(Type::Bool(span)),
 
+                    TokenType::F16 => Ok(Type::F16 { span }),
+                    TokenType::BF16 => Ok(Type::BF16 { span }),
                     TokenType::F32 => Ok(Type::F32 { span }),
                     TokenType::F64 => Ok(Type::F64 { span }),
                     TokenType::F128 => Ok(Type::F128 { span }),
@@ -163,6 +167,10 @@ pub fn build_type(ctx: &mut ParserContext<'_>, parse_expr: bool) -> Result<Type,
             let name: &str = identifier_tk.get_lexeme();
             let span: Span = identifier_tk.get_span();
 
+            if ctx.get_symbols().is_interface(name) {
+                return Ok(Type::Interface(name.to_string(), span));
+            }
+
             let object: Result<FoundSymbolId, CompilationIssue> =
                 ctx.get_symbols().get_symbols_id(name, span);
 
@@ -304,6 +312,75 @@ pub fn build_type(ctx: &mut ParserContext<'_>, parse_expr: bool) -> Result<Type,
     }
 }
 
+pub fn build_bitfield_width(
+    ctx: &mut ParserContext<'_>,
+    field_type: &Type,
+    is_union: bool,
+) -> Result<Option<u8>, CompilationIssue> {
+    if !ctx.check(TokenType::Colon) {
+        return Ok(None);
+    }
+
+    let colon_tk: &Token = ctx.advance()?;
+    let span: Span = colon_tk.get_span();
+
+    let width: Ast = expressions::parse_expr(ctx)?;
+
+    if is_union {
+        return Err(CompilationIssue::Error(
+            CompilationIssueCode::E0001,
+            "Bitfields are not allowed inside a union.".into(),
+            "You should declare it inside a structure.".into(),
+            None,
+            span,
+        ));
+    }
+
+    let max_width: u128 = match field_type {
+        Type::Bool(..) => 1,
+        Type::S8 { .. } | Type::U8 { .. } | Type::Char(..) => 8,
+        Type::S16 { .. } | Type::U16 { .. } => 16,
+        Type::S32 { .. } | Type::U32 { .. } => 32,
+        Type::S64 { .. } | Type::U64 { .. } => 64,
+        Type::S128 { .. } | Type::U128 { .. } => 128,
+
+        _ => {
+            return Err(CompilationIssue::Error(
+                CompilationIssueCode::E0001,
+                format!("Bitfields cannot have the type '{}'.", field_type),
+                "You should use a fixed width integer, 'char' or 'bool' type.".into(),
+                None,
+                span,
+            ));
+        }
+    };
+
+    let Ast::Integer { value, .. } = width else {
+        return Err(CompilationIssue::Error(
+            CompilationIssueCode::E0001,
+            "Expected literal integer value".into(),
+            "You should pass the bitfield width as an integer literal.".into(),
+            None,
+            span,
+        ));
+    };
+
+    if value == 0 || value > max_width {
+        return Err(CompilationIssue::Error(
+            CompilationIssueCode::E0001,
+            format!("Invalid bitfield width '{}'.", value),
+            format!(
+                "You should pass a width between 1 and {} for the type '{}'.",
+                max_width, field_type
+            ),
+            None,
+            span,
+        ));
+    }
+
+    Ok(u8::try_from(value).ok())
+}
+
 fn parse_anonymous_function_type(
     ctx: &mut ParserContext<'_>,
     span: Span,
@@ -371,6 +448,61 @@ fn parse_constant_type(ctx: &mut ParserContext<'_>, span: Span) -> Result<Type,
     Ok(Type::Const(inner_type.into(), span))
 }
 
+fn parse_vector_type(ctx: &mut ParserContext<'_>, span: Span) -> Result<Type, CompilationIssue> {
+    ctx.consume(
+        TokenType::LBracket,
+        CompilationIssueCode::E0001,
+        "Expected '['.".into(),
+    )?;
+
+    let element_type: Type = self::build_type(ctx, false)?;
+
+    if !element_type.is_numeric_type() {
+        ctx.add_error_report(CompilationIssue::Error(
+            CompilationIssueCode::E0019,
+            format!(
+                "Expected integer, floating point or boolean element type, got '{}' type.",
+                element_type
+            ),
+            "Vector lanes can only hold scalar values.".into(),
+            None,
+            span,
+        ));
+    }
+
+    ctx.consume(
+        TokenType::SemiColon,
+        CompilationIssueCode::E0001,
+        "Expected ';'.".into(),
+    )?;
+
+    let lanes: Ast = expressions::parse_expr(ctx)?;
+
+    let lanes: u32 = if let Ast::Integer { value, .. } = lanes {
+        u32::try_from(value).unwrap_or_default()
+    } else {
+        0
+    };
+
+    if lanes == 0 || !lanes.is_power_of_two() {
+        ctx.add_error_report(CompilationIssue::Error(
+            CompilationIssueCode::E0001,
+            "Expected a power of two literal integer value.".into(),
+            "The number of vector lanes must be a literal power of two, like '4' or '8'.".into(),
+            None,
+            span,
+        ));
+    }
+
+    ctx.consume(
+        TokenType::RBracket,
+        CompilationIssueCode::E0001,
+        "Expected ']'.".into(),
+    )?;
+
+    Ok(Type::Vector(element_type.into(), lanes, span))
+}
+
 fn parse_array_type(ctx: &mut ParserContext<'_>, span: Span) -> Result<Type, CompilationIssue> {
     ctx.consume(
         TokenType::LBracket,
@@ -410,7 +542,7 @@ fn parse_array_type(ctx: &mut ParserContext<'_>, span: Span) -> Result<Type, Com
             ));
         }
 
-        let size: u64 = if let Ast::Integer { value, .. } = size {
+        let size: u128 = if let Ast::Integer { value, .. } = size {
             value
         } else {
             0
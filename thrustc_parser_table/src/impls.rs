@@ -341,6 +341,6 @@ impl ConstructorExtensions for ConstructorData<'_> {
     #[inline]
     fn get_type(&self, name: &str, modificator: StructureTypeModificator, span: Span) -> Type {
         let types: Vec<Type> = self.iter().map(|field| field.2.clone()).collect();
-        Type::create_struct_type(name.to_string(), types.as_slice(), modificator, span)
+        Type::create_struct_type(name.to_string(), types.as_slice(), &[], modificator, span)
     }
 }
@@ -22,16 +22,19 @@ pub mod traits;
 
 use thrustc_ast::Ast;
 use thrustc_diagnostician::Diagnostician;
-use thrustc_errors::{CompilationIssue, CompilationIssueCode, CompilationPosition};
+use thrustc_errors::{
+    CompilationIssue, CompilationIssueCode, CompilationLabel, CompilationPosition,
+};
 use thrustc_options::{CompilationUnit, CompilerOptions};
 use thrustc_span::Span;
 
 use thrustc_entities::parser::{
     AssemblerFunction, AssemblerFunctions, ConstantSymbol, CustomTypeSymbol, EnumSymbol,
     FoundSymbolId, Function, Functions, GlobalConstants, GlobalCustomTypes, GlobalEnums,
-    GlobalStatics, GlobalStructs, Intrinsic, Intrinsics, LLISymbol, LLIs, LocalConstants,
-    LocalCustomTypes, LocalEnums, LocalStatics, LocalStructs, LocalSymbol, Locals, ParameterSymbol,
-    Parameters, StaticSymbol, Struct,
+    GlobalStatics, GlobalStructs, ImplementationSymbol, Implementations, InterfaceSymbol,
+    Interfaces, Intrinsic, Intrinsics, LLISymbol, LLIs, LocalConstants, LocalCustomTypes,
+    LocalEnums, LocalStatics, LocalStructs, LocalSymbol, Locals, ParameterSymbol, Parameters,
+    StaticSymbol, Struct,
 };
 
 #[derive(Clone, Debug, Default)]
@@ -46,6 +49,9 @@ pub struct SymbolTable<'parser> {
     global_constants: GlobalConstants<'parser>,
     global_enums: GlobalEnums<'parser>,
 
+    interfaces: Interfaces<'parser>,
+    implementations: Implementations<'parser>,
+
     local_structs: LocalStructs<'parser>,
     local_statics: LocalStatics<'parser>,
     local_constants: LocalConstants<'parser>,
@@ -56,6 +62,8 @@ pub struct SymbolTable<'parser> {
     llis: LLIs<'parser>,
     parameters: Parameters<'parser>,
 
+    external_symbols: Vec<&'parser str>,
+
     diagnostician: Diagnostician,
 }
 
@@ -78,6 +86,9 @@ impl<'parser> SymbolTable<'parser> {
             global_custom_types: ahash::AHashMap::with_capacity(u8::MAX as usize),
             global_enums: ahash::AHashMap::with_capacity(u8::MAX as usize),
 
+            interfaces: ahash::AHashMap::with_capacity(u8::MAX as usize),
+            implementations: ahash::AHashMap::with_capacity(u8::MAX as usize),
+
             local_structs: Vec::with_capacity(u8::MAX as usize),
             local_statics: Vec::with_capacity(u8::MAX as usize),
             local_constants: Vec::with_capacity(u8::MAX as usize),
@@ -87,11 +98,49 @@ impl<'parser> SymbolTable<'parser> {
             llis: Vec::with_capacity(u8::MAX as usize),
 
             parameters: ahash::AHashMap::with_capacity(10),
+            external_symbols: Vec::with_capacity(u8::MAX as usize),
             diagnostician: Diagnostician::new(file, options),
         }
     }
 }
 
+impl<'parser> SymbolTable<'parser> {
+    #[inline]
+    pub fn add_external_symbols(&mut self, symbols: impl IntoIterator<Item = &'parser str>) {
+        self.external_symbols.extend(symbols);
+    }
+
+    pub fn get_similar_symbols(&self, id: &str) -> Option<String> {
+        let candidates = self
+            .functions
+            .keys()
+            .chain(self.asm_functions.keys())
+            .chain(self.intrinsics.keys())
+            .chain(self.global_custom_types.keys())
+            .chain(self.global_statics.keys())
+            .chain(self.global_structs.keys())
+            .chain(self.global_constants.keys())
+            .chain(self.global_enums.keys())
+            .chain(self.interfaces.keys())
+            .chain(self.local_structs.iter().flat_map(|scope| scope.keys()))
+            .chain(self.local_statics.iter().flat_map(|scope| scope.keys()))
+            .chain(self.local_constants.iter().flat_map(|scope| scope.keys()))
+            .chain(
+                self.local_custom_types
+                    .iter()
+                    .flat_map(|scope| scope.keys()),
+            )
+            .chain(self.local_enums.iter().flat_map(|scope| scope.keys()))
+            .chain(self.locals.iter().flat_map(|scope| scope.keys()))
+            .chain(self.llis.iter().flat_map(|scope| scope.keys()))
+            .chain(self.parameters.keys())
+            .chain(self.external_symbols.iter())
+            .copied();
+
+        thrustc_utils::did_you_mean(id, candidates)
+    }
+}
+
 impl SymbolTable<'_> {
     #[inline]
     pub fn begin_scope(&mut self) {
@@ -154,14 +203,18 @@ impl<'parser> SymbolTable<'parser> {
                     ..
                 } = node
                 {
-                    if self.parameters.contains_key(id) {
+                    if let Some((.., previous_span)) = self.parameters.get(id) {
                         return Err(CompilationIssue::Error(
                             CompilationIssueCode::E0004,
                             format!("'{}' parameter was declared before.", id),
                             "You should rename it or remove the copy.".into(),
                             None,
                             *span,
-                        ));
+                        )
+                        .with_labels(vec![CompilationLabel::new(
+                            *previous_span,
+                            format!("'{}' was first declared here.", id),
+                        )]));
                     }
 
                     self.parameters.insert(id, (kind.clone(), *metadata, *span));
@@ -341,17 +394,21 @@ impl<'parser> SymbolTable<'parser> {
         span: Span,
     ) -> Result<(), CompilationIssue> {
         if let Some(last_scope) = self.local_enums.last_mut() {
-            if last_scope.contains_key(id) {
+            if let Some((_, previous_span)) = last_scope.get(id) {
                 return Err(CompilationIssue::Error(
                     CompilationIssueCode::E0004,
                     format!("Enum '{}' was declared before.", id),
                     "You should rename it or remove the copy.".into(),
                     None,
                     span,
-                ));
+                )
+                .with_labels(vec![CompilationLabel::new(
+                    *previous_span,
+                    format!("'{}' was first declared here.", id),
+                )]));
             }
 
-            last_scope.insert(id, union);
+            last_scope.insert(id, (union, span));
 
             Ok(())
         } else {
@@ -386,6 +443,44 @@ impl<'parser> SymbolTable<'parser> {
         Ok(())
     }
 
+    pub fn new_interface(
+        &mut self,
+        id: &'parser str,
+        interface: InterfaceSymbol<'parser>,
+    ) -> Result<(), CompilationIssue> {
+        self.interfaces.insert(id, interface);
+
+        Ok(())
+    }
+
+    pub fn new_implementation(
+        &mut self,
+        target: &'parser str,
+        interface: &'parser str,
+        implementation: ImplementationSymbol<'parser>,
+    ) -> Result<(), CompilationIssue> {
+        if let Some((_, previous_span)) = self.implementations.get(&(target, interface)) {
+            if *previous_span != implementation.1 {
+                return Err(CompilationIssue::Error(
+                    CompilationIssueCode::E0004,
+                    format!("'{}' already implements '{}'.", target, interface),
+                    "You should remove the copy.".into(),
+                    None,
+                    implementation.1,
+                )
+                .with_labels(vec![CompilationLabel::new(
+                    *previous_span,
+                    "It was first implemented here.".into(),
+                )]));
+            }
+        }
+
+        self.implementations
+            .insert((target, interface), implementation);
+
+        Ok(())
+    }
+
     pub fn new_intrinsic(
         &mut self,
         id: &'parser str,
@@ -673,7 +768,7 @@ impl<'parser> SymbolTable<'parser> {
             CompilationIssueCode::E0028,
             format!("'{}' not found", id),
             "You should either create it or reference it correctly.".into(),
-            None,
+            self.get_similar_symbols(id),
             span,
         ))
     }
@@ -706,7 +801,7 @@ impl<'parser> SymbolTable<'parser> {
             CompilationIssueCode::E0028,
             String::from("LLI not found."),
             "You should either create it or reference it correctly.".into(),
-            None,
+            self.get_similar_symbols(id),
             span,
         ))
     }
@@ -724,7 +819,7 @@ impl<'parser> SymbolTable<'parser> {
                 CompilationIssueCode::E0028,
                 format!("Assembler function '{}' not found in this scope.", id),
                 "You should either create it or reference it correctly.".into(),
-                None,
+                self.get_similar_symbols(id),
                 span,
             ))
         }
@@ -743,12 +838,106 @@ impl<'parser> SymbolTable<'parser> {
                 CompilationIssueCode::E0028,
                 format!("Function '{}' not found in this scope.", id),
                 "You should either create it or reference it correctly.".into(),
-                None,
+                self.get_similar_symbols(id),
                 span,
             ))
         }
     }
 
+    #[inline]
+    pub fn get_interface_by_id(
+        &self,
+        span: Span,
+        id: &str,
+    ) -> Result<InterfaceSymbol<'parser>, CompilationIssue> {
+        if let Some(interface) = self.interfaces.get(id).cloned() {
+            Ok(interface)
+        } else {
+            Err(CompilationIssue::Error(
+                CompilationIssueCode::E0028,
+                format!("Interface '{}' not found in this scope.", id),
+                "You should either create it or reference it correctly.".into(),
+                self.get_similar_symbols(id),
+                span,
+            ))
+        }
+    }
+
+    #[inline]
+    pub fn get_implementation(
+        &self,
+        target: &str,
+        interface: &str,
+    ) -> Option<(
+        &(&'parser str, &'parser str),
+        &ImplementationSymbol<'parser>,
+    )> {
+        self.implementations
+            .iter()
+            .find(|((other_target, other_interface), _)| {
+                *other_target == target && *other_interface == interface
+            })
+    }
+
+    pub fn get_implemented_method(
+        &self,
+        span: Span,
+        target: &str,
+        method: &str,
+    ) -> Result<&'parser str, CompilationIssue> {
+        let mut candidates: Vec<(&'parser str, &'parser str)> = Vec::with_capacity(10);
+
+        {
+            for ((other_target, interface), (methods, _)) in self.implementations.iter() {
+                if *other_target != target {
+                    continue;
+                }
+
+                let Some((interface_methods, _)) = self.interfaces.get(interface) else {
+                    continue;
+                };
+
+                let position: Option<usize> = interface_methods
+                    .iter()
+                    .position(|(name, ..)| *name == method);
+
+                if let Some(function) = position.and_then(|index| methods.get(index)) {
+                    candidates.push((*interface, *function));
+                }
+            }
+        }
+
+        match candidates.as_slice() {
+            [(_, function)] => Ok(*function),
+            [] => Err(CompilationIssue::Error(
+                CompilationIssueCode::E0028,
+                format!("Method '{}' not found for '{}'.", method, target),
+                "You should implement an interface that declares it.".into(),
+                None,
+                span,
+            )),
+            _ => Err(CompilationIssue::Error(
+                CompilationIssueCode::E0042,
+                format!("Method '{}' is ambiguous for '{}'.", method, target),
+                "You should cast the value to the interface that you want to use.".into(),
+                Some(format!(
+                    "It is declared by {}.",
+                    candidates
+                        .iter()
+                        .map(|(interface, _)| format!("'{}'", interface))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                )),
+                span,
+            )),
+        }
+    }
+
+    #[inline]
+    pub fn is_interface(&self, id: &str) -> bool {
+        self.interfaces.contains_key(id)
+    }
+
     #[inline]
     pub fn get_intrinsic_by_id(
         &self,
@@ -762,7 +951,7 @@ impl<'parser> SymbolTable<'parser> {
                 CompilationIssueCode::E0028,
                 format!("Compiler intrinsic '{}' not found in this scope.", id),
                 "You should either create it or reference it correctly.".into(),
-                None,
+                self.get_similar_symbols(id),
                 span,
             ))
         }
@@ -782,8 +971,8 @@ impl<'parser> SymbolTable<'parser> {
         }
 
         if let Some(scope) = self.local_enums.get(scope_idx) {
-            if let Some(lenum) = scope.get(id).cloned() {
-                return Ok(lenum);
+            if let Some((lenum, _)) = scope.get(id) {
+                return Ok(lenum.clone());
             }
         } else {
             return Err(CompilationIssue::FrontEndBug(
@@ -800,7 +989,7 @@ impl<'parser> SymbolTable<'parser> {
             CompilationIssueCode::E0028,
             format!("Enum '{}' not found.", id),
             "You should either create it or reference it correctly.".into(),
-            None,
+            self.get_similar_symbols(id),
             span,
         ))
     }
@@ -837,7 +1026,7 @@ impl<'parser> SymbolTable<'parser> {
             CompilationIssueCode::E0028,
             format!("Type '{}' not found in this scope.", id),
             "You should either create it or reference it correctly.".into(),
-            None,
+            self.get_similar_symbols(id),
             span,
         ))
     }
@@ -868,7 +1057,7 @@ impl<'parser> SymbolTable<'parser> {
             CompilationIssueCode::E0028,
             format!("Variable '{}' not found in this scope.", local_id),
             "You should either create it or reference it correctly.".into(),
-            None,
+            self.get_similar_symbols(local_id),
             span,
         ))
     }
@@ -905,7 +1094,7 @@ impl<'parser> SymbolTable<'parser> {
             CompilationIssueCode::E0028,
             format!("Static '{}' not found in this scope.", id),
             "You should either create it or reference it correctly.".into(),
-            None,
+            self.get_similar_symbols(id),
             span,
         ))
     }
@@ -942,7 +1131,7 @@ impl<'parser> SymbolTable<'parser> {
             CompilationIssueCode::E0028,
             format!("Constant '{}' not found in this scope.", id),
             "You should either create it or reference it correctly.".into(),
-            None,
+            self.get_similar_symbols(id),
             span,
         ))
     }
@@ -960,7 +1149,7 @@ impl<'parser> SymbolTable<'parser> {
                 CompilationIssueCode::E0028,
                 format!("Parameter '{}' not found in this scope.", parameter_id),
                 "You should either create it or reference it correctly.".into(),
-                None,
+                self.get_similar_symbols(parameter_id),
                 span,
             ))
         }
@@ -998,7 +1187,7 @@ impl<'parser> SymbolTable<'parser> {
             CompilationIssueCode::E0028,
             format!("'{}' structure not found in this scope.", id),
             "You should either create it or reference it correctly.".into(),
-            None,
+            self.get_similar_symbols(id),
             span,
         ))
     }
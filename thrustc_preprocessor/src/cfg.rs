@@ -0,0 +1,373 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+
+use thrustc_diagnostician::Diagnostician;
+use thrustc_errors::{CompilationIssue, CompilationIssueCode};
+use thrustc_llvm_target_triple::LLVMTargetTriple;
+use thrustc_logging::LoggingType;
+use thrustc_options::{CompilationUnit, CompilerOptions};
+use thrustc_span::Span;
+use thrustc_token::{Token, traits::TokenExtensions};
+use thrustc_token_type::TokenType;
+
+const TARGET_KEYS: [&str; 4] = ["os", "arch", "vendor", "env"];
+
+pub fn configure(
+    tokens: Vec<Token>,
+    options: &CompilerOptions,
+    file: &CompilationUnit,
+) -> Result<Vec<Token>, ()> {
+    if !tokens
+        .iter()
+        .any(|token| token.get_type() == TokenType::Cfg)
+    {
+        return Ok(tokens);
+    }
+
+    let (removed, errors): (Vec<bool>, Vec<CompilationIssue>) =
+        CfgEvaluator::new(&tokens, options).evaluate();
+
+    if !errors.is_empty() {
+        let mut diagnostician: Diagnostician = Diagnostician::new(file, options);
+
+        for error in errors.iter() {
+            diagnostician.dispatch_diagnostic(error, LoggingType::Error);
+        }
+
+        return Err(());
+    }
+
+    Ok(tokens
+        .into_iter()
+        .zip(removed)
+        .filter_map(|(token, removed)| (!removed).then_some(token))
+        .collect())
+}
+
+#[derive(Debug)]
+struct CfgEvaluator<'cfg> {
+    tokens: &'cfg [Token],
+    target: &'cfg LLVMTargetTriple,
+    user: &'cfg [(String, String)],
+    removed: Vec<bool>,
+    errors: Vec<CompilationIssue>,
+    current: usize,
+}
+
+impl<'cfg> CfgEvaluator<'cfg> {
+    fn new(tokens: &'cfg [Token], options: &'cfg CompilerOptions) -> Self {
+        Self {
+            tokens,
+            target: options
+                .get_llvm_backend()
+                .get_target()
+                .get_normalized_target_triple(),
+            user: options.get_cfg(),
+            removed: vec![false; tokens.len()],
+            errors: Vec::with_capacity(u8::MAX as usize),
+            current: 0,
+        }
+    }
+}
+
+impl CfgEvaluator<'_> {
+    fn evaluate(mut self) -> (Vec<bool>, Vec<CompilationIssue>) {
+        while self.current < self.tokens.len() {
+            if self.tokens[self.current].get_type() != TokenType::Cfg {
+                self.current += 1;
+                continue;
+            }
+
+            let start: usize = self.current;
+
+            let Ok(enabled) = self.parse_predicate() else {
+                break;
+            };
+
+            let mut end: usize = self.current;
+
+            if !enabled {
+                end = self.get_item_end();
+
+                if end == self.current {
+                    let span: Span = self.tokens[start].get_span();
+
+                    self.errors.push(CompilationIssue::Error(
+                        CompilationIssueCode::E0012,
+                        "Expected a declaration, statement or block after the '@cfg' attribute."
+                            .into(),
+                        "You should place the '@cfg' attribute right before the item it configures."
+                            .into(),
+                        None,
+                        span,
+                    ));
+
+                    break;
+                }
+            }
+
+            self.removed[start..end].fill(true);
+            self.current = end;
+        }
+
+        (self.removed, self.errors)
+    }
+}
+
+impl<'cfg> CfgEvaluator<'cfg> {
+    fn parse_predicate(&mut self) -> Result<bool, ()> {
+        let tokens: &'cfg [Token] = self.tokens;
+
+        self.expect(TokenType::Cfg, "Expected '@cfg'.")?;
+        self.expect(TokenType::LParen, "Expected '('.")?;
+
+        let mut enabled: bool = true;
+
+        loop {
+            let key_tk: &'cfg Token =
+                self.expect(TokenType::Identifier, "Expected a configuration key.")?;
+
+            let key: &'cfg str = key_tk.get_lexeme();
+            let span: Span = key_tk.get_span();
+
+            self.expect(TokenType::Eq, "Expected '='.")?;
+
+            let value: &'cfg str = match tokens.get(self.current) {
+                Some(token)
+                    if matches!(token.get_type(), TokenType::CString | TokenType::CNString) =>
+                {
+                    self.current += 1;
+                    token.get_lexeme()
+                }
+                _ => {
+                    self.report("Expected a string literal as the configuration value.");
+                    return Err(());
+                }
+            };
+
+            enabled &= self.matches(key, value, span)?;
+
+            if !self.check(TokenType::Comma) {
+                break;
+            }
+
+            self.current += 1;
+        }
+
+        self.expect(TokenType::RParen, "Expected ')'.")?;
+
+        Ok(enabled)
+    }
+
+    fn matches(&mut self, key: &str, value: &str, span: Span) -> Result<bool, ()> {
+        match key {
+            "os" => {
+                let os: &str = self.target.get_os();
+                Ok(value == os || value == get_canonical_os(os))
+            }
+            "arch" => {
+                let arch: &str = self.target.get_arch();
+                Ok(value == arch || value == get_canonical_arch(arch))
+            }
+            "vendor" => Ok(value == self.target.get_vendor()),
+            "env" => {
+                let abi: &str = self.target.get_abi();
+                Ok(value == abi || value == get_unversioned(abi))
+            }
+
+            _ if key == "feature" || self.user.iter().any(|(name, _)| name == key) => Ok(self
+                .user
+                .iter()
+                .any(|(name, enabled)| name == key && enabled == value)),
+
+            _ => {
+                self.errors.push(CompilationIssue::Error(
+                    CompilationIssueCode::E0012,
+                    format!("Unknown configuration key '{}'.", key),
+                    format!(
+                        "Valid keys are {}, 'feature' or any key passed with '--cfg key=value'.",
+                        TARGET_KEYS
+                            .iter()
+                            .map(|key| format!("'{}'", key))
+                            .collect::<Vec<String>>()
+                            .join(", ")
+                    ),
+                    None,
+                    span,
+                ));
+
+                Err(())
+            }
+        }
+    }
+}
+
+impl CfgEvaluator<'_> {
+    fn get_item_end(&self) -> usize {
+        let mut depth: usize = 0;
+        let mut position: usize = self.current;
+
+        while let Some(token) = self.tokens.get(position) {
+            match token.get_type() {
+                TokenType::Eof => return position,
+
+                TokenType::LParen | TokenType::LBracket | TokenType::LBrace => depth += 1,
+
+                TokenType::RParen | TokenType::RBracket if depth == 0 => return position,
+                TokenType::RParen | TokenType::RBracket => depth -= 1,
+
+                TokenType::RBrace if depth == 0 => return position,
+                TokenType::RBrace => {
+                    depth -= 1;
+
+                    if depth == 0 {
+                        match self.tokens.get(position + 1).map(|next| next.get_type()) {
+                            Some(TokenType::Else | TokenType::Elif) => (),
+                            Some(TokenType::SemiColon) => return position + 2,
+                            _ => return position + 1,
+                        }
+                    }
+                }
+
+                TokenType::SemiColon if depth == 0 => return position + 1,
+
+                // Fields and parameters end at the comma that separates them from the next one.
+                TokenType::Comma if depth == 0 => return position + 1,
+
+                _ => (),
+            }
+
+            position += 1;
+        }
+
+        position
+    }
+}
+
+impl<'cfg> CfgEvaluator<'cfg> {
+    fn expect(&mut self, kind: TokenType, message: &str) -> Result<&'cfg Token, ()> {
+        let tokens: &'cfg [Token] = self.tokens;
+
+        if self.check(kind) {
+            self.current += 1;
+            return Ok(&tokens[self.current - 1]);
+        }
+
+        self.report(message);
+
+        Err(())
+    }
+
+    fn report(&mut self, message: &str) {
+        let span: Span = self
+            .tokens
+            .get(self.current)
+            .or_else(|| self.tokens.last())
+            .map_or_else(Span::nothing, |token| token.get_span());
+
+        self.errors.push(CompilationIssue::Error(
+            CompilationIssueCode::E0012,
+            message.into(),
+            "The syntax is '@cfg(key = \"value\", ...)'.".into(),
+            None,
+            span,
+        ));
+    }
+
+    #[inline]
+    fn check(&self, kind: TokenType) -> bool {
+        self.tokens
+            .get(self.current)
+            .is_some_and(|token| token.get_type() == kind)
+    }
+}
+
+fn get_unversioned(name: &str) -> &str {
+    name.trim_end_matches(|c: char| c.is_ascii_digit() || c == '.')
+}
+
+fn get_canonical_os(os: &str) -> &str {
+    match get_unversioned(os) {
+        "darwin" | "macosx" => "macos",
+        "win32" => "windows",
+        os => os,
+    }
+}
+
+fn get_canonical_arch(arch: &str) -> &str {
+    match arch {
+        "arm64" => "aarch64",
+        "amd64" => "x86_64",
+        "i386" | "i486" | "i586" | "i686" => "x86",
+        arch => arch,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use thrustc_lexer::Lexer;
+    use thrustc_options::{CompilationUnit, CompilerOptions};
+    use thrustc_token::{Token, traits::TokenExtensions};
+    use thrustc_token_type::TokenType;
+
+    fn configure(code: &str) -> Result<String, ()> {
+        let options: CompilerOptions = CompilerOptions::new();
+        let file: CompilationUnit = CompilationUnit::new(
+            "test".into(),
+            PathBuf::from("test.thrust"),
+            code.into(),
+            "test".into(),
+        );
+
+        let tokens: Vec<Token> = Lexer::lex(&file, &options)?;
+
+        Ok(super::configure(tokens, &options, &file)?
+            .iter()
+            .filter(|token| token.get_type() != TokenType::Eof)
+            .map(|token| token.get_lexeme())
+            .collect::<Vec<&str>>()
+            .join(" "))
+    }
+
+    #[test]
+    fn removes_only_the_configured_field() {
+        assert_eq!(
+            self::configure("struct S { @cfg(feature = \"x\") a: u32, b: u32 }"),
+            Ok("struct S { b : u32 }".into())
+        );
+    }
+
+    #[test]
+    fn removes_only_the_configured_parameter() {
+        assert_eq!(
+            self::configure("fn f(@cfg(feature = \"x\") a: u32, b: u32) {}"),
+            Ok("fn f ( b : u32 ) { }".into())
+        );
+    }
+
+    #[test]
+    fn removes_the_whole_configured_declaration() {
+        assert_eq!(
+            self::configure("@cfg(feature = \"x\") fn f(a: u32, b: u32) {} fn g() {}"),
+            Ok("fn g ( ) { }".into())
+        );
+    }
+}
@@ -19,23 +19,27 @@
 
 use std::path::PathBuf;
 
-use thrustc_diagnostician::Diagnostician;
+use thrustc_diagnostician::{Diagnostician, warnings::AllowedWarnings};
 use thrustc_errors::{CompilationIssue, CompilationPosition};
 use thrustc_options::{CompilationUnit, CompilerOptions};
 use thrustc_span::Span;
 use thrustc_token::{Token, traits::TokenExtensions};
 use thrustc_token_type::TokenType;
 
-use ahash::AHashSet as HashSet;
+use ahash::AHashMap as HashMap;
+
+/// Every module in the import graph, mapped to the file and span where it was first imported.
+pub type VisitedModules = HashMap<PathBuf, Option<(PathBuf, Span)>>;
 
 #[derive(Debug)]
 pub struct PreprocessorContext<'preprocessor> {
     tokens: &'preprocessor [Token],
     options: &'preprocessor CompilerOptions,
-    visited: HashSet<PathBuf>,
+    visited: VisitedModules,
     file: &'preprocessor CompilationUnit,
     diagnostician: Diagnostician,
     errors: Vec<CompilationIssue>,
+    warnings: Vec<CompilationIssue>,
     current: usize,
 }
 
@@ -44,15 +48,20 @@ impl<'preprocessor> PreprocessorContext<'preprocessor> {
         tokens: &'preprocessor [Token],
         options: &'preprocessor CompilerOptions,
         file: &'preprocessor CompilationUnit,
-        visited: HashSet<PathBuf>,
+        visited: VisitedModules,
     ) -> Self {
         Self {
             tokens,
             options,
             visited,
             file,
-            diagnostician: Diagnostician::new(file, options),
+            diagnostician: Diagnostician::with_allowed_warnings_set(
+                file,
+                options,
+                AllowedWarnings::from_tokens(file.get_path(), tokens),
+            ),
             errors: Vec::with_capacity(u8::MAX as usize),
+            warnings: Vec::with_capacity(u8::MAX as usize),
             current: 0,
         }
     }
@@ -60,6 +69,13 @@ impl<'preprocessor> PreprocessorContext<'preprocessor> {
 
 impl PreprocessorContext<'_> {
     pub fn check_status(&mut self) -> Result<(), ()> {
+        {
+            for warning in self.warnings.iter() {
+                self.diagnostician
+                    .dispatch_diagnostic(warning, thrustc_logging::LoggingType::Warning);
+            }
+        }
+
         if !self.errors.is_empty() {
             {
                 for error in self.errors.iter() {
@@ -69,6 +85,10 @@ impl PreprocessorContext<'_> {
             }
         }
 
+        if self.diagnostician.has_denied_warnings() {
+            return Err(());
+        }
+
         Ok(())
     }
 }
@@ -260,17 +280,22 @@ impl PreprocessorContext<'_> {
     pub fn add_error(&mut self, error: CompilationIssue) {
         self.errors.push(error);
     }
+
+    #[inline]
+    pub fn add_warning(&mut self, warning: CompilationIssue) {
+        self.warnings.push(warning);
+    }
 }
 
 impl PreprocessorContext<'_> {
     #[inline]
     pub fn has_visited(&self, path: &PathBuf) -> bool {
-        self.visited.contains(path)
+        self.visited.contains_key(path)
     }
 
     #[inline]
-    pub fn mark_visited(&mut self, path: PathBuf) {
-        self.visited.insert(path);
+    pub fn mark_visited(&mut self, path: PathBuf, imported_from: (PathBuf, Span)) {
+        self.visited.insert(path, Some(imported_from));
     }
 }
 
@@ -281,7 +306,7 @@ impl<'module_parser> PreprocessorContext<'module_parser> {
     }
 
     #[inline]
-    pub fn get_global_visited_modules(&self) -> HashSet<PathBuf> {
+    pub fn get_global_visited_modules(&self) -> VisitedModules {
         self.visited.clone()
     }
 
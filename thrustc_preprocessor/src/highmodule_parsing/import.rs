@@ -31,7 +31,7 @@ use crate::{context::PreprocessorContext, module::Module, parser::ModuleParser};
 pub fn parse_import<'preprocessor>(
     parser: &mut PreprocessorContext<'preprocessor>,
 ) -> Result<Option<Module>, ()> {
-    parser.consume(TokenType::Import)?;
+    let import_span: Span = parser.consume(TokenType::Import)?.get_span();
 
     let current_path: PathBuf = parser.get_compilation_unit().get_path().to_path_buf();
 
@@ -55,7 +55,19 @@ pub fn parse_import<'preprocessor>(
         module_path = current_dir.join(import_str);
     }
 
-    parser.consume(TokenType::SemiColon)?;
+    // Trailing attributes, such as '@allow(W0027)', are validated later by the parser.
+    parser.advance_until_check(TokenType::SemiColon)?;
+
+    let semicolon_span: Span = parser.consume(TokenType::SemiColon)?.get_span();
+
+    let statement_span: Span = if import_span.get_line() == semicolon_span.get_line() {
+        Span::new((
+            import_span.get_line(),
+            (import_span.get_span_start(), semicolon_span.get_span_end()),
+        ))
+    } else {
+        span
+    };
 
     if module_path == current_dir {
         parser.add_error(CompilationIssue::Error(
@@ -72,7 +84,7 @@ pub fn parse_import<'preprocessor>(
     if parser.has_visited(&module_path) {
         return Ok(None);
     } else {
-        parser.mark_visited(module_path.clone());
+        parser.mark_visited(module_path.clone(), (current_path.clone(), span));
     }
 
     if !module_path.exists() {
@@ -153,10 +165,14 @@ pub fn parse_import<'preprocessor>(
 
     let options: &CompilerOptions = parser.get_options();
 
+    thrustc_ice::register_source(&module_path);
+
     let content: String = thrustc_reader::get_file_source_code(&module_path);
     let file: CompilationUnit = CompilationUnit::new(name, module_path, content, base_name.clone());
 
     let tokens: Vec<Token> = Lexer::lex_for_preprocessor(&file, options)?;
+    let tokens: Vec<Token> = crate::cfg::configure(tokens, options, &file)?;
+    let tokens: Vec<Token> = crate::macros::expand(tokens, options, &file)?;
 
     let subparser: ModuleParser = ModuleParser::new(
         base_name,
@@ -166,7 +182,40 @@ pub fn parse_import<'preprocessor>(
         parser.get_global_visited_modules(),
     );
 
-    let submodule: Module = subparser.parse()?;
+    let mut submodule: Module = subparser.parse()?;
+
+    submodule.set_import_span(statement_span);
 
     Ok(Some(submodule))
 }
+
+pub fn check_unused_imports(
+    parser: &mut PreprocessorContext<'_>,
+    tokens: &[Token],
+    modules: &[Module],
+) {
+    for module in modules.iter() {
+        let Some(span) = module.get_import_span() else {
+            continue;
+        };
+
+        if module.get_symbols().is_empty() {
+            continue;
+        }
+
+        let used: bool = tokens.iter().any(|token| {
+            token.get_type() == TokenType::Identifier && module.contains_symbol(token.get_lexeme())
+        });
+
+        if !used {
+            parser.add_warning(CompilationIssue::Warning(
+                CompilationIssueCode::W0027,
+                format!(
+                    "None of the symbols of '{}' are used. You should remove the import.",
+                    module.get_name()
+                ),
+                span,
+            ));
+        }
+    }
+}
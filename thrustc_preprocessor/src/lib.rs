@@ -23,12 +23,15 @@ use thrustc_options::{CompilationUnit, CompilerOptions};
 use thrustc_token::Token;
 use thrustc_token_type::TokenType;
 
-use crate::{context::PreprocessorContext, module::Module};
-
-use ahash::AHashSet as HashSet;
+use crate::{
+    context::{PreprocessorContext, VisitedModules},
+    module::Module,
+};
 
+mod cfg;
 mod context;
 mod highmodule_parsing;
+mod macros;
 pub mod module;
 mod moduletable;
 mod parser;
@@ -48,6 +51,26 @@ impl Preprocessor {
     }
 }
 
+impl Preprocessor {
+    #[inline]
+    pub fn configure(
+        tokens: Vec<Token>,
+        options: &CompilerOptions,
+        file: &CompilationUnit,
+    ) -> Result<Vec<Token>, ()> {
+        cfg::configure(tokens, options, file)
+    }
+
+    #[inline]
+    pub fn expand(
+        tokens: Vec<Token>,
+        options: &CompilerOptions,
+        file: &CompilationUnit,
+    ) -> Result<Vec<Token>, ()> {
+        macros::expand(tokens, options, file)
+    }
+}
+
 impl<'preprocessor> Preprocessor {
     pub fn generate_modules(
         &mut self,
@@ -57,8 +80,8 @@ impl<'preprocessor> Preprocessor {
     ) -> Result<&[Module], ()> {
         let file_path: std::path::PathBuf = file.get_path().to_path_buf();
 
-        let mut visited: HashSet<std::path::PathBuf> = HashSet::with_capacity(u8::MAX as usize);
-        visited.insert(file_path);
+        let mut visited: VisitedModules = VisitedModules::with_capacity(u8::MAX as usize);
+        visited.insert(file_path, None);
 
         let mut context: PreprocessorContext<'_> =
             PreprocessorContext::new(tokens, options, file, visited);
@@ -73,6 +96,8 @@ impl<'preprocessor> Preprocessor {
             let _ = context.only_advance();
         }
 
+        highmodule_parsing::import::check_unused_imports(&mut context, tokens, &self.modules);
+
         context.check_status()?;
 
         Ok(self.modules.as_slice())
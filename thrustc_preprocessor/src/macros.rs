@@ -0,0 +1,579 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+
+use thrustc_diagnostician::Diagnostician;
+use thrustc_errors::{CompilationIssue, CompilationIssueCode, CompilationLabel};
+use thrustc_logging::LoggingType;
+use thrustc_options::{CompilationUnit, CompilerOptions};
+use thrustc_span::Span;
+use thrustc_token::{Token, traits::TokenExtensions};
+use thrustc_token_type::TokenType;
+
+use ahash::AHashMap as HashMap;
+
+const MAX_EXPANSION_DEPTH: usize = 64;
+
+pub fn expand(
+    tokens: Vec<Token>,
+    options: &CompilerOptions,
+    file: &CompilationUnit,
+) -> Result<Vec<Token>, ()> {
+    if !tokens
+        .iter()
+        .any(|token| token.get_type() == TokenType::Macro)
+    {
+        return Ok(tokens);
+    }
+
+    let (expanded, errors): (Vec<Token>, Vec<CompilationIssue>) =
+        MacroExpander::new().expand(tokens);
+
+    if !errors.is_empty() {
+        let mut diagnostician: Diagnostician = Diagnostician::new(file, options);
+
+        for error in errors.iter() {
+            diagnostician.dispatch_diagnostic(error, LoggingType::Error);
+        }
+
+        return Err(());
+    }
+
+    Ok(expanded)
+}
+
+#[derive(Debug)]
+struct Macro {
+    parameters: Vec<String>,
+    body: Vec<Token>,
+    span: Span,
+}
+
+#[derive(Debug)]
+struct MacroExpander {
+    macros: HashMap<String, Macro>,
+    errors: Vec<CompilationIssue>,
+    expansions: usize,
+}
+
+impl MacroExpander {
+    fn new() -> Self {
+        Self {
+            macros: HashMap::with_capacity(u8::MAX as usize),
+            errors: Vec::with_capacity(u8::MAX as usize),
+            expansions: 0,
+        }
+    }
+}
+
+impl MacroExpander {
+    fn expand(mut self, tokens: Vec<Token>) -> (Vec<Token>, Vec<CompilationIssue>) {
+        let removed: Vec<bool> = self.collect_definitions(&tokens);
+
+        if !self.errors.is_empty() {
+            return (tokens, self.errors);
+        }
+
+        let tokens: Vec<Token> = tokens
+            .into_iter()
+            .zip(removed)
+            .filter_map(|(token, removed)| (!removed).then_some(token))
+            .collect();
+
+        let expanded: Vec<Token> = self.expand_tokens(&tokens, 0);
+
+        (expanded, self.errors)
+    }
+}
+
+impl MacroExpander {
+    fn collect_definitions(&mut self, tokens: &[Token]) -> Vec<bool> {
+        let mut removed: Vec<bool> = vec![false; tokens.len()];
+
+        let mut depth: usize = 0;
+        let mut position: usize = 0;
+
+        while let Some(token) = tokens.get(position) {
+            match token.get_type() {
+                TokenType::Macro if depth == 0 => {
+                    let Ok(end) = self.parse_definition(tokens, position) else {
+                        break;
+                    };
+
+                    removed[position..end].fill(true);
+                    position = end;
+
+                    continue;
+                }
+
+                TokenType::Macro => {
+                    self.errors.push(CompilationIssue::Error(
+                        CompilationIssueCode::E0043,
+                        "Macros can only be defined at the top level.".into(),
+                        "You should move the macro definition outside of any block.".into(),
+                        None,
+                        token.get_span(),
+                    ));
+
+                    break;
+                }
+
+                TokenType::LBrace => depth += 1,
+                TokenType::RBrace => depth = depth.saturating_sub(1),
+
+                _ => (),
+            }
+
+            position += 1;
+        }
+
+        removed
+    }
+
+    fn parse_definition(&mut self, tokens: &[Token], start: usize) -> Result<usize, ()> {
+        let mut position: usize = start + 1;
+
+        let name_tk: &Token = self.expect(
+            tokens,
+            &mut position,
+            TokenType::Identifier,
+            "Expected the macro name.",
+        )?;
+
+        let name: &str = name_tk.get_lexeme();
+        let span: Span = name_tk.get_span();
+
+        self.expect(tokens, &mut position, TokenType::LParen, "Expected '('.")?;
+
+        let mut parameters: Vec<String> = Vec::with_capacity(u8::MAX as usize);
+
+        while !self.check(tokens, position, TokenType::RParen) {
+            let parameter_tk: &Token = self.expect(
+                tokens,
+                &mut position,
+                TokenType::Identifier,
+                "Expected a macro parameter.",
+            )?;
+
+            let parameter: &str = parameter_tk.get_lexeme();
+
+            if parameters.iter().any(|other| other == parameter) {
+                self.errors.push(CompilationIssue::Error(
+                    CompilationIssueCode::E0004,
+                    format!("'{}' parameter was declared before.", parameter),
+                    "You should rename it or remove the copy.".into(),
+                    None,
+                    parameter_tk.get_span(),
+                ));
+
+                return Err(());
+            }
+
+            parameters.push(parameter.to_string());
+
+            if !self.check(tokens, position, TokenType::Comma) {
+                break;
+            }
+
+            position += 1;
+        }
+
+        self.expect(tokens, &mut position, TokenType::RParen, "Expected ')'.")?;
+        self.expect(tokens, &mut position, TokenType::LBrace, "Expected '{'.")?;
+
+        let body_start: usize = position;
+        let mut depth: usize = 0;
+
+        loop {
+            match tokens.get(position).map(|token| token.get_type()) {
+                Some(TokenType::RBrace) if depth == 0 => break,
+                Some(TokenType::RBrace) => depth -= 1,
+                Some(TokenType::LBrace) => depth += 1,
+
+                Some(TokenType::Eof) | None => {
+                    self.errors.push(CompilationIssue::Error(
+                        CompilationIssueCode::E0043,
+                        format!("Unclosed body of the macro '{}'.", name),
+                        "You should close the macro body with '}'.".into(),
+                        None,
+                        span,
+                    ));
+
+                    return Err(());
+                }
+
+                _ => (),
+            }
+
+            position += 1;
+        }
+
+        if let Some(previous) = self.macros.get(name) {
+            self.errors.push(
+                CompilationIssue::Error(
+                    CompilationIssueCode::E0004,
+                    format!("'{}' macro was declared before.", name),
+                    "You should rename it or remove the copy.".into(),
+                    None,
+                    span,
+                )
+                .with_labels(vec![CompilationLabel::new(
+                    previous.span,
+                    "Previously declared here.".into(),
+                )]),
+            );
+
+            return Err(());
+        }
+
+        self.macros.insert(
+            name.to_string(),
+            Macro {
+                parameters,
+                body: tokens[body_start..position].to_vec(),
+                span,
+            },
+        );
+
+        Ok(position + 1)
+    }
+}
+
+impl MacroExpander {
+    fn expand_tokens(&mut self, tokens: &[Token], depth: usize) -> Vec<Token> {
+        let mut output: Vec<Token> = Vec::with_capacity(tokens.len());
+        let mut position: usize = 0;
+
+        while let Some(token) = tokens.get(position) {
+            if self.is_invocation(tokens, position) {
+                let Ok((expanded, end)) = self.expand_invocation(tokens, position, depth) else {
+                    return output;
+                };
+
+                output.extend(expanded);
+                position = end;
+
+                continue;
+            }
+
+            output.push(token.clone());
+            position += 1;
+        }
+
+        output
+    }
+
+    fn expand_invocation(
+        &mut self,
+        tokens: &[Token],
+        start: usize,
+        depth: usize,
+    ) -> Result<(Vec<Token>, usize), ()> {
+        let name_tk: &Token = &tokens[start];
+
+        let name: &str = name_tk.get_lexeme();
+        let ascii_name: &str = name_tk.get_ascii_lexeme();
+        let call_site: Span = name_tk.get_span();
+
+        let Some(definition) = self.macros.get(name) else {
+            self.errors.push(CompilationIssue::Error(
+                CompilationIssueCode::E0043,
+                format!("Unknown macro '{}'.", name),
+                "You should define the macro at the top level before using it.".into(),
+                thrustc_utils::did_you_mean(name, self.macros.keys().map(|name| name.as_str())),
+                call_site,
+            ));
+
+            return Err(());
+        };
+
+        if depth >= MAX_EXPANSION_DEPTH {
+            self.errors.push(CompilationIssue::Error(
+                CompilationIssueCode::E0043,
+                format!("Recursion limit reached while expanding '{}'.", name),
+                format!(
+                    "Macro expansions can only be nested {} times.",
+                    MAX_EXPANSION_DEPTH
+                ),
+                None,
+                call_site,
+            ));
+
+            return Err(());
+        }
+
+        let Some((arguments, end)) = self::split_arguments(tokens, start + 3) else {
+            self.errors.push(CompilationIssue::Error(
+                CompilationIssueCode::E0043,
+                format!("Unclosed invocation of the macro '{}'.", name),
+                "You should close the macro arguments with ')'.".into(),
+                None,
+                call_site,
+            ));
+
+            return Err(());
+        };
+
+        if arguments.len() != definition.parameters.len() {
+            self.errors.push(
+                CompilationIssue::Error(
+                    CompilationIssueCode::E0043,
+                    format!(
+                        "Macro '{}' expects {} arguments, but {} were given.",
+                        name,
+                        definition.parameters.len(),
+                        arguments.len()
+                    ),
+                    "You should pass one argument for each macro parameter.".into(),
+                    None,
+                    call_site,
+                )
+                .with_labels(vec![CompilationLabel::new(
+                    definition.span,
+                    "Macro defined here.".into(),
+                )]),
+            );
+
+            return Err(());
+        }
+
+        self.expansions += 1;
+
+        let mut expanded: Vec<Token> = Vec::with_capacity(definition.body.len());
+
+        // Bindings introduced by the macro body are renamed on every expansion to a name
+        // the lexer can never produce, so they can neither capture nor shadow the names
+        // at the call site. Only the binding and the uses inside its scope are renamed.
+        let mut scopes: Vec<HashMap<&str, (String, String)>> = vec![HashMap::new()];
+
+        for (position, token) in definition.body.iter().enumerate() {
+            let previous: Option<TokenType> = position
+                .checked_sub(1)
+                .map(|previous| definition.body[previous].get_type());
+
+            match token.get_type() {
+                TokenType::LBrace => scopes.push(HashMap::new()),
+                TokenType::RBrace if scopes.len() > 1 => {
+                    scopes.pop();
+                }
+
+                _ => (),
+            }
+
+            if token.get_type() == TokenType::Identifier {
+                if let Some(index) = definition
+                    .parameters
+                    .iter()
+                    .position(|parameter| parameter == token.get_lexeme())
+                {
+                    expanded.extend(arguments[index].iter().cloned());
+                    continue;
+                }
+            }
+
+            let mut token: Token = token.clone();
+
+            token.span = token.span.with_expansion(call_site);
+
+            if token.get_type() != TokenType::Identifier || previous == Some(TokenType::Dot) {
+                expanded.push(token);
+                continue;
+            }
+
+            let binding: &str = definition.body[position].get_lexeme();
+
+            if matches!(
+                previous,
+                Some(TokenType::Var | TokenType::Const | TokenType::Static)
+            ) {
+                let renamed: (String, String) = (
+                    format!("{}#{}#{}", token.lexeme, name, self.expansions),
+                    format!("{}#{}#{}", token.ascii, ascii_name, self.expansions),
+                );
+
+                if let Some(scope) = scopes.last_mut() {
+                    scope.insert(binding, renamed);
+                }
+            }
+
+            if let Some((lexeme, ascii)) = scopes.iter().rev().find_map(|scope| scope.get(binding))
+            {
+                token.lexeme = lexeme.clone();
+                token.ascii = ascii.clone();
+            }
+
+            expanded.push(token);
+        }
+
+        Ok((self.expand_tokens(&expanded, depth + 1), end))
+    }
+
+    fn is_invocation(&self, tokens: &[Token], position: usize) -> bool {
+        self.check(tokens, position, TokenType::Identifier)
+            && self.check(tokens, position + 1, TokenType::Bang)
+            && self.check(tokens, position + 2, TokenType::LParen)
+    }
+}
+
+impl MacroExpander {
+    fn expect<'tokens>(
+        &mut self,
+        tokens: &'tokens [Token],
+        position: &mut usize,
+        kind: TokenType,
+        message: &str,
+    ) -> Result<&'tokens Token, ()> {
+        if self.check(tokens, *position, kind) {
+            *position += 1;
+            return Ok(&tokens[*position - 1]);
+        }
+
+        let span: Span = tokens
+            .get(*position)
+            .or_else(|| tokens.last())
+            .map_or_else(Span::nothing, |token| token.get_span());
+
+        self.errors.push(CompilationIssue::Error(
+            CompilationIssueCode::E0043,
+            message.into(),
+            "The syntax is 'macro name(parameters) { ... }'.".into(),
+            None,
+            span,
+        ));
+
+        Err(())
+    }
+
+    #[inline]
+    fn check(&self, tokens: &[Token], position: usize, kind: TokenType) -> bool {
+        tokens
+            .get(position)
+            .is_some_and(|token| token.get_type() == kind)
+    }
+}
+
+fn split_arguments(tokens: &[Token], start: usize) -> Option<(Vec<&[Token]>, usize)> {
+    let mut arguments: Vec<&[Token]> = Vec::with_capacity(u8::MAX as usize);
+
+    let mut depth: usize = 0;
+    let mut argument_start: usize = start;
+    let mut position: usize = start;
+
+    loop {
+        match tokens.get(position)?.get_type() {
+            TokenType::Eof => return None,
+
+            TokenType::LParen | TokenType::LBracket | TokenType::LBrace => depth += 1,
+
+            TokenType::RParen if depth == 0 => break,
+            TokenType::RParen | TokenType::RBracket | TokenType::RBrace => {
+                depth = depth.saturating_sub(1)
+            }
+
+            TokenType::Comma if depth == 0 => {
+                arguments.push(&tokens[argument_start..position]);
+                argument_start = position + 1;
+            }
+
+            _ => (),
+        }
+
+        position += 1;
+    }
+
+    if position > start || !arguments.is_empty() {
+        arguments.push(&tokens[argument_start..position]);
+    }
+
+    Some((arguments, position + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use thrustc_lexer::Lexer;
+    use thrustc_options::{CompilationUnit, CompilerOptions};
+    use thrustc_token::{Token, traits::TokenExtensions};
+    use thrustc_token_type::TokenType;
+
+    fn expand(code: &str) -> Result<String, ()> {
+        let options: CompilerOptions = CompilerOptions::new();
+        let file: CompilationUnit = CompilationUnit::new(
+            "test".into(),
+            PathBuf::from("test.thrust"),
+            code.into(),
+            "test".into(),
+        );
+
+        let tokens: Vec<Token> = Lexer::lex(&file, &options)?;
+
+        Ok(super::expand(tokens, &options, &file)?
+            .iter()
+            .filter(|token| token.get_type() != TokenType::Eof)
+            .map(|token| token.get_lexeme())
+            .collect::<Vec<&str>>()
+            .join(" "))
+    }
+
+    #[test]
+    fn substitutes_the_arguments() {
+        assert_eq!(
+            self::expand("macro add(a, b) { a + b } fn f() { add!(1, 2 * 3); }"),
+            Ok("fn f ( ) { 1 + 2 * 3 ; }".into())
+        );
+    }
+
+    #[test]
+    fn renames_bindings_and_their_uses() {
+        assert_eq!(
+            self::expand("macro m() { var x: u32 = 1; x; } fn f() { m!(); }"),
+            Ok("fn f ( ) { var x#m#1 : u32 = 1 ; x#m#1 ; ; }".into())
+        );
+    }
+
+    #[test]
+    fn leaves_field_names_and_free_identifiers_alone() {
+        assert_eq!(
+            self::expand("macro m(p) { var x: u32 = p.x; y = x; } fn f() { m!(q); }"),
+            Ok("fn f ( ) { var x#m#1 : u32 = q . x ; y = x#m#1 ; ; }".into())
+        );
+    }
+
+    #[test]
+    fn only_renames_uses_inside_the_binding_scope() {
+        assert_eq!(
+            self::expand("macro m() { x; { var x: u32 = 1; x; } x; } fn f() { m!(); }"),
+            Ok("fn f ( ) { x ; { var x#m#1 : u32 = 1 ; x#m#1 ; } x ; ; }".into())
+        );
+    }
+
+    #[test]
+    fn every_expansion_gets_a_fresh_name() {
+        assert_eq!(
+            self::expand("macro m() { var x: u32 = 1; } fn f() { m!(); m!(); }"),
+            Ok("fn f ( ) { var x#m#1 : u32 = 1 ; ; var x#m#2 : u32 = 1 ; ; }".into())
+        );
+    }
+
+    #[test]
+    fn rejects_a_wrong_number_of_arguments() {
+        assert!(self::expand("macro m(a) { a } fn f() { m!(1, 2); }").is_err());
+        assert!(self::expand("macro m() { } fn f() { n!(); }").is_err());
+    }
+}
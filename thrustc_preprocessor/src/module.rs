@@ -19,6 +19,7 @@
 
 use std::path::{Path, PathBuf};
 
+use thrustc_span::Span;
 use uuid::Uuid;
 
 use crate::signatures::{Symbol, Variant};
@@ -29,17 +30,19 @@ pub struct Module {
     symbols: Vec<Symbol>,
     submodules: Vec<Module>,
     path: PathBuf,
+    import_span: Option<Span>,
     unique_id: Uuid,
 }
 
 impl Module {
-    pub fn new(base_name: String, path: PathBuf) -> Self {
+    pub fn new(base_name: String, path: PathBuf, unique_id: Uuid) -> Self {
         Module {
             base_name,
             symbols: Vec::with_capacity(u8::MAX as usize),
             submodules: Vec::with_capacity(u8::MAX as usize),
             path,
-            unique_id: Uuid::new_v4(),
+            import_span: None,
+            unique_id,
         }
     }
 }
@@ -54,12 +57,10 @@ impl Module {
     pub fn add_symbol(&mut self, symbol: Symbol) {
         self.symbols.push(symbol);
     }
-}
 
-impl Module {
     #[inline]
-    pub fn get_path(&self) -> &Path {
-        &self.path
+    pub fn set_import_span(&mut self, span: Span) {
+        self.import_span = Some(span);
     }
 }
 
@@ -78,6 +79,14 @@ impl Module {
         None
     }
 
+    pub fn contains_symbol(&self, hint: &str) -> bool {
+        self.symbols.iter().any(|symbol| symbol.name == hint)
+            || self
+                .submodules
+                .iter()
+                .any(|submodule| submodule.contains_symbol(hint))
+    }
+
     #[inline]
     pub fn find_submodule(&self, access: Vec<String>) -> Option<&Module> {
         let mut current_module: &Module = self;
@@ -108,6 +117,26 @@ impl Module {
         &self.base_name
     }
 
+    #[inline]
+    pub fn get_symbols(&self) -> &[Symbol] {
+        &self.symbols
+    }
+
+    #[inline]
+    pub fn get_path(&self) -> &Path {
+        &self.path
+    }
+
+    #[inline]
+    pub fn get_submodules(&self) -> &[Module] {
+        &self.submodules
+    }
+
+    #[inline]
+    pub fn get_import_span(&self) -> Option<Span> {
+        self.import_span
+    }
+
     #[inline]
     pub fn get_unique_id(&self) -> &Uuid {
         &self.unique_id
@@ -19,16 +19,16 @@
 
 use std::path::PathBuf;
 
-use thrustc_diagnostician::Diagnostician;
+use thrustc_diagnostician::{Diagnostician, warnings::AllowedWarnings};
 use thrustc_errors::{CompilationIssue, CompilationPosition};
 use thrustc_options::{CompilationUnit, CompilerOptions};
 use thrustc_span::Span;
 use thrustc_token::{Token, traits::TokenExtensions};
 use thrustc_token_type::TokenType;
 
-use crate::{module::Module, signatures::Symbol, submodule_parsing};
+use crate::{context::VisitedModules, module::Module, signatures::Symbol, submodule_parsing};
 
-use ahash::AHashSet as HashSet;
+use uuid::Uuid;
 
 #[derive(Debug)]
 pub struct ModuleParser<'module_parser> {
@@ -37,7 +37,7 @@ pub struct ModuleParser<'module_parser> {
     diagnostician: Diagnostician,
     errors: Vec<CompilationIssue>,
     warnings: Vec<CompilationIssue>,
-    visited: HashSet<PathBuf>,
+    visited: VisitedModules,
     options: &'module_parser CompilerOptions,
     current: usize,
 }
@@ -48,12 +48,30 @@ impl<'module_parser> ModuleParser<'module_parser> {
         tokens: Vec<Token>,
         options: &'module_parser CompilerOptions,
         file: &CompilationUnit,
-        visited: HashSet<PathBuf>,
+        visited: VisitedModules,
     ) -> Self {
+        let unique_id: Uuid = if options.is_deterministic() {
+            let content: &[u8] = file.get_unit_content().as_bytes();
+            let path: &[u8] = file.get_name().as_bytes();
+
+            Uuid::from_u64_pair(
+                thrustc_utils::get_content_hash(content),
+                thrustc_utils::get_content_hash(path),
+            )
+        } else {
+            Uuid::new_v4()
+        };
+
+        let diagnostician: Diagnostician = Diagnostician::with_allowed_warnings_set(
+            file,
+            options,
+            AllowedWarnings::from_tokens(file.get_path(), &tokens),
+        );
+
         Self {
-            module: Module::new(name, file.get_path().to_path_buf()),
+            module: Module::new(name, file.get_path().to_path_buf(), unique_id),
             tokens,
-            diagnostician: Diagnostician::new(file, options),
+            diagnostician,
             errors: Vec::with_capacity(u8::MAX as usize),
             warnings: Vec::with_capacity(u8::MAX as usize),
             visited,
@@ -87,6 +105,10 @@ impl<'module_parser> ModuleParser<'module_parser> {
             return Err(());
         }
 
+        if self.diagnostician.has_denied_warnings() {
+            return Err(());
+        }
+
         Ok(self.module)
     }
 }
@@ -311,12 +333,17 @@ impl<'module_parser> ModuleParser<'module_parser> {
 impl ModuleParser<'_> {
     #[inline]
     pub fn has_visited(&self, path: &PathBuf) -> bool {
-        self.visited.contains(path)
+        self.visited.contains_key(path)
+    }
+
+    #[inline]
+    pub fn mark_visited(&mut self, path: PathBuf, imported_from: (PathBuf, Span)) {
+        self.visited.insert(path, Some(imported_from));
     }
 
     #[inline]
-    pub fn mark_visited(&mut self, path: PathBuf) {
-        self.visited.insert(path);
+    pub fn get_first_import(&self, path: &PathBuf) -> Option<&(PathBuf, Span)> {
+        self.visited.get(path).and_then(|site| site.as_ref())
     }
 }
 
@@ -327,7 +354,7 @@ impl<'module_parser> ModuleParser<'module_parser> {
     }
 
     #[inline]
-    pub fn get_global_visited_modules(&self) -> HashSet<PathBuf> {
+    pub fn get_global_visited_modules(&self) -> VisitedModules {
         self.visited.clone()
     }
 }
@@ -19,6 +19,7 @@
 
 use thrustc_ast::Ast;
 use thrustc_attributes::{ThrustAttribute, ThrustAttributes, linkage::ThrustLinkage};
+use thrustc_errors::CompilationIssueCode;
 use thrustc_span::Span;
 
 use thrustc_token::{Token, traits::TokenExtensions};
@@ -89,6 +90,24 @@ pub fn build_attributes<'parser>(
                 ))
             }
 
+            TokenType::Allow => {
+                parser.consume(TokenType::Allow)?;
+
+                attributes.push(ThrustAttribute::Allow(
+                    self::build_allow_attribute(parser)?,
+                    span,
+                ))
+            }
+
+            TokenType::Format => {
+                parser.consume(TokenType::Format)?;
+
+                attributes.push(ThrustAttribute::Format(
+                    self::build_format_attribute(parser)?,
+                    span,
+                ))
+            }
+
             tk_type if tk_type.is_attribute() => {
                 if let Some(compiler_attribute) = thrustc_attributes::as_attribute(tk_type, span) {
                     attributes.push(compiler_attribute);
@@ -105,6 +124,52 @@ pub fn build_attributes<'parser>(
     Ok(attributes)
 }
 
+fn build_allow_attribute<'parser>(
+    parser: &mut ModuleParser<'parser>,
+) -> Result<Vec<CompilationIssueCode>, ()> {
+    parser.consume(TokenType::LParen)?;
+
+    let mut codes: Vec<CompilationIssueCode> = Vec::with_capacity(10);
+
+    loop {
+        let code_tk: &Token = parser.consume(TokenType::Identifier)?;
+        codes.push(CompilationIssueCode::from_warning(code_tk.get_lexeme()).ok_or(())?);
+
+        if !parser.match_token(TokenType::Comma)? {
+            break;
+        }
+    }
+
+    parser.consume(TokenType::RParen)?;
+
+    Ok(codes)
+}
+
+fn build_format_attribute<'parser>(parser: &mut ModuleParser<'parser>) -> Result<u64, ()> {
+    parser.consume(TokenType::LParen)?;
+
+    let archetype_tk: &Token = parser.consume(TokenType::Identifier)?;
+
+    if archetype_tk.get_lexeme() != "printf" {
+        return Err(());
+    }
+
+    parser.consume(TokenType::Comma)?;
+
+    let expr: Ast<'_> = expressions::parse_expr(parser)?;
+
+    parser.consume(TokenType::RParen)?;
+
+    match expr {
+        Ast::Integer {
+            value,
+            signed: false,
+            ..
+        } if value != 0 => Ok(u64::try_from(value).unwrap_or(u64::MAX)),
+        _ => Err(()),
+    }
+}
+
 fn build_align_attribute<'parser>(parser: &mut ModuleParser<'parser>) -> Result<u64, ()> {
     parser.consume(TokenType::LParen)?;
 
@@ -118,7 +183,7 @@ fn build_align_attribute<'parser>(parser: &mut ModuleParser<'parser>) -> Result<
         ..
     } = expr
     {
-        Ok(value)
+        Ok(u64::try_from(value).unwrap_or(u64::MAX))
     } else {
         Err(())
     }
@@ -33,10 +33,10 @@ pub fn parse_expr<'module_parser>(ctx: &mut ModuleParser) -> Result<Ast<'module_
             let integer: &str = tk.get_lexeme();
             let span: Span = tk.get_span();
 
-            let parsed_integer: (Type, u64) = reinterpret::integer(integer, span)?;
+            let parsed_integer: (Type, u128) = reinterpret::integer(integer, span)?;
 
             let integer_type: Type = parsed_integer.0;
-            let integer_value: u64 = parsed_integer.1;
+            let integer_value: u128 = parsed_integer.1;
 
             Ok(Ast::new_integer(integer_type, integer_value, false, span))
         }
@@ -19,7 +19,7 @@
 
 use std::path::PathBuf;
 
-use thrustc_errors::{CompilationIssue, CompilationIssueCode};
+use thrustc_errors::{CompilationIssue, CompilationIssueCode, CompilationLabel};
 use thrustc_lexer::Lexer;
 use thrustc_options::{CompilationUnit, CompilerOptions};
 use thrustc_span::Span;
@@ -53,6 +53,7 @@ pub fn parse_import<'module_parser>(parser: &mut ModuleParser<'module_parser>) -
         module_path = current_dir.join(import_str);
     }
 
+    parser.advance_until_check(TokenType::SemiColon)?;
     parser.consume(TokenType::SemiColon)?;
 
     if module_path == current_dir {
@@ -68,16 +69,26 @@ pub fn parse_import<'module_parser>(parser: &mut ModuleParser<'module_parser>) -
     }
 
     if parser.has_visited(&module_path) {
-        parser.add_warning(CompilationIssue::Warning(
+        let mut warning: CompilationIssue = CompilationIssue::Warning(
             CompilationIssueCode::W0018,
             "A circular import was founded here. Omitting it by default. The recomendation is to remove it."
                 .into(),
             span,
-        ));
+        );
+
+        if let Some((first_file, first_span)) = parser.get_first_import(&module_path) {
+            warning = warning.with_labels(vec![CompilationLabel::in_file(
+                first_file.clone(),
+                *first_span,
+                "This module was first imported here.".into(),
+            )]);
+        }
+
+        parser.add_warning(warning);
 
         return Ok(());
     } else {
-        parser.mark_visited(module_path.clone());
+        parser.mark_visited(module_path.clone(), (current_path.clone(), span));
     }
 
     if !module_path.exists() {
@@ -154,10 +165,14 @@ pub fn parse_import<'module_parser>(parser: &mut ModuleParser<'module_parser>) -
 
     let options: &CompilerOptions = parser.get_options();
 
+    thrustc_ice::register_source(&module_path);
+
     let content: String = thrustc_reader::get_file_source_code(&module_path);
     let file: CompilationUnit = CompilationUnit::new(name, module_path, content, base_name.clone());
 
     let tokens: Vec<Token> = Lexer::lex_for_preprocessor(&file, options)?;
+    let tokens: Vec<Token> = crate::cfg::configure(tokens, options, &file)?;
+    let tokens: Vec<Token> = crate::macros::expand(tokens, options, &file)?;
     let subparser: ModuleParser = ModuleParser::new(
         base_name,
         tokens,
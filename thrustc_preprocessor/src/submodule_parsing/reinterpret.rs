@@ -20,7 +20,39 @@
 use thrustc_span::Span;
 use thrustc_typesystem::Type;
 
+fn suffix_type(suffix: &str, span: Span) -> Option<Type> {
+    match suffix {
+        "s8" => Some(Type::S8 { span }),
+        "s16" => Some(Type::S16 { span }),
+        "s32" => Some(Type::S32 { span }),
+        "s64" => Some(Type::S64 { span }),
+        "s128" => Some(Type::S128 { span }),
+        "ssize" => Some(Type::SSize { span }),
+        "u8" => Some(Type::U8 { span }),
+        "u16" => Some(Type::U16 { span }),
+        "u32" => Some(Type::U32 { span }),
+        "u64" => Some(Type::U64 { span }),
+        "u128" => Some(Type::U128 { span }),
+        "usize" => Some(Type::USize { span }),
+        "f16" => Some(Type::F16 { span }),
+        "bf16" => Some(Type::BF16 { span }),
+        "f32" => Some(Type::F32 { span }),
+        "f64" => Some(Type::F64 { span }),
+        "f128" => Some(Type::F128 { span }),
+        _ => None,
+    }
+}
+
 pub fn floating_point(lexeme: &str, span: Span) -> Result<(Type, f64), ()> {
+    let (lexeme, suffix) = thrustc_utils::split_literal_suffix(lexeme);
+    let lexeme: String = lexeme.replace('_', "");
+
+    if let Some(suffix_type) = self::suffix_type(suffix, span) {
+        if let Ok(value) = lexeme.parse::<f64>() {
+            return Ok((suffix_type, value));
+        }
+    }
+
     if lexeme.bytes().filter(|&b| b == b'.').count() > 1 {
         Err(())
     } else {
@@ -32,40 +64,44 @@ pub fn floating_point(lexeme: &str, span: Span) -> Result<(Type, f64), ()> {
     }
 }
 
-pub fn integer(lexeme: &str, span: Span) -> Result<(Type, u64), ()> {
-    const I8_MIN: isize = -128;
-    const I8_MAX: isize = 127;
-    const I16_MIN: isize = -32768;
-    const I16_MAX: isize = 32767;
-    const I32_MIN: isize = -2147483648;
-    const I32_MAX: isize = 2147483647;
-
-    const U8_MAX: usize = 255;
-    const U16_MAX: usize = 65535;
-    const U32_MAX: usize = 4294967295;
-
-    fn match_signed(number: isize, span: Span) -> Result<(Type, u64), ()> {
+pub fn integer(lexeme: &str, span: Span) -> Result<(Type, u128), ()> {
+    const I8_MIN: i128 = -128;
+    const I8_MAX: i128 = 127;
+    const I16_MIN: i128 = -32768;
+    const I16_MAX: i128 = 32767;
+    const I32_MIN: i128 = -2147483648;
+    const I32_MAX: i128 = 2147483647;
+    const I64_MIN: i128 = i64::MIN as i128;
+    const I64_MAX: i128 = i64::MAX as i128;
+
+    const U8_MAX: u128 = 255;
+    const U16_MAX: u128 = 65535;
+    const U32_MAX: u128 = 4294967295;
+    const U64_MAX: u128 = u64::MAX as u128;
+
+    fn match_signed(number: i128, span: Span) -> Result<(Type, u128), ()> {
         match number {
-            n if (I8_MIN..=I8_MAX).contains(&n) => Ok((Type::S8 { span }, n as u64)),
-            n if (I16_MIN..=I16_MAX).contains(&n) => Ok((Type::S16 { span }, n as u64)),
-            n if (I32_MIN..=I32_MAX).contains(&n) => Ok((Type::S32 { span }, n as u64)),
-            n if (isize::MIN..=isize::MAX).contains(&n) => Ok((Type::S64 { span }, n as u64)),
-
-            _ => Err(()),
+            n if (I8_MIN..=I8_MAX).contains(&n) => Ok((Type::S8 { span }, n as u128)),
+            n if (I16_MIN..=I16_MAX).contains(&n) => Ok((Type::S16 { span }, n as u128)),
+            n if (I32_MIN..=I32_MAX).contains(&n) => Ok((Type::S32 { span }, n as u128)),
+            n if (I64_MIN..=I64_MAX).contains(&n) => Ok((Type::S64 { span }, n as u128)),
+            n => Ok((Type::S128 { span }, n as u128)),
         }
     }
 
-    fn match_unsigned(number: usize, span: Span) -> Result<(Type, u64), ()> {
+    fn match_unsigned(number: u128, span: Span) -> Result<(Type, u128), ()> {
         match number {
-            n if (0..=U8_MAX).contains(&n) => Ok((Type::U8 { span }, n as u64)),
-            n if (0..=U16_MAX).contains(&n) => Ok((Type::U16 { span }, n as u64)),
-            n if (0..=U32_MAX).contains(&n) => Ok((Type::U32 { span }, n as u64)),
-            n if (0..=usize::MAX).contains(&n) => Ok((Type::U64 { span }, n as u64)),
-
-            _ => Err(()),
+            n if (0..=U8_MAX).contains(&n) => Ok((Type::U8 { span }, n)),
+            n if (0..=U16_MAX).contains(&n) => Ok((Type::U16 { span }, n)),
+            n if (0..=U32_MAX).contains(&n) => Ok((Type::U32 { span }, n)),
+            n if (0..=U64_MAX).contains(&n) => Ok((Type::U64 { span }, n)),
+            n => Ok((Type::U128 { span }, n)),
         }
     }
 
+    let (lexeme, suffix) = thrustc_utils::split_literal_suffix(lexeme);
+    let suffix_type: Option<Type> = self::suffix_type(suffix, span);
+
     let hexadecimal: bool = lexeme.strip_prefix("0x").is_some();
     let octal: bool = lexeme.strip_prefix("0o").is_some();
     let binary: bool = lexeme.strip_prefix("0b").is_some();
@@ -90,21 +126,29 @@ pub fn integer(lexeme: &str, span: Span) -> Result<(Type, u64), ()> {
     };
 
     if radix != 10 {
-        if let Ok(n) = usize::from_str_radix(&cleaned, radix) {
+        if let Ok(n) = u128::from_str_radix(&cleaned, radix) {
+            if let Some(suffix_type) = suffix_type {
+                return Ok((suffix_type, n));
+            }
+
             return match_unsigned(n, span);
         }
 
-        if let Ok(n) = isize::from_str_radix(&cleaned, radix) {
+        if let Ok(n) = i128::from_str_radix(&cleaned, radix) {
             return match_signed(n, span);
         }
 
         Err(())
     } else {
-        if let Ok(n) = lexeme.parse::<usize>() {
+        if let Ok(n) = cleaned.parse::<u128>() {
+            if let Some(suffix_type) = suffix_type {
+                return Ok((suffix_type, n));
+            }
+
             return match_unsigned(n, span);
         }
 
-        if let Ok(n) = lexeme.parse::<isize>() {
+        if let Ok(n) = cleaned.parse::<i128>() {
             return match_signed(n, span);
         }
 
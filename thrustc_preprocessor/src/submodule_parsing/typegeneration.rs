@@ -51,6 +51,7 @@ pub fn build_type(ctx: &mut ModuleParser<'_>) -> Result<Type, ()> {
 
             match tk_kind {
                 _ if tk_kind.is_array() => self::parse_array_type(ctx, span),
+                _ if tk_kind.is_vector() => self::parse_vector_type(ctx, span),
                 _ if tk_kind.is_const() => self::parse_constant_type(ctx, span),
                 _ if tk_kind.is_fn_ref() => self::parse_anonymous_function_type(ctx, span),
                 _ => match tk_kind {
@@ -63,6 +64,7 @@ pub fn build_type(ctx: &mut ModuleParser<'_>) -> Result<Type, ()> {
                     TokenType::S16 => Ok(Type::S16 { span }),
                     TokenType::S32 => Ok(Type::S32 { span }),
                     TokenType::S64 => Ok(Type::S64 { span }),
+                    TokenType::S128 => Ok(Type::S128 { span }),
                     TokenType::Ssize => Ok(Type::SSize { span }),
 
                     TokenType::U8 => Ok(Type::U8 { span }),
@@ -74,6 +76,8 @@ pub fn build_type(ctx: &mut ModuleParser<'_>) -> Result<Type, ()> {
 
                     TokenType::Bool => Ok(Type::Bool(span)),
 
+                    TokenType::F16 => Ok(Type::F16 { span }),
+                    TokenType::BF16 => Ok(Type::BF16 { span }),
                     TokenType::F32 => Ok(Type::F32 { span }),
                     TokenType::F64 => Ok(Type::F64 { span }),
                     TokenType::F128 => Ok(Type::F128 { span }),
@@ -167,6 +171,26 @@ fn parse_constant_type(ctx: &mut ModuleParser<'_>, span: Span) -> Result<Type, (
     Ok(Type::Const(inner_type.into(), span))
 }
 
+fn parse_vector_type(ctx: &mut ModuleParser<'_>, span: Span) -> Result<Type, ()> {
+    ctx.consume(TokenType::LBracket)?;
+
+    let element_type: Type = self::build_type(ctx)?;
+
+    ctx.consume(TokenType::SemiColon)?;
+
+    let lanes: Ast = expressions::parse_expr(ctx)?;
+
+    let Ast::Integer { value, .. } = lanes else {
+        return Err(());
+    };
+
+    let lanes: u32 = u32::try_from(value).map_err(|_| ())?;
+
+    ctx.consume(TokenType::RBracket)?;
+
+    Ok(Type::Vector(element_type.into(), lanes, span))
+}
+
 fn parse_array_type(ctx: &mut ModuleParser<'_>, span: Span) -> Result<Type, ()> {
     ctx.consume(TokenType::LBracket)?;
 
@@ -198,7 +222,7 @@ fn parse_array_type(ctx: &mut ModuleParser<'_>, span: Span) -> Result<Type, ()>
             ));
         }
 
-        let size: u64 = if let Ast::Integer { value, .. } = size {
+        let size: u128 = if let Ast::Integer { value, .. } = size {
             value
         } else {
             0
@@ -242,7 +242,7 @@ impl<'scoper> Scoper<'scoper> {
                     ));
                 }
             }
-            Ast::Return { span, .. } => {
+            Ast::Return { span, .. } | Ast::Raise { span, .. } => {
                 if !self.get_context().is_inside_function() {
                     self.add_error(CompilationIssue::Error(
                         CompilationIssueCode::E0018,
@@ -21,7 +21,7 @@ use thrustc_analyzer::Analyzer;
 use thrustc_ast::Ast;
 use thrustc_ast_verifier::AstVerifier;
 use thrustc_attribute_checker::AttributeChecker;
-use thrustc_linter::Linter;
+use thrustc_linter::{Linter, usage::LinterSymbolUsage};
 use thrustc_options::{CompilationUnit, CompilerOptions};
 use thrustc_scoper::Scoper;
 use thrustc_typechecker::TypeChecker;
@@ -83,17 +83,27 @@ impl<'semantic_analyzer> SemanticAnalysis<'semantic_analyzer> {
         let analyzer_threw_errors: bool = self.analyzer.start();
         let attr_checker_threw_errors: bool = self.attr_checker.start();
 
+        let mut linter_threw_errors: bool = false;
+
         if !type_checker_threw_errors
             && !analyzer_threw_errors
             && !attr_checker_threw_errors
             && !scoper_threw_errors
         {
-            self.linter.check();
+            linter_threw_errors = self.linter.check();
         }
 
         type_checker_threw_errors
             || analyzer_threw_errors
             || attr_checker_threw_errors
             || scoper_threw_errors
+            || linter_threw_errors
+    }
+}
+
+impl SemanticAnalysis<'_> {
+    #[inline]
+    pub fn get_symbol_usage(&self) -> LinterSymbolUsage {
+        self.linter.get_symbol_usage()
     }
 }
@@ -25,6 +25,7 @@ use arbitrary::Arbitrary;
 pub struct Span {
     pub line: u32,
     pub span: (u32, u32),
+    pub expansion: Option<(u32, (u32, u32))>,
 }
 
 impl std::fmt::Display for Span {
@@ -43,6 +44,7 @@ impl Span {
         Self {
             line,
             span: (start, end),
+            expansion: None,
         }
     }
 
@@ -50,6 +52,16 @@ impl Span {
     pub fn nothing() -> Self {
         Self::new((1, (0, 0)))
     }
+
+    #[inline]
+    pub fn with_expansion(mut self, call_site: Span) -> Self {
+        // Nested expansions keep pointing at the outermost invocation.
+        self.expansion = call_site
+            .expansion
+            .or(Some((call_site.line, call_site.span)));
+
+        self
+    }
 }
 
 impl Span {
@@ -67,4 +79,9 @@ impl Span {
     pub fn get_span_end(&self) -> u32 {
         self.span.1
     }
+
+    #[inline]
+    pub fn get_expansion(&self) -> Option<Span> {
+        self.expansion.map(Span::new)
+    }
 }
@@ -31,7 +31,7 @@ use thrustc_token_type::TokenType;
 use crate::traits::TokenExtensions;
 
 #[cfg_attr(feature = "fuzz", derive(Arbitrary))]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Token {
     pub lexeme: String,
     pub ascii: String,
@@ -71,6 +71,11 @@ impl TokenTypeExtensions for TokenType {
         matches!(self, TokenType::Array)
     }
 
+    #[inline]
+    fn is_vector(&self) -> bool {
+        matches!(self, TokenType::Vector)
+    }
+
     #[inline]
     fn is_ptr(&self) -> bool {
         matches!(self, TokenType::Ptr)
@@ -80,7 +85,9 @@ impl TokenTypeExtensions for TokenType {
     fn is_float(&self) -> bool {
         matches!(
             self,
-            TokenType::F32
+            TokenType::F16
+                | TokenType::BF16
+                | TokenType::F32
                 | TokenType::F64
                 | TokenType::F128
                 | TokenType::FX8680
@@ -106,6 +113,7 @@ impl TokenTypeExtensions for TokenType {
                 | TokenType::S16
                 | TokenType::S32
                 | TokenType::S64
+                | TokenType::S128
                 | TokenType::Ssize
                 | TokenType::U8
                 | TokenType::U16
@@ -123,6 +131,7 @@ impl TokenTypeExtensions for TokenType {
             || self.is_float()
             || self.is_bool()
             || self.is_array()
+            || self.is_vector()
             || self.is_ptr()
             || self.is_void()
             || self.is_address()
@@ -143,6 +152,7 @@ impl TokenTypeExtensions for TokenType {
             TokenType::Static,
             TokenType::Const,
             TokenType::Struct,
+            TokenType::Union,
             TokenType::Type,
             TokenType::Enum,
             TokenType::Var,
@@ -155,6 +165,7 @@ impl TokenTypeExtensions for TokenType {
             TokenType::Break,
             TokenType::BreakAll,
             TokenType::Defer,
+            TokenType::Raise,
         ]
         .contains(self)
     }
@@ -164,9 +175,13 @@ impl TokenTypeExtensions for TokenType {
         [
             TokenType::Type,
             TokenType::Struct,
+            TokenType::Union,
             TokenType::Const,
             TokenType::Static,
             TokenType::Enum,
+            TokenType::ErrSet,
+            TokenType::Interface,
+            TokenType::Impl,
             TokenType::Fn,
             TokenType::AsmFn,
             TokenType::Intrinsic,
@@ -209,6 +224,8 @@ impl TokenTypeAttributesExtensions for TokenType {
                 | TokenType::Convention
                 | TokenType::Pure
                 | TokenType::Thunk
+                | TokenType::Allow
+                | TokenType::Format
         )
     }
 }
@@ -221,6 +238,13 @@ impl TokenTypeBuiltinExtensions for TokenType {
                 | TokenType::MemCpy
                 | TokenType::MemMove
                 | TokenType::MemSet
+                | TokenType::Splat
+                | TokenType::Shuffle
+                | TokenType::VaStart
+                | TokenType::VaArg
+                | TokenType::VaEnd
+                | TokenType::VaCopy
+                | TokenType::Print
                 | TokenType::AlignOf
                 | TokenType::SizeOf
                 | TokenType::BitSizeOf
@@ -240,9 +264,15 @@ impl std::fmt::Display for TokenType {
             TokenType::Continue => write!(f, "continue"),
             TokenType::ContinueAll => write!(f, "continueall"),
             TokenType::Defer => write!(f, "defer"),
+            TokenType::Raise => write!(f, "raise"),
+            TokenType::Catch => write!(f, "catch"),
             TokenType::Elif => write!(f, "elif"),
             TokenType::Else => write!(f, "else"),
             TokenType::Enum => write!(f, "enum"),
+            TokenType::ErrSet => write!(f, "errset"),
+            TokenType::Interface => write!(f, "interface"),
+            TokenType::Impl => write!(f, "impl"),
+            TokenType::Macro => write!(f, "macro"),
             TokenType::False => write!(f, "false"),
             TokenType::Intrinsic => write!(f, "intrinsic"),
             TokenType::Embedded => write!(f, "embedded"),
@@ -254,6 +284,7 @@ impl std::fmt::Display for TokenType {
             TokenType::New => write!(f, "new"),
             TokenType::Return => write!(f, "return"),
             TokenType::Struct => write!(f, "struct"),
+            TokenType::Union => write!(f, "union"),
             TokenType::True => write!(f, "true"),
             TokenType::Type => write!(f, "type"),
             TokenType::While => write!(f, "while"),
@@ -270,6 +301,8 @@ impl std::fmt::Display for TokenType {
             TokenType::Address => write!(f, "address"),
             TokenType::Bool => write!(f, "bool"),
             TokenType::Char => write!(f, "char"),
+            TokenType::F16 => write!(f, "f16"),
+            TokenType::BF16 => write!(f, "bf16"),
             TokenType::F32 => write!(f, "f32"),
             TokenType::F64 => write!(f, "f64"),
             TokenType::FX8680 => write!(f, "f80"),
@@ -277,10 +310,12 @@ impl std::fmt::Display for TokenType {
             TokenType::FPPC128 => write!(f, "fppc_128"),
             TokenType::Ptr => write!(f, "ptr"),
             TokenType::Array => write!(f, "array"),
+            TokenType::Vector => write!(f, "vec"),
             TokenType::S8 => write!(f, "s8"),
             TokenType::S16 => write!(f, "s16"),
             TokenType::S32 => write!(f, "s32"),
             TokenType::S64 => write!(f, "s64"),
+            TokenType::S128 => write!(f, "s128"),
             TokenType::Ssize => write!(f, "ssize"),
             TokenType::CString => write!(f, "const array[char]"),
             TokenType::CNString => write!(f, "const array[char]"),
@@ -341,6 +376,9 @@ impl std::fmt::Display for TokenType {
             TokenType::Pure => write!(f, "@pure"),
             TokenType::Destructor => write!(f, "@destructor"),
             TokenType::Constructor => write!(f, "@constructor"),
+            TokenType::Allow => write!(f, "@allow"),
+            TokenType::Format => write!(f, "@format"),
+            TokenType::Cfg => write!(f, "@cfg"),
 
             // Operators, Punctuation, and Special Constructs
             TokenType::Or => write!(f, "||"),
@@ -351,6 +389,7 @@ impl std::fmt::Display for TokenType {
             TokenType::Alloc => write!(f, "alloc"),
             TokenType::Arith => write!(f, "%"),
             TokenType::Arrow => write!(f, "->"),
+            TokenType::Question => write!(f, "?"),
             TokenType::AsmFn => write!(f, "asmfn"),
             TokenType::Bang => write!(f, "!"),
             TokenType::BangEq => write!(f, "!="),
@@ -401,6 +440,13 @@ impl std::fmt::Display for TokenType {
             TokenType::MemSet => write!(f, "memset"),
             TokenType::MemMove => write!(f, "memmove"),
             TokenType::MemCpy => write!(f, "memcpy"),
+            TokenType::Splat => write!(f, "splat"),
+            TokenType::Shuffle => write!(f, "shuffle"),
+            TokenType::VaStart => write!(f, "vaStart"),
+            TokenType::VaArg => write!(f, "vaArg"),
+            TokenType::VaEnd => write!(f, "vaEnd"),
+            TokenType::VaCopy => write!(f, "vaCopy"),
+            TokenType::Print => write!(f, "print"),
             TokenType::SizeOf => write!(f, "sizeOf"),
             TokenType::AbiSizeOf => write!(f, "abiSizeOf"),
             TokenType::BitSizeOf => write!(f, "bitSizeOf"),
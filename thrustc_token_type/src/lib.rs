@@ -64,6 +64,7 @@ pub enum TokenType {
     LShift,     // ' << '
     RShift,     // ' >> '
     Arrow,      // ->
+    Question,   // ' ? '
 
     // --- Literals ---
     Identifier,
@@ -98,6 +99,9 @@ pub enum TokenType {
     Thunk,
     Constructor,
     Destructor,
+    Allow,
+    Format,
+    Cfg,
 
     // --- Special ---
     Unreachable,
@@ -141,8 +145,13 @@ pub enum TokenType {
     Mut,
     Type,
     Enum,
+    ErrSet,
+    Interface,
+    Impl,
+    Macro,
     And,
     Struct,
+    Union,
     Else,
     Fn,
     For,
@@ -151,6 +160,8 @@ pub enum TokenType {
     ContinueAll,
     BreakAll,
     Defer,
+    Raise,
+    Catch,
     If,
     Elif,
     Or,
@@ -176,12 +187,20 @@ pub enum TokenType {
     MemCpy,
     MemMove,
     MemSet,
+    Splat,
+    Shuffle,
+    VaStart,
+    VaArg,
+    VaEnd,
+    VaCopy,
+    Print,
 
     // --- Types ---
     S8,
     S16,
     S32,
     S64,
+    S128,
     Ssize,
 
     U8,
@@ -191,6 +210,8 @@ pub enum TokenType {
     U128,
     Usize,
 
+    F16,
+    BF16,
     F32,
     F64,
     FX8680,
@@ -205,6 +226,7 @@ pub enum TokenType {
     Void,
     Addr,
     Array,
+    Vector,
 
     FnRef,
 
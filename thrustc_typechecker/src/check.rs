@@ -17,12 +17,12 @@
 
 */
 
-use thrustc_ast::{Ast, metadata::CastingMetadata};
-use thrustc_errors::{CompilationIssue, CompilationIssueCode};
+use thrustc_ast::{Ast, metadata::CastingMetadata, traits::AstCodeLocation};
+use thrustc_errors::{CompilationIssue, CompilationIssueCode, CompilationLabel};
 use thrustc_span::Span;
 
 use thrustc_token_type::TokenType;
-use thrustc_typesystem::Type;
+use thrustc_typesystem::{Type, traits::TypeCodeLocation};
 
 use crate::{context::TypeCheckerControlContext, metadata::TypeCheckerNodeMetadata};
 
@@ -54,7 +54,8 @@ pub fn check_type_together(
         "You should make the type match.".into(),
         None,
         span,
-    );
+    )
+    .with_labels(self::get_mismatched_type_labels(target, node, span));
 
     if let Some(Ast::BinaryOp {
         operator,
@@ -240,6 +241,45 @@ pub fn check_type_together(
             Err(error)
         }
 
+        (
+            Type::Vector(target, target_lanes, ..),
+            Type::Vector(provided, provided_lanes, ..),
+            operator,
+        ) => {
+            if target_lanes == provided_lanes {
+                self::check_type_together(
+                    target,
+                    provided,
+                    None,
+                    operator,
+                    metadata,
+                    span,
+                    control_context,
+                )?;
+                return Ok(());
+            }
+
+            Err(error)
+        }
+
+        (Type::Interface(target, ..), Type::Interface(provided, ..), None)
+            if target == provided =>
+        {
+            Ok(())
+        }
+
+        (Type::ErrorUnion(target, ..), Type::ErrorUnion(provided, ..), None) => {
+            self::check_type_together(
+                target,
+                provided,
+                None,
+                None,
+                metadata,
+                span,
+                control_context,
+            )
+        }
+
         (
             Type::Array {
                 base_type: target, ..
@@ -396,6 +436,33 @@ pub fn check_type_together(
             | None,
         ) => Ok(()),
 
+        (
+            Type::S128 { .. },
+            Type::S128 { .. }
+            | Type::S64 { .. }
+            | Type::S32 { .. }
+            | Type::S16 { .. }
+            | Type::S8 { .. },
+            Some(
+                TokenType::Plus
+                | TokenType::Minus
+                | TokenType::Slash
+                | TokenType::Arith
+                | TokenType::Star
+                | TokenType::LShift
+                | TokenType::RShift
+                | TokenType::PlusPlus
+                | TokenType::MinusMinus
+                | TokenType::MinusEq
+                | TokenType::PlusEq
+                | TokenType::Xor
+                | TokenType::Bor
+                | TokenType::Not
+                | TokenType::BAnd,
+            )
+            | None,
+        ) => Ok(()),
+
         (
             Type::SSize { .. },
             Type::SSize { .. }
@@ -569,9 +636,43 @@ pub fn check_type_together(
             | None,
         ) => Ok(()),
 
+        (
+            Type::F16 { .. },
+            Type::F16 { .. },
+            Some(
+                TokenType::Plus
+                | TokenType::Minus
+                | TokenType::Slash
+                | TokenType::Star
+                | TokenType::Arith
+                | TokenType::PlusPlus
+                | TokenType::MinusMinus
+                | TokenType::MinusEq
+                | TokenType::PlusEq,
+            )
+            | None,
+        ) => Ok(()),
+
+        (
+            Type::BF16 { .. },
+            Type::BF16 { .. },
+            Some(
+                TokenType::Plus
+                | TokenType::Minus
+                | TokenType::Slash
+                | TokenType::Star
+                | TokenType::Arith
+                | TokenType::PlusPlus
+                | TokenType::MinusMinus
+                | TokenType::MinusEq
+                | TokenType::PlusEq,
+            )
+            | None,
+        ) => Ok(()),
+
         (
             Type::F32 { .. },
-            Type::F32 { .. },
+            Type::F32 { .. } | Type::F16 { .. } | Type::BF16 { .. },
             Some(
                 TokenType::Plus
                 | TokenType::Minus
@@ -588,7 +689,7 @@ pub fn check_type_together(
 
         (
             Type::F64 { .. },
-            Type::F64 { .. } | Type::F32 { .. },
+            Type::F64 { .. } | Type::F32 { .. } | Type::F16 { .. } | Type::BF16 { .. },
             Some(
                 TokenType::Plus
                 | TokenType::Minus
@@ -605,7 +706,11 @@ pub fn check_type_together(
 
         (
             Type::F128 { .. },
-            Type::F128 { .. } | Type::F64 { .. } | Type::F32 { .. },
+            Type::F128 { .. }
+            | Type::F64 { .. }
+            | Type::F32 { .. }
+            | Type::F16 { .. }
+            | Type::BF16 { .. },
             Some(
                 TokenType::Plus
                 | TokenType::Minus
@@ -750,6 +855,50 @@ pub fn check_type_together(
             | None,
         ) if metadata.is_literal_value() => Ok(()),
 
+        (
+            Type::S128 { .. },
+            Type::U128 { .. }
+            | Type::U64 { .. }
+            | Type::U32 { .. }
+            | Type::U16 { .. }
+            | Type::U8 { .. },
+            Some(
+                TokenType::Plus
+                | TokenType::Minus
+                | TokenType::Slash
+                | TokenType::Arith
+                | TokenType::Star
+                | TokenType::LShift
+                | TokenType::RShift
+                | TokenType::PlusPlus
+                | TokenType::MinusMinus
+                | TokenType::MinusEq
+                | TokenType::PlusEq
+                | TokenType::Xor
+                | TokenType::Bor
+                | TokenType::Not
+                | TokenType::BAnd,
+            )
+            | None,
+        ) if metadata.is_literal_value() => Ok(()),
+
+        (
+            Type::F16 { .. } | Type::BF16 { .. },
+            Type::F32 { .. } | Type::F64 { .. },
+            Some(
+                TokenType::Plus
+                | TokenType::Minus
+                | TokenType::Slash
+                | TokenType::Star
+                | TokenType::Arith
+                | TokenType::PlusPlus
+                | TokenType::MinusMinus
+                | TokenType::MinusEq
+                | TokenType::PlusEq,
+            )
+            | None,
+        ) if metadata.is_literal_value() => Ok(()),
+
         (Type::Void(..), Type::Void(..), None) => Ok(()),
 
         _ => Err(error),
@@ -784,6 +933,7 @@ pub fn check_type_cast(
             | Type::S16 { .. }
             | Type::S32 { .. }
             | Type::S64 { .. }
+            | Type::S128 { .. }
             | Type::U8 { .. }
             | Type::U16 { .. }
             | Type::U32 { .. }
@@ -792,6 +942,8 @@ pub fn check_type_cast(
             | Type::USize { .. }
             | Type::SSize { .. }
             | Type::Char(..)
+            | Type::F16 { .. }
+            | Type::BF16 { .. }
             | Type::F32 { .. }
             | Type::F64 { .. }
             | Type::F128 { .. },
@@ -799,6 +951,7 @@ pub fn check_type_cast(
             | Type::S16 { .. }
             | Type::S32 { .. }
             | Type::S64 { .. }
+            | Type::S128 { .. }
             | Type::U8 { .. }
             | Type::U16 { .. }
             | Type::U32 { .. }
@@ -807,6 +960,8 @@ pub fn check_type_cast(
             | Type::USize { .. }
             | Type::SSize { .. }
             | Type::Char(..)
+            | Type::F16 { .. }
+            | Type::BF16 { .. }
             | Type::F32 { .. }
             | Type::F64 { .. }
             | Type::F128 { .. },
@@ -821,6 +976,7 @@ pub fn check_type_cast(
             | Type::S16 { .. }
             | Type::S32 { .. }
             | Type::S64 { .. }
+            | Type::S128 { .. }
             | Type::U8 { .. }
             | Type::U16 { .. }
             | Type::U32 { .. }
@@ -839,6 +995,7 @@ pub fn check_type_cast(
             | Type::S16 { .. }
             | Type::S32 { .. }
             | Type::S64 { .. }
+            | Type::S128 { .. }
             | Type::U8 { .. }
             | Type::U16 { .. }
             | Type::U32 { .. }
@@ -847,6 +1004,8 @@ pub fn check_type_cast(
             | Type::USize { .. }
             | Type::SSize { .. }
             | Type::Char(..)
+            | Type::F16 { .. }
+            | Type::BF16 { .. }
             | Type::F32 { .. }
             | Type::F64 { .. }
             | Type::F128 { .. }
@@ -856,6 +1015,7 @@ pub fn check_type_cast(
             | Type::Struct { .. }
             | Type::Array { .. }
             | Type::FixedArray(..)
+            | Type::Vector(..)
             | Type::Fn(..),
             Type::Ptr(..) | Type::Addr(..),
         ) if is_allocated => Ok(()),
@@ -865,6 +1025,7 @@ pub fn check_type_cast(
             | Type::S16 { .. }
             | Type::S32 { .. }
             | Type::S64 { .. }
+            | Type::S128 { .. }
             | Type::U8 { .. }
             | Type::U16 { .. }
             | Type::U32 { .. }
@@ -914,3 +1075,33 @@ pub fn check_type_cast(
         )),
     }
 }
+
+fn get_mismatched_type_labels(
+    target: &Type,
+    node: Option<&Ast>,
+    span: Span,
+) -> Vec<CompilationLabel> {
+    let mut labels: Vec<CompilationLabel> = Vec::with_capacity(2);
+
+    let target_span: Span = target.get_span();
+
+    if target_span != span && target_span != Span::nothing() {
+        labels.push(CompilationLabel::new(
+            target_span,
+            format!("The type '{}' is expected because of this.", target),
+        ));
+    }
+
+    if let Some(node) = node {
+        let node_span: Span = node.get_span();
+
+        if node_span != span && node_span != target_span {
+            labels.push(CompilationLabel::new(
+                node_span,
+                "This value has a different type.".into(),
+            ));
+        }
+    }
+
+    labels
+}
@@ -73,6 +73,7 @@ impl TypeCheckerControlContext {
 #[derive(Debug)]
 pub struct TypeCheckerTypeContext<'type_checker> {
     current_function_type: Option<(&'type_checker Type, Span)>,
+    current_function_variadic: bool,
     call_depth: u64,
 }
 
@@ -81,6 +82,7 @@ impl<'type_checker> TypeCheckerTypeContext<'type_checker> {
     pub fn new() -> Self {
         Self {
             current_function_type: None,
+            current_function_variadic: false,
             call_depth: 0,
         }
     }
@@ -95,6 +97,12 @@ impl<'type_checker> TypeCheckerTypeContext<'type_checker> {
     #[inline]
     pub fn unset_current_function_type(&mut self) {
         self.current_function_type = None;
+        self.current_function_variadic = false;
+    }
+
+    #[inline]
+    pub fn set_current_function_variadic(&mut self, variadic: bool) {
+        self.current_function_variadic = variadic;
     }
 
     #[inline]
@@ -113,4 +121,9 @@ impl<'type_checker> TypeCheckerTypeContext<'type_checker> {
     pub fn get_current_function_type(&self) -> Option<(&'type_checker Type, Span)> {
         self.current_function_type
     }
+
+    #[inline]
+    pub fn is_current_function_variadic(&self) -> bool {
+        self.current_function_variadic
+    }
 }
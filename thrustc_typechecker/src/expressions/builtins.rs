@@ -20,16 +20,18 @@
 use thrustc_ast::{
     Ast,
     builtins::AstBuiltin,
-    traits::{AstCodeLocation, AstGetType},
+    traits::{AstCodeLocation, AstGetType, AstLiteralExtensions, AstStandardExtensions},
 };
 use thrustc_errors::{CompilationIssue, CompilationIssueCode};
 use thrustc_span::Span;
 use thrustc_typesystem::{
     Type,
-    traits::{TypeCodeLocation, TypeIsExtensions, VoidTypeExtensions},
+    traits::{TypeCodeLocation, TypeIsExtensions, TypeVectorExtensions, VoidTypeExtensions},
 };
 
-use crate::TypeChecker;
+use crate::{
+    TypeChecker, check, context::TypeCheckerControlContext, metadata::TypeCheckerNodeMetadata,
+};
 
 pub fn validate<'type_checker>(
     typechecker: &mut TypeChecker<'type_checker>,
@@ -51,6 +53,29 @@ pub fn validate<'type_checker>(
             self::validate_memcpy(typechecker, dst, src, size)
         }
 
+        AstBuiltin::Splat { of, value, span } => {
+            self::validate_splat(typechecker, of, value, *span)
+        }
+
+        AstBuiltin::Shuffle {
+            left,
+            right,
+            mask,
+            span,
+        } => self::validate_shuffle(typechecker, left, right, mask, *span),
+
+        AstBuiltin::VaStart { span } => self::validate_va_start(typechecker, *span),
+
+        AstBuiltin::VaArg { list, of, span } => self::validate_va_arg(typechecker, list, of, *span),
+
+        AstBuiltin::VaEnd { list, .. } | AstBuiltin::VaCopy { list, .. } => {
+            self::validate_va_list(typechecker, list)
+        }
+
+        AstBuiltin::Print { pieces, args, span } => {
+            self::validate_print(typechecker, pieces, args, *span)
+        }
+
         AstBuiltin::Halloc { .. }
         | AstBuiltin::AlignOf { .. }
         | AstBuiltin::SizeOf { .. }
@@ -60,6 +85,95 @@ pub fn validate<'type_checker>(
     }
 }
 
+pub fn validate_splat<'type_checker>(
+    typechecker: &mut TypeChecker<'type_checker>,
+    of: &Type,
+    value: &'type_checker Ast,
+    span: Span,
+) -> Result<(), CompilationIssue> {
+    let value_type: &Type = value.get_value_type()?;
+    let element_type: Type = of.get_vector_base_type();
+
+    let metadata: TypeCheckerNodeMetadata =
+        TypeCheckerNodeMetadata::new(value.is_totaly_literal_value());
+
+    {
+        let control_context: &mut TypeCheckerControlContext = typechecker.get_mut_control_context();
+
+        control_context.reset_checking_depth();
+
+        if let Err(error) = check::check_type_together(
+            &element_type,
+            value_type,
+            Some(value),
+            None,
+            metadata,
+            span,
+            control_context,
+        ) {
+            typechecker.add_error_report(error);
+        }
+    }
+
+    typechecker.analyze_expr(value)?;
+
+    Ok(())
+}
+
+pub fn validate_shuffle<'type_checker>(
+    typechecker: &mut TypeChecker<'type_checker>,
+    left: &'type_checker Ast,
+    right: &'type_checker Ast,
+    mask: &[u32],
+    span: Span,
+) -> Result<(), CompilationIssue> {
+    let left_type: &Type = left.get_value_type()?;
+    let right_type: &Type = right.get_value_type()?;
+
+    if !left_type.is_vector_type() || left_type != right_type {
+        typechecker.add_error_report(CompilationIssue::Error(
+            CompilationIssueCode::E0019,
+            format!(
+                "Expected two vectors of the same type, got '{}' and '{}' types.",
+                left_type, right_type
+            ),
+            "You should make the type match.".into(),
+            None,
+            span,
+        ));
+    }
+
+    if mask.is_empty() || !mask.len().is_power_of_two() {
+        typechecker.add_error_report(CompilationIssue::Error(
+            CompilationIssueCode::E0019,
+            format!(
+                "Expected a power of two number of lanes, got '{}'.",
+                mask.len()
+            ),
+            "The shuffle mask defines the lanes of the resulting vector.".into(),
+            None,
+            span,
+        ));
+    }
+
+    let lanes: u32 = left_type.get_vector_lanes().saturating_mul(2);
+
+    if let Some(lane) = mask.iter().find(|lane| **lane >= lanes) {
+        typechecker.add_error_report(CompilationIssue::Error(
+            CompilationIssueCode::E0019,
+            format!("Shuffle lane '{}' is out of bounds.", lane),
+            format!("Lanes should be lower than '{}'.", lanes),
+            None,
+            span,
+        ));
+    }
+
+    typechecker.analyze_expr(left)?;
+    typechecker.analyze_expr(right)?;
+
+    Ok(())
+}
+
 pub fn validate_memmove<'type_checker>(
     typechecker: &mut TypeChecker<'type_checker>,
     destination: &'type_checker Ast,
@@ -224,6 +338,152 @@ pub fn validate_memcpy<'type_checker>(
     Ok(())
 }
 
+pub fn validate_va_start<'type_checker>(
+    typechecker: &mut TypeChecker<'type_checker>,
+    span: Span,
+) -> Result<(), CompilationIssue> {
+    if !typechecker
+        .get_type_context()
+        .is_current_function_variadic()
+    {
+        typechecker.add_error_report(CompilationIssue::Error(
+            CompilationIssueCode::E0016,
+            "Cannot start a variable argument list outside of a variadic function.".into(),
+            "You should add '@arbitraryArgs' to the function attributes.".into(),
+            None,
+            span,
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn validate_va_arg<'type_checker>(
+    typechecker: &mut TypeChecker<'type_checker>,
+    list: &'type_checker Ast,
+    of: &Type,
+    span: Span,
+) -> Result<(), CompilationIssue> {
+    if of.contains_void_type() || of.is_void_type() {
+        typechecker.add_error_report(CompilationIssue::Error(
+            CompilationIssueCode::E0019,
+            "Cannot use 'void' as a value.".into(),
+            "You should remove whatever type or value where void type belongs.".into(),
+            None,
+            span,
+        ));
+    }
+
+    self::validate_va_list(typechecker, list)
+}
+
+pub fn validate_va_list<'type_checker>(
+    typechecker: &mut TypeChecker<'type_checker>,
+    list: &'type_checker Ast,
+) -> Result<(), CompilationIssue> {
+    let list_type: &Type = list.get_value_type()?;
+
+    if !list_type.is_ptr_type() {
+        typechecker.add_error_report(CompilationIssue::Error(
+            CompilationIssueCode::E0019,
+            format!("Expected pointer type, got '{}' type.", list_type),
+            "You should pass the list returned by 'vaStart' or 'vaCopy'.".into(),
+            None,
+            list.get_span(),
+        ));
+    }
+
+    typechecker.analyze_expr(list)?;
+
+    Ok(())
+}
+
+pub fn validate_print<'type_checker>(
+    typechecker: &mut TypeChecker<'type_checker>,
+    pieces: &[String],
+    args: &'type_checker [Ast],
+    span: Span,
+) -> Result<(), CompilationIssue> {
+    let placeholders: usize = pieces.len().saturating_sub(1);
+
+    if placeholders != args.len() {
+        typechecker.add_error_report(CompilationIssue::Error(
+            CompilationIssueCode::E0023,
+            format!(
+                "Expected {} arguments for the format string, got {}.",
+                placeholders,
+                args.len()
+            ),
+            "Every '{}' in the format string needs exactly one argument.".into(),
+            None,
+            span,
+        ));
+    }
+
+    for arg in args.iter() {
+        let arg_type: &Type = arg.get_value_type()?;
+
+        // Literals are the only arrays known to end with a NUL byte.
+        let is_string_literal: bool = arg.is_cstring() || arg.is_cnstring();
+
+        if !is_string_literal && !self::is_printable_type(arg_type) {
+            typechecker.add_error_report(CompilationIssue::Error(
+                CompilationIssueCode::E0019,
+                format!("Cannot format a value of '{}' type.", arg_type),
+                "Only integers up to 64 bits, 'f16', 'bf16', 'f32', 'f64', 'char', 'bool', 'ptr[char]' and string literals can be printed."
+                    .into(),
+                None,
+                arg.get_span(),
+            ));
+        } else if typechecker.is_freestanding() && arg_type.is_float_type() {
+            typechecker.add_error_report(CompilationIssue::Error(
+                CompilationIssueCode::E0019,
+                format!(
+                    "Cannot format a value of '{}' type in a freestanding build.",
+                    arg_type
+                ),
+                "You should convert the value to an integer before printing it.".into(),
+                None,
+                arg.get_span(),
+            ));
+        }
+
+        typechecker.analyze_expr(arg)?;
+    }
+
+    Ok(())
+}
+
+fn is_printable_type(kind: &Type) -> bool {
+    match kind {
+        Type::Const(subtype, ..) => self::is_printable_type(subtype),
+
+        Type::Ptr(Some(base_type), ..) => {
+            base_type.is_char_type()
+                || matches!(&**base_type, Type::Const(subtype, ..) if subtype.is_char_type())
+        }
+
+        Type::S8 { .. }
+        | Type::S16 { .. }
+        | Type::S32 { .. }
+        | Type::S64 { .. }
+        | Type::SSize { .. }
+        | Type::U8 { .. }
+        | Type::U16 { .. }
+        | Type::U32 { .. }
+        | Type::U64 { .. }
+        | Type::USize { .. }
+        | Type::F16 { .. }
+        | Type::BF16 { .. }
+        | Type::F32 { .. }
+        | Type::F64 { .. }
+        | Type::Char(..)
+        | Type::Bool(..) => true,
+
+        _ => false,
+    }
+}
+
 pub fn validate_memset<'type_checker>(
     typechecker: &mut TypeChecker<'type_checker>,
     destination: &'type_checker Ast,
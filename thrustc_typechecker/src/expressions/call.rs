@@ -28,7 +28,8 @@ use thrustc_span::Span;
 use thrustc_typesystem::{Type, traits::VoidTypeExtensions};
 
 use crate::{
-    TypeChecker, check, context::TypeCheckerControlContext, metadata::TypeCheckerNodeMetadata,
+    TypeChecker, check, context::TypeCheckerControlContext, expressions::format,
+    metadata::TypeCheckerNodeMetadata,
 };
 
 pub fn validate<'type_checker>(
@@ -39,11 +40,31 @@ pub fn validate<'type_checker>(
 ) -> Result<(), CompilationIssue> {
     let (return_type, parameter_types, attributes) = metadata;
 
+    self::validate_arguments(
+        typechecker,
+        (return_type, parameter_types),
+        (
+            attributes.has_ignore_attribute(),
+            attributes.get_format_argument(),
+        ),
+        args,
+        span,
+    )
+}
+
+pub fn validate_arguments<'type_checker>(
+    typechecker: &mut TypeChecker<'type_checker>,
+    signature: (&Type, &[Type]),
+    arguments: (bool, Option<u64>),
+    args: &'type_checker [Ast],
+    span: &Span,
+) -> Result<(), CompilationIssue> {
+    let (return_type, parameter_types) = signature;
+    let (var_args, format_argument) = arguments;
+
     let required_count: usize = parameter_types.len();
     let provided_count: usize = args.len();
 
-    let var_args: bool = attributes.has_ignore_attribute();
-
     if return_type.contains_void_type() {
         typechecker.add_error_report(CompilationIssue::Error(
             CompilationIssueCode::E0019,
@@ -120,6 +141,10 @@ pub fn validate<'type_checker>(
         }
     }
 
+    if let Some(position) = format_argument {
+        format::validate(typechecker, position, required_count, args)?;
+    }
+
     {
         for arg in args.iter() {
             typechecker.analyze_expr(arg)?;
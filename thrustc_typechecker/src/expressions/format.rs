@@ -0,0 +1,240 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+
+use std::iter::Peekable;
+
+use thrustc_ast::{
+    Ast,
+    traits::{AstCodeLocation, AstGetType},
+};
+use thrustc_errors::{CompilationIssue, CompilationIssueCode};
+use thrustc_span::Span;
+use thrustc_typesystem::{
+    Type,
+    traits::{ConstantTypeExtensions, TypePointerExtensions},
+};
+
+use crate::TypeChecker;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FormatArgument {
+    Integer(u32),
+    Float(u32),
+    Pointer,
+}
+
+impl std::fmt::Display for FormatArgument {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FormatArgument::Integer(bits) => write!(f, "a {}-bit integer", bits),
+            FormatArgument::Float(64) => write!(f, "a 'f32' or 'f64' float"),
+            FormatArgument::Float(..) => write!(f, "a 'f128', 'fx8680' or 'fppc128' float"),
+            FormatArgument::Pointer => write!(f, "a pointer"),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct FormatSpecifier {
+    text: String,
+    expected: FormatArgument,
+}
+
+pub fn validate<'type_checker>(
+    typechecker: &mut TypeChecker<'type_checker>,
+    position: u64,
+    fixed_count: usize,
+    args: &'type_checker [Ast],
+) -> Result<(), CompilationIssue> {
+    let Some(format) = args.get(position.saturating_sub(1) as usize) else {
+        return Ok(());
+    };
+
+    let (Ast::CString { bytes, span, .. } | Ast::CNString { bytes, span, .. }) = format else {
+        return Ok(());
+    };
+
+    let specifiers: Vec<FormatSpecifier> = match self::parse_format_string(bytes) {
+        Ok(specifiers) => specifiers,
+        Err(specifier) => {
+            typechecker.add_error_report(CompilationIssue::Error(
+                CompilationIssueCode::E0019,
+                format!("Invalid conversion specifier '{}'.", specifier),
+                "You should use a valid printf conversion, for example '%d', '%s' or '%f'.".into(),
+                None,
+                *span,
+            ));
+
+            return Ok(());
+        }
+    };
+
+    let variadic_args: &[Ast] = args.get(fixed_count..).unwrap_or_default();
+
+    if specifiers.len() != variadic_args.len() {
+        typechecker.add_error_report(CompilationIssue::Error(
+            CompilationIssueCode::E0022,
+            format!(
+                "The format string expects '{}' arguments, not '{}'.",
+                specifiers.len(),
+                variadic_args.len()
+            ),
+            "You should pass one argument for each conversion specifier.".into(),
+            None,
+            *span,
+        ));
+    }
+
+    for (specifier, arg) in specifiers.iter().zip(variadic_args.iter()) {
+        let arg_type: &Type = arg.get_value_type()?;
+        let arg_span: Span = arg.get_span();
+
+        if self::promote_argument(arg_type) != Some(specifier.expected) {
+            typechecker.add_error_report(CompilationIssue::Error(
+                CompilationIssueCode::E0020,
+                format!(
+                    "Conversion specifier '{}' expects {}, but got '{}'.",
+                    specifier.text, specifier.expected, arg_type
+                ),
+                "You should cast the argument or change the conversion specifier.".into(),
+                None,
+                arg_span,
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_format_string(bytes: &[u8]) -> Result<Vec<FormatSpecifier>, String> {
+    let mut specifiers: Vec<FormatSpecifier> = Vec::with_capacity(10);
+    let mut chars = bytes
+        .iter()
+        .take_while(|byte| **byte != b'\0')
+        .map(|byte| *byte as char)
+        .peekable();
+
+    while let Some(current) = chars.next() {
+        if current != '%' {
+            continue;
+        }
+
+        let mut text: String = String::from("%");
+
+        if chars.next_if_eq(&'%').is_some() {
+            continue;
+        }
+
+        while let Some(flag) = chars.next_if(|c| matches!(c, '-' | '+' | ' ' | '#' | '0')) {
+            text.push(flag);
+        }
+
+        self::parse_format_count(&mut chars, &mut text, &mut specifiers);
+
+        if let Some(dot) = chars.next_if_eq(&'.') {
+            text.push(dot);
+            self::parse_format_count(&mut chars, &mut text, &mut specifiers);
+        }
+
+        let mut length: String = String::with_capacity(2);
+
+        while let Some(modifier) = chars.next_if(|c| matches!(c, 'h' | 'l' | 'j' | 'z' | 't' | 'L'))
+        {
+            length.push(modifier);
+        }
+
+        text.push_str(&length);
+
+        let Some(conversion) = chars.next() else {
+            return Err(text);
+        };
+
+        text.push(conversion);
+
+        let expected: FormatArgument = match (conversion, length.as_str()) {
+            ('d' | 'i' | 'u' | 'o' | 'x' | 'X', "" | "h" | "hh") => FormatArgument::Integer(32),
+            ('d' | 'i' | 'u' | 'o' | 'x' | 'X', "l" | "ll" | "j" | "z" | "t") => {
+                FormatArgument::Integer(64)
+            }
+            ('c', "" | "l") => FormatArgument::Integer(32),
+            ('f' | 'F' | 'e' | 'E' | 'g' | 'G' | 'a' | 'A', "" | "l") => FormatArgument::Float(64),
+            ('f' | 'F' | 'e' | 'E' | 'g' | 'G' | 'a' | 'A', "L") => FormatArgument::Float(128),
+            ('s', "" | "l") | ('p' | 'n', "") => FormatArgument::Pointer,
+
+            _ => return Err(text),
+        };
+
+        specifiers.push(FormatSpecifier { text, expected });
+    }
+
+    Ok(specifiers)
+}
+
+fn parse_format_count(
+    chars: &mut Peekable<impl Iterator<Item = char>>,
+    text: &mut String,
+    specifiers: &mut Vec<FormatSpecifier>,
+) {
+    if let Some(star) = chars.next_if_eq(&'*') {
+        text.push(star);
+
+        specifiers.push(FormatSpecifier {
+            text: text.clone(),
+            expected: FormatArgument::Integer(32),
+        });
+
+        return;
+    }
+
+    while let Some(digit) = chars.next_if(|c| c.is_ascii_digit()) {
+        text.push(digit);
+    }
+}
+
+fn promote_argument(kind: &Type) -> Option<FormatArgument> {
+    if kind.is_ptr_like_type() {
+        return Some(FormatArgument::Pointer);
+    }
+
+    match kind.remove_all_constant_type() {
+        Type::S8 { .. }
+        | Type::S16 { .. }
+        | Type::S32 { .. }
+        | Type::U8 { .. }
+        | Type::U16 { .. }
+        | Type::U32 { .. }
+        | Type::Char(..)
+        | Type::Bool(..) => Some(FormatArgument::Integer(32)),
+
+        Type::S64 { .. } | Type::U64 { .. } | Type::SSize { .. } | Type::USize { .. } => {
+            Some(FormatArgument::Integer(64))
+        }
+
+        Type::S128 { .. } | Type::U128 { .. } => Some(FormatArgument::Integer(128)),
+
+        Type::F16 { .. } | Type::BF16 { .. } | Type::F32 { .. } | Type::F64 { .. } => {
+            Some(FormatArgument::Float(64))
+        }
+        Type::F128 { .. } | Type::FX8680 { .. } | Type::FPPC128 { .. } => {
+            Some(FormatArgument::Float(128))
+        }
+
+        _ => None,
+    }
+}
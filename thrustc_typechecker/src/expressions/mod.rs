@@ -27,8 +27,8 @@ use thrustc_span::Span;
 use thrustc_typesystem::{
     Type,
     traits::{
-        TypeArrayEntensions, TypeCodeLocation, TypeFixedArrayEntensions, TypeIsExtensions,
-        TypePointerExtensions, VoidTypeExtensions,
+        TypeArrayEntensions, TypeCodeLocation, TypeErrorUnionExtensions, TypeFixedArrayEntensions,
+        TypeIsExtensions, TypePointerExtensions, VoidTypeExtensions,
     },
 };
 
@@ -39,6 +39,7 @@ use crate::{
 
 mod builtins;
 mod call;
+mod format;
 
 pub fn validate<'type_checker>(
     typechecker: &mut TypeChecker<'type_checker>,
@@ -140,6 +141,100 @@ pub fn validate<'type_checker>(
             Ok(())
         }
 
+        Ast::Try {
+            expression, span, ..
+        } => {
+            typechecker.analyze_expr(expression)?;
+
+            let expression_type: &Type = expression.get_value_type()?;
+
+            if !expression_type.is_error_union_type() {
+                typechecker.add_error_report(CompilationIssue::Error(
+                    CompilationIssueCode::E0019,
+                    format!(
+                        "Expected an error union to propagate, got '{}' type.",
+                        expression_type
+                    ),
+                    "You should only use '?' over a call that returns an error union.".into(),
+                    None,
+                    *span,
+                ));
+            }
+
+            let propagates: bool = typechecker
+                .get_type_context()
+                .get_current_function_type()
+                .is_some_and(|(return_type, _)| return_type.is_error_union_type());
+
+            if !propagates {
+                typechecker.add_error_report(CompilationIssue::Error(
+                    CompilationIssueCode::E0041,
+                    "Cannot propagate an error from a function that doesn't return an error union."
+                        .into(),
+                    "You should handle it with 'catch' or declare an error union return type."
+                        .into(),
+                    None,
+                    *span,
+                ));
+            }
+
+            Ok(())
+        }
+
+        Ast::Catch {
+            expression,
+            fallback,
+            kind,
+            span,
+            ..
+        } => {
+            typechecker.analyze_expr(expression)?;
+            typechecker.analyze_expr(fallback)?;
+
+            let expression_type: &Type = expression.get_value_type()?;
+
+            if !expression_type.is_error_union_type() {
+                typechecker.add_error_report(CompilationIssue::Error(
+                    CompilationIssueCode::E0019,
+                    format!(
+                        "Expected an error union to handle, got '{}' type.",
+                        expression_type
+                    ),
+                    "You should only use 'catch' over a call that returns an error union.".into(),
+                    None,
+                    *span,
+                ));
+
+                return Ok(());
+            }
+
+            if kind.is_void_type() {
+                return Ok(());
+            }
+
+            let metadata: TypeCheckerNodeMetadata =
+                TypeCheckerNodeMetadata::new(fallback.is_totaly_literal_value());
+
+            let control_context: &mut TypeCheckerControlContext =
+                typechecker.get_mut_control_context();
+
+            control_context.reset_checking_depth();
+
+            if let Err(error) = check::check_type_together(
+                expression_type.get_error_union_value_type(),
+                fallback.get_value_type()?,
+                Some(fallback),
+                None,
+                metadata,
+                fallback.get_span(),
+                control_context,
+            ) {
+                typechecker.add_error_report(error);
+            }
+
+            Ok(())
+        }
+
         Ast::FixedArray {
             items, kind, span, ..
         } => {
@@ -457,6 +552,39 @@ pub fn validate<'type_checker>(
             Ok(())
         }
 
+        Ast::DynamicCall {
+            object,
+            function_type,
+            args,
+            span,
+            ..
+        } => {
+            typechecker.analyze_expr(object)?;
+
+            let Type::Fn(parameters, return_type, ..) = function_type else {
+                typechecker.add_error_report(CompilationIssue::FrontEndBug(
+                    "Method not found".into(),
+                    "Interface method could not be found for processing.".into(),
+                    *span,
+                    CompilationPosition::TypeChecker,
+                    std::path::PathBuf::from(file!()),
+                    line!(),
+                ));
+
+                return Ok(());
+            };
+
+            call::validate_arguments(
+                typechecker,
+                (&**return_type, parameters.get(1..).unwrap_or_default()),
+                (false, None),
+                args,
+                span,
+            )
+        }
+
+        Ast::InterfaceObject { value, .. } => typechecker.analyze_expr(value),
+
         Ast::Deref { value, kind, .. } => {
             let value_type: &Type = value.get_value_type()?;
 
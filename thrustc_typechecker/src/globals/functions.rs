@@ -22,11 +22,12 @@ use thrustc_ast::{
     traits::{AstCodeBlockEntensions, AstCodeLocation, AstGetType},
 };
 
+use thrustc_attributes::traits::ThrustAttributesExtensions;
 use thrustc_errors::{CompilationIssue, CompilationIssueCode, CompilationPosition};
 use thrustc_span::Span;
 use thrustc_typesystem::{
     Type,
-    traits::{TypeCodeLocation, TypeIsExtensions, VoidTypeExtensions},
+    traits::{TypeCodeLocation, TypeErrorUnionExtensions, TypeIsExtensions, VoidTypeExtensions},
 };
 
 use crate::TypeChecker;
@@ -140,6 +141,10 @@ pub fn validate<'type_checker>(
                 .get_mut_type_context()
                 .set_current_function_type((return_type, *span));
 
+            typechecker
+                .get_mut_type_context()
+                .set_current_function_variadic(attributes.has_ignore_attribute());
+
             if !typechecker.get_table().constains_function(name) {
                 typechecker
                     .get_mut_table()
@@ -177,7 +182,10 @@ pub fn validate<'type_checker>(
             if let Some(body) = body {
                 typechecker.analyze_stmt(body)?;
 
-                if !body.has_terminator() && !return_type.is_void_type() {
+                let returns_value: bool = !return_type.is_void_type()
+                    && !return_type.get_error_union_value_type().is_void_type();
+
+                if !body.has_terminator() && returns_value {
                     typechecker.add_error_report(CompilationIssue::Error(
                         CompilationIssueCode::E0019,
                         format!("Expected return with type '{}'.", return_type),
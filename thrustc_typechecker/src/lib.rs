@@ -29,8 +29,8 @@ use thrustc_span::Span;
 use thrustc_typesystem::{
     Type,
     traits::{
-        DereferenceExtensions, TypeCodeLocation, TypeIsExtensions, TypePointerExtensions,
-        VoidTypeExtensions,
+        DereferenceExtensions, TypeCodeLocation, TypeErrorUnionExtensions, TypeIsExtensions,
+        TypePointerExtensions, VoidTypeExtensions,
     },
 };
 
@@ -62,6 +62,8 @@ pub struct TypeChecker<'type_checker> {
 
     table: TypeCheckerSymbolsTable<'type_checker>,
 
+    freestanding: bool,
+
     diagnostician: Diagnostician,
 }
 
@@ -82,7 +84,8 @@ impl<'type_checker> TypeChecker<'type_checker> {
             control_context: TypeCheckerControlContext::new(),
             type_context: TypeCheckerTypeContext::new(),
             table: TypeCheckerSymbolsTable::new(),
-            diagnostician: Diagnostician::new(file, options),
+            freestanding: options.is_freestanding(),
+            diagnostician: Diagnostician::with_allowed_warnings(file, options, ast),
         }
     }
 }
@@ -135,7 +138,7 @@ impl<'type_checker> TypeChecker<'type_checker> {
 
                 true
             } else {
-                false
+                self.diagnostician.has_denied_warnings()
             }
         }
     }
@@ -660,6 +663,15 @@ impl<'type_checker> TypeChecker<'type_checker> {
                     ));
                 };
 
+                let expr_type: &Type = expr.get_value_type()?;
+
+                let return_type: &Type =
+                    if return_type.is_error_union_type() && !expr_type.is_error_union_type() {
+                        return_type.get_error_union_value_type()
+                    } else {
+                        return_type
+                    };
+
                 {
                     let control_context: &mut TypeCheckerControlContext =
                         self.get_mut_control_context();
@@ -668,7 +680,7 @@ impl<'type_checker> TypeChecker<'type_checker> {
 
                     if let Err(error) = check::check_type_together(
                         return_type,
-                        expr.get_value_type()?,
+                        expr_type,
                         Some(expr),
                         None,
                         metadata,
@@ -683,6 +695,69 @@ impl<'type_checker> TypeChecker<'type_checker> {
 
                 Ok(())
             }
+            Ast::Raise {
+                expression, span, ..
+            } => {
+                let metadata: TypeCheckerNodeMetadata =
+                    TypeCheckerNodeMetadata::new(expression.is_totaly_literal_value());
+
+                let Some((return_type, _)) = self.get_type_context().get_current_function_type()
+                else {
+                    return Err(CompilationIssue::Error(
+                        CompilationIssueCode::E0018,
+                        "Terminator is outside a function.".into(),
+                        "It should be inside a function. Reposition inside it.".into(),
+                        None,
+                        *span,
+                    ));
+                };
+
+                if !return_type.is_error_union_type() {
+                    self.add_error_report(CompilationIssue::Error(
+                        CompilationIssueCode::E0041,
+                        format!(
+                            "Cannot raise an error from a function that returns '{}'.",
+                            return_type
+                        ),
+                        "You should declare an error union return type, for example '!s32'.".into(),
+                        None,
+                        *span,
+                    ));
+                }
+
+                if let Ast::Integer { value: 0, .. } = &**expression {
+                    self.add_error_report(CompilationIssue::Error(
+                        CompilationIssueCode::E0041,
+                        "Error code '0' is reserved for success.".into(),
+                        "You should raise a member of an error set.".into(),
+                        None,
+                        expression.get_span(),
+                    ));
+                }
+
+                {
+                    let control_context: &mut TypeCheckerControlContext =
+                        self.get_mut_control_context();
+
+                    control_context.reset_checking_depth();
+
+                    if let Err(error) = check::check_type_together(
+                        &Type::U32 { span: *span },
+                        expression.get_value_type()?,
+                        Some(expression),
+                        None,
+                        metadata,
+                        expression.get_span(),
+                        control_context,
+                    ) {
+                        self.add_error_report(error);
+                    }
+                }
+
+                self.analyze_expr(expression)?;
+
+                Ok(())
+            }
             Ast::Mut { source, value, .. } => {
                 let metadata: TypeCheckerNodeMetadata =
                     TypeCheckerNodeMetadata::new(value.is_totaly_literal_value());
@@ -719,7 +794,22 @@ impl<'type_checker> TypeChecker<'type_checker> {
 
                 Ok(())
             }
-            _ => self.analyze_expr(node),
+            _ => {
+                if node
+                    .get_value_type()
+                    .is_ok_and(|kind| kind.is_error_union_type())
+                {
+                    self.add_error_report(CompilationIssue::Error(
+                        CompilationIssueCode::E0041,
+                        "Error union value is silently discarded.".into(),
+                        "You should propagate it with '?' or handle it with 'catch'.".into(),
+                        None,
+                        node.get_span(),
+                    ));
+                }
+
+                self.analyze_expr(node)
+            }
         }
     }
 
@@ -823,6 +913,11 @@ impl<'type_checker> TypeChecker<'type_checker> {
     fn get_control_context(&self) -> &TypeCheckerControlContext {
         &self.control_context
     }
+
+    #[inline]
+    fn is_freestanding(&self) -> bool {
+        self.freestanding
+    }
 }
 
 impl<'type_checker> TypeChecker<'type_checker> {
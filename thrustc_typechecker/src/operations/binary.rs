@@ -21,7 +21,11 @@ use thrustc_errors::{CompilationIssue, CompilationIssueCode};
 use thrustc_span::Span;
 
 use thrustc_token_type::TokenType;
-use thrustc_typesystem::{Type, traits::TypeIsExtensions};
+use thrustc_token_type::traits::TokenTypeExtensions;
+use thrustc_typesystem::{
+    Type,
+    traits::{TypeIsExtensions, TypeVectorExtensions},
+};
 
 #[inline]
 pub fn validate_binary(
@@ -30,6 +34,10 @@ pub fn validate_binary(
     right: &Type,
     span: Span,
 ) -> Result<(), CompilationIssue> {
+    if left.is_vector_type() || right.is_vector_type() {
+        return self::validate_vector_expression(operator, left, right, span);
+    }
+
     match *operator {
         TokenType::Arith
         | TokenType::Star
@@ -69,6 +77,38 @@ pub fn validate_binary(
     }
 }
 
+#[inline]
+fn validate_vector_expression(
+    operator: &TokenType,
+    left: &Type,
+    right: &Type,
+    span: Span,
+) -> Result<(), CompilationIssue> {
+    let left_element: Type = left.get_vector_base_type();
+    let right_element: Type = right.get_vector_base_type();
+
+    if !left.is_vector_type()
+        || !right.is_vector_type()
+        || operator.is_logical_gate()
+        || left.get_vector_lanes() != right.get_vector_lanes()
+        || left_element != right_element
+    {
+        return Err(CompilationIssue::Error(
+            CompilationIssueCode::E0030,
+            format!(
+                "'{} {} {}' isn't a valid vector operation.",
+                left, operator, right
+            ),
+            "Both operands should be vectors with the same element type and number of lanes."
+                .into(),
+            None,
+            span,
+        ));
+    }
+
+    self::validate_binary(operator, &left_element, &right_element, span)
+}
+
 #[inline]
 fn validate_band_expression(
     operator: &TokenType,
@@ -82,6 +122,7 @@ fn validate_band_expression(
             | Type::S16 { .. }
             | Type::S32 { .. }
             | Type::S64 { .. }
+            | Type::S128 { .. }
             | Type::U8 { .. }
             | Type::U16 { .. }
             | Type::U32 { .. }
@@ -91,6 +132,7 @@ fn validate_band_expression(
             | Type::S16 { .. }
             | Type::S32 { .. }
             | Type::S64 { .. }
+            | Type::S128 { .. }
             | Type::U8 { .. }
             | Type::U16 { .. }
             | Type::U32 { .. }
@@ -126,6 +168,7 @@ fn validate_bor_expression(
             | Type::S16 { .. }
             | Type::S32 { .. }
             | Type::S64 { .. }
+            | Type::S128 { .. }
             | Type::U8 { .. }
             | Type::U16 { .. }
             | Type::U32 { .. }
@@ -135,6 +178,7 @@ fn validate_bor_expression(
             | Type::S16 { .. }
             | Type::S32 { .. }
             | Type::S64 { .. }
+            | Type::S128 { .. }
             | Type::U8 { .. }
             | Type::U16 { .. }
             | Type::U32 { .. }
@@ -170,18 +214,22 @@ fn validate_xor_expression(
             | Type::S16 { .. }
             | Type::S32 { .. }
             | Type::S64 { .. }
+            | Type::S128 { .. }
             | Type::U8 { .. }
             | Type::U16 { .. }
             | Type::U32 { .. }
-            | Type::U64 { .. },
+            | Type::U64 { .. }
+            | Type::U128 { .. },
             Type::S8 { .. }
             | Type::S16 { .. }
             | Type::S32 { .. }
             | Type::S64 { .. }
+            | Type::S128 { .. }
             | Type::U8 { .. }
             | Type::U16 { .. }
             | Type::U32 { .. }
-            | Type::U64 { .. },
+            | Type::U64 { .. }
+            | Type::U128 { .. },
         ) => Ok(()),
         (Type::SSize { .. }, Type::SSize { .. }) => Ok(()),
         (Type::USize { .. }, Type::USize { .. }) => Ok(()),
@@ -235,6 +283,7 @@ fn validate_binary_shift_expression(
             | Type::S16 { .. }
             | Type::S32 { .. }
             | Type::S64 { .. }
+            | Type::S128 { .. }
             | Type::U8 { .. }
             | Type::SSize { .. }
             | Type::U16 { .. }
@@ -246,6 +295,7 @@ fn validate_binary_shift_expression(
             | Type::S16 { .. }
             | Type::S32 { .. }
             | Type::S64 { .. }
+            | Type::S128 { .. }
             | Type::SSize { .. }
             | Type::U8 { .. }
             | Type::U16 { .. }
@@ -281,6 +331,7 @@ fn validate_binary_comparasion_expression(
             | Type::S16 { .. }
             | Type::S32 { .. }
             | Type::S64 { .. }
+            | Type::S128 { .. }
             | Type::U8 { .. }
             | Type::U16 { .. }
             | Type::U32 { .. }
@@ -290,6 +341,7 @@ fn validate_binary_comparasion_expression(
             | Type::S16 { .. }
             | Type::S32 { .. }
             | Type::S64 { .. }
+            | Type::S128 { .. }
             | Type::U8 { .. }
             | Type::U16 { .. }
             | Type::U32 { .. }
@@ -301,8 +353,16 @@ fn validate_binary_comparasion_expression(
         (Type::FPPC128 { .. }, Type::FPPC128 { .. }) => Ok(()),
         (Type::FX8680 { .. }, Type::FX8680 { .. }) => Ok(()),
         (
-            Type::F32 { .. } | Type::F64 { .. } | Type::F128 { .. },
-            Type::F32 { .. } | Type::F64 { .. } | Type::F128 { .. },
+            Type::F16 { .. }
+            | Type::BF16 { .. }
+            | Type::F32 { .. }
+            | Type::F64 { .. }
+            | Type::F128 { .. },
+            Type::F16 { .. }
+            | Type::BF16 { .. }
+            | Type::F32 { .. }
+            | Type::F64 { .. }
+            | Type::F128 { .. },
         ) => Ok(()),
 
         _ => Err(CompilationIssue::Error(
@@ -331,6 +391,7 @@ fn validate_binary_equality_expression(
             | Type::S16 { .. }
             | Type::S32 { .. }
             | Type::S64 { .. }
+            | Type::S128 { .. }
             | Type::U8 { .. }
             | Type::U16 { .. }
             | Type::U32 { .. }
@@ -340,6 +401,7 @@ fn validate_binary_equality_expression(
             | Type::S16 { .. }
             | Type::S32 { .. }
             | Type::S64 { .. }
+            | Type::S128 { .. }
             | Type::U8 { .. }
             | Type::U16 { .. }
             | Type::U32 { .. }
@@ -349,8 +411,16 @@ fn validate_binary_equality_expression(
         (Type::SSize { .. }, Type::SSize { .. }) => Ok(()),
         (Type::USize { .. }, Type::USize { .. }) => Ok(()),
         (
-            Type::F32 { .. } | Type::F64 { .. } | Type::F128 { .. },
-            Type::F32 { .. } | Type::F64 { .. } | Type::F128 { .. },
+            Type::F16 { .. }
+            | Type::BF16 { .. }
+            | Type::F32 { .. }
+            | Type::F64 { .. }
+            | Type::F128 { .. },
+            Type::F16 { .. }
+            | Type::BF16 { .. }
+            | Type::F32 { .. }
+            | Type::F64 { .. }
+            | Type::F128 { .. },
         ) => Ok(()),
         (Type::Bool(..), Type::Bool(..)) => Ok(()),
         (Type::Char(..), Type::Char(..)) => Ok(()),
@@ -385,28 +455,40 @@ fn validate_binary_arithmetic_expression(
             | Type::S16 { .. }
             | Type::S32 { .. }
             | Type::S64 { .. }
+            | Type::S128 { .. }
             | Type::SSize { .. }
             | Type::U8 { .. }
             | Type::U16 { .. }
             | Type::U32 { .. }
             | Type::U64 { .. }
+            | Type::U128 { .. }
             | Type::USize { .. },
             Type::S8 { .. }
             | Type::S16 { .. }
             | Type::S32 { .. }
             | Type::S64 { .. }
+            | Type::S128 { .. }
             | Type::SSize { .. }
             | Type::U8 { .. }
             | Type::U16 { .. }
             | Type::U32 { .. }
             | Type::U64 { .. }
+            | Type::U128 { .. }
             | Type::USize { .. },
         ) => Ok(()),
         (Type::FPPC128 { .. }, Type::FPPC128 { .. }) => Ok(()),
         (Type::FX8680 { .. }, Type::FX8680 { .. }) => Ok(()),
         (
-            Type::F32 { .. } | Type::F64 { .. } | Type::F128 { .. },
-            Type::F32 { .. } | Type::F64 { .. } | Type::F128 { .. },
+            Type::F16 { .. }
+            | Type::BF16 { .. }
+            | Type::F32 { .. }
+            | Type::F64 { .. }
+            | Type::F128 { .. },
+            Type::F16 { .. }
+            | Type::BF16 { .. }
+            | Type::F32 { .. }
+            | Type::F64 { .. }
+            | Type::F128 { .. },
         ) => Ok(()),
         (Type::Ptr(..), Type::Ptr(..)) if left == right && *operator == TokenType::Minus => Ok(()),
 
@@ -69,28 +69,34 @@ impl TypeArrayEntensions for Type {
             Type::S16 { .. } => 10,
             Type::S32 { .. } => 11,
             Type::S64 { .. } => 12,
-            Type::SSize { .. } => 13,
+            Type::S128 { .. } => 13,
+            Type::SSize { .. } => 14,
 
-            Type::F32 { .. } => 15,
-            Type::F64 { .. } => 16,
-            Type::F128 { .. } => 17,
-            Type::FX8680 { .. } => 18,
-            Type::FPPC128 { .. } => 19,
+            Type::F16 { .. } => 15,
+            Type::BF16 { .. } => 16,
+            Type::F32 { .. } => 17,
+            Type::F64 { .. } => 18,
+            Type::F128 { .. } => 19,
+            Type::FX8680 { .. } => 20,
+            Type::FPPC128 { .. } => 21,
 
             Type::Const(subtype, ..) => subtype.get_array_type_herarchy(),
 
-            Type::Addr(..) => 20,
+            Type::Addr(..) => 22,
             Type::Ptr(Some(subtype), ..) => subtype.get_array_type_herarchy(),
-            Type::Ptr(None, ..) => 21,
+            Type::Ptr(None, ..) => 23,
 
-            Type::Fn(..) => 22,
+            Type::Fn(..) => 24,
 
-            Type::Array { .. } => 23,
-            Type::FixedArray(..) => 24,
-            Type::Struct { .. } => 25,
+            Type::Array { .. } => 25,
+            Type::FixedArray(..) => 26,
+            Type::Vector(..) => 27,
+            Type::Struct { .. } => 28,
+            Type::ErrorUnion(..) => 29,
+            Type::Interface(..) => 30,
 
-            Type::Void(..) => 26,
-            Type::Unresolved { .. } => 27,
+            Type::Void(..) => 31,
+            Type::Unresolved { .. } => 32,
         }
     }
 }
@@ -0,0 +1,48 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+
+use crate::{
+    Type,
+    traits::{TypeCodeLocation, TypeErrorUnionExtensions, TypeIsExtensions},
+};
+
+impl TypeErrorUnionExtensions for Type {
+    #[inline(always)]
+    fn get_error_union_value_type(&self) -> &Type {
+        match self {
+            Type::ErrorUnion(inner, ..) => inner,
+            Type::Const(inner, ..) => inner.get_error_union_value_type(),
+
+            _ => self,
+        }
+    }
+
+    fn get_error_union_fields(&self) -> Vec<Type> {
+        let value_type: &Type = self.get_error_union_value_type();
+        let error_code: Type = Type::U32 {
+            span: self.get_span(),
+        };
+
+        if value_type.is_void_type() {
+            return vec![error_code];
+        }
+
+        vec![error_code, value_type.clone()]
+    }
+}
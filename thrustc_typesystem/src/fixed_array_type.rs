@@ -54,27 +54,33 @@ impl TypeFixedArrayEntensions for Type {
             Type::S16 { .. } => 10,
             Type::S32 { .. } => 11,
             Type::S64 { .. } => 12,
-            Type::SSize { .. } => 13,
+            Type::S128 { .. } => 13,
+            Type::SSize { .. } => 14,
 
-            Type::F32 { .. } => 15,
-            Type::F64 { .. } => 16,
-            Type::F128 { .. } => 17,
-            Type::FX8680 { .. } => 18,
-            Type::FPPC128 { .. } => 19,
+            Type::F16 { .. } => 15,
+            Type::BF16 { .. } => 16,
+            Type::F32 { .. } => 17,
+            Type::F64 { .. } => 18,
+            Type::F128 { .. } => 19,
+            Type::FX8680 { .. } => 20,
+            Type::FPPC128 { .. } => 21,
 
             Type::Const(subtype, ..) => subtype.get_fixed_array_type_herarchy(),
 
-            Type::Addr(..) => 20,
+            Type::Addr(..) => 22,
             Type::Ptr(Some(subtype), ..) => subtype.get_fixed_array_type_herarchy(),
-            Type::Ptr(None, ..) => 21,
-
-            Type::Array { .. } => 22,
-            Type::FixedArray(..) => 23,
-            Type::Struct { .. } => 24,
-
-            Type::Fn(..) => 25,
-            Type::Void(..) => 26,
-            Type::Unresolved { .. } => 27,
+            Type::Ptr(None, ..) => 23,
+
+            Type::Array { .. } => 24,
+            Type::FixedArray(..) => 25,
+            Type::Vector(..) => 26,
+            Type::Struct { .. } => 27,
+            Type::ErrorUnion(..) => 28,
+            Type::Interface(..) => 29,
+
+            Type::Fn(..) => 30,
+            Type::Void(..) => 31,
+            Type::Unresolved { .. } => 32,
         }
     }
 }
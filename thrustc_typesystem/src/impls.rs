@@ -54,11 +54,39 @@ impl TypeIsExtensions for Type {
         matches!(self, Type::Struct { .. })
     }
 
+    #[inline(always)]
+    fn is_union_type(&self) -> bool {
+        if let Type::Const(subtype, ..) = self {
+            return subtype.is_union_type();
+        }
+
+        matches!(self, Type::Struct { modifier, .. } if modifier.is_union())
+    }
+
     #[inline(always)]
     fn is_fixed_array_type(&self) -> bool {
         matches!(self, Type::FixedArray(..))
     }
 
+    #[inline(always)]
+    fn is_vector_type(&self) -> bool {
+        if let Type::Const(subtype, ..) = self {
+            return subtype.is_vector_type();
+        }
+
+        matches!(self, Type::Vector(..))
+    }
+
+    #[inline(always)]
+    fn is_error_union_type(&self) -> bool {
+        matches!(self, Type::ErrorUnion(..))
+    }
+
+    #[inline(always)]
+    fn is_interface_type(&self) -> bool {
+        matches!(self, Type::Interface(..))
+    }
+
     #[inline(always)]
     fn is_array_type(&self) -> bool {
         matches!(self, Type::Array { .. })
@@ -68,7 +96,9 @@ impl TypeIsExtensions for Type {
     fn is_float_type(&self) -> bool {
         matches!(
             self,
-            Type::F32 { .. }
+            Type::F16 { .. }
+                | Type::BF16 { .. }
+                | Type::F32 { .. }
                 | Type::F64 { .. }
                 | Type::F128 { .. }
                 | Type::FX8680 { .. }
@@ -122,6 +152,7 @@ impl TypeIsExtensions for Type {
                 | Type::S16 { .. }
                 | Type::S32 { .. }
                 | Type::S64 { .. }
+                | Type::S128 { .. }
                 | Type::SSize { .. }
         )
     }
@@ -139,6 +170,7 @@ impl TypeIsExtensions for Type {
                 | Type::S16 { .. }
                 | Type::S32 { .. }
                 | Type::S64 { .. }
+                | Type::S128 { .. }
                 | Type::SSize { .. }
                 | Type::U8 { .. }
                 | Type::U16 { .. }
@@ -172,28 +204,34 @@ impl TypeIsExtensions for Type {
             Type::S16 { .. } => 10,
             Type::S32 { .. } => 11,
             Type::S64 { .. } => 12,
-            Type::SSize { .. } => 13,
+            Type::S128 { .. } => 13,
+            Type::SSize { .. } => 14,
 
-            Type::F32 { .. } => 15,
-            Type::F64 { .. } => 16,
-            Type::F128 { .. } => 17,
-            Type::FX8680 { .. } => 18,
-            Type::FPPC128 { .. } => 19,
+            Type::F16 { .. } => 15,
+            Type::BF16 { .. } => 16,
+            Type::F32 { .. } => 17,
+            Type::F64 { .. } => 18,
+            Type::F128 { .. } => 19,
+            Type::FX8680 { .. } => 20,
+            Type::FPPC128 { .. } => 21,
 
             Type::Const(subtype, ..) => subtype.get_type_herarchy(),
 
-            Type::Addr(..) => 20,
+            Type::Addr(..) => 22,
             Type::Ptr(Some(subtype), ..) => subtype.get_type_herarchy(),
-            Type::Ptr(None, ..) => 21,
+            Type::Ptr(None, ..) => 23,
 
-            Type::Fn(..) => 22,
+            Type::Fn(..) => 24,
 
-            Type::Array { .. } => 23,
-            Type::FixedArray(..) => 24,
-            Type::Struct { .. } => 25,
+            Type::Array { .. } => 25,
+            Type::FixedArray(..) => 26,
+            Type::Vector(..) => 27,
+            Type::Struct { .. } => 28,
+            Type::ErrorUnion(..) => 29,
+            Type::Interface(..) => 30,
 
-            Type::Void(..) => 26,
-            Type::Unresolved { .. } => 27,
+            Type::Void(..) => 31,
+            Type::Unresolved { .. } => 32,
         }
     }
 }
@@ -203,6 +241,7 @@ impl TypeExtensions for Type {
     fn is_value(&self) -> bool {
         self.is_numeric_type()
             || self.is_fixed_array_type()
+            || self.is_vector_type()
             || self.is_struct_type()
             || self.is_const_value()
     }
@@ -213,7 +252,10 @@ impl TypeExtensions for Type {
             return inner.is_const_value();
         }
 
-        self.is_numeric_type() || self.is_fixed_array_type() || self.is_struct_type()
+        self.is_numeric_type()
+            || self.is_fixed_array_type()
+            || self.is_vector_type()
+            || self.is_struct_type()
     }
 
     fn get_type_with_depth(&self, base_depth: u64) -> &Type {
@@ -223,6 +265,7 @@ impl TypeExtensions for Type {
 
         match self {
             Type::FixedArray(element_type, ..) => element_type.get_type_with_depth(base_depth - 1),
+            Type::Vector(element_type, ..) => element_type.get_type_with_depth(base_depth - 1),
             Type::Array {
                 infered_type: Some((infered_type, 0)),
                 ..
@@ -238,6 +281,7 @@ impl TypeExtensions for Type {
             | Type::S16 { .. }
             | Type::S32 { .. }
             | Type::S64 { .. }
+            | Type::S128 { .. }
             | Type::SSize { .. }
             | Type::U8 { .. }
             | Type::U16 { .. }
@@ -245,6 +289,8 @@ impl TypeExtensions for Type {
             | Type::U64 { .. }
             | Type::U128 { .. }
             | Type::USize { .. }
+            | Type::F16 { .. }
+            | Type::BF16 { .. }
             | Type::F32 { .. }
             | Type::F64 { .. }
             | Type::F128 { .. }
@@ -256,6 +302,8 @@ impl TypeExtensions for Type {
             | Type::Void(..)
             | Type::Ptr(None, ..)
             | Type::Fn(..)
+            | Type::ErrorUnion(..)
+            | Type::Interface(..)
             | Type::Unresolved { .. } => self,
         }
     }
@@ -275,6 +323,7 @@ impl Hash for Type {
             | Type::S16 { .. }
             | Type::S32 { .. }
             | Type::S64 { .. }
+            | Type::S128 { .. }
             | Type::SSize { .. }
             | Type::U8 { .. }
             | Type::U16 { .. }
@@ -282,6 +331,8 @@ impl Hash for Type {
             | Type::U64 { .. }
             | Type::U128 { .. }
             | Type::USize { .. }
+            | Type::F16 { .. }
+            | Type::BF16 { .. }
             | Type::F32 { .. }
             | Type::F64 { .. }
             | Type::F128 { .. }
@@ -292,19 +343,22 @@ impl Hash for Type {
             | Type::Addr(_)
             | Type::Void(_) => {}
 
-            Type::Const(inner, _) => inner.hash(state),
+            Type::Const(inner, _) | Type::ErrorUnion(inner, _) => inner.hash(state),
+            Type::Interface(name, _) => name.hash(state),
             Type::Ptr(inner, _) => inner.hash(state),
             Type::Struct {
                 name,
                 fields,
+                bitfields,
                 modifier,
                 ..
             } => {
                 name.hash(state);
                 fields.hash(state);
+                bitfields.hash(state);
                 modifier.hash(state);
             }
-            Type::FixedArray(inner, size, _) => {
+            Type::FixedArray(inner, size, _) | Type::Vector(inner, size, _) => {
                 inner.hash(state);
                 size.hash(state);
             }
@@ -342,12 +396,14 @@ impl PartialEq for Type {
                 Type::Struct {
                     name: a,
                     fields: fields1,
+                    bitfields: bitfields1,
                     modifier: mod1,
                     ..
                 },
                 Type::Struct {
                     name: b,
                     fields: fields2,
+                    bitfields: bitfields2,
                     modifier: mod2,
                     ..
                 },
@@ -355,6 +411,7 @@ impl PartialEq for Type {
                 fields1.len() == fields2.len()
                     && a == b
                     && fields1.iter().zip(fields2.iter()).all(|(f1, f2)| f1 == f2)
+                    && bitfields1 == bitfields2
                     && mod1 == mod2
             }
 
@@ -362,6 +419,10 @@ impl PartialEq for Type {
                 type_a == type_b && size_a == size_b
             }
 
+            (Type::Vector(type_a, lanes_a, ..), Type::Vector(type_b, lanes_b, ..)) => {
+                type_a == type_b && lanes_a == lanes_b
+            }
+
             (
                 Type::Array {
                     base_type: target, ..
@@ -371,12 +432,15 @@ impl PartialEq for Type {
                 },
             ) => target == from,
             (Type::Const(target, ..), Type::Const(from, ..)) => target == from,
+            (Type::ErrorUnion(target, ..), Type::ErrorUnion(from, ..)) => target == from,
+            (Type::Interface(target, ..), Type::Interface(from, ..)) => target == from,
 
             (Type::Char(..), Type::Char(..)) => true,
             (Type::S8 { .. }, Type::S8 { .. }) => true,
             (Type::S16 { .. }, Type::S16 { .. }) => true,
             (Type::S32 { .. }, Type::S32 { .. }) => true,
             (Type::S64 { .. }, Type::S64 { .. }) => true,
+            (Type::S128 { .. }, Type::S128 { .. }) => true,
             (Type::SSize { .. }, Type::SSize { .. }) => true,
             (Type::U8 { .. }, Type::U8 { .. }) => true,
             (Type::U16 { .. }, Type::U16 { .. }) => true,
@@ -384,6 +448,8 @@ impl PartialEq for Type {
             (Type::U64 { .. }, Type::U64 { .. }) => true,
             (Type::U128 { .. }, Type::U128 { .. }) => true,
             (Type::USize { .. }, Type::USize { .. }) => true,
+            (Type::F16 { .. }, Type::F16 { .. }) => true,
+            (Type::BF16 { .. }, Type::BF16 { .. }) => true,
             (Type::F32 { .. }, Type::F32 { .. }) => true,
             (Type::F64 { .. }, Type::F64 { .. }) => true,
             (Type::F128 { .. }, Type::F128 { .. }) => true,
@@ -408,6 +474,7 @@ impl std::fmt::Display for Type {
             Type::S16 { .. } => write!(f, "s16"),
             Type::S32 { .. } => write!(f, "s32"),
             Type::S64 { .. } => write!(f, "s64"),
+            Type::S128 { .. } => write!(f, "s128"),
             Type::SSize { .. } => write!(f, "ssize"),
             Type::U8 { .. } => write!(f, "u8"),
             Type::U16 { .. } => write!(f, "u16"),
@@ -415,6 +482,8 @@ impl std::fmt::Display for Type {
             Type::U64 { .. } => write!(f, "u64"),
             Type::U128 { .. } => write!(f, "u128"),
             Type::USize { .. } => write!(f, "usize"),
+            Type::F16 { .. } => write!(f, "f16"),
+            Type::BF16 { .. } => write!(f, "bf16"),
             Type::F32 { .. } => write!(f, "f32"),
             Type::F64 { .. } => write!(f, "f64"),
             Type::F128 { .. } => write!(f, "f128"),
@@ -443,15 +512,21 @@ impl std::fmt::Display for Type {
                 )
             }
             Type::Const(inner_type, ..) => write!(f, "const {}", inner_type),
+            Type::ErrorUnion(inner_type, ..) => write!(f, "!{}", inner_type),
+            Type::Interface(name, ..) => write!(f, "{}", name),
             Type::FixedArray(kind, size, ..) => {
                 write!(f, "array[{}; {}]", kind, size)
             }
+            Type::Vector(kind, lanes, ..) => {
+                write!(f, "vec[{}; {}]", kind, lanes)
+            }
             Type::Array { base_type, .. } => {
                 write!(f, "array[{}]", base_type)
             }
             Type::Struct {
                 name,
                 fields,
+                bitfields,
                 modifier,
                 ..
             } => {
@@ -461,10 +536,19 @@ impl std::fmt::Display for Type {
                     ""
                 };
 
-                write!(f, "struct {}{} {{ ", name, has_llvm_packed_attribute)?;
+                let keyword: &str = if modifier.is_union() {
+                    "union"
+                } else {
+                    "struct"
+                };
+
+                write!(f, "{} {}{} {{ ", keyword, name, has_llvm_packed_attribute)?;
 
-                for field in fields.iter() {
-                    write!(f, "{} ", field)?;
+                for (index, field) in fields.iter().enumerate() {
+                    match bitfields.iter().find(|(bit, _)| *bit as usize == index) {
+                        Some((_, width)) => write!(f, "{}:{} ", field, width)?,
+                        None => write!(f, "{} ", field)?,
+                    }
                 }
 
                 write!(f, "}}")
@@ -0,0 +1,44 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+
+use thrustc_span::Span;
+
+use crate::{
+    Type,
+    traits::{TypeCodeLocation, TypeInterfaceExtensions},
+};
+
+impl TypeInterfaceExtensions for Type {
+    #[inline(always)]
+    fn get_interface_name(&self) -> &str {
+        match self {
+            Type::Interface(name, ..) => name,
+            Type::Const(inner, ..) => inner.get_interface_name(),
+
+            _ => "",
+        }
+    }
+
+    fn get_interface_fields(&self) -> Vec<Type> {
+        let span: Span = self.get_span();
+
+        // The object data pointer, followed by the pointer to its vtable.
+        vec![Type::Ptr(None, span), Type::Ptr(None, span)]
+    }
+}
@@ -19,9 +19,11 @@
 
 mod array_type;
 mod constant_type;
+mod error_union_type;
 mod fixed_array_type;
 mod function_reference_type;
 mod impls;
+mod interface_type;
 mod pointer_dereference;
 mod pointer_type;
 mod structure_type;
@@ -33,6 +35,7 @@ pub mod type_layout;
 mod type_location;
 pub mod type_modificators;
 mod type_precedence;
+mod vector_type;
 mod void_type;
 
 use thrustc_span::Span;
@@ -58,6 +61,9 @@ pub enum Type {
     S64 {
         span: Span,
     },
+    S128 {
+        span: Span,
+    },
     SSize {
         span: Span,
     },
@@ -83,6 +89,12 @@ pub enum Type {
     },
 
     // Floating Point Type
+    F16 {
+        span: Span,
+    },
+    BF16 {
+        span: Span,
+    },
     F32 {
         span: Span,
     },
@@ -115,6 +127,7 @@ pub enum Type {
     Struct {
         name: String,
         fields: std::vec::Vec<Type>,
+        bitfields: std::vec::Vec<(u32, u8)>,
         modifier: StructureTypeModificator,
         span: Span,
     },
@@ -122,6 +135,9 @@ pub enum Type {
     // Fixed FixedArray
     FixedArray(std::boxed::Box<Type>, u32, Span),
 
+    // SIMD Vector
+    Vector(std::boxed::Box<Type>, u32, Span),
+
     // Array Type
     Array {
         base_type: std::boxed::Box<Type>,
@@ -140,6 +156,12 @@ pub enum Type {
         Span,
     ),
 
+    // Error Union
+    ErrorUnion(std::boxed::Box<Type>, Span),
+
+    // Interface Object
+    Interface(String, Span),
+
     // Void Type
     Void(Span),
 
@@ -52,7 +52,7 @@ impl TypePointerExtensions for Type {
 
     #[inline]
     fn is_ptr_aggregate_value_like_type(&self) -> bool {
-        self.is_ptr_fixed_array_type()
+        self.is_ptr_fixed_array_type() || self.is_ptr_vector_type()
     }
 
     #[inline]
@@ -99,6 +99,15 @@ impl TypePointerExtensions for Type {
         false
     }
 
+    #[inline]
+    fn is_ptr_vector_type(&self) -> bool {
+        if let Type::Ptr(Some(inner), ..) = self {
+            return inner.is_vector_type();
+        }
+
+        false
+    }
+
     #[inline]
     fn is_ptr_numeric_type(&self) -> bool {
         if let Type::Ptr(Some(inner), ..) = self {
@@ -26,12 +26,14 @@ impl TypeStructExtensions for Type {
     fn create_struct_type(
         name: String,
         fields: &[Type],
+        bitfields: &[(u32, u8)],
         modifier: StructureTypeModificator,
         span: Span,
     ) -> Type {
         Type::Struct {
             name,
             fields: fields.to_vec(),
+            bitfields: bitfields.to_vec(),
             modifier,
             span,
         }
@@ -45,4 +47,21 @@ impl TypeStructExtensions for Type {
 
         &[]
     }
+
+    #[inline]
+    fn get_struct_bitfields(&self) -> &[(u32, u8)] {
+        match self {
+            Type::Struct { bitfields, .. } => bitfields,
+            Type::Const(inner, ..) => inner.get_struct_bitfields(),
+            _ => &[],
+        }
+    }
+
+    #[inline]
+    fn get_struct_bitfield_width(&self, index: u32) -> Option<u8> {
+        self.get_struct_bitfields()
+            .iter()
+            .find(|(field_index, _)| *field_index == index)
+            .map(|(_, width)| *width)
+    }
 }
@@ -28,7 +28,11 @@ pub trait TypeIsExtensions {
     fn is_void_type(&self) -> bool;
     fn is_bool_type(&self) -> bool;
     fn is_struct_type(&self) -> bool;
+    fn is_union_type(&self) -> bool;
     fn is_fixed_array_type(&self) -> bool;
+    fn is_vector_type(&self) -> bool;
+    fn is_error_union_type(&self) -> bool;
+    fn is_interface_type(&self) -> bool;
     fn is_array_type(&self) -> bool;
     fn is_float_type(&self) -> bool;
     fn is_ptr_type(&self) -> bool;
@@ -70,6 +74,22 @@ pub trait TypeFixedArrayEntensions {
     fn get_fixed_array_type_herarchy(&self) -> u8;
 }
 
+pub trait TypeVectorExtensions {
+    fn get_vector_base_type(&self) -> Type;
+    fn get_vector_lanes(&self) -> u32;
+    fn get_vector_comparison_type(&self, span: Span) -> Type;
+}
+
+pub trait TypeErrorUnionExtensions {
+    fn get_error_union_value_type(&self) -> &Type;
+    fn get_error_union_fields(&self) -> Vec<Type>;
+}
+
+pub trait TypeInterfaceExtensions {
+    fn get_interface_name(&self) -> &str;
+    fn get_interface_fields(&self) -> Vec<Type>;
+}
+
 pub trait TypeArrayEntensions {
     fn get_array_skipping_array_as_base_type(&self) -> Type;
     fn get_array_base_type(&self) -> Type;
@@ -88,15 +108,19 @@ pub trait TypePointerExtensions {
 
     fn is_ptr_struct_type(&self) -> bool;
     fn is_ptr_fixed_array_type(&self) -> bool;
+    fn is_ptr_vector_type(&self) -> bool;
     fn is_ptr_array_type(&self) -> bool;
     fn is_ptr_numeric_type(&self) -> bool;
 }
 
 pub trait TypeStructExtensions {
     fn get_struct_fields(&self) -> &[Type];
+    fn get_struct_bitfields(&self) -> &[(u32, u8)];
+    fn get_struct_bitfield_width(&self, index: u32) -> Option<u8>;
     fn create_struct_type(
         name: String,
         fields: &[Type],
+        bitfields: &[(u32, u8)],
         modificator: StructureTypeModificator,
         span: Span,
     ) -> Type;
@@ -33,6 +33,10 @@ impl IndexExtensions for Type {
                 let decreased_type: u64 = depth.saturating_sub(1);
                 inner_type.get_type_with_depth(decreased_type)
             }
+            Type::Vector(inner_type, ..) => {
+                let decreased_type: u64 = depth.saturating_sub(1);
+                inner_type.get_type_with_depth(decreased_type)
+            }
             Type::Array {
                 infered_type: Some((infered_type, 0)),
                 ..
@@ -54,6 +58,7 @@ impl IndexExtensions for Type {
             | Type::S16 { .. }
             | Type::S32 { .. }
             | Type::S64 { .. }
+            | Type::S128 { .. }
             | Type::SSize { .. }
             | Type::U8 { .. }
             | Type::U16 { .. }
@@ -61,6 +66,8 @@ impl IndexExtensions for Type {
             | Type::U64 { .. }
             | Type::U128 { .. }
             | Type::USize { .. }
+            | Type::F16 { .. }
+            | Type::BF16 { .. }
             | Type::F32 { .. }
             | Type::F64 { .. }
             | Type::F128 { .. }
@@ -72,6 +79,8 @@ impl IndexExtensions for Type {
             | Type::Void(..)
             | Type::Ptr(None, ..)
             | Type::Fn(..)
+            | Type::ErrorUnion(..)
+            | Type::Interface(..)
             | Type::Unresolved { .. } => self,
         }
     }
@@ -6,6 +6,8 @@ use ahash::AHashMap as HashMap;
 use either::Either;
 
 use super::Type;
+use crate::traits::{TypeErrorUnionExtensions, TypeInterfaceExtensions};
+use crate::type_modificators::StructureTypeModificator;
 
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct Layout {
@@ -57,6 +59,57 @@ impl StructTypeLayout {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StructStorageUnit {
+    Field(u32),
+    Integer(u32),
+    Bytes(u32),
+}
+
+impl StructStorageUnit {
+    #[inline]
+    pub fn get_bit_width(&self) -> Option<u32> {
+        match self {
+            StructStorageUnit::Field(..) => None,
+            StructStorageUnit::Integer(bits) => Some(*bits),
+            StructStorageUnit::Bytes(bytes) => Some(bytes.saturating_mul(8)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StructMemberStorage {
+    Field { unit: u32 },
+    BitField { unit: u32, offset: u32, width: u32 },
+}
+
+impl StructMemberStorage {
+    #[inline]
+    pub fn get_unit(&self) -> u32 {
+        match self {
+            StructMemberStorage::Field { unit } | StructMemberStorage::BitField { unit, .. } => {
+                *unit
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct StructStorageLayout {
+    pub width: u32,
+    pub align: u32,
+    pub units: Vec<StructStorageUnit>,
+    pub members: Vec<StructMemberStorage>,
+    pub field_offsets: Vec<u32>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct BitFieldRun {
+    unit: usize,
+    start: u32,
+    type_width: u32,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct TargetInfo {
     bool_width: u32,
@@ -93,6 +146,8 @@ pub struct TargetInfo {
     ptr_width: u32,
     ptr_align: u32,
 
+    ms_bitfield_layout: bool,
+
     type_cached: HashMap<Type, Either<TypeLayout, StructTypeLayout>>,
 }
 
@@ -253,6 +308,14 @@ impl TargetInfo {
             }
         }
 
+        // MSVC
+        {
+            // llvm-project/clang/lib/AST/RecordLayoutBuilder.cpp
+            if target_triple.get_os() == "windows" && target_triple.get_abi() == "msvc" {
+                self.ms_bitfield_layout = true;
+            }
+        }
+
         // XCORE
         {
             // llvm-project/clang/lib/Basic/Targets/XCore.h
@@ -456,7 +519,7 @@ impl TargetInfo {
                 either::Either::Left(type_info)
             }
 
-            Type::U128 { .. } => {
+            Type::S128 { .. } | Type::U128 { .. } => {
                 type_info.width = self.i128_width();
                 type_info.align = self.i128_align();
                 type_info.alignof = type_info.align / self.i8_width;
@@ -492,6 +555,15 @@ impl TargetInfo {
                 either::Either::Left(type_info)
             }
 
+            Type::F16 { .. } | Type::BF16 { .. } => {
+                type_info.width = self.f16_width();
+                type_info.align = self.f16_align();
+                type_info.alignof = type_info.align / self.i8_width;
+                type_info.sizeof = type_info.width / self.i8_width;
+
+                either::Either::Left(type_info)
+            }
+
             Type::F32 { .. } => {
                 type_info.width = self.f32_width();
                 type_info.align = self.f32_align();
@@ -556,6 +628,27 @@ impl TargetInfo {
                 either::Either::Left(type_info)
             }
 
+            Type::Vector(element_type, lanes, ..) => {
+                let element_width: u32 = if matches!(**element_type, Type::Bool(..)) {
+                    1
+                } else {
+                    match self.get_type_layout(element_type) {
+                        either::Either::Left(left) => left.width,
+                        either::Either::Right(right) => right.width,
+                    }
+                };
+
+                let lanes_width: u32 =
+                    (element_width * lanes).div_ceil(self.i8_width) * self.i8_width;
+
+                type_info.align = lanes_width.next_power_of_two().max(self.i8_width);
+                type_info.width = lanes_width.div_ceil(type_info.align) * type_info.align;
+                type_info.alignof = type_info.align / self.i8_width;
+                type_info.sizeof = type_info.width / self.i8_width;
+
+                either::Either::Left(type_info)
+            }
+
             Type::Array {
                 base_type: element_type,
                 infered_type,
@@ -598,6 +691,73 @@ impl TargetInfo {
                 }
             }
 
+            Type::ErrorUnion(_, span) => self.get_type_layout(&Type::Struct {
+                name: String::new(),
+                fields: r#type.get_error_union_fields(),
+                bitfields: Vec::new(),
+                modifier: StructureTypeModificator::default(),
+                span: *span,
+            }),
+
+            Type::Interface(_, span) => self.get_type_layout(&Type::Struct {
+                name: String::new(),
+                fields: r#type.get_interface_fields(),
+                bitfields: Vec::new(),
+                modifier: StructureTypeModificator::default(),
+                span: *span,
+            }),
+
+            Type::Struct {
+                fields, modifier, ..
+            } if modifier.is_union() => {
+                let packed: bool = modifier.llvm().is_packed();
+
+                let mut max_width_bits: u32 = 0;
+                let mut max_align_bits: u32 = 8;
+
+                for field in fields {
+                    let (f_width, f_align) = match self.get_type_layout(field) {
+                        Either::Left(l) => (l.width, l.align),
+                        Either::Right(r) => (r.width, r.align),
+                    };
+
+                    max_width_bits = max_width_bits.max(f_width);
+
+                    if !packed {
+                        max_align_bits = max_align_bits.max(f_align);
+                    }
+                }
+
+                let total_width_bits: u32 =
+                    max_width_bits.div_ceil(max_align_bits) * max_align_bits;
+
+                either::Either::Right(StructTypeLayout {
+                    width: total_width_bits,
+                    align: max_align_bits,
+                    alignof: max_align_bits / self.i8_width,
+                    sizeof: total_width_bits / self.i8_width,
+                    field_offsets: vec![0; fields.len()],
+                })
+            }
+
+            Type::Struct {
+                fields,
+                bitfields,
+                modifier,
+                ..
+            } if !bitfields.is_empty() => {
+                let storage: StructStorageLayout =
+                    self.get_struct_storage_layout(fields, bitfields, modifier.llvm().is_packed());
+
+                either::Either::Right(StructTypeLayout {
+                    width: storage.width,
+                    align: storage.align,
+                    alignof: storage.align / self.i8_width,
+                    sizeof: storage.width / self.i8_width,
+                    field_offsets: storage.field_offsets,
+                })
+            }
+
             Type::Struct {
                 fields, modifier, ..
             } => {
@@ -650,3 +810,211 @@ impl TargetInfo {
         layout
     }
 }
+
+impl TargetInfo {
+    pub fn get_struct_storage_layout(
+        &mut self,
+        fields: &[Type],
+        bitfields: &[(u32, u8)],
+        packed: bool,
+    ) -> StructStorageLayout {
+        let mut storage: StructStorageLayout = StructStorageLayout {
+            width: 0,
+            align: 8,
+            units: Vec::with_capacity(fields.len()),
+            members: Vec::with_capacity(fields.len()),
+            field_offsets: Vec::with_capacity(fields.len()),
+        };
+
+        let mut current_offset_bits: u32 = 0;
+        let mut units_end_bits: u32 = 0;
+        let mut run: Option<BitFieldRun> = None;
+
+        for (index, field) in fields.iter().enumerate() {
+            let (f_width, f_align) = match self.get_type_layout(field) {
+                Either::Left(l) => (l.width, l.align),
+                Either::Right(r) => (r.width, r.align),
+            };
+
+            let f_align: u32 = f_align.max(8);
+
+            if !packed {
+                storage.align = storage.align.max(f_align);
+            }
+
+            let bit_width: Option<u32> = bitfields
+                .iter()
+                .find(|(field_index, _)| *field_index as usize == index)
+                .map(|(_, width)| u32::from(*width));
+
+            let Some(bit_width) = bit_width else {
+                if let Some(current) = run.take() {
+                    let end: u32 = self.get_bitfield_run_end(current, current_offset_bits, packed);
+                    units_end_bits = self.close_bitfield_run(&mut storage, current, end);
+                }
+
+                let offset: u32 = if packed {
+                    units_end_bits
+                } else {
+                    units_end_bits.div_ceil(f_align) * f_align
+                };
+
+                let unit: u32 = u32::try_from(storage.units.len()).unwrap_or(u32::MAX);
+
+                storage.units.push(StructStorageUnit::Field(
+                    u32::try_from(index).unwrap_or(u32::MAX),
+                ));
+                storage.members.push(StructMemberStorage::Field { unit });
+                storage.field_offsets.push(offset);
+
+                current_offset_bits = offset.saturating_add(f_width);
+                units_end_bits = current_offset_bits;
+
+                continue;
+            };
+
+            let offset: u32 = if self.ms_bitfield_layout && !packed {
+                // Microsoft layout: a bit-field shares the storage unit of the
+                // previous one only when both have the same declared size and
+                // it still fits; otherwise it opens a new unit of its type.
+                match run {
+                    Some(current)
+                        if current.type_width == f_width
+                            && current_offset_bits.saturating_add(bit_width)
+                                <= current.start.saturating_add(f_width) =>
+                    {
+                        current_offset_bits
+                    }
+                    _ => {
+                        if let Some(previous) = run.take() {
+                            let end: u32 =
+                                self.get_bitfield_run_end(previous, current_offset_bits, packed);
+                            units_end_bits = self.close_bitfield_run(&mut storage, previous, end);
+                        }
+
+                        let start: u32 = units_end_bits.div_ceil(f_align) * f_align;
+
+                        run = Some(self::open_bitfield_run(&mut storage, start, f_width));
+
+                        start
+                    }
+                }
+            } else {
+                // System V layout: a bit-field is packed right after the previous
+                // one unless it would straddle a unit aligned to its declared type.
+                let window_start: u32 = (current_offset_bits / f_align) * f_align;
+
+                let offset: u32 = if !packed
+                    && current_offset_bits.saturating_add(bit_width)
+                        > window_start.saturating_add(f_width)
+                {
+                    current_offset_bits.div_ceil(f_align) * f_align
+                } else {
+                    current_offset_bits
+                };
+
+                match run.as_mut() {
+                    Some(current) => current.type_width = current.type_width.max(f_width),
+                    None => {
+                        run = Some(self::open_bitfield_run(
+                            &mut storage,
+                            units_end_bits,
+                            f_width,
+                        ));
+                    }
+                }
+
+                offset
+            };
+
+            let Some(current) = run else {
+                continue;
+            };
+
+            storage.members.push(StructMemberStorage::BitField {
+                unit: u32::try_from(current.unit).unwrap_or(u32::MAX),
+                offset: offset.saturating_sub(current.start),
+                width: bit_width,
+            });
+
+            storage.field_offsets.push(offset);
+
+            current_offset_bits = offset.saturating_add(bit_width);
+        }
+
+        if let Some(current) = run.take() {
+            let end: u32 = self.get_bitfield_run_end(current, current_offset_bits, packed);
+            units_end_bits = self.close_bitfield_run(&mut storage, current, end);
+        }
+
+        storage.width = if packed {
+            units_end_bits
+        } else {
+            units_end_bits.div_ceil(storage.align) * storage.align
+        };
+
+        if storage.width > units_end_bits {
+            storage.units.push(StructStorageUnit::Bytes(
+                (storage.width - units_end_bits) / self.i8_width,
+            ));
+        }
+
+        storage
+    }
+
+    #[inline]
+    fn get_bitfield_run_end(
+        &self,
+        run: BitFieldRun,
+        current_offset_bits: u32,
+        packed: bool,
+    ) -> u32 {
+        if self.ms_bitfield_layout && !packed {
+            run.start.saturating_add(run.type_width)
+        } else {
+            current_offset_bits
+        }
+    }
+
+    fn close_bitfield_run(
+        &self,
+        storage: &mut StructStorageLayout,
+        run: BitFieldRun,
+        end: u32,
+    ) -> u32 {
+        let bytes: u32 = end.saturating_sub(run.start).div_ceil(self.i8_width).max(1);
+        let bits: u32 = bytes * self.i8_width;
+
+        let unit: StructStorageUnit = if bytes.is_power_of_two()
+            && bits <= run.type_width
+            && bits <= self.i64_width
+            && run.start % bits == 0
+        {
+            StructStorageUnit::Integer(bits)
+        } else {
+            StructStorageUnit::Bytes(bytes)
+        };
+
+        if let Some(slot) = storage.units.get_mut(run.unit) {
+            *slot = unit;
+        }
+
+        run.start.saturating_add(bits)
+    }
+}
+
+fn open_bitfield_run(
+    storage: &mut StructStorageLayout,
+    start: u32,
+    type_width: u32,
+) -> BitFieldRun {
+    let unit: usize = storage.units.len();
+
+    storage.units.push(StructStorageUnit::Bytes(0));
+
+    BitFieldRun {
+        unit,
+        start,
+        type_width,
+    }
+}
@@ -29,6 +29,7 @@ impl TypeCodeLocation for Type {
             | Type::S16 { span }
             | Type::S32 { span }
             | Type::S64 { span }
+            | Type::S128 { span }
             | Type::SSize { span }
             | Type::U8 { span }
             | Type::U16 { span }
@@ -36,6 +37,8 @@ impl TypeCodeLocation for Type {
             | Type::U64 { span }
             | Type::U128 { span }
             | Type::USize { span }
+            | Type::F16 { span }
+            | Type::BF16 { span }
             | Type::F32 { span }
             | Type::F64 { span }
             | Type::F128 { span }
@@ -46,7 +49,10 @@ impl TypeCodeLocation for Type {
             | Type::Addr(span)
             | Type::Array { span, .. }
             | Type::FixedArray(_, _, span)
+            | Type::Vector(_, _, span)
             | Type::Const(_, span)
+            | Type::ErrorUnion(_, span)
+            | Type::Interface(_, span)
             | Type::Ptr(_, span)
             | Type::Struct { span, .. }
             | Type::Fn(_, _, _, span) => *span,
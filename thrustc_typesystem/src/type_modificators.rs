@@ -87,12 +87,22 @@ impl GCCFunctionReferenceTypeModificator {
 pub struct StructureTypeModificator {
     llvm: LLVMStructureTypeModificator,
     gcc: GCCStructureTypeModificator,
+    union: bool,
 }
 
 impl StructureTypeModificator {
     #[inline]
-    pub fn new(llvm: LLVMStructureTypeModificator, gcc: GCCStructureTypeModificator) -> Self {
-        Self { llvm, gcc }
+    pub fn new(
+        llvm: LLVMStructureTypeModificator,
+        gcc: GCCStructureTypeModificator,
+        union: bool,
+    ) -> Self {
+        Self { llvm, gcc, union }
+    }
+
+    #[inline]
+    pub fn is_union(&self) -> bool {
+        self.union
     }
 
     #[inline]
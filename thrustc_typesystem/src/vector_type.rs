@@ -0,0 +1,54 @@
+/*
+
+    Copyright (C) 2026  Stevens Benavides
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+*/
+
+use thrustc_span::Span;
+
+use crate::{Type, traits::TypeVectorExtensions};
+
+impl TypeVectorExtensions for Type {
+    #[inline(always)]
+    fn get_vector_base_type(&self) -> Type {
+        match self {
+            Type::Vector(inner, ..) => (**inner).clone(),
+            Type::Const(inner, ..) | Type::Ptr(Some(inner), ..) => inner.get_vector_base_type(),
+
+            _ => self.clone(),
+        }
+    }
+
+    #[inline(always)]
+    fn get_vector_lanes(&self) -> u32 {
+        match self {
+            Type::Vector(_, lanes, ..) => *lanes,
+            Type::Const(inner, ..) | Type::Ptr(Some(inner), ..) => inner.get_vector_lanes(),
+
+            _ => 1,
+        }
+    }
+
+    #[inline]
+    fn get_vector_comparison_type(&self, span: Span) -> Type {
+        match self {
+            Type::Vector(_, lanes, ..) => Type::Vector(Type::Bool(span).into(), *lanes, span),
+            Type::Const(inner, ..) => inner.get_vector_comparison_type(span),
+
+            _ => Type::Bool(span),
+        }
+    }
+}
@@ -56,3 +56,195 @@ pub fn generate_random_string() -> String {
 
     random_string
 }
+
+pub fn get_content_hash(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+
+    for byte in bytes.iter() {
+        hash = (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3);
+    }
+
+    hash
+}
+
+/// Splits a numeric literal into its digits and its type suffix, e.g. `0xffu8` into `0xff` and `u8`.
+pub fn split_literal_suffix(lexeme: &str) -> (&str, &str) {
+    let is_hexadecimal: bool = lexeme.starts_with("0x");
+
+    let digits_start: usize =
+        if is_hexadecimal || lexeme.starts_with("0b") || lexeme.starts_with("0o") {
+            2
+        } else {
+            0
+        };
+
+    let suffix_start: usize = lexeme[digits_start..]
+        .find(|c: char| {
+            if is_hexadecimal {
+                c == 's' || c == 'u'
+            } else {
+                c.is_ascii_alphabetic()
+            }
+        })
+        .map(|position| position + digits_start)
+        .unwrap_or(lexeme.len());
+
+    lexeme.split_at(suffix_start)
+}
+
+pub fn get_edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current: Vec<usize> = vec![0; b.len() + 1];
+
+    for (i, a_char) in a.iter().enumerate() {
+        current[0] = i + 1;
+
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution: usize = previous[j] + usize::from(a_char != b_char);
+            let insertion: usize = current[j] + 1;
+            let deletion: usize = previous[j + 1] + 1;
+
+            current[j + 1] = substitution.min(insertion).min(deletion);
+        }
+
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+pub fn get_similar_names<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Vec<&'a str> {
+    let max_distance: usize = name.chars().count().max(3) / 3;
+
+    let mut similar: Vec<(usize, &'a str)> = candidates
+        .into_iter()
+        .filter(|candidate| *candidate != name && !candidate.is_empty())
+        .filter_map(|candidate| {
+            let distance: usize = if candidate.eq_ignore_ascii_case(name) {
+                0
+            } else {
+                self::get_edit_distance(name, candidate)
+            };
+
+            (distance <= max_distance).then_some((distance, candidate))
+        })
+        .collect();
+
+    similar.sort_unstable();
+    similar.dedup_by(|a, b| a.1 == b.1);
+
+    similar.into_iter().take(3).map(|(_, name)| name).collect()
+}
+
+pub fn did_you_mean<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<String> {
+    let similar: Vec<&str> = self::get_similar_names(name, candidates);
+
+    match similar.as_slice() {
+        [] => None,
+        [single] => Some(format!("Did you mean '{}'?", single)),
+        [rest @ .., last] => Some(format!(
+            "Did you mean one of {} or '{}'?",
+            rest.iter()
+                .map(|name| format!("'{}'", name))
+                .collect::<Vec<String>>()
+                .join(", "),
+            last
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{did_you_mean, get_edit_distance, get_similar_names, split_literal_suffix};
+
+    #[test]
+    fn splits_decimal_literals() {
+        assert_eq!(split_literal_suffix("255u8"), ("255", "u8"));
+        assert_eq!(split_literal_suffix("1_000s64"), ("1_000", "s64"));
+        assert_eq!(split_literal_suffix("1.5f32"), ("1.5", "f32"));
+        assert_eq!(split_literal_suffix("42"), ("42", ""));
+    }
+
+    #[test]
+    fn keeps_hexadecimal_digits_out_of_the_suffix() {
+        assert_eq!(split_literal_suffix("0xbf16"), ("0xbf16", ""));
+        assert_eq!(split_literal_suffix("0xffu8"), ("0xff", "u8"));
+        assert_eq!(split_literal_suffix("0xdeads32"), ("0xdead", "s32"));
+    }
+
+    #[test]
+    fn skips_the_radix_prefix() {
+        assert_eq!(split_literal_suffix("0b1010u8"), ("0b1010", "u8"));
+        assert_eq!(split_literal_suffix("0o777u16"), ("0o777", "u16"));
+    }
+
+    #[test]
+    fn measures_the_edit_distance() {
+        assert_eq!(get_edit_distance("inline", "inline"), 0);
+        assert_eq!(get_edit_distance("inline", "inlin"), 1);
+        assert_eq!(get_edit_distance("inline", "inlime"), 1);
+        assert_eq!(get_edit_distance("public", "pubilc"), 2);
+        assert_eq!(get_edit_distance("", "extern"), 6);
+    }
+
+    #[test]
+    fn only_suggests_names_within_a_third_of_the_length() {
+        assert_eq!(get_similar_names("abc", ["abd", "axy"]), vec!["abd"]);
+        assert_eq!(
+            get_similar_names("pubilc", ["public", "pbl"]),
+            vec!["public"]
+        );
+        assert_eq!(
+            get_similar_names("inline", ["noinline", "noinlined", "extern"]),
+            vec!["noinline"]
+        );
+    }
+
+    #[test]
+    fn ignores_the_case_of_candidates() {
+        assert_eq!(get_similar_names("INLINE", ["inline"]), vec!["inline"]);
+        assert_eq!(
+            get_similar_names("Public", ["publik", "public"]),
+            vec!["public", "publik"]
+        );
+    }
+
+    #[test]
+    fn never_suggests_the_name_itself_or_duplicates() {
+        assert!(get_similar_names("inline", ["inline"]).is_empty());
+        assert_eq!(
+            get_similar_names("inlin", ["inline", "inline", "", "inline"]),
+            vec!["inline"]
+        );
+    }
+
+    #[test]
+    fn keeps_the_three_closest_names() {
+        assert_eq!(
+            get_similar_names("abcdef", ["abcxyz", "abcdeh", "abcdeg", "abcdxf", "abcdez"]),
+            vec!["abcdeg", "abcdeh", "abcdez"]
+        );
+    }
+
+    #[test]
+    fn words_the_suggestion_by_the_number_of_names() {
+        assert_eq!(did_you_mean("inlin", ["extern"]), None);
+        assert_eq!(
+            did_you_mean("inlin", ["inline", "extern"]),
+            Some("Did you mean 'inline'?".into())
+        );
+        assert_eq!(
+            did_you_mean("abcdef", ["abcdeg", "abcdeh", "abcdez"]),
+            Some("Did you mean one of 'abcdeg', 'abcdeh' or 'abcdez'?".into())
+        );
+    }
+}